@@ -72,6 +72,29 @@ pub struct KubernetesMetrics {
     pub pod_uptime_seconds: i64,
     pub owner_references: ::protobuf::RepeatedField<OwnerReference>,
     pub containers: ::protobuf::RepeatedField<Container>,
+    pub resource: ::protobuf::SingularPtrField<ResourceReference>,
+    pub workload: ::protobuf::SingularPtrField<WorkloadStatus>,
+    pub rollout: ::protobuf::SingularPtrField<RolloutStatus>,
+    pub job: ::protobuf::SingularPtrField<JobStatus>,
+    pub cron_job: ::protobuf::SingularPtrField<CronJobStatus>,
+    pub event: ::protobuf::SingularPtrField<KubernetesEvent>,
+    pub persistent_volume_claim: ::protobuf::SingularPtrField<PersistentVolumeClaimStatus>,
+    pub persistent_volume: ::protobuf::SingularPtrField<PersistentVolumeStatus>,
+    pub horizontal_pod_autoscaler: ::protobuf::SingularPtrField<HorizontalPodAutoscalerStatus>,
+    pub resource_quota: ::protobuf::SingularPtrField<ResourceQuotaStatus>,
+    pub limit_range: ::protobuf::SingularPtrField<LimitRangeStatus>,
+    pub service: ::protobuf::SingularPtrField<ServiceStatus>,
+    pub route: ::protobuf::SingularPtrField<RouteStatus>,
+    pub custom_resource: ::protobuf::SingularPtrField<CustomResourceStatus>,
+    pub certificate: ::protobuf::SingularPtrField<CertificateStatus>,
+    pub annotations: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    pub cluster_name: ::std::string::String,
+    pub cluster_id: ::std::string::String,
+    pub shard: ::std::string::String,
+    pub cpu_cfs_periods: i64,
+    pub cpu_cfs_throttled_periods: i64,
+    pub cpu_throttling: i32,
+    pub pressure: ::protobuf::SingularPtrField<PressureStall>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -884,304 +907,8350 @@ impl KubernetesMetrics {
     pub fn take_containers(&mut self) -> ::protobuf::RepeatedField<Container> {
         ::std::mem::replace(&mut self.containers, ::protobuf::RepeatedField::new())
     }
+
+    // .protocol.kubernetes.ResourceReference resource = 51;
+
+
+    pub fn get_resource(&self) -> &ResourceReference {
+        self.resource.as_ref().unwrap_or_else(|| <ResourceReference as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    pub fn has_resource(&self) -> bool {
+        self.resource.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: ResourceReference) {
+        self.resource = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut ResourceReference {
+        if self.resource.is_none() {
+            self.resource.set_default();
+        }
+        self.resource.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> ResourceReference {
+        self.resource.take().unwrap_or_else(|| ResourceReference::new())
+    }
+
+    // .protocol.kubernetes.WorkloadStatus workload = 52;
+
+
+    pub fn get_workload(&self) -> &WorkloadStatus {
+        self.workload.as_ref().unwrap_or_else(|| <WorkloadStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workload(&mut self) {
+        self.workload.clear();
+    }
+
+    pub fn has_workload(&self) -> bool {
+        self.workload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workload(&mut self, v: WorkloadStatus) {
+        self.workload = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workload(&mut self) -> &mut WorkloadStatus {
+        if self.workload.is_none() {
+            self.workload.set_default();
+        }
+        self.workload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workload(&mut self) -> WorkloadStatus {
+        self.workload.take().unwrap_or_else(|| WorkloadStatus::new())
+    }
+
+    // .protocol.kubernetes.RolloutStatus rollout = 53;
+
+
+    pub fn get_rollout(&self) -> &RolloutStatus {
+        self.rollout.as_ref().unwrap_or_else(|| <RolloutStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rollout(&mut self) {
+        self.rollout.clear();
+    }
+
+    pub fn has_rollout(&self) -> bool {
+        self.rollout.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollout(&mut self, v: RolloutStatus) {
+        self.rollout = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rollout(&mut self) -> &mut RolloutStatus {
+        if self.rollout.is_none() {
+            self.rollout.set_default();
+        }
+        self.rollout.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rollout(&mut self) -> RolloutStatus {
+        self.rollout.take().unwrap_or_else(|| RolloutStatus::new())
+    }
+
+    // .protocol.kubernetes.JobStatus job = 54;
+
+
+    pub fn get_job(&self) -> &JobStatus {
+        self.job.as_ref().unwrap_or_else(|| <JobStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_job(&mut self) {
+        self.job.clear();
+    }
+
+    pub fn has_job(&self) -> bool {
+        self.job.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job(&mut self, v: JobStatus) {
+        self.job = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_job(&mut self) -> &mut JobStatus {
+        if self.job.is_none() {
+            self.job.set_default();
+        }
+        self.job.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_job(&mut self) -> JobStatus {
+        self.job.take().unwrap_or_else(|| JobStatus::new())
+    }
+
+    // .protocol.kubernetes.CronJobStatus cron_job = 55;
+
+
+    pub fn get_cron_job(&self) -> &CronJobStatus {
+        self.cron_job.as_ref().unwrap_or_else(|| <CronJobStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_cron_job(&mut self) {
+        self.cron_job.clear();
+    }
+
+    pub fn has_cron_job(&self) -> bool {
+        self.cron_job.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cron_job(&mut self, v: CronJobStatus) {
+        self.cron_job = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cron_job(&mut self) -> &mut CronJobStatus {
+        if self.cron_job.is_none() {
+            self.cron_job.set_default();
+        }
+        self.cron_job.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cron_job(&mut self) -> CronJobStatus {
+        self.cron_job.take().unwrap_or_else(|| CronJobStatus::new())
+    }
+
+    // .protocol.kubernetes.KubernetesEvent event = 56;
+
+
+    pub fn get_event(&self) -> &KubernetesEvent {
+        self.event.as_ref().unwrap_or_else(|| <KubernetesEvent as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_event(&mut self) {
+        self.event.clear();
+    }
+
+    pub fn has_event(&self) -> bool {
+        self.event.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event(&mut self, v: KubernetesEvent) {
+        self.event = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_event(&mut self) -> &mut KubernetesEvent {
+        if self.event.is_none() {
+            self.event.set_default();
+        }
+        self.event.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_event(&mut self) -> KubernetesEvent {
+        self.event.take().unwrap_or_else(|| KubernetesEvent::new())
+    }
+
+    // .protocol.kubernetes.PersistentVolumeClaimStatus persistent_volume_claim = 57;
+
+
+    pub fn get_persistent_volume_claim(&self) -> &PersistentVolumeClaimStatus {
+        self.persistent_volume_claim.as_ref().unwrap_or_else(|| <PersistentVolumeClaimStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_persistent_volume_claim(&mut self) {
+        self.persistent_volume_claim.clear();
+    }
+
+    pub fn has_persistent_volume_claim(&self) -> bool {
+        self.persistent_volume_claim.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_persistent_volume_claim(&mut self, v: PersistentVolumeClaimStatus) {
+        self.persistent_volume_claim = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_persistent_volume_claim(&mut self) -> &mut PersistentVolumeClaimStatus {
+        if self.persistent_volume_claim.is_none() {
+            self.persistent_volume_claim.set_default();
+        }
+        self.persistent_volume_claim.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_persistent_volume_claim(&mut self) -> PersistentVolumeClaimStatus {
+        self.persistent_volume_claim.take().unwrap_or_else(|| PersistentVolumeClaimStatus::new())
+    }
+
+    // .protocol.kubernetes.PersistentVolumeStatus persistent_volume = 58;
+
+
+    pub fn get_persistent_volume(&self) -> &PersistentVolumeStatus {
+        self.persistent_volume.as_ref().unwrap_or_else(|| <PersistentVolumeStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_persistent_volume(&mut self) {
+        self.persistent_volume.clear();
+    }
+
+    pub fn has_persistent_volume(&self) -> bool {
+        self.persistent_volume.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_persistent_volume(&mut self, v: PersistentVolumeStatus) {
+        self.persistent_volume = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_persistent_volume(&mut self) -> &mut PersistentVolumeStatus {
+        if self.persistent_volume.is_none() {
+            self.persistent_volume.set_default();
+        }
+        self.persistent_volume.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_persistent_volume(&mut self) -> PersistentVolumeStatus {
+        self.persistent_volume.take().unwrap_or_else(|| PersistentVolumeStatus::new())
+    }
+
+    // .protocol.kubernetes.HorizontalPodAutoscalerStatus horizontal_pod_autoscaler = 59;
+
+
+    pub fn get_horizontal_pod_autoscaler(&self) -> &HorizontalPodAutoscalerStatus {
+        self.horizontal_pod_autoscaler.as_ref().unwrap_or_else(|| <HorizontalPodAutoscalerStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_horizontal_pod_autoscaler(&mut self) {
+        self.horizontal_pod_autoscaler.clear();
+    }
+
+    pub fn has_horizontal_pod_autoscaler(&self) -> bool {
+        self.horizontal_pod_autoscaler.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_horizontal_pod_autoscaler(&mut self, v: HorizontalPodAutoscalerStatus) {
+        self.horizontal_pod_autoscaler = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_horizontal_pod_autoscaler(&mut self) -> &mut HorizontalPodAutoscalerStatus {
+        if self.horizontal_pod_autoscaler.is_none() {
+            self.horizontal_pod_autoscaler.set_default();
+        }
+        self.horizontal_pod_autoscaler.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_horizontal_pod_autoscaler(&mut self) -> HorizontalPodAutoscalerStatus {
+        self.horizontal_pod_autoscaler.take().unwrap_or_else(|| HorizontalPodAutoscalerStatus::new())
+    }
+
+    // .protocol.kubernetes.ResourceQuotaStatus resource_quota = 60;
+
+
+    pub fn get_resource_quota(&self) -> &ResourceQuotaStatus {
+        self.resource_quota.as_ref().unwrap_or_else(|| <ResourceQuotaStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_resource_quota(&mut self) {
+        self.resource_quota.clear();
+    }
+
+    pub fn has_resource_quota(&self) -> bool {
+        self.resource_quota.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource_quota(&mut self, v: ResourceQuotaStatus) {
+        self.resource_quota = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource_quota(&mut self) -> &mut ResourceQuotaStatus {
+        if self.resource_quota.is_none() {
+            self.resource_quota.set_default();
+        }
+        self.resource_quota.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_resource_quota(&mut self) -> ResourceQuotaStatus {
+        self.resource_quota.take().unwrap_or_else(|| ResourceQuotaStatus::new())
+    }
+
+    // .protocol.kubernetes.LimitRangeStatus limit_range = 61;
+
+
+    pub fn get_limit_range(&self) -> &LimitRangeStatus {
+        self.limit_range.as_ref().unwrap_or_else(|| <LimitRangeStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_limit_range(&mut self) {
+        self.limit_range.clear();
+    }
+
+    pub fn has_limit_range(&self) -> bool {
+        self.limit_range.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit_range(&mut self, v: LimitRangeStatus) {
+        self.limit_range = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_limit_range(&mut self) -> &mut LimitRangeStatus {
+        if self.limit_range.is_none() {
+            self.limit_range.set_default();
+        }
+        self.limit_range.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_limit_range(&mut self) -> LimitRangeStatus {
+        self.limit_range.take().unwrap_or_else(|| LimitRangeStatus::new())
+    }
+
+    // .protocol.kubernetes.ServiceStatus service = 62;
+
+
+    pub fn get_service(&self) -> &ServiceStatus {
+        self.service.as_ref().unwrap_or_else(|| <ServiceStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_service(&mut self) {
+        self.service.clear();
+    }
+
+    pub fn has_service(&self) -> bool {
+        self.service.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ServiceStatus) {
+        self.service = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service(&mut self) -> &mut ServiceStatus {
+        if self.service.is_none() {
+            self.service.set_default();
+        }
+        self.service.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> ServiceStatus {
+        self.service.take().unwrap_or_else(|| ServiceStatus::new())
+    }
+
+    // .protocol.kubernetes.RouteStatus route = 63;
+
+
+    pub fn get_route(&self) -> &RouteStatus {
+        self.route.as_ref().unwrap_or_else(|| <RouteStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_route(&mut self) {
+        self.route.clear();
+    }
+
+    pub fn has_route(&self) -> bool {
+        self.route.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_route(&mut self, v: RouteStatus) {
+        self.route = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_route(&mut self) -> &mut RouteStatus {
+        if self.route.is_none() {
+            self.route.set_default();
+        }
+        self.route.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_route(&mut self) -> RouteStatus {
+        self.route.take().unwrap_or_else(|| RouteStatus::new())
+    }
+
+    // .protocol.kubernetes.CustomResourceStatus custom_resource = 64;
+
+
+    pub fn get_custom_resource(&self) -> &CustomResourceStatus {
+        self.custom_resource.as_ref().unwrap_or_else(|| <CustomResourceStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_custom_resource(&mut self) {
+        self.custom_resource.clear();
+    }
+
+    pub fn has_custom_resource(&self) -> bool {
+        self.custom_resource.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_custom_resource(&mut self, v: CustomResourceStatus) {
+        self.custom_resource = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_custom_resource(&mut self) -> &mut CustomResourceStatus {
+        if self.custom_resource.is_none() {
+            self.custom_resource.set_default();
+        }
+        self.custom_resource.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_custom_resource(&mut self) -> CustomResourceStatus {
+        self.custom_resource.take().unwrap_or_else(|| CustomResourceStatus::new())
+    }
+
+    // .protocol.kubernetes.CertificateStatus certificate = 65;
+
+
+    pub fn get_certificate(&self) -> &CertificateStatus {
+        self.certificate.as_ref().unwrap_or_else(|| <CertificateStatus as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_certificate(&mut self) {
+        self.certificate.clear();
+    }
+
+    pub fn has_certificate(&self) -> bool {
+        self.certificate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_certificate(&mut self, v: CertificateStatus) {
+        self.certificate = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_certificate(&mut self) -> &mut CertificateStatus {
+        if self.certificate.is_none() {
+            self.certificate.set_default();
+        }
+        self.certificate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_certificate(&mut self) -> CertificateStatus {
+        self.certificate.take().unwrap_or_else(|| CertificateStatus::new())
+    }
+
+    // repeated .protocol.kubernetes.KubernetesMetrics.AnnotationsEntry annotations = 66;
+
+
+    pub fn get_annotations(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.annotations
+    }
+    pub fn clear_annotations(&mut self) {
+        self.annotations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_annotations(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.annotations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_annotations(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.annotations
+    }
+
+    // Take field
+    pub fn take_annotations(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.annotations, ::std::collections::HashMap::new())
+    }
+
+    // string cluster_name = 67;
+
+
+    pub fn get_cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+    pub fn clear_cluster_name(&mut self) {
+        self.cluster_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cluster_name(&mut self, v: ::std::string::String) {
+        self.cluster_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cluster_name(&mut self) -> &mut ::std::string::String {
+        &mut self.cluster_name
+    }
+
+    // Take field
+    pub fn take_cluster_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cluster_name, ::std::string::String::new())
+    }
+
+    // string cluster_id = 68;
+
+
+    pub fn get_cluster_id(&self) -> &str {
+        &self.cluster_id
+    }
+    pub fn clear_cluster_id(&mut self) {
+        self.cluster_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cluster_id(&mut self, v: ::std::string::String) {
+        self.cluster_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cluster_id(&mut self) -> &mut ::std::string::String {
+        &mut self.cluster_id
+    }
+
+    // Take field
+    pub fn take_cluster_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cluster_id, ::std::string::String::new())
+    }
+
+    // string shard = 69;
+
+
+    pub fn get_shard(&self) -> &str {
+        &self.shard
+    }
+    pub fn clear_shard(&mut self) {
+        self.shard.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard(&mut self, v: ::std::string::String) {
+        self.shard = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shard(&mut self) -> &mut ::std::string::String {
+        &mut self.shard
+    }
+
+    // Take field
+    pub fn take_shard(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.shard, ::std::string::String::new())
+    }
+
+    // int64 cpu_cfs_periods = 70;
+
+
+    pub fn get_cpu_cfs_periods(&self) -> i64 {
+        self.cpu_cfs_periods
+    }
+    pub fn clear_cpu_cfs_periods(&mut self) {
+        self.cpu_cfs_periods = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_cfs_periods(&mut self, v: i64) {
+        self.cpu_cfs_periods = v;
+    }
+
+    // int64 cpu_cfs_throttled_periods = 71;
+
+
+    pub fn get_cpu_cfs_throttled_periods(&self) -> i64 {
+        self.cpu_cfs_throttled_periods
+    }
+    pub fn clear_cpu_cfs_throttled_periods(&mut self) {
+        self.cpu_cfs_throttled_periods = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_cfs_throttled_periods(&mut self, v: i64) {
+        self.cpu_cfs_throttled_periods = v;
+    }
+
+    // int32 cpu_throttling = 72;
+
+
+    pub fn get_cpu_throttling(&self) -> i32 {
+        self.cpu_throttling
+    }
+    pub fn clear_cpu_throttling(&mut self) {
+        self.cpu_throttling = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_throttling(&mut self, v: i32) {
+        self.cpu_throttling = v;
+    }
+
+    // .protocol.kubernetes.PressureStall pressure = 73;
+
+
+    pub fn get_pressure(&self) -> &PressureStall {
+        self.pressure.as_ref().unwrap_or_else(|| <PressureStall as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_pressure(&mut self) {
+        self.pressure.clear();
+    }
+
+    pub fn has_pressure(&self) -> bool {
+        self.pressure.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pressure(&mut self, v: PressureStall) {
+        self.pressure = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pressure(&mut self) -> &mut PressureStall {
+        if self.pressure.is_none() {
+            self.pressure.set_default();
+        }
+        self.pressure.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_pressure(&mut self) -> PressureStall {
+        self.pressure.take().unwrap_or_else(|| PressureStall::new())
+    }
+}
+
+impl ::protobuf::Message for KubernetesMetrics {
+    fn is_initialized(&self) -> bool {
+        for v in &self.owner_references {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.containers {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.resource {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.workload {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rollout {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.job {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.cron_job {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.persistent_volume_claim {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.persistent_volume {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.horizontal_pod_autoscaler {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.resource_quota {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.limit_range {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.service {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.route {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.custom_resource {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.certificate {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.pressure {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                32 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.site_id)?;
+                },
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pod_name)?;
+                },
+                33 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pod_namespace)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pod_uuid)?;
+                },
+                35 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.volume_name)?;
+                },
+                31 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.timestamp = tmp;
+                },
+                41 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.labels)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cpu_usage_nano_cores = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cpu_usage_core_nano_seconds = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.memory_available_bytes = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.memory_usage_bytes = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.memory_working_set_bytes = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.memory_rss_bytes = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.memory_page_faults = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.memory_major_page_faults = tmp;
+                },
+                38 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.memory_usage = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.network_rx_bytes = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.network_rx_errors = tmp;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.network_tx_bytes = tmp;
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.network_tx_errors = tmp;
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.fs_available_bytes = tmp;
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.fs_capacity_bytes = tmp;
+                },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.fs_used_bytes = tmp;
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.fs_inodes_free = tmp;
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.fs_inodes = tmp;
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.fs_inodes_used = tmp;
+                },
+                37 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.disk_usage = tmp;
+                },
+                21 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.rlimit_maxpid = tmp;
+                },
+                22 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.rlimit_curproc = tmp;
+                },
+                23 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ephemeral_storage_available_bytes = tmp;
+                },
+                24 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ephemeral_storage_capacity_bytes = tmp;
+                },
+                25 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ephemeral_storage_used_bytes = tmp;
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ephemeral_storage_inodes_free = tmp;
+                },
+                27 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ephemeral_storage_inodes = tmp;
+                },
+                28 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ephemeral_storage_inodes_used = tmp;
+                },
+                29 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.process_count = tmp;
+                },
+                30 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.swap_usage_bytes = tmp;
+                },
+                36 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.swap_available_bytes = tmp;
+                },
+                39 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.swap_usage = tmp;
+                },
+                40 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.phase)?;
+                },
+                50 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.pod_phase, 50, &mut self.unknown_fields)?
+                },
+                42 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.pod_restart_count = tmp;
+                },
+                43 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pod_uptime_seconds = tmp;
+                },
+                44 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.owner_references)?;
+                },
+                49 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.containers)?;
+                },
+                51 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.resource)?;
+                },
+                52 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workload)?;
+                },
+                53 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rollout)?;
+                },
+                54 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.job)?;
+                },
+                55 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.cron_job)?;
+                },
+                56 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.event)?;
+                },
+                57 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.persistent_volume_claim)?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.persistent_volume)?;
+                },
+                59 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.horizontal_pod_autoscaler)?;
+                },
+                60 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.resource_quota)?;
+                },
+                61 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.limit_range)?;
+                },
+                62 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.service)?;
+                },
+                63 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.route)?;
+                },
+                64 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.custom_resource)?;
+                },
+                65 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.certificate)?;
+                },
+                66 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.annotations)?;
+                },
+                67 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cluster_name)?;
+                },
+                68 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cluster_id)?;
+                },
+                69 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.shard)?;
+                },
+                70 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cpu_cfs_periods = tmp;
+                },
+                71 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cpu_cfs_throttled_periods = tmp;
+                },
+                72 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.cpu_throttling = tmp;
+                },
+                73 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.pressure)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.site_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(32, &self.site_id);
+        }
+        if !self.node_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.node_name);
+        }
+        if !self.pod_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.pod_name);
+        }
+        if !self.pod_namespace.is_empty() {
+            my_size += ::protobuf::rt::string_size(33, &self.pod_namespace);
+        }
+        if !self.pod_uuid.is_empty() {
+            my_size += ::protobuf::rt::string_size(34, &self.pod_uuid);
+        }
+        if !self.volume_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(35, &self.volume_name);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(31, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(41, &self.labels);
+        if self.cpu_usage_nano_cores != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.cpu_usage_nano_cores, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_usage_core_nano_seconds != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.cpu_usage_core_nano_seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_available_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.memory_available_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_usage_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.memory_usage_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_working_set_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.memory_working_set_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_rss_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.memory_rss_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_page_faults != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.memory_page_faults, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_major_page_faults != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.memory_major_page_faults, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.memory_usage != 0 {
+            my_size += ::protobuf::rt::value_size(38, self.memory_usage, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.network_rx_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.network_rx_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.network_rx_errors != 0 {
+            my_size += ::protobuf::rt::value_size(12, self.network_rx_errors, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.network_tx_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(13, self.network_tx_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.network_tx_errors != 0 {
+            my_size += ::protobuf::rt::value_size(14, self.network_tx_errors, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.fs_available_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(15, self.fs_available_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.fs_capacity_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(16, self.fs_capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.fs_used_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(17, self.fs_used_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.fs_inodes_free != 0 {
+            my_size += ::protobuf::rt::value_size(18, self.fs_inodes_free, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.fs_inodes != 0 {
+            my_size += ::protobuf::rt::value_size(19, self.fs_inodes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.fs_inodes_used != 0 {
+            my_size += ::protobuf::rt::value_size(20, self.fs_inodes_used, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.disk_usage != 0 {
+            my_size += ::protobuf::rt::value_size(37, self.disk_usage, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rlimit_maxpid != 0 {
+            my_size += ::protobuf::rt::value_size(21, self.rlimit_maxpid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rlimit_curproc != 0 {
+            my_size += ::protobuf::rt::value_size(22, self.rlimit_curproc, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ephemeral_storage_available_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(23, self.ephemeral_storage_available_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ephemeral_storage_capacity_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(24, self.ephemeral_storage_capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ephemeral_storage_used_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(25, self.ephemeral_storage_used_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ephemeral_storage_inodes_free != 0 {
+            my_size += ::protobuf::rt::value_size(26, self.ephemeral_storage_inodes_free, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ephemeral_storage_inodes != 0 {
+            my_size += ::protobuf::rt::value_size(27, self.ephemeral_storage_inodes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ephemeral_storage_inodes_used != 0 {
+            my_size += ::protobuf::rt::value_size(28, self.ephemeral_storage_inodes_used, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.process_count != 0 {
+            my_size += ::protobuf::rt::value_size(29, self.process_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.swap_usage_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(30, self.swap_usage_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.swap_available_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(36, self.swap_available_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.swap_usage != 0 {
+            my_size += ::protobuf::rt::value_size(39, self.swap_usage, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.phase.is_empty() {
+            my_size += ::protobuf::rt::string_size(40, &self.phase);
+        }
+        if self.pod_phase != PodPhase::POD_PHASE_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(50, self.pod_phase);
+        }
+        if self.pod_restart_count != 0 {
+            my_size += ::protobuf::rt::value_size(42, self.pod_restart_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pod_uptime_seconds != 0 {
+            my_size += ::protobuf::rt::value_size(43, self.pod_uptime_seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.owner_references {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.containers {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.resource.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.workload.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.rollout.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.job.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.cron_job.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.event.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.persistent_volume_claim.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.persistent_volume.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.horizontal_pod_autoscaler.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.resource_quota.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.limit_range.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.service.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.route.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.custom_resource.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.certificate.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(66, &self.annotations);
+        if !self.cluster_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(67, &self.cluster_name);
+        }
+        if !self.cluster_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(68, &self.cluster_id);
+        }
+        if !self.shard.is_empty() {
+            my_size += ::protobuf::rt::string_size(69, &self.shard);
+        }
+        if self.cpu_cfs_periods != 0 {
+            my_size += ::protobuf::rt::value_size(70, self.cpu_cfs_periods, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_cfs_throttled_periods != 0 {
+            my_size += ::protobuf::rt::value_size(71, self.cpu_cfs_throttled_periods, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_throttling != 0 {
+            my_size += ::protobuf::rt::value_size(72, self.cpu_throttling, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.pressure.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.site_id.is_empty() {
+            os.write_string(32, &self.site_id)?;
+        }
+        if !self.node_name.is_empty() {
+            os.write_string(1, &self.node_name)?;
+        }
+        if !self.pod_name.is_empty() {
+            os.write_string(2, &self.pod_name)?;
+        }
+        if !self.pod_namespace.is_empty() {
+            os.write_string(33, &self.pod_namespace)?;
+        }
+        if !self.pod_uuid.is_empty() {
+            os.write_string(34, &self.pod_uuid)?;
+        }
+        if !self.volume_name.is_empty() {
+            os.write_string(35, &self.volume_name)?;
+        }
+        if self.timestamp != 0 {
+            os.write_int64(31, self.timestamp)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(41, &self.labels, os)?;
+        if self.cpu_usage_nano_cores != 0 {
+            os.write_int64(3, self.cpu_usage_nano_cores)?;
+        }
+        if self.cpu_usage_core_nano_seconds != 0 {
+            os.write_int64(4, self.cpu_usage_core_nano_seconds)?;
+        }
+        if self.memory_available_bytes != 0 {
+            os.write_int64(5, self.memory_available_bytes)?;
+        }
+        if self.memory_usage_bytes != 0 {
+            os.write_int64(6, self.memory_usage_bytes)?;
+        }
+        if self.memory_working_set_bytes != 0 {
+            os.write_int64(7, self.memory_working_set_bytes)?;
+        }
+        if self.memory_rss_bytes != 0 {
+            os.write_int64(8, self.memory_rss_bytes)?;
+        }
+        if self.memory_page_faults != 0 {
+            os.write_int32(9, self.memory_page_faults)?;
+        }
+        if self.memory_major_page_faults != 0 {
+            os.write_int32(10, self.memory_major_page_faults)?;
+        }
+        if self.memory_usage != 0 {
+            os.write_int32(38, self.memory_usage)?;
+        }
+        if self.network_rx_bytes != 0 {
+            os.write_int64(11, self.network_rx_bytes)?;
+        }
+        if self.network_rx_errors != 0 {
+            os.write_int32(12, self.network_rx_errors)?;
+        }
+        if self.network_tx_bytes != 0 {
+            os.write_int64(13, self.network_tx_bytes)?;
+        }
+        if self.network_tx_errors != 0 {
+            os.write_int32(14, self.network_tx_errors)?;
+        }
+        if self.fs_available_bytes != 0 {
+            os.write_int64(15, self.fs_available_bytes)?;
+        }
+        if self.fs_capacity_bytes != 0 {
+            os.write_int64(16, self.fs_capacity_bytes)?;
+        }
+        if self.fs_used_bytes != 0 {
+            os.write_int64(17, self.fs_used_bytes)?;
+        }
+        if self.fs_inodes_free != 0 {
+            os.write_int64(18, self.fs_inodes_free)?;
+        }
+        if self.fs_inodes != 0 {
+            os.write_int64(19, self.fs_inodes)?;
+        }
+        if self.fs_inodes_used != 0 {
+            os.write_int64(20, self.fs_inodes_used)?;
+        }
+        if self.disk_usage != 0 {
+            os.write_int32(37, self.disk_usage)?;
+        }
+        if self.rlimit_maxpid != 0 {
+            os.write_int32(21, self.rlimit_maxpid)?;
+        }
+        if self.rlimit_curproc != 0 {
+            os.write_int32(22, self.rlimit_curproc)?;
+        }
+        if self.ephemeral_storage_available_bytes != 0 {
+            os.write_int64(23, self.ephemeral_storage_available_bytes)?;
+        }
+        if self.ephemeral_storage_capacity_bytes != 0 {
+            os.write_int64(24, self.ephemeral_storage_capacity_bytes)?;
+        }
+        if self.ephemeral_storage_used_bytes != 0 {
+            os.write_int64(25, self.ephemeral_storage_used_bytes)?;
+        }
+        if self.ephemeral_storage_inodes_free != 0 {
+            os.write_int64(26, self.ephemeral_storage_inodes_free)?;
+        }
+        if self.ephemeral_storage_inodes != 0 {
+            os.write_int64(27, self.ephemeral_storage_inodes)?;
+        }
+        if self.ephemeral_storage_inodes_used != 0 {
+            os.write_int64(28, self.ephemeral_storage_inodes_used)?;
+        }
+        if self.process_count != 0 {
+            os.write_int32(29, self.process_count)?;
+        }
+        if self.swap_usage_bytes != 0 {
+            os.write_int64(30, self.swap_usage_bytes)?;
+        }
+        if self.swap_available_bytes != 0 {
+            os.write_int64(36, self.swap_available_bytes)?;
+        }
+        if self.swap_usage != 0 {
+            os.write_int32(39, self.swap_usage)?;
+        }
+        if !self.phase.is_empty() {
+            os.write_string(40, &self.phase)?;
+        }
+        if self.pod_phase != PodPhase::POD_PHASE_UNKNOWN {
+            os.write_enum(50, ::protobuf::ProtobufEnum::value(&self.pod_phase))?;
+        }
+        if self.pod_restart_count != 0 {
+            os.write_int32(42, self.pod_restart_count)?;
+        }
+        if self.pod_uptime_seconds != 0 {
+            os.write_int64(43, self.pod_uptime_seconds)?;
+        }
+        for v in &self.owner_references {
+            os.write_tag(44, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.containers {
+            os.write_tag(49, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.resource.as_ref() {
+            os.write_tag(51, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.workload.as_ref() {
+            os.write_tag(52, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.rollout.as_ref() {
+            os.write_tag(53, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.job.as_ref() {
+            os.write_tag(54, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.cron_job.as_ref() {
+            os.write_tag(55, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.event.as_ref() {
+            os.write_tag(56, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.persistent_volume_claim.as_ref() {
+            os.write_tag(57, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.persistent_volume.as_ref() {
+            os.write_tag(58, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.horizontal_pod_autoscaler.as_ref() {
+            os.write_tag(59, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.resource_quota.as_ref() {
+            os.write_tag(60, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.limit_range.as_ref() {
+            os.write_tag(61, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.service.as_ref() {
+            os.write_tag(62, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.route.as_ref() {
+            os.write_tag(63, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.custom_resource.as_ref() {
+            os.write_tag(64, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.certificate.as_ref() {
+            os.write_tag(65, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(66, &self.annotations, os)?;
+        if !self.cluster_name.is_empty() {
+            os.write_string(67, &self.cluster_name)?;
+        }
+        if !self.cluster_id.is_empty() {
+            os.write_string(68, &self.cluster_id)?;
+        }
+        if !self.shard.is_empty() {
+            os.write_string(69, &self.shard)?;
+        }
+        if self.cpu_cfs_periods != 0 {
+            os.write_int64(70, self.cpu_cfs_periods)?;
+        }
+        if self.cpu_cfs_throttled_periods != 0 {
+            os.write_int64(71, self.cpu_cfs_throttled_periods)?;
+        }
+        if self.cpu_throttling != 0 {
+            os.write_int32(72, self.cpu_throttling)?;
+        }
+        if let Some(ref v) = self.pressure.as_ref() {
+            os.write_tag(73, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KubernetesMetrics {
+        KubernetesMetrics::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "site_id",
+                |m: &KubernetesMetrics| { &m.site_id },
+                |m: &mut KubernetesMetrics| { &mut m.site_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_name",
+                |m: &KubernetesMetrics| { &m.node_name },
+                |m: &mut KubernetesMetrics| { &mut m.node_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "pod_name",
+                |m: &KubernetesMetrics| { &m.pod_name },
+                |m: &mut KubernetesMetrics| { &mut m.pod_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "pod_namespace",
+                |m: &KubernetesMetrics| { &m.pod_namespace },
+                |m: &mut KubernetesMetrics| { &mut m.pod_namespace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "pod_uuid",
+                |m: &KubernetesMetrics| { &m.pod_uuid },
+                |m: &mut KubernetesMetrics| { &mut m.pod_uuid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "volume_name",
+                |m: &KubernetesMetrics| { &m.volume_name },
+                |m: &mut KubernetesMetrics| { &mut m.volume_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "timestamp",
+                |m: &KubernetesMetrics| { &m.timestamp },
+                |m: &mut KubernetesMetrics| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "labels",
+                |m: &KubernetesMetrics| { &m.labels },
+                |m: &mut KubernetesMetrics| { &mut m.labels },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cpu_usage_nano_cores",
+                |m: &KubernetesMetrics| { &m.cpu_usage_nano_cores },
+                |m: &mut KubernetesMetrics| { &mut m.cpu_usage_nano_cores },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cpu_usage_core_nano_seconds",
+                |m: &KubernetesMetrics| { &m.cpu_usage_core_nano_seconds },
+                |m: &mut KubernetesMetrics| { &mut m.cpu_usage_core_nano_seconds },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "memory_available_bytes",
+                |m: &KubernetesMetrics| { &m.memory_available_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.memory_available_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "memory_usage_bytes",
+                |m: &KubernetesMetrics| { &m.memory_usage_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.memory_usage_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "memory_working_set_bytes",
+                |m: &KubernetesMetrics| { &m.memory_working_set_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.memory_working_set_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "memory_rss_bytes",
+                |m: &KubernetesMetrics| { &m.memory_rss_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.memory_rss_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "memory_page_faults",
+                |m: &KubernetesMetrics| { &m.memory_page_faults },
+                |m: &mut KubernetesMetrics| { &mut m.memory_page_faults },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "memory_major_page_faults",
+                |m: &KubernetesMetrics| { &m.memory_major_page_faults },
+                |m: &mut KubernetesMetrics| { &mut m.memory_major_page_faults },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "memory_usage",
+                |m: &KubernetesMetrics| { &m.memory_usage },
+                |m: &mut KubernetesMetrics| { &mut m.memory_usage },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "network_rx_bytes",
+                |m: &KubernetesMetrics| { &m.network_rx_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.network_rx_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "network_rx_errors",
+                |m: &KubernetesMetrics| { &m.network_rx_errors },
+                |m: &mut KubernetesMetrics| { &mut m.network_rx_errors },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "network_tx_bytes",
+                |m: &KubernetesMetrics| { &m.network_tx_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.network_tx_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "network_tx_errors",
+                |m: &KubernetesMetrics| { &m.network_tx_errors },
+                |m: &mut KubernetesMetrics| { &mut m.network_tx_errors },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "fs_available_bytes",
+                |m: &KubernetesMetrics| { &m.fs_available_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.fs_available_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "fs_capacity_bytes",
+                |m: &KubernetesMetrics| { &m.fs_capacity_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.fs_capacity_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "fs_used_bytes",
+                |m: &KubernetesMetrics| { &m.fs_used_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.fs_used_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "fs_inodes_free",
+                |m: &KubernetesMetrics| { &m.fs_inodes_free },
+                |m: &mut KubernetesMetrics| { &mut m.fs_inodes_free },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "fs_inodes",
+                |m: &KubernetesMetrics| { &m.fs_inodes },
+                |m: &mut KubernetesMetrics| { &mut m.fs_inodes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "fs_inodes_used",
+                |m: &KubernetesMetrics| { &m.fs_inodes_used },
+                |m: &mut KubernetesMetrics| { &mut m.fs_inodes_used },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "disk_usage",
+                |m: &KubernetesMetrics| { &m.disk_usage },
+                |m: &mut KubernetesMetrics| { &mut m.disk_usage },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "rlimit_maxpid",
+                |m: &KubernetesMetrics| { &m.rlimit_maxpid },
+                |m: &mut KubernetesMetrics| { &mut m.rlimit_maxpid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "rlimit_curproc",
+                |m: &KubernetesMetrics| { &m.rlimit_curproc },
+                |m: &mut KubernetesMetrics| { &mut m.rlimit_curproc },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "ephemeral_storage_available_bytes",
+                |m: &KubernetesMetrics| { &m.ephemeral_storage_available_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_available_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "ephemeral_storage_capacity_bytes",
+                |m: &KubernetesMetrics| { &m.ephemeral_storage_capacity_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_capacity_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "ephemeral_storage_used_bytes",
+                |m: &KubernetesMetrics| { &m.ephemeral_storage_used_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_used_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "ephemeral_storage_inodes_free",
+                |m: &KubernetesMetrics| { &m.ephemeral_storage_inodes_free },
+                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_inodes_free },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "ephemeral_storage_inodes",
+                |m: &KubernetesMetrics| { &m.ephemeral_storage_inodes },
+                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_inodes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "ephemeral_storage_inodes_used",
+                |m: &KubernetesMetrics| { &m.ephemeral_storage_inodes_used },
+                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_inodes_used },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "process_count",
+                |m: &KubernetesMetrics| { &m.process_count },
+                |m: &mut KubernetesMetrics| { &mut m.process_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "swap_usage_bytes",
+                |m: &KubernetesMetrics| { &m.swap_usage_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.swap_usage_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "swap_available_bytes",
+                |m: &KubernetesMetrics| { &m.swap_available_bytes },
+                |m: &mut KubernetesMetrics| { &mut m.swap_available_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "swap_usage",
+                |m: &KubernetesMetrics| { &m.swap_usage },
+                |m: &mut KubernetesMetrics| { &mut m.swap_usage },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "phase",
+                |m: &KubernetesMetrics| { &m.phase },
+                |m: &mut KubernetesMetrics| { &mut m.phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<PodPhase>>(
+                "pod_phase",
+                |m: &KubernetesMetrics| { &m.pod_phase },
+                |m: &mut KubernetesMetrics| { &mut m.pod_phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "pod_restart_count",
+                |m: &KubernetesMetrics| { &m.pod_restart_count },
+                |m: &mut KubernetesMetrics| { &mut m.pod_restart_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pod_uptime_seconds",
+                |m: &KubernetesMetrics| { &m.pod_uptime_seconds },
+                |m: &mut KubernetesMetrics| { &mut m.pod_uptime_seconds },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OwnerReference>>(
+                "owner_references",
+                |m: &KubernetesMetrics| { &m.owner_references },
+                |m: &mut KubernetesMetrics| { &mut m.owner_references },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Container>>(
+                "containers",
+                |m: &KubernetesMetrics| { &m.containers },
+                |m: &mut KubernetesMetrics| { &mut m.containers },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceReference>>(
+                "resource",
+                |m: &KubernetesMetrics| { &m.resource },
+                |m: &mut KubernetesMetrics| { &mut m.resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkloadStatus>>(
+                "workload",
+                |m: &KubernetesMetrics| { &m.workload },
+                |m: &mut KubernetesMetrics| { &mut m.workload },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RolloutStatus>>(
+                "rollout",
+                |m: &KubernetesMetrics| { &m.rollout },
+                |m: &mut KubernetesMetrics| { &mut m.rollout },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobStatus>>(
+                "job",
+                |m: &KubernetesMetrics| { &m.job },
+                |m: &mut KubernetesMetrics| { &mut m.job },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CronJobStatus>>(
+                "cron_job",
+                |m: &KubernetesMetrics| { &m.cron_job },
+                |m: &mut KubernetesMetrics| { &mut m.cron_job },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KubernetesEvent>>(
+                "event",
+                |m: &KubernetesMetrics| { &m.event },
+                |m: &mut KubernetesMetrics| { &mut m.event },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PersistentVolumeClaimStatus>>(
+                "persistent_volume_claim",
+                |m: &KubernetesMetrics| { &m.persistent_volume_claim },
+                |m: &mut KubernetesMetrics| { &mut m.persistent_volume_claim },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PersistentVolumeStatus>>(
+                "persistent_volume",
+                |m: &KubernetesMetrics| { &m.persistent_volume },
+                |m: &mut KubernetesMetrics| { &mut m.persistent_volume },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HorizontalPodAutoscalerStatus>>(
+                "horizontal_pod_autoscaler",
+                |m: &KubernetesMetrics| { &m.horizontal_pod_autoscaler },
+                |m: &mut KubernetesMetrics| { &mut m.horizontal_pod_autoscaler },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceQuotaStatus>>(
+                "resource_quota",
+                |m: &KubernetesMetrics| { &m.resource_quota },
+                |m: &mut KubernetesMetrics| { &mut m.resource_quota },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LimitRangeStatus>>(
+                "limit_range",
+                |m: &KubernetesMetrics| { &m.limit_range },
+                |m: &mut KubernetesMetrics| { &mut m.limit_range },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceStatus>>(
+                "service",
+                |m: &KubernetesMetrics| { &m.service },
+                |m: &mut KubernetesMetrics| { &mut m.service },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RouteStatus>>(
+                "route",
+                |m: &KubernetesMetrics| { &m.route },
+                |m: &mut KubernetesMetrics| { &mut m.route },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CustomResourceStatus>>(
+                "custom_resource",
+                |m: &KubernetesMetrics| { &m.custom_resource },
+                |m: &mut KubernetesMetrics| { &mut m.custom_resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CertificateStatus>>(
+                "certificate",
+                |m: &KubernetesMetrics| { &m.certificate },
+                |m: &mut KubernetesMetrics| { &mut m.certificate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "annotations",
+                |m: &KubernetesMetrics| { &m.annotations },
+                |m: &mut KubernetesMetrics| { &mut m.annotations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "cluster_name",
+                |m: &KubernetesMetrics| { &m.cluster_name },
+                |m: &mut KubernetesMetrics| { &mut m.cluster_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "cluster_id",
+                |m: &KubernetesMetrics| { &m.cluster_id },
+                |m: &mut KubernetesMetrics| { &mut m.cluster_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "shard",
+                |m: &KubernetesMetrics| { &m.shard },
+                |m: &mut KubernetesMetrics| { &mut m.shard },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cpu_cfs_periods",
+                |m: &KubernetesMetrics| { &m.cpu_cfs_periods },
+                |m: &mut KubernetesMetrics| { &mut m.cpu_cfs_periods },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cpu_cfs_throttled_periods",
+                |m: &KubernetesMetrics| { &m.cpu_cfs_throttled_periods },
+                |m: &mut KubernetesMetrics| { &mut m.cpu_cfs_throttled_periods },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "cpu_throttling",
+                |m: &KubernetesMetrics| { &m.cpu_throttling },
+                |m: &mut KubernetesMetrics| { &mut m.cpu_throttling },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PressureStall>>(
+                "pressure",
+                |m: &KubernetesMetrics| { &m.pressure },
+                |m: &mut KubernetesMetrics| { &mut m.pressure },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KubernetesMetrics>(
+                "KubernetesMetrics",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static KubernetesMetrics {
+        static instance: ::protobuf::rt::LazyV2<KubernetesMetrics> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KubernetesMetrics::new)
+    }
+}
+
+impl ::protobuf::Clear for KubernetesMetrics {
+    fn clear(&mut self) {
+        self.site_id.clear();
+        self.node_name.clear();
+        self.pod_name.clear();
+        self.pod_namespace.clear();
+        self.pod_uuid.clear();
+        self.volume_name.clear();
+        self.timestamp = 0;
+        self.labels.clear();
+        self.cpu_usage_nano_cores = 0;
+        self.cpu_usage_core_nano_seconds = 0;
+        self.memory_available_bytes = 0;
+        self.memory_usage_bytes = 0;
+        self.memory_working_set_bytes = 0;
+        self.memory_rss_bytes = 0;
+        self.memory_page_faults = 0;
+        self.memory_major_page_faults = 0;
+        self.memory_usage = 0;
+        self.network_rx_bytes = 0;
+        self.network_rx_errors = 0;
+        self.network_tx_bytes = 0;
+        self.network_tx_errors = 0;
+        self.fs_available_bytes = 0;
+        self.fs_capacity_bytes = 0;
+        self.fs_used_bytes = 0;
+        self.fs_inodes_free = 0;
+        self.fs_inodes = 0;
+        self.fs_inodes_used = 0;
+        self.disk_usage = 0;
+        self.rlimit_maxpid = 0;
+        self.rlimit_curproc = 0;
+        self.ephemeral_storage_available_bytes = 0;
+        self.ephemeral_storage_capacity_bytes = 0;
+        self.ephemeral_storage_used_bytes = 0;
+        self.ephemeral_storage_inodes_free = 0;
+        self.ephemeral_storage_inodes = 0;
+        self.ephemeral_storage_inodes_used = 0;
+        self.process_count = 0;
+        self.swap_usage_bytes = 0;
+        self.swap_available_bytes = 0;
+        self.swap_usage = 0;
+        self.phase.clear();
+        self.pod_phase = PodPhase::POD_PHASE_UNKNOWN;
+        self.pod_restart_count = 0;
+        self.pod_uptime_seconds = 0;
+        self.owner_references.clear();
+        self.containers.clear();
+        self.resource.clear();
+        self.workload.clear();
+        self.rollout.clear();
+        self.job.clear();
+        self.cron_job.clear();
+        self.event.clear();
+        self.persistent_volume_claim.clear();
+        self.persistent_volume.clear();
+        self.horizontal_pod_autoscaler.clear();
+        self.resource_quota.clear();
+        self.limit_range.clear();
+        self.service.clear();
+        self.route.clear();
+        self.custom_resource.clear();
+        self.certificate.clear();
+        self.annotations.clear();
+        self.cluster_name.clear();
+        self.cluster_id.clear();
+        self.shard.clear();
+        self.cpu_cfs_periods = 0;
+        self.cpu_cfs_throttled_periods = 0;
+        self.cpu_throttling = 0;
+        self.pressure.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KubernetesMetrics {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KubernetesMetrics {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OwnerReference {
+    // message fields
+    pub name: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub namespace: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OwnerReference {
+    fn default() -> &'a OwnerReference {
+        <OwnerReference as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OwnerReference {
+    pub fn new() -> OwnerReference {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string kind = 2;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string namespace = 3;
+
+
+    pub fn get_namespace(&self) -> &str {
+        &self.namespace
+    }
+    pub fn clear_namespace(&mut self) {
+        self.namespace.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_namespace(&mut self, v: ::std::string::String) {
+        self.namespace = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_namespace(&mut self) -> &mut ::std::string::String {
+        &mut self.namespace
+    }
+
+    // Take field
+    pub fn take_namespace(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.namespace, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OwnerReference {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.namespace)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.kind);
+        }
+        if !self.namespace.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.namespace);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(2, &self.kind)?;
+        }
+        if !self.namespace.is_empty() {
+            os.write_string(3, &self.namespace)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OwnerReference {
+        OwnerReference::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &OwnerReference| { &m.name },
+                |m: &mut OwnerReference| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &OwnerReference| { &m.kind },
+                |m: &mut OwnerReference| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "namespace",
+                |m: &OwnerReference| { &m.namespace },
+                |m: &mut OwnerReference| { &mut m.namespace },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OwnerReference>(
+                "OwnerReference",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OwnerReference {
+        static instance: ::protobuf::rt::LazyV2<OwnerReference> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OwnerReference::new)
+    }
+}
+
+impl ::protobuf::Clear for OwnerReference {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.kind.clear();
+        self.namespace.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OwnerReference {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OwnerReference {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Container {
+    // message fields
+    pub name: ::std::string::String,
+    pub status: ContainerStatus,
+    pub reason: ::std::string::String,
+    pub exit_code: i32,
+    pub cpu_cfs_periods: i64,
+    pub cpu_cfs_throttled_periods: i64,
+    pub cpu_throttling: i32,
+    pub pressure: ::protobuf::SingularPtrField<PressureStall>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Container {
+    fn default() -> &'a Container {
+        <Container as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Container {
+    pub fn new() -> Container {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .protocol.kubernetes.ContainerStatus status = 2;
+
+
+    pub fn get_status(&self) -> ContainerStatus {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = ContainerStatus::CONTAINER_STATUS_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: ContainerStatus) {
+        self.status = v;
+    }
+
+    // string reason = 3;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+
+    // int32 exit_code = 4;
+
+
+    pub fn get_exit_code(&self) -> i32 {
+        self.exit_code
+    }
+    pub fn clear_exit_code(&mut self) {
+        self.exit_code = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_code(&mut self, v: i32) {
+        self.exit_code = v;
+    }
+
+    // int64 cpu_cfs_periods = 5;
+
+
+    pub fn get_cpu_cfs_periods(&self) -> i64 {
+        self.cpu_cfs_periods
+    }
+    pub fn clear_cpu_cfs_periods(&mut self) {
+        self.cpu_cfs_periods = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_cfs_periods(&mut self, v: i64) {
+        self.cpu_cfs_periods = v;
+    }
+
+    // int64 cpu_cfs_throttled_periods = 6;
+
+
+    pub fn get_cpu_cfs_throttled_periods(&self) -> i64 {
+        self.cpu_cfs_throttled_periods
+    }
+    pub fn clear_cpu_cfs_throttled_periods(&mut self) {
+        self.cpu_cfs_throttled_periods = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_cfs_throttled_periods(&mut self, v: i64) {
+        self.cpu_cfs_throttled_periods = v;
+    }
+
+    // int32 cpu_throttling = 7;
+
+
+    pub fn get_cpu_throttling(&self) -> i32 {
+        self.cpu_throttling
+    }
+    pub fn clear_cpu_throttling(&mut self) {
+        self.cpu_throttling = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_throttling(&mut self, v: i32) {
+        self.cpu_throttling = v;
+    }
+
+    // .protocol.kubernetes.PressureStall pressure = 8;
+
+
+    pub fn get_pressure(&self) -> &PressureStall {
+        self.pressure.as_ref().unwrap_or_else(|| <PressureStall as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_pressure(&mut self) {
+        self.pressure.clear();
+    }
+
+    pub fn has_pressure(&self) -> bool {
+        self.pressure.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pressure(&mut self, v: PressureStall) {
+        self.pressure = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pressure(&mut self) -> &mut PressureStall {
+        if self.pressure.is_none() {
+            self.pressure.set_default();
+        }
+        self.pressure.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_pressure(&mut self) -> PressureStall {
+        self.pressure.take().unwrap_or_else(|| PressureStall::new())
+    }
+}
+
+impl ::protobuf::Message for Container {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pressure {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.exit_code = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cpu_cfs_periods = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cpu_cfs_throttled_periods = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.cpu_throttling = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.pressure)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.status != ContainerStatus::CONTAINER_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(2, self.status);
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.reason);
+        }
+        if self.exit_code != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.exit_code, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_cfs_periods != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.cpu_cfs_periods, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_cfs_throttled_periods != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.cpu_cfs_throttled_periods, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_throttling != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.cpu_throttling, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.pressure.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.status != ContainerStatus::CONTAINER_STATUS_UNKNOWN {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(3, &self.reason)?;
+        }
+        if self.exit_code != 0 {
+            os.write_int32(4, self.exit_code)?;
+        }
+        if self.cpu_cfs_periods != 0 {
+            os.write_int64(5, self.cpu_cfs_periods)?;
+        }
+        if self.cpu_cfs_throttled_periods != 0 {
+            os.write_int64(6, self.cpu_cfs_throttled_periods)?;
+        }
+        if self.cpu_throttling != 0 {
+            os.write_int32(7, self.cpu_throttling)?;
+        }
+        if let Some(ref v) = self.pressure.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Container {
+        Container::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Container| { &m.name },
+                |m: &mut Container| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ContainerStatus>>(
+                "status",
+                |m: &Container| { &m.status },
+                |m: &mut Container| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &Container| { &m.reason },
+                |m: &mut Container| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "exit_code",
+                |m: &Container| { &m.exit_code },
+                |m: &mut Container| { &mut m.exit_code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cpu_cfs_periods",
+                |m: &Container| { &m.cpu_cfs_periods },
+                |m: &mut Container| { &mut m.cpu_cfs_periods },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cpu_cfs_throttled_periods",
+                |m: &Container| { &m.cpu_cfs_throttled_periods },
+                |m: &mut Container| { &mut m.cpu_cfs_throttled_periods },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "cpu_throttling",
+                |m: &Container| { &m.cpu_throttling },
+                |m: &mut Container| { &mut m.cpu_throttling },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PressureStall>>(
+                "pressure",
+                |m: &Container| { &m.pressure },
+                |m: &mut Container| { &mut m.pressure },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Container>(
+                "Container",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Container {
+        static instance: ::protobuf::rt::LazyV2<Container> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Container::new)
+    }
+}
+
+impl ::protobuf::Clear for Container {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.status = ContainerStatus::CONTAINER_STATUS_UNKNOWN;
+        self.reason.clear();
+        self.exit_code = 0;
+        self.cpu_cfs_periods = 0;
+        self.cpu_cfs_throttled_periods = 0;
+        self.cpu_throttling = 0;
+        self.pressure.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Container {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Container {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PressureStall {
+    // message fields
+    pub cpu_some: f64,
+    pub cpu_full: f64,
+    pub memory_some: f64,
+    pub memory_full: f64,
+    pub io_some: f64,
+    pub io_full: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PressureStall {
+    fn default() -> &'a PressureStall {
+        <PressureStall as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PressureStall {
+    pub fn new() -> PressureStall {
+        ::std::default::Default::default()
+    }
+
+    // double cpu_some = 1;
+
+
+    pub fn get_cpu_some(&self) -> f64 {
+        self.cpu_some
+    }
+    pub fn clear_cpu_some(&mut self) {
+        self.cpu_some = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_some(&mut self, v: f64) {
+        self.cpu_some = v;
+    }
+
+    // double cpu_full = 2;
+
+
+    pub fn get_cpu_full(&self) -> f64 {
+        self.cpu_full
+    }
+    pub fn clear_cpu_full(&mut self) {
+        self.cpu_full = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_full(&mut self, v: f64) {
+        self.cpu_full = v;
+    }
+
+    // double memory_some = 3;
+
+
+    pub fn get_memory_some(&self) -> f64 {
+        self.memory_some
+    }
+    pub fn clear_memory_some(&mut self) {
+        self.memory_some = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_some(&mut self, v: f64) {
+        self.memory_some = v;
+    }
+
+    // double memory_full = 4;
+
+
+    pub fn get_memory_full(&self) -> f64 {
+        self.memory_full
+    }
+    pub fn clear_memory_full(&mut self) {
+        self.memory_full = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_full(&mut self, v: f64) {
+        self.memory_full = v;
+    }
+
+    // double io_some = 5;
+
+
+    pub fn get_io_some(&self) -> f64 {
+        self.io_some
+    }
+    pub fn clear_io_some(&mut self) {
+        self.io_some = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_io_some(&mut self, v: f64) {
+        self.io_some = v;
+    }
+
+    // double io_full = 6;
+
+
+    pub fn get_io_full(&self) -> f64 {
+        self.io_full
+    }
+    pub fn clear_io_full(&mut self) {
+        self.io_full = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_io_full(&mut self, v: f64) {
+        self.io_full = v;
+    }
+}
+
+impl ::protobuf::Message for PressureStall {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.cpu_some = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.cpu_full = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.memory_some = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.memory_full = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.io_some = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.io_full = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cpu_some != 0. {
+            my_size += 9;
+        }
+        if self.cpu_full != 0. {
+            my_size += 9;
+        }
+        if self.memory_some != 0. {
+            my_size += 9;
+        }
+        if self.memory_full != 0. {
+            my_size += 9;
+        }
+        if self.io_some != 0. {
+            my_size += 9;
+        }
+        if self.io_full != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cpu_some != 0. {
+            os.write_double(1, self.cpu_some)?;
+        }
+        if self.cpu_full != 0. {
+            os.write_double(2, self.cpu_full)?;
+        }
+        if self.memory_some != 0. {
+            os.write_double(3, self.memory_some)?;
+        }
+        if self.memory_full != 0. {
+            os.write_double(4, self.memory_full)?;
+        }
+        if self.io_some != 0. {
+            os.write_double(5, self.io_some)?;
+        }
+        if self.io_full != 0. {
+            os.write_double(6, self.io_full)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PressureStall {
+        PressureStall::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "cpu_some",
+                |m: &PressureStall| { &m.cpu_some },
+                |m: &mut PressureStall| { &mut m.cpu_some },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "cpu_full",
+                |m: &PressureStall| { &m.cpu_full },
+                |m: &mut PressureStall| { &mut m.cpu_full },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "memory_some",
+                |m: &PressureStall| { &m.memory_some },
+                |m: &mut PressureStall| { &mut m.memory_some },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "memory_full",
+                |m: &PressureStall| { &m.memory_full },
+                |m: &mut PressureStall| { &mut m.memory_full },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "io_some",
+                |m: &PressureStall| { &m.io_some },
+                |m: &mut PressureStall| { &mut m.io_some },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "io_full",
+                |m: &PressureStall| { &m.io_full },
+                |m: &mut PressureStall| { &mut m.io_full },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PressureStall>(
+                "PressureStall",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PressureStall {
+        static instance: ::protobuf::rt::LazyV2<PressureStall> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PressureStall::new)
+    }
+}
+
+impl ::protobuf::Clear for PressureStall {
+    fn clear(&mut self) {
+        self.cpu_some = 0.;
+        self.cpu_full = 0.;
+        self.memory_some = 0.;
+        self.memory_full = 0.;
+        self.io_some = 0.;
+        self.io_full = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PressureStall {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PressureStall {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceReference {
+    // message fields
+    pub kind: ::std::string::String,
+    pub name: ::std::string::String,
+    pub namespace: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResourceReference {
+    fn default() -> &'a ResourceReference {
+        <ResourceReference as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResourceReference {
+    pub fn new() -> ResourceReference {
+        ::std::default::Default::default()
+    }
+
+    // string kind = 1;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string namespace = 3;
+
+
+    pub fn get_namespace(&self) -> &str {
+        &self.namespace
+    }
+    pub fn clear_namespace(&mut self) {
+        self.namespace.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_namespace(&mut self, v: ::std::string::String) {
+        self.namespace = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_namespace(&mut self) -> &mut ::std::string::String {
+        &mut self.namespace
+    }
+
+    // Take field
+    pub fn take_namespace(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.namespace, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ResourceReference {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.namespace)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.namespace.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.namespace);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.namespace.is_empty() {
+            os.write_string(3, &self.namespace)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResourceReference {
+        ResourceReference::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &ResourceReference| { &m.kind },
+                |m: &mut ResourceReference| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ResourceReference| { &m.name },
+                |m: &mut ResourceReference| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "namespace",
+                |m: &ResourceReference| { &m.namespace },
+                |m: &mut ResourceReference| { &mut m.namespace },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResourceReference>(
+                "ResourceReference",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResourceReference {
+        static instance: ::protobuf::rt::LazyV2<ResourceReference> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResourceReference::new)
+    }
+}
+
+impl ::protobuf::Clear for ResourceReference {
+    fn clear(&mut self) {
+        self.kind.clear();
+        self.name.clear();
+        self.namespace.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceReference {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceReference {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkloadStatus {
+    // message fields
+    pub pods: i32,
+    pub pods_running: i32,
+    pub pods_not_ready: i32,
+    pub restart_count: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkloadStatus {
+    fn default() -> &'a WorkloadStatus {
+        <WorkloadStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkloadStatus {
+    pub fn new() -> WorkloadStatus {
+        ::std::default::Default::default()
+    }
+
+    // int32 pods = 1;
+
+
+    pub fn get_pods(&self) -> i32 {
+        self.pods
+    }
+    pub fn clear_pods(&mut self) {
+        self.pods = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pods(&mut self, v: i32) {
+        self.pods = v;
+    }
+
+    // int32 pods_running = 2;
+
+
+    pub fn get_pods_running(&self) -> i32 {
+        self.pods_running
+    }
+    pub fn clear_pods_running(&mut self) {
+        self.pods_running = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pods_running(&mut self, v: i32) {
+        self.pods_running = v;
+    }
+
+    // int32 pods_not_ready = 3;
+
+
+    pub fn get_pods_not_ready(&self) -> i32 {
+        self.pods_not_ready
+    }
+    pub fn clear_pods_not_ready(&mut self) {
+        self.pods_not_ready = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pods_not_ready(&mut self, v: i32) {
+        self.pods_not_ready = v;
+    }
+
+    // int32 restart_count = 4;
+
+
+    pub fn get_restart_count(&self) -> i32 {
+        self.restart_count
+    }
+    pub fn clear_restart_count(&mut self) {
+        self.restart_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_restart_count(&mut self, v: i32) {
+        self.restart_count = v;
+    }
+}
+
+impl ::protobuf::Message for WorkloadStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.pods = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.pods_running = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.pods_not_ready = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.restart_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pods != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pods, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pods_running != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.pods_running, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pods_not_ready != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.pods_not_ready, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.restart_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.restart_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pods != 0 {
+            os.write_int32(1, self.pods)?;
+        }
+        if self.pods_running != 0 {
+            os.write_int32(2, self.pods_running)?;
+        }
+        if self.pods_not_ready != 0 {
+            os.write_int32(3, self.pods_not_ready)?;
+        }
+        if self.restart_count != 0 {
+            os.write_int32(4, self.restart_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkloadStatus {
+        WorkloadStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "pods",
+                |m: &WorkloadStatus| { &m.pods },
+                |m: &mut WorkloadStatus| { &mut m.pods },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "pods_running",
+                |m: &WorkloadStatus| { &m.pods_running },
+                |m: &mut WorkloadStatus| { &mut m.pods_running },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "pods_not_ready",
+                |m: &WorkloadStatus| { &m.pods_not_ready },
+                |m: &mut WorkloadStatus| { &mut m.pods_not_ready },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "restart_count",
+                |m: &WorkloadStatus| { &m.restart_count },
+                |m: &mut WorkloadStatus| { &mut m.restart_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkloadStatus>(
+                "WorkloadStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkloadStatus {
+        static instance: ::protobuf::rt::LazyV2<WorkloadStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkloadStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkloadStatus {
+    fn clear(&mut self) {
+        self.pods = 0;
+        self.pods_running = 0;
+        self.pods_not_ready = 0;
+        self.restart_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkloadStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkloadStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RolloutStatus {
+    // message fields
+    pub desired_replicas: i32,
+    pub updated_replicas: i32,
+    pub ready_replicas: i32,
+    pub available_replicas: i32,
+    pub unavailable_replicas: i32,
+    pub generation: i64,
+    pub observed_generation: i64,
+    pub progressing: ConditionStatus,
+    pub progressing_reason: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RolloutStatus {
+    fn default() -> &'a RolloutStatus {
+        <RolloutStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RolloutStatus {
+    pub fn new() -> RolloutStatus {
+        ::std::default::Default::default()
+    }
+
+    // int32 desired_replicas = 1;
+
+
+    pub fn get_desired_replicas(&self) -> i32 {
+        self.desired_replicas
+    }
+    pub fn clear_desired_replicas(&mut self) {
+        self.desired_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_desired_replicas(&mut self, v: i32) {
+        self.desired_replicas = v;
+    }
+
+    // int32 updated_replicas = 2;
+
+
+    pub fn get_updated_replicas(&self) -> i32 {
+        self.updated_replicas
+    }
+    pub fn clear_updated_replicas(&mut self) {
+        self.updated_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_replicas(&mut self, v: i32) {
+        self.updated_replicas = v;
+    }
+
+    // int32 ready_replicas = 3;
+
+
+    pub fn get_ready_replicas(&self) -> i32 {
+        self.ready_replicas
+    }
+    pub fn clear_ready_replicas(&mut self) {
+        self.ready_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready_replicas(&mut self, v: i32) {
+        self.ready_replicas = v;
+    }
+
+    // int32 available_replicas = 4;
+
+
+    pub fn get_available_replicas(&self) -> i32 {
+        self.available_replicas
+    }
+    pub fn clear_available_replicas(&mut self) {
+        self.available_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_available_replicas(&mut self, v: i32) {
+        self.available_replicas = v;
+    }
+
+    // int32 unavailable_replicas = 5;
+
+
+    pub fn get_unavailable_replicas(&self) -> i32 {
+        self.unavailable_replicas
+    }
+    pub fn clear_unavailable_replicas(&mut self) {
+        self.unavailable_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unavailable_replicas(&mut self, v: i32) {
+        self.unavailable_replicas = v;
+    }
+
+    // int64 generation = 6;
+
+
+    pub fn get_generation(&self) -> i64 {
+        self.generation
+    }
+    pub fn clear_generation(&mut self) {
+        self.generation = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generation(&mut self, v: i64) {
+        self.generation = v;
+    }
+
+    // int64 observed_generation = 7;
+
+
+    pub fn get_observed_generation(&self) -> i64 {
+        self.observed_generation
+    }
+    pub fn clear_observed_generation(&mut self) {
+        self.observed_generation = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_observed_generation(&mut self, v: i64) {
+        self.observed_generation = v;
+    }
+
+    // .protocol.kubernetes.ConditionStatus progressing = 8;
+
+
+    pub fn get_progressing(&self) -> ConditionStatus {
+        self.progressing
+    }
+    pub fn clear_progressing(&mut self) {
+        self.progressing = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progressing(&mut self, v: ConditionStatus) {
+        self.progressing = v;
+    }
+
+    // string progressing_reason = 9;
+
+
+    pub fn get_progressing_reason(&self) -> &str {
+        &self.progressing_reason
+    }
+    pub fn clear_progressing_reason(&mut self) {
+        self.progressing_reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progressing_reason(&mut self, v: ::std::string::String) {
+        self.progressing_reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_progressing_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.progressing_reason
+    }
+
+    // Take field
+    pub fn take_progressing_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.progressing_reason, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RolloutStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.desired_replicas = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.updated_replicas = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ready_replicas = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.available_replicas = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.unavailable_replicas = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.generation = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.observed_generation = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.progressing, 8, &mut self.unknown_fields)?
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.progressing_reason)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.desired_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.desired_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.updated_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.updated_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ready_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.ready_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.available_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.available_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.unavailable_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.unavailable_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.generation != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.generation, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.observed_generation != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.observed_generation, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.progressing != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(8, self.progressing);
+        }
+        if !self.progressing_reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.progressing_reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.desired_replicas != 0 {
+            os.write_int32(1, self.desired_replicas)?;
+        }
+        if self.updated_replicas != 0 {
+            os.write_int32(2, self.updated_replicas)?;
+        }
+        if self.ready_replicas != 0 {
+            os.write_int32(3, self.ready_replicas)?;
+        }
+        if self.available_replicas != 0 {
+            os.write_int32(4, self.available_replicas)?;
+        }
+        if self.unavailable_replicas != 0 {
+            os.write_int32(5, self.unavailable_replicas)?;
+        }
+        if self.generation != 0 {
+            os.write_int64(6, self.generation)?;
+        }
+        if self.observed_generation != 0 {
+            os.write_int64(7, self.observed_generation)?;
+        }
+        if self.progressing != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.progressing))?;
+        }
+        if !self.progressing_reason.is_empty() {
+            os.write_string(9, &self.progressing_reason)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RolloutStatus {
+        RolloutStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "desired_replicas",
+                |m: &RolloutStatus| { &m.desired_replicas },
+                |m: &mut RolloutStatus| { &mut m.desired_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "updated_replicas",
+                |m: &RolloutStatus| { &m.updated_replicas },
+                |m: &mut RolloutStatus| { &mut m.updated_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ready_replicas",
+                |m: &RolloutStatus| { &m.ready_replicas },
+                |m: &mut RolloutStatus| { &mut m.ready_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "available_replicas",
+                |m: &RolloutStatus| { &m.available_replicas },
+                |m: &mut RolloutStatus| { &mut m.available_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "unavailable_replicas",
+                |m: &RolloutStatus| { &m.unavailable_replicas },
+                |m: &mut RolloutStatus| { &mut m.unavailable_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "generation",
+                |m: &RolloutStatus| { &m.generation },
+                |m: &mut RolloutStatus| { &mut m.generation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "observed_generation",
+                |m: &RolloutStatus| { &m.observed_generation },
+                |m: &mut RolloutStatus| { &mut m.observed_generation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConditionStatus>>(
+                "progressing",
+                |m: &RolloutStatus| { &m.progressing },
+                |m: &mut RolloutStatus| { &mut m.progressing },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "progressing_reason",
+                |m: &RolloutStatus| { &m.progressing_reason },
+                |m: &mut RolloutStatus| { &mut m.progressing_reason },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RolloutStatus>(
+                "RolloutStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RolloutStatus {
+        static instance: ::protobuf::rt::LazyV2<RolloutStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RolloutStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for RolloutStatus {
+    fn clear(&mut self) {
+        self.desired_replicas = 0;
+        self.updated_replicas = 0;
+        self.ready_replicas = 0;
+        self.available_replicas = 0;
+        self.unavailable_replicas = 0;
+        self.generation = 0;
+        self.observed_generation = 0;
+        self.progressing = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+        self.progressing_reason.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RolloutStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RolloutStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobStatus {
+    // message fields
+    pub start_time: i64,
+    pub completion_time: i64,
+    pub duration_seconds: i64,
+    pub active: i32,
+    pub succeeded: i32,
+    pub failed: i32,
+    pub complete: bool,
+    pub backoff_limit_reached: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a JobStatus {
+    fn default() -> &'a JobStatus {
+        <JobStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl JobStatus {
+    pub fn new() -> JobStatus {
+        ::std::default::Default::default()
+    }
+
+    // int64 start_time = 1;
+
+
+    pub fn get_start_time(&self) -> i64 {
+        self.start_time
+    }
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: i64) {
+        self.start_time = v;
+    }
+
+    // int64 completion_time = 2;
+
+
+    pub fn get_completion_time(&self) -> i64 {
+        self.completion_time
+    }
+    pub fn clear_completion_time(&mut self) {
+        self.completion_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_completion_time(&mut self, v: i64) {
+        self.completion_time = v;
+    }
+
+    // int64 duration_seconds = 3;
+
+
+    pub fn get_duration_seconds(&self) -> i64 {
+        self.duration_seconds
+    }
+    pub fn clear_duration_seconds(&mut self) {
+        self.duration_seconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_duration_seconds(&mut self, v: i64) {
+        self.duration_seconds = v;
+    }
+
+    // int32 active = 4;
+
+
+    pub fn get_active(&self) -> i32 {
+        self.active
+    }
+    pub fn clear_active(&mut self) {
+        self.active = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_active(&mut self, v: i32) {
+        self.active = v;
+    }
+
+    // int32 succeeded = 5;
+
+
+    pub fn get_succeeded(&self) -> i32 {
+        self.succeeded
+    }
+    pub fn clear_succeeded(&mut self) {
+        self.succeeded = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_succeeded(&mut self, v: i32) {
+        self.succeeded = v;
+    }
+
+    // int32 failed = 6;
+
+
+    pub fn get_failed(&self) -> i32 {
+        self.failed
+    }
+    pub fn clear_failed(&mut self) {
+        self.failed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failed(&mut self, v: i32) {
+        self.failed = v;
+    }
+
+    // bool complete = 7;
+
+
+    pub fn get_complete(&self) -> bool {
+        self.complete
+    }
+    pub fn clear_complete(&mut self) {
+        self.complete = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_complete(&mut self, v: bool) {
+        self.complete = v;
+    }
+
+    // bool backoff_limit_reached = 8;
+
+
+    pub fn get_backoff_limit_reached(&self) -> bool {
+        self.backoff_limit_reached
+    }
+    pub fn clear_backoff_limit_reached(&mut self) {
+        self.backoff_limit_reached = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backoff_limit_reached(&mut self, v: bool) {
+        self.backoff_limit_reached = v;
+    }
+}
+
+impl ::protobuf::Message for JobStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start_time = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.completion_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.duration_seconds = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.active = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.succeeded = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.failed = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.complete = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.backoff_limit_reached = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.completion_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.completion_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.duration_seconds != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.duration_seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.active != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.active, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.succeeded != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.succeeded, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.failed != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.failed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.complete != false {
+            my_size += 2;
+        }
+        if self.backoff_limit_reached != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start_time != 0 {
+            os.write_int64(1, self.start_time)?;
+        }
+        if self.completion_time != 0 {
+            os.write_int64(2, self.completion_time)?;
+        }
+        if self.duration_seconds != 0 {
+            os.write_int64(3, self.duration_seconds)?;
+        }
+        if self.active != 0 {
+            os.write_int32(4, self.active)?;
+        }
+        if self.succeeded != 0 {
+            os.write_int32(5, self.succeeded)?;
+        }
+        if self.failed != 0 {
+            os.write_int32(6, self.failed)?;
+        }
+        if self.complete != false {
+            os.write_bool(7, self.complete)?;
+        }
+        if self.backoff_limit_reached != false {
+            os.write_bool(8, self.backoff_limit_reached)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JobStatus {
+        JobStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start_time",
+                |m: &JobStatus| { &m.start_time },
+                |m: &mut JobStatus| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "completion_time",
+                |m: &JobStatus| { &m.completion_time },
+                |m: &mut JobStatus| { &mut m.completion_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "duration_seconds",
+                |m: &JobStatus| { &m.duration_seconds },
+                |m: &mut JobStatus| { &mut m.duration_seconds },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "active",
+                |m: &JobStatus| { &m.active },
+                |m: &mut JobStatus| { &mut m.active },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "succeeded",
+                |m: &JobStatus| { &m.succeeded },
+                |m: &mut JobStatus| { &mut m.succeeded },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "failed",
+                |m: &JobStatus| { &m.failed },
+                |m: &mut JobStatus| { &mut m.failed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "complete",
+                |m: &JobStatus| { &m.complete },
+                |m: &mut JobStatus| { &mut m.complete },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "backoff_limit_reached",
+                |m: &JobStatus| { &m.backoff_limit_reached },
+                |m: &mut JobStatus| { &mut m.backoff_limit_reached },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<JobStatus>(
+                "JobStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static JobStatus {
+        static instance: ::protobuf::rt::LazyV2<JobStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(JobStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for JobStatus {
+    fn clear(&mut self) {
+        self.start_time = 0;
+        self.completion_time = 0;
+        self.duration_seconds = 0;
+        self.active = 0;
+        self.succeeded = 0;
+        self.failed = 0;
+        self.complete = false;
+        self.backoff_limit_reached = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CronJobStatus {
+    // message fields
+    pub last_schedule_time: i64,
+    pub last_successful_time: i64,
+    pub active: i32,
+    pub suspended: bool,
+    pub overdue: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CronJobStatus {
+    fn default() -> &'a CronJobStatus {
+        <CronJobStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CronJobStatus {
+    pub fn new() -> CronJobStatus {
+        ::std::default::Default::default()
+    }
+
+    // int64 last_schedule_time = 1;
+
+
+    pub fn get_last_schedule_time(&self) -> i64 {
+        self.last_schedule_time
+    }
+    pub fn clear_last_schedule_time(&mut self) {
+        self.last_schedule_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_schedule_time(&mut self, v: i64) {
+        self.last_schedule_time = v;
+    }
+
+    // int64 last_successful_time = 2;
+
+
+    pub fn get_last_successful_time(&self) -> i64 {
+        self.last_successful_time
+    }
+    pub fn clear_last_successful_time(&mut self) {
+        self.last_successful_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_successful_time(&mut self, v: i64) {
+        self.last_successful_time = v;
+    }
+
+    // int32 active = 3;
+
+
+    pub fn get_active(&self) -> i32 {
+        self.active
+    }
+    pub fn clear_active(&mut self) {
+        self.active = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_active(&mut self, v: i32) {
+        self.active = v;
+    }
+
+    // bool suspended = 4;
+
+
+    pub fn get_suspended(&self) -> bool {
+        self.suspended
+    }
+    pub fn clear_suspended(&mut self) {
+        self.suspended = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suspended(&mut self, v: bool) {
+        self.suspended = v;
+    }
+
+    // bool overdue = 5;
+
+
+    pub fn get_overdue(&self) -> bool {
+        self.overdue
+    }
+    pub fn clear_overdue(&mut self) {
+        self.overdue = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overdue(&mut self, v: bool) {
+        self.overdue = v;
+    }
+}
+
+impl ::protobuf::Message for CronJobStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_schedule_time = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_successful_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.active = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.suspended = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.overdue = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.last_schedule_time != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.last_schedule_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_successful_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.last_successful_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.active != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.active, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.suspended != false {
+            my_size += 2;
+        }
+        if self.overdue != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.last_schedule_time != 0 {
+            os.write_int64(1, self.last_schedule_time)?;
+        }
+        if self.last_successful_time != 0 {
+            os.write_int64(2, self.last_successful_time)?;
+        }
+        if self.active != 0 {
+            os.write_int32(3, self.active)?;
+        }
+        if self.suspended != false {
+            os.write_bool(4, self.suspended)?;
+        }
+        if self.overdue != false {
+            os.write_bool(5, self.overdue)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CronJobStatus {
+        CronJobStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_schedule_time",
+                |m: &CronJobStatus| { &m.last_schedule_time },
+                |m: &mut CronJobStatus| { &mut m.last_schedule_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_successful_time",
+                |m: &CronJobStatus| { &m.last_successful_time },
+                |m: &mut CronJobStatus| { &mut m.last_successful_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "active",
+                |m: &CronJobStatus| { &m.active },
+                |m: &mut CronJobStatus| { &mut m.active },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "suspended",
+                |m: &CronJobStatus| { &m.suspended },
+                |m: &mut CronJobStatus| { &mut m.suspended },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "overdue",
+                |m: &CronJobStatus| { &m.overdue },
+                |m: &mut CronJobStatus| { &mut m.overdue },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CronJobStatus>(
+                "CronJobStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CronJobStatus {
+        static instance: ::protobuf::rt::LazyV2<CronJobStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CronJobStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for CronJobStatus {
+    fn clear(&mut self) {
+        self.last_schedule_time = 0;
+        self.last_successful_time = 0;
+        self.active = 0;
+        self.suspended = false;
+        self.overdue = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CronJobStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CronJobStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KubernetesEvent {
+    // message fields
+    pub field_type: ::std::string::String,
+    pub reason: ::std::string::String,
+    pub note: ::std::string::String,
+    pub action: ::std::string::String,
+    pub reporting_controller: ::std::string::String,
+    pub count: i32,
+    pub first_timestamp: i64,
+    pub last_timestamp: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KubernetesEvent {
+    fn default() -> &'a KubernetesEvent {
+        <KubernetesEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KubernetesEvent {
+    pub fn new() -> KubernetesEvent {
+        ::std::default::Default::default()
+    }
+
+    // string type = 1;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // string reason = 2;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+
+    // string note = 3;
+
+
+    pub fn get_note(&self) -> &str {
+        &self.note
+    }
+    pub fn clear_note(&mut self) {
+        self.note.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_note(&mut self, v: ::std::string::String) {
+        self.note = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_note(&mut self) -> &mut ::std::string::String {
+        &mut self.note
+    }
+
+    // Take field
+    pub fn take_note(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.note, ::std::string::String::new())
+    }
+
+    // string action = 4;
+
+
+    pub fn get_action(&self) -> &str {
+        &self.action
+    }
+    pub fn clear_action(&mut self) {
+        self.action.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_action(&mut self, v: ::std::string::String) {
+        self.action = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_action(&mut self) -> &mut ::std::string::String {
+        &mut self.action
+    }
+
+    // Take field
+    pub fn take_action(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.action, ::std::string::String::new())
+    }
+
+    // string reporting_controller = 5;
+
+
+    pub fn get_reporting_controller(&self) -> &str {
+        &self.reporting_controller
+    }
+    pub fn clear_reporting_controller(&mut self) {
+        self.reporting_controller.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reporting_controller(&mut self, v: ::std::string::String) {
+        self.reporting_controller = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reporting_controller(&mut self) -> &mut ::std::string::String {
+        &mut self.reporting_controller
+    }
+
+    // Take field
+    pub fn take_reporting_controller(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reporting_controller, ::std::string::String::new())
+    }
+
+    // int32 count = 6;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+
+    // int64 first_timestamp = 7;
+
+
+    pub fn get_first_timestamp(&self) -> i64 {
+        self.first_timestamp
+    }
+    pub fn clear_first_timestamp(&mut self) {
+        self.first_timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_first_timestamp(&mut self, v: i64) {
+        self.first_timestamp = v;
+    }
+
+    // int64 last_timestamp = 8;
+
+
+    pub fn get_last_timestamp(&self) -> i64 {
+        self.last_timestamp
+    }
+    pub fn clear_last_timestamp(&mut self) {
+        self.last_timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_timestamp(&mut self, v: i64) {
+        self.last_timestamp = v;
+    }
+}
+
+impl ::protobuf::Message for KubernetesEvent {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.note)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.action)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reporting_controller)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.first_timestamp = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_timestamp = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reason);
+        }
+        if !self.note.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.note);
+        }
+        if !self.action.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.action);
+        }
+        if !self.reporting_controller.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.reporting_controller);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.first_timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.first_timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.last_timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(2, &self.reason)?;
+        }
+        if !self.note.is_empty() {
+            os.write_string(3, &self.note)?;
+        }
+        if !self.action.is_empty() {
+            os.write_string(4, &self.action)?;
+        }
+        if !self.reporting_controller.is_empty() {
+            os.write_string(5, &self.reporting_controller)?;
+        }
+        if self.count != 0 {
+            os.write_int32(6, self.count)?;
+        }
+        if self.first_timestamp != 0 {
+            os.write_int64(7, self.first_timestamp)?;
+        }
+        if self.last_timestamp != 0 {
+            os.write_int64(8, self.last_timestamp)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KubernetesEvent {
+        KubernetesEvent::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type",
+                |m: &KubernetesEvent| { &m.field_type },
+                |m: &mut KubernetesEvent| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &KubernetesEvent| { &m.reason },
+                |m: &mut KubernetesEvent| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "note",
+                |m: &KubernetesEvent| { &m.note },
+                |m: &mut KubernetesEvent| { &mut m.note },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "action",
+                |m: &KubernetesEvent| { &m.action },
+                |m: &mut KubernetesEvent| { &mut m.action },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reporting_controller",
+                |m: &KubernetesEvent| { &m.reporting_controller },
+                |m: &mut KubernetesEvent| { &mut m.reporting_controller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &KubernetesEvent| { &m.count },
+                |m: &mut KubernetesEvent| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "first_timestamp",
+                |m: &KubernetesEvent| { &m.first_timestamp },
+                |m: &mut KubernetesEvent| { &mut m.first_timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_timestamp",
+                |m: &KubernetesEvent| { &m.last_timestamp },
+                |m: &mut KubernetesEvent| { &mut m.last_timestamp },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KubernetesEvent>(
+                "KubernetesEvent",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static KubernetesEvent {
+        static instance: ::protobuf::rt::LazyV2<KubernetesEvent> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KubernetesEvent::new)
+    }
+}
+
+impl ::protobuf::Clear for KubernetesEvent {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.reason.clear();
+        self.note.clear();
+        self.action.clear();
+        self.reporting_controller.clear();
+        self.count = 0;
+        self.first_timestamp = 0;
+        self.last_timestamp = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KubernetesEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KubernetesEvent {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PersistentVolumeClaimStatus {
+    // message fields
+    pub name: ::std::string::String,
+    pub namespace: ::std::string::String,
+    pub storage_class: ::std::string::String,
+    pub phase: PersistentVolumeClaimPhase,
+    pub requested_bytes: i64,
+    pub capacity_bytes: i64,
+    pub access_modes: ::protobuf::RepeatedField<::std::string::String>,
+    pub volume_name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PersistentVolumeClaimStatus {
+    fn default() -> &'a PersistentVolumeClaimStatus {
+        <PersistentVolumeClaimStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PersistentVolumeClaimStatus {
+    pub fn new() -> PersistentVolumeClaimStatus {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string namespace = 2;
+
+
+    pub fn get_namespace(&self) -> &str {
+        &self.namespace
+    }
+    pub fn clear_namespace(&mut self) {
+        self.namespace.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_namespace(&mut self, v: ::std::string::String) {
+        self.namespace = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_namespace(&mut self) -> &mut ::std::string::String {
+        &mut self.namespace
+    }
+
+    // Take field
+    pub fn take_namespace(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.namespace, ::std::string::String::new())
+    }
+
+    // string storage_class = 3;
+
+
+    pub fn get_storage_class(&self) -> &str {
+        &self.storage_class
+    }
+    pub fn clear_storage_class(&mut self) {
+        self.storage_class.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storage_class(&mut self, v: ::std::string::String) {
+        self.storage_class = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_storage_class(&mut self) -> &mut ::std::string::String {
+        &mut self.storage_class
+    }
+
+    // Take field
+    pub fn take_storage_class(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.storage_class, ::std::string::String::new())
+    }
+
+    // .protocol.kubernetes.PersistentVolumeClaimPhase phase = 4;
+
+
+    pub fn get_phase(&self) -> PersistentVolumeClaimPhase {
+        self.phase
+    }
+    pub fn clear_phase(&mut self) {
+        self.phase = PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_phase(&mut self, v: PersistentVolumeClaimPhase) {
+        self.phase = v;
+    }
+
+    // int64 requested_bytes = 5;
+
+
+    pub fn get_requested_bytes(&self) -> i64 {
+        self.requested_bytes
+    }
+    pub fn clear_requested_bytes(&mut self) {
+        self.requested_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requested_bytes(&mut self, v: i64) {
+        self.requested_bytes = v;
+    }
+
+    // int64 capacity_bytes = 6;
+
+
+    pub fn get_capacity_bytes(&self) -> i64 {
+        self.capacity_bytes
+    }
+    pub fn clear_capacity_bytes(&mut self) {
+        self.capacity_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capacity_bytes(&mut self, v: i64) {
+        self.capacity_bytes = v;
+    }
+
+    // repeated string access_modes = 7;
+
+
+    pub fn get_access_modes(&self) -> &[::std::string::String] {
+        &self.access_modes
+    }
+    pub fn clear_access_modes(&mut self) {
+        self.access_modes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_modes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.access_modes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_access_modes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.access_modes
+    }
+
+    // Take field
+    pub fn take_access_modes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.access_modes, ::protobuf::RepeatedField::new())
+    }
+
+    // string volume_name = 8;
+
+
+    pub fn get_volume_name(&self) -> &str {
+        &self.volume_name
+    }
+    pub fn clear_volume_name(&mut self) {
+        self.volume_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_name(&mut self, v: ::std::string::String) {
+        self.volume_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_volume_name(&mut self) -> &mut ::std::string::String {
+        &mut self.volume_name
+    }
+
+    // Take field
+    pub fn take_volume_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.volume_name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PersistentVolumeClaimStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.namespace)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.storage_class)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.phase, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.requested_bytes = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.capacity_bytes = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.access_modes)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.volume_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.namespace.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.namespace);
+        }
+        if !self.storage_class.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.storage_class);
+        }
+        if self.phase != PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(4, self.phase);
+        }
+        if self.requested_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.requested_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.capacity_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.access_modes {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if !self.volume_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.volume_name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.namespace.is_empty() {
+            os.write_string(2, &self.namespace)?;
+        }
+        if !self.storage_class.is_empty() {
+            os.write_string(3, &self.storage_class)?;
+        }
+        if self.phase != PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.phase))?;
+        }
+        if self.requested_bytes != 0 {
+            os.write_int64(5, self.requested_bytes)?;
+        }
+        if self.capacity_bytes != 0 {
+            os.write_int64(6, self.capacity_bytes)?;
+        }
+        for v in &self.access_modes {
+            os.write_string(7, &v)?;
+        };
+        if !self.volume_name.is_empty() {
+            os.write_string(8, &self.volume_name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PersistentVolumeClaimStatus {
+        PersistentVolumeClaimStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &PersistentVolumeClaimStatus| { &m.name },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "namespace",
+                |m: &PersistentVolumeClaimStatus| { &m.namespace },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.namespace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "storage_class",
+                |m: &PersistentVolumeClaimStatus| { &m.storage_class },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.storage_class },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<PersistentVolumeClaimPhase>>(
+                "phase",
+                |m: &PersistentVolumeClaimStatus| { &m.phase },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "requested_bytes",
+                |m: &PersistentVolumeClaimStatus| { &m.requested_bytes },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.requested_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "capacity_bytes",
+                |m: &PersistentVolumeClaimStatus| { &m.capacity_bytes },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.capacity_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "access_modes",
+                |m: &PersistentVolumeClaimStatus| { &m.access_modes },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.access_modes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "volume_name",
+                |m: &PersistentVolumeClaimStatus| { &m.volume_name },
+                |m: &mut PersistentVolumeClaimStatus| { &mut m.volume_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PersistentVolumeClaimStatus>(
+                "PersistentVolumeClaimStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PersistentVolumeClaimStatus {
+        static instance: ::protobuf::rt::LazyV2<PersistentVolumeClaimStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PersistentVolumeClaimStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for PersistentVolumeClaimStatus {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.namespace.clear();
+        self.storage_class.clear();
+        self.phase = PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN;
+        self.requested_bytes = 0;
+        self.capacity_bytes = 0;
+        self.access_modes.clear();
+        self.volume_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PersistentVolumeClaimStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PersistentVolumeClaimStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PersistentVolumeStatus {
+    // message fields
+    pub phase: PersistentVolumePhase,
+    pub capacity_bytes: i64,
+    pub access_modes: ::protobuf::RepeatedField<::std::string::String>,
+    pub reclaim_policy: ::std::string::String,
+    pub storage_class: ::std::string::String,
+    pub claim_name: ::std::string::String,
+    pub claim_namespace: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PersistentVolumeStatus {
+    fn default() -> &'a PersistentVolumeStatus {
+        <PersistentVolumeStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PersistentVolumeStatus {
+    pub fn new() -> PersistentVolumeStatus {
+        ::std::default::Default::default()
+    }
+
+    // .protocol.kubernetes.PersistentVolumePhase phase = 1;
+
+
+    pub fn get_phase(&self) -> PersistentVolumePhase {
+        self.phase
+    }
+    pub fn clear_phase(&mut self) {
+        self.phase = PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_phase(&mut self, v: PersistentVolumePhase) {
+        self.phase = v;
+    }
+
+    // int64 capacity_bytes = 2;
+
+
+    pub fn get_capacity_bytes(&self) -> i64 {
+        self.capacity_bytes
+    }
+    pub fn clear_capacity_bytes(&mut self) {
+        self.capacity_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capacity_bytes(&mut self, v: i64) {
+        self.capacity_bytes = v;
+    }
+
+    // repeated string access_modes = 3;
+
+
+    pub fn get_access_modes(&self) -> &[::std::string::String] {
+        &self.access_modes
+    }
+    pub fn clear_access_modes(&mut self) {
+        self.access_modes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_modes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.access_modes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_access_modes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.access_modes
+    }
+
+    // Take field
+    pub fn take_access_modes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.access_modes, ::protobuf::RepeatedField::new())
+    }
+
+    // string reclaim_policy = 4;
+
+
+    pub fn get_reclaim_policy(&self) -> &str {
+        &self.reclaim_policy
+    }
+    pub fn clear_reclaim_policy(&mut self) {
+        self.reclaim_policy.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reclaim_policy(&mut self, v: ::std::string::String) {
+        self.reclaim_policy = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reclaim_policy(&mut self) -> &mut ::std::string::String {
+        &mut self.reclaim_policy
+    }
+
+    // Take field
+    pub fn take_reclaim_policy(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reclaim_policy, ::std::string::String::new())
+    }
+
+    // string storage_class = 5;
+
+
+    pub fn get_storage_class(&self) -> &str {
+        &self.storage_class
+    }
+    pub fn clear_storage_class(&mut self) {
+        self.storage_class.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storage_class(&mut self, v: ::std::string::String) {
+        self.storage_class = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_storage_class(&mut self) -> &mut ::std::string::String {
+        &mut self.storage_class
+    }
+
+    // Take field
+    pub fn take_storage_class(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.storage_class, ::std::string::String::new())
+    }
+
+    // string claim_name = 6;
+
+
+    pub fn get_claim_name(&self) -> &str {
+        &self.claim_name
+    }
+    pub fn clear_claim_name(&mut self) {
+        self.claim_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_claim_name(&mut self, v: ::std::string::String) {
+        self.claim_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_claim_name(&mut self) -> &mut ::std::string::String {
+        &mut self.claim_name
+    }
+
+    // Take field
+    pub fn take_claim_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.claim_name, ::std::string::String::new())
+    }
+
+    // string claim_namespace = 7;
+
+
+    pub fn get_claim_namespace(&self) -> &str {
+        &self.claim_namespace
+    }
+    pub fn clear_claim_namespace(&mut self) {
+        self.claim_namespace.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_claim_namespace(&mut self, v: ::std::string::String) {
+        self.claim_namespace = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_claim_namespace(&mut self) -> &mut ::std::string::String {
+        &mut self.claim_namespace
+    }
+
+    // Take field
+    pub fn take_claim_namespace(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.claim_namespace, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PersistentVolumeStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.phase, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.capacity_bytes = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.access_modes)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reclaim_policy)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.storage_class)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.claim_name)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.claim_namespace)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.phase != PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(1, self.phase);
+        }
+        if self.capacity_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.access_modes {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if !self.reclaim_policy.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.reclaim_policy);
+        }
+        if !self.storage_class.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.storage_class);
+        }
+        if !self.claim_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.claim_name);
+        }
+        if !self.claim_namespace.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.claim_namespace);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.phase != PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.phase))?;
+        }
+        if self.capacity_bytes != 0 {
+            os.write_int64(2, self.capacity_bytes)?;
+        }
+        for v in &self.access_modes {
+            os.write_string(3, &v)?;
+        };
+        if !self.reclaim_policy.is_empty() {
+            os.write_string(4, &self.reclaim_policy)?;
+        }
+        if !self.storage_class.is_empty() {
+            os.write_string(5, &self.storage_class)?;
+        }
+        if !self.claim_name.is_empty() {
+            os.write_string(6, &self.claim_name)?;
+        }
+        if !self.claim_namespace.is_empty() {
+            os.write_string(7, &self.claim_namespace)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PersistentVolumeStatus {
+        PersistentVolumeStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<PersistentVolumePhase>>(
+                "phase",
+                |m: &PersistentVolumeStatus| { &m.phase },
+                |m: &mut PersistentVolumeStatus| { &mut m.phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "capacity_bytes",
+                |m: &PersistentVolumeStatus| { &m.capacity_bytes },
+                |m: &mut PersistentVolumeStatus| { &mut m.capacity_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "access_modes",
+                |m: &PersistentVolumeStatus| { &m.access_modes },
+                |m: &mut PersistentVolumeStatus| { &mut m.access_modes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reclaim_policy",
+                |m: &PersistentVolumeStatus| { &m.reclaim_policy },
+                |m: &mut PersistentVolumeStatus| { &mut m.reclaim_policy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "storage_class",
+                |m: &PersistentVolumeStatus| { &m.storage_class },
+                |m: &mut PersistentVolumeStatus| { &mut m.storage_class },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "claim_name",
+                |m: &PersistentVolumeStatus| { &m.claim_name },
+                |m: &mut PersistentVolumeStatus| { &mut m.claim_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "claim_namespace",
+                |m: &PersistentVolumeStatus| { &m.claim_namespace },
+                |m: &mut PersistentVolumeStatus| { &mut m.claim_namespace },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PersistentVolumeStatus>(
+                "PersistentVolumeStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PersistentVolumeStatus {
+        static instance: ::protobuf::rt::LazyV2<PersistentVolumeStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PersistentVolumeStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for PersistentVolumeStatus {
+    fn clear(&mut self) {
+        self.phase = PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN;
+        self.capacity_bytes = 0;
+        self.access_modes.clear();
+        self.reclaim_policy.clear();
+        self.storage_class.clear();
+        self.claim_name.clear();
+        self.claim_namespace.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PersistentVolumeStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PersistentVolumeStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HorizontalPodAutoscalerStatus {
+    // message fields
+    pub scale_target: ::protobuf::SingularPtrField<ResourceReference>,
+    pub current_replicas: i32,
+    pub desired_replicas: i32,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub able_to_scale: ConditionStatus,
+    pub scaling_active: ConditionStatus,
+    pub scaling_limited: ConditionStatus,
+    pub scaling_limited_reason: ::std::string::String,
+    pub metrics: ::protobuf::RepeatedField<HorizontalPodAutoscalerMetric>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HorizontalPodAutoscalerStatus {
+    fn default() -> &'a HorizontalPodAutoscalerStatus {
+        <HorizontalPodAutoscalerStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HorizontalPodAutoscalerStatus {
+    pub fn new() -> HorizontalPodAutoscalerStatus {
+        ::std::default::Default::default()
+    }
+
+    // .protocol.kubernetes.ResourceReference scale_target = 1;
+
+
+    pub fn get_scale_target(&self) -> &ResourceReference {
+        self.scale_target.as_ref().unwrap_or_else(|| <ResourceReference as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_scale_target(&mut self) {
+        self.scale_target.clear();
+    }
+
+    pub fn has_scale_target(&self) -> bool {
+        self.scale_target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scale_target(&mut self, v: ResourceReference) {
+        self.scale_target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_scale_target(&mut self) -> &mut ResourceReference {
+        if self.scale_target.is_none() {
+            self.scale_target.set_default();
+        }
+        self.scale_target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_scale_target(&mut self) -> ResourceReference {
+        self.scale_target.take().unwrap_or_else(|| ResourceReference::new())
+    }
+
+    // int32 current_replicas = 2;
+
+
+    pub fn get_current_replicas(&self) -> i32 {
+        self.current_replicas
+    }
+    pub fn clear_current_replicas(&mut self) {
+        self.current_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_replicas(&mut self, v: i32) {
+        self.current_replicas = v;
+    }
+
+    // int32 desired_replicas = 3;
+
+
+    pub fn get_desired_replicas(&self) -> i32 {
+        self.desired_replicas
+    }
+    pub fn clear_desired_replicas(&mut self) {
+        self.desired_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_desired_replicas(&mut self, v: i32) {
+        self.desired_replicas = v;
+    }
+
+    // int32 min_replicas = 4;
+
+
+    pub fn get_min_replicas(&self) -> i32 {
+        self.min_replicas
+    }
+    pub fn clear_min_replicas(&mut self) {
+        self.min_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min_replicas(&mut self, v: i32) {
+        self.min_replicas = v;
+    }
+
+    // int32 max_replicas = 5;
+
+
+    pub fn get_max_replicas(&self) -> i32 {
+        self.max_replicas
+    }
+    pub fn clear_max_replicas(&mut self) {
+        self.max_replicas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_replicas(&mut self, v: i32) {
+        self.max_replicas = v;
+    }
+
+    // .protocol.kubernetes.ConditionStatus able_to_scale = 6;
+
+
+    pub fn get_able_to_scale(&self) -> ConditionStatus {
+        self.able_to_scale
+    }
+    pub fn clear_able_to_scale(&mut self) {
+        self.able_to_scale = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_able_to_scale(&mut self, v: ConditionStatus) {
+        self.able_to_scale = v;
+    }
+
+    // .protocol.kubernetes.ConditionStatus scaling_active = 7;
+
+
+    pub fn get_scaling_active(&self) -> ConditionStatus {
+        self.scaling_active
+    }
+    pub fn clear_scaling_active(&mut self) {
+        self.scaling_active = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scaling_active(&mut self, v: ConditionStatus) {
+        self.scaling_active = v;
+    }
+
+    // .protocol.kubernetes.ConditionStatus scaling_limited = 8;
+
+
+    pub fn get_scaling_limited(&self) -> ConditionStatus {
+        self.scaling_limited
+    }
+    pub fn clear_scaling_limited(&mut self) {
+        self.scaling_limited = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scaling_limited(&mut self, v: ConditionStatus) {
+        self.scaling_limited = v;
+    }
+
+    // string scaling_limited_reason = 9;
+
+
+    pub fn get_scaling_limited_reason(&self) -> &str {
+        &self.scaling_limited_reason
+    }
+    pub fn clear_scaling_limited_reason(&mut self) {
+        self.scaling_limited_reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scaling_limited_reason(&mut self, v: ::std::string::String) {
+        self.scaling_limited_reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_scaling_limited_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.scaling_limited_reason
+    }
+
+    // Take field
+    pub fn take_scaling_limited_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.scaling_limited_reason, ::std::string::String::new())
+    }
+
+    // repeated .protocol.kubernetes.HorizontalPodAutoscalerMetric metrics = 10;
+
+
+    pub fn get_metrics(&self) -> &[HorizontalPodAutoscalerMetric] {
+        &self.metrics
+    }
+    pub fn clear_metrics(&mut self) {
+        self.metrics.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metrics(&mut self, v: ::protobuf::RepeatedField<HorizontalPodAutoscalerMetric>) {
+        self.metrics = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_metrics(&mut self) -> &mut ::protobuf::RepeatedField<HorizontalPodAutoscalerMetric> {
+        &mut self.metrics
+    }
+
+    // Take field
+    pub fn take_metrics(&mut self) -> ::protobuf::RepeatedField<HorizontalPodAutoscalerMetric> {
+        ::std::mem::replace(&mut self.metrics, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for HorizontalPodAutoscalerStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.scale_target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.metrics {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.scale_target)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.current_replicas = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.desired_replicas = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.min_replicas = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.max_replicas = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.able_to_scale, 6, &mut self.unknown_fields)?
+                },
+                7 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.scaling_active, 7, &mut self.unknown_fields)?
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.scaling_limited, 8, &mut self.unknown_fields)?
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.scaling_limited_reason)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.metrics)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.scale_target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.current_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.current_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.desired_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.desired_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.min_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.min_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_replicas != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.max_replicas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.able_to_scale != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(6, self.able_to_scale);
+        }
+        if self.scaling_active != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(7, self.scaling_active);
+        }
+        if self.scaling_limited != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(8, self.scaling_limited);
+        }
+        if !self.scaling_limited_reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.scaling_limited_reason);
+        }
+        for value in &self.metrics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.scale_target.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.current_replicas != 0 {
+            os.write_int32(2, self.current_replicas)?;
+        }
+        if self.desired_replicas != 0 {
+            os.write_int32(3, self.desired_replicas)?;
+        }
+        if self.min_replicas != 0 {
+            os.write_int32(4, self.min_replicas)?;
+        }
+        if self.max_replicas != 0 {
+            os.write_int32(5, self.max_replicas)?;
+        }
+        if self.able_to_scale != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            os.write_enum(6, ::protobuf::ProtobufEnum::value(&self.able_to_scale))?;
+        }
+        if self.scaling_active != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            os.write_enum(7, ::protobuf::ProtobufEnum::value(&self.scaling_active))?;
+        }
+        if self.scaling_limited != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.scaling_limited))?;
+        }
+        if !self.scaling_limited_reason.is_empty() {
+            os.write_string(9, &self.scaling_limited_reason)?;
+        }
+        for v in &self.metrics {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HorizontalPodAutoscalerStatus {
+        HorizontalPodAutoscalerStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceReference>>(
+                "scale_target",
+                |m: &HorizontalPodAutoscalerStatus| { &m.scale_target },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.scale_target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "current_replicas",
+                |m: &HorizontalPodAutoscalerStatus| { &m.current_replicas },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.current_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "desired_replicas",
+                |m: &HorizontalPodAutoscalerStatus| { &m.desired_replicas },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.desired_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "min_replicas",
+                |m: &HorizontalPodAutoscalerStatus| { &m.min_replicas },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.min_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "max_replicas",
+                |m: &HorizontalPodAutoscalerStatus| { &m.max_replicas },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.max_replicas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConditionStatus>>(
+                "able_to_scale",
+                |m: &HorizontalPodAutoscalerStatus| { &m.able_to_scale },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.able_to_scale },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConditionStatus>>(
+                "scaling_active",
+                |m: &HorizontalPodAutoscalerStatus| { &m.scaling_active },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.scaling_active },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConditionStatus>>(
+                "scaling_limited",
+                |m: &HorizontalPodAutoscalerStatus| { &m.scaling_limited },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.scaling_limited },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "scaling_limited_reason",
+                |m: &HorizontalPodAutoscalerStatus| { &m.scaling_limited_reason },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.scaling_limited_reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HorizontalPodAutoscalerMetric>>(
+                "metrics",
+                |m: &HorizontalPodAutoscalerStatus| { &m.metrics },
+                |m: &mut HorizontalPodAutoscalerStatus| { &mut m.metrics },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HorizontalPodAutoscalerStatus>(
+                "HorizontalPodAutoscalerStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HorizontalPodAutoscalerStatus {
+        static instance: ::protobuf::rt::LazyV2<HorizontalPodAutoscalerStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HorizontalPodAutoscalerStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for HorizontalPodAutoscalerStatus {
+    fn clear(&mut self) {
+        self.scale_target.clear();
+        self.current_replicas = 0;
+        self.desired_replicas = 0;
+        self.min_replicas = 0;
+        self.max_replicas = 0;
+        self.able_to_scale = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+        self.scaling_active = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+        self.scaling_limited = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+        self.scaling_limited_reason.clear();
+        self.metrics.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HorizontalPodAutoscalerStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HorizontalPodAutoscalerStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HorizontalPodAutoscalerMetric {
+    // message fields
+    pub field_type: ::std::string::String,
+    pub name: ::std::string::String,
+    pub container: ::std::string::String,
+    pub target_type: ::std::string::String,
+    pub target_value: f64,
+    pub current_value: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HorizontalPodAutoscalerMetric {
+    fn default() -> &'a HorizontalPodAutoscalerMetric {
+        <HorizontalPodAutoscalerMetric as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HorizontalPodAutoscalerMetric {
+    pub fn new() -> HorizontalPodAutoscalerMetric {
+        ::std::default::Default::default()
+    }
+
+    // string type = 1;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string container = 3;
+
+
+    pub fn get_container(&self) -> &str {
+        &self.container
+    }
+    pub fn clear_container(&mut self) {
+        self.container.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_container(&mut self, v: ::std::string::String) {
+        self.container = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_container(&mut self) -> &mut ::std::string::String {
+        &mut self.container
+    }
+
+    // Take field
+    pub fn take_container(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container, ::std::string::String::new())
+    }
+
+    // string target_type = 4;
+
+
+    pub fn get_target_type(&self) -> &str {
+        &self.target_type
+    }
+    pub fn clear_target_type(&mut self) {
+        self.target_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_type(&mut self, v: ::std::string::String) {
+        self.target_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_type(&mut self) -> &mut ::std::string::String {
+        &mut self.target_type
+    }
+
+    // Take field
+    pub fn take_target_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_type, ::std::string::String::new())
+    }
+
+    // double target_value = 5;
+
+
+    pub fn get_target_value(&self) -> f64 {
+        self.target_value
+    }
+    pub fn clear_target_value(&mut self) {
+        self.target_value = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_value(&mut self, v: f64) {
+        self.target_value = v;
+    }
+
+    // double current_value = 6;
+
+
+    pub fn get_current_value(&self) -> f64 {
+        self.current_value
+    }
+    pub fn clear_current_value(&mut self) {
+        self.current_value = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_value(&mut self, v: f64) {
+        self.current_value = v;
+    }
+}
+
+impl ::protobuf::Message for HorizontalPodAutoscalerMetric {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_type)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.target_value = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.current_value = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.container.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.container);
+        }
+        if !self.target_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.target_type);
+        }
+        if self.target_value != 0. {
+            my_size += 9;
+        }
+        if self.current_value != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.container.is_empty() {
+            os.write_string(3, &self.container)?;
+        }
+        if !self.target_type.is_empty() {
+            os.write_string(4, &self.target_type)?;
+        }
+        if self.target_value != 0. {
+            os.write_double(5, self.target_value)?;
+        }
+        if self.current_value != 0. {
+            os.write_double(6, self.current_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HorizontalPodAutoscalerMetric {
+        HorizontalPodAutoscalerMetric::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type",
+                |m: &HorizontalPodAutoscalerMetric| { &m.field_type },
+                |m: &mut HorizontalPodAutoscalerMetric| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &HorizontalPodAutoscalerMetric| { &m.name },
+                |m: &mut HorizontalPodAutoscalerMetric| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "container",
+                |m: &HorizontalPodAutoscalerMetric| { &m.container },
+                |m: &mut HorizontalPodAutoscalerMetric| { &mut m.container },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_type",
+                |m: &HorizontalPodAutoscalerMetric| { &m.target_type },
+                |m: &mut HorizontalPodAutoscalerMetric| { &mut m.target_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "target_value",
+                |m: &HorizontalPodAutoscalerMetric| { &m.target_value },
+                |m: &mut HorizontalPodAutoscalerMetric| { &mut m.target_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "current_value",
+                |m: &HorizontalPodAutoscalerMetric| { &m.current_value },
+                |m: &mut HorizontalPodAutoscalerMetric| { &mut m.current_value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HorizontalPodAutoscalerMetric>(
+                "HorizontalPodAutoscalerMetric",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HorizontalPodAutoscalerMetric {
+        static instance: ::protobuf::rt::LazyV2<HorizontalPodAutoscalerMetric> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HorizontalPodAutoscalerMetric::new)
+    }
+}
+
+impl ::protobuf::Clear for HorizontalPodAutoscalerMetric {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.name.clear();
+        self.container.clear();
+        self.target_type.clear();
+        self.target_value = 0.;
+        self.current_value = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HorizontalPodAutoscalerMetric {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HorizontalPodAutoscalerMetric {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceQuotaStatus {
+    // message fields
+    pub resources: ::protobuf::RepeatedField<ResourceQuotaResource>,
+    pub max_usage: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResourceQuotaStatus {
+    fn default() -> &'a ResourceQuotaStatus {
+        <ResourceQuotaStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResourceQuotaStatus {
+    pub fn new() -> ResourceQuotaStatus {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protocol.kubernetes.ResourceQuotaResource resources = 1;
+
+
+    pub fn get_resources(&self) -> &[ResourceQuotaResource] {
+        &self.resources
+    }
+    pub fn clear_resources(&mut self) {
+        self.resources.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resources(&mut self, v: ::protobuf::RepeatedField<ResourceQuotaResource>) {
+        self.resources = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_resources(&mut self) -> &mut ::protobuf::RepeatedField<ResourceQuotaResource> {
+        &mut self.resources
+    }
+
+    // Take field
+    pub fn take_resources(&mut self) -> ::protobuf::RepeatedField<ResourceQuotaResource> {
+        ::std::mem::replace(&mut self.resources, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 max_usage = 2;
+
+
+    pub fn get_max_usage(&self) -> i32 {
+        self.max_usage
+    }
+    pub fn clear_max_usage(&mut self) {
+        self.max_usage = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_usage(&mut self, v: i32) {
+        self.max_usage = v;
+    }
+}
+
+impl ::protobuf::Message for ResourceQuotaStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.resources {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.resources)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.max_usage = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.resources {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.max_usage != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.max_usage, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.resources {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.max_usage != 0 {
+            os.write_int32(2, self.max_usage)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResourceQuotaStatus {
+        ResourceQuotaStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceQuotaResource>>(
+                "resources",
+                |m: &ResourceQuotaStatus| { &m.resources },
+                |m: &mut ResourceQuotaStatus| { &mut m.resources },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "max_usage",
+                |m: &ResourceQuotaStatus| { &m.max_usage },
+                |m: &mut ResourceQuotaStatus| { &mut m.max_usage },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResourceQuotaStatus>(
+                "ResourceQuotaStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResourceQuotaStatus {
+        static instance: ::protobuf::rt::LazyV2<ResourceQuotaStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResourceQuotaStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for ResourceQuotaStatus {
+    fn clear(&mut self) {
+        self.resources.clear();
+        self.max_usage = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceQuotaStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceQuotaStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceQuotaResource {
+    // message fields
+    pub name: ::std::string::String,
+    pub hard: f64,
+    pub used: f64,
+    pub usage: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResourceQuotaResource {
+    fn default() -> &'a ResourceQuotaResource {
+        <ResourceQuotaResource as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResourceQuotaResource {
+    pub fn new() -> ResourceQuotaResource {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // double hard = 2;
+
+
+    pub fn get_hard(&self) -> f64 {
+        self.hard
+    }
+    pub fn clear_hard(&mut self) {
+        self.hard = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hard(&mut self, v: f64) {
+        self.hard = v;
+    }
+
+    // double used = 3;
+
+
+    pub fn get_used(&self) -> f64 {
+        self.used
+    }
+    pub fn clear_used(&mut self) {
+        self.used = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_used(&mut self, v: f64) {
+        self.used = v;
+    }
+
+    // int32 usage = 4;
+
+
+    pub fn get_usage(&self) -> i32 {
+        self.usage
+    }
+    pub fn clear_usage(&mut self) {
+        self.usage = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_usage(&mut self, v: i32) {
+        self.usage = v;
+    }
+}
+
+impl ::protobuf::Message for ResourceQuotaResource {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.hard = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.used = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.usage = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.hard != 0. {
+            my_size += 9;
+        }
+        if self.used != 0. {
+            my_size += 9;
+        }
+        if self.usage != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.usage, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.hard != 0. {
+            os.write_double(2, self.hard)?;
+        }
+        if self.used != 0. {
+            os.write_double(3, self.used)?;
+        }
+        if self.usage != 0 {
+            os.write_int32(4, self.usage)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResourceQuotaResource {
+        ResourceQuotaResource::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ResourceQuotaResource| { &m.name },
+                |m: &mut ResourceQuotaResource| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "hard",
+                |m: &ResourceQuotaResource| { &m.hard },
+                |m: &mut ResourceQuotaResource| { &mut m.hard },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "used",
+                |m: &ResourceQuotaResource| { &m.used },
+                |m: &mut ResourceQuotaResource| { &mut m.used },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "usage",
+                |m: &ResourceQuotaResource| { &m.usage },
+                |m: &mut ResourceQuotaResource| { &mut m.usage },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResourceQuotaResource>(
+                "ResourceQuotaResource",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResourceQuotaResource {
+        static instance: ::protobuf::rt::LazyV2<ResourceQuotaResource> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResourceQuotaResource::new)
+    }
+}
+
+impl ::protobuf::Clear for ResourceQuotaResource {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.hard = 0.;
+        self.used = 0.;
+        self.usage = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceQuotaResource {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceQuotaResource {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LimitRangeStatus {
+    // message fields
+    pub limits: ::protobuf::RepeatedField<LimitRangeLimit>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LimitRangeStatus {
+    fn default() -> &'a LimitRangeStatus {
+        <LimitRangeStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LimitRangeStatus {
+    pub fn new() -> LimitRangeStatus {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protocol.kubernetes.LimitRangeLimit limits = 1;
+
+
+    pub fn get_limits(&self) -> &[LimitRangeLimit] {
+        &self.limits
+    }
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limits(&mut self, v: ::protobuf::RepeatedField<LimitRangeLimit>) {
+        self.limits = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_limits(&mut self) -> &mut ::protobuf::RepeatedField<LimitRangeLimit> {
+        &mut self.limits
+    }
+
+    // Take field
+    pub fn take_limits(&mut self) -> ::protobuf::RepeatedField<LimitRangeLimit> {
+        ::std::mem::replace(&mut self.limits, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for LimitRangeStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.limits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.limits)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.limits {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.limits {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LimitRangeStatus {
+        LimitRangeStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LimitRangeLimit>>(
+                "limits",
+                |m: &LimitRangeStatus| { &m.limits },
+                |m: &mut LimitRangeStatus| { &mut m.limits },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LimitRangeStatus>(
+                "LimitRangeStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LimitRangeStatus {
+        static instance: ::protobuf::rt::LazyV2<LimitRangeStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LimitRangeStatus::new)
+    }
 }
 
-impl ::protobuf::Message for KubernetesMetrics {
-    fn is_initialized(&self) -> bool {
-        for v in &self.owner_references {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.containers {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
+impl ::protobuf::Clear for LimitRangeStatus {
+    fn clear(&mut self) {
+        self.limits.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                32 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.site_id)?;
-                },
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_name)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pod_name)?;
-                },
-                33 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pod_namespace)?;
-                },
-                34 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pod_uuid)?;
-                },
-                35 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.volume_name)?;
-                },
-                31 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.timestamp = tmp;
-                },
-                41 => {
-                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.labels)?;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.cpu_usage_nano_cores = tmp;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.cpu_usage_core_nano_seconds = tmp;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.memory_available_bytes = tmp;
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.memory_usage_bytes = tmp;
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.memory_working_set_bytes = tmp;
-                },
-                8 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.memory_rss_bytes = tmp;
-                },
-                9 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.memory_page_faults = tmp;
-                },
-                10 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.memory_major_page_faults = tmp;
-                },
-                38 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.memory_usage = tmp;
-                },
-                11 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.network_rx_bytes = tmp;
-                },
-                12 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.network_rx_errors = tmp;
-                },
-                13 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.network_tx_bytes = tmp;
-                },
-                14 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.network_tx_errors = tmp;
-                },
-                15 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.fs_available_bytes = tmp;
-                },
-                16 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.fs_capacity_bytes = tmp;
-                },
-                17 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.fs_used_bytes = tmp;
-                },
-                18 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.fs_inodes_free = tmp;
-                },
-                19 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.fs_inodes = tmp;
+impl ::std::fmt::Debug for LimitRangeStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LimitRangeStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LimitRangeLimit {
+    // message fields
+    pub field_type: ::std::string::String,
+    pub resource: ::std::string::String,
+    pub min: f64,
+    pub max: f64,
+    pub default_limit: f64,
+    pub default_request: f64,
+    pub max_limit_request_ratio: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LimitRangeLimit {
+    fn default() -> &'a LimitRangeLimit {
+        <LimitRangeLimit as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LimitRangeLimit {
+    pub fn new() -> LimitRangeLimit {
+        ::std::default::Default::default()
+    }
+
+    // string type = 1;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // string resource = 2;
+
+
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: ::std::string::String) {
+        self.resource = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut ::std::string::String {
+        &mut self.resource
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource, ::std::string::String::new())
+    }
+
+    // double min = 3;
+
+
+    pub fn get_min(&self) -> f64 {
+        self.min
+    }
+    pub fn clear_min(&mut self) {
+        self.min = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min(&mut self, v: f64) {
+        self.min = v;
+    }
+
+    // double max = 4;
+
+
+    pub fn get_max(&self) -> f64 {
+        self.max
+    }
+    pub fn clear_max(&mut self) {
+        self.max = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max(&mut self, v: f64) {
+        self.max = v;
+    }
+
+    // double default_limit = 5;
+
+
+    pub fn get_default_limit(&self) -> f64 {
+        self.default_limit
+    }
+    pub fn clear_default_limit(&mut self) {
+        self.default_limit = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_limit(&mut self, v: f64) {
+        self.default_limit = v;
+    }
+
+    // double default_request = 6;
+
+
+    pub fn get_default_request(&self) -> f64 {
+        self.default_request
+    }
+    pub fn clear_default_request(&mut self) {
+        self.default_request = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_request(&mut self, v: f64) {
+        self.default_request = v;
+    }
+
+    // double max_limit_request_ratio = 7;
+
+
+    pub fn get_max_limit_request_ratio(&self) -> f64 {
+        self.max_limit_request_ratio
+    }
+    pub fn clear_max_limit_request_ratio(&mut self) {
+        self.max_limit_request_ratio = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_limit_request_ratio(&mut self, v: f64) {
+        self.max_limit_request_ratio = v;
+    }
+}
+
+impl ::protobuf::Message for LimitRangeLimit {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
                 },
-                20 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.fs_inodes_used = tmp;
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource)?;
                 },
-                37 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.disk_usage = tmp;
+                    let tmp = is.read_double()?;
+                    self.min = tmp;
                 },
-                21 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.rlimit_maxpid = tmp;
+                    let tmp = is.read_double()?;
+                    self.max = tmp;
                 },
-                22 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.rlimit_curproc = tmp;
+                    let tmp = is.read_double()?;
+                    self.default_limit = tmp;
                 },
-                23 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.ephemeral_storage_available_bytes = tmp;
+                    let tmp = is.read_double()?;
+                    self.default_request = tmp;
                 },
-                24 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.ephemeral_storage_capacity_bytes = tmp;
+                    let tmp = is.read_double()?;
+                    self.max_limit_request_ratio = tmp;
                 },
-                25 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.ephemeral_storage_used_bytes = tmp;
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
-                26 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.ephemeral_storage_inodes_free = tmp;
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        if !self.resource.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.resource);
+        }
+        if self.min != 0. {
+            my_size += 9;
+        }
+        if self.max != 0. {
+            my_size += 9;
+        }
+        if self.default_limit != 0. {
+            my_size += 9;
+        }
+        if self.default_request != 0. {
+            my_size += 9;
+        }
+        if self.max_limit_request_ratio != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        if !self.resource.is_empty() {
+            os.write_string(2, &self.resource)?;
+        }
+        if self.min != 0. {
+            os.write_double(3, self.min)?;
+        }
+        if self.max != 0. {
+            os.write_double(4, self.max)?;
+        }
+        if self.default_limit != 0. {
+            os.write_double(5, self.default_limit)?;
+        }
+        if self.default_request != 0. {
+            os.write_double(6, self.default_request)?;
+        }
+        if self.max_limit_request_ratio != 0. {
+            os.write_double(7, self.max_limit_request_ratio)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LimitRangeLimit {
+        LimitRangeLimit::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type",
+                |m: &LimitRangeLimit| { &m.field_type },
+                |m: &mut LimitRangeLimit| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource",
+                |m: &LimitRangeLimit| { &m.resource },
+                |m: &mut LimitRangeLimit| { &mut m.resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "min",
+                |m: &LimitRangeLimit| { &m.min },
+                |m: &mut LimitRangeLimit| { &mut m.min },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "max",
+                |m: &LimitRangeLimit| { &m.max },
+                |m: &mut LimitRangeLimit| { &mut m.max },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "default_limit",
+                |m: &LimitRangeLimit| { &m.default_limit },
+                |m: &mut LimitRangeLimit| { &mut m.default_limit },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "default_request",
+                |m: &LimitRangeLimit| { &m.default_request },
+                |m: &mut LimitRangeLimit| { &mut m.default_request },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "max_limit_request_ratio",
+                |m: &LimitRangeLimit| { &m.max_limit_request_ratio },
+                |m: &mut LimitRangeLimit| { &mut m.max_limit_request_ratio },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LimitRangeLimit>(
+                "LimitRangeLimit",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LimitRangeLimit {
+        static instance: ::protobuf::rt::LazyV2<LimitRangeLimit> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LimitRangeLimit::new)
+    }
+}
+
+impl ::protobuf::Clear for LimitRangeLimit {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.resource.clear();
+        self.min = 0.;
+        self.max = 0.;
+        self.default_limit = 0.;
+        self.default_request = 0.;
+        self.max_limit_request_ratio = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LimitRangeLimit {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LimitRangeLimit {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServiceStatus {
+    // message fields
+    pub field_type: ::std::string::String,
+    pub ready_endpoints: i32,
+    pub not_ready_endpoints: i32,
+    pub terminating_endpoints: i32,
+    pub has_selector: bool,
+    pub no_ready_pods: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ServiceStatus {
+    fn default() -> &'a ServiceStatus {
+        <ServiceStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ServiceStatus {
+    pub fn new() -> ServiceStatus {
+        ::std::default::Default::default()
+    }
+
+    // string type = 1;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // int32 ready_endpoints = 2;
+
+
+    pub fn get_ready_endpoints(&self) -> i32 {
+        self.ready_endpoints
+    }
+    pub fn clear_ready_endpoints(&mut self) {
+        self.ready_endpoints = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready_endpoints(&mut self, v: i32) {
+        self.ready_endpoints = v;
+    }
+
+    // int32 not_ready_endpoints = 3;
+
+
+    pub fn get_not_ready_endpoints(&self) -> i32 {
+        self.not_ready_endpoints
+    }
+    pub fn clear_not_ready_endpoints(&mut self) {
+        self.not_ready_endpoints = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_not_ready_endpoints(&mut self, v: i32) {
+        self.not_ready_endpoints = v;
+    }
+
+    // int32 terminating_endpoints = 4;
+
+
+    pub fn get_terminating_endpoints(&self) -> i32 {
+        self.terminating_endpoints
+    }
+    pub fn clear_terminating_endpoints(&mut self) {
+        self.terminating_endpoints = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_terminating_endpoints(&mut self, v: i32) {
+        self.terminating_endpoints = v;
+    }
+
+    // bool has_selector = 5;
+
+
+    pub fn get_has_selector(&self) -> bool {
+        self.has_selector
+    }
+    pub fn clear_has_selector(&mut self) {
+        self.has_selector = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_selector(&mut self, v: bool) {
+        self.has_selector = v;
+    }
+
+    // bool no_ready_pods = 6;
+
+
+    pub fn get_no_ready_pods(&self) -> bool {
+        self.no_ready_pods
+    }
+    pub fn clear_no_ready_pods(&mut self) {
+        self.no_ready_pods = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_no_ready_pods(&mut self, v: bool) {
+        self.no_ready_pods = v;
+    }
+}
+
+impl ::protobuf::Message for ServiceStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
                 },
-                27 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.ephemeral_storage_inodes = tmp;
+                    let tmp = is.read_int32()?;
+                    self.ready_endpoints = tmp;
                 },
-                28 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.ephemeral_storage_inodes_used = tmp;
+                    let tmp = is.read_int32()?;
+                    self.not_ready_endpoints = tmp;
                 },
-                29 => {
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int32()?;
-                    self.process_count = tmp;
+                    self.terminating_endpoints = tmp;
                 },
-                30 => {
+                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.swap_usage_bytes = tmp;
+                    let tmp = is.read_bool()?;
+                    self.has_selector = tmp;
                 },
-                36 => {
+                6 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.swap_available_bytes = tmp;
+                    let tmp = is.read_bool()?;
+                    self.no_ready_pods = tmp;
                 },
-                39 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.swap_usage = tmp;
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
-                40 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.phase)?;
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        if self.ready_endpoints != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ready_endpoints, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.not_ready_endpoints != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.not_ready_endpoints, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.terminating_endpoints != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.terminating_endpoints, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.has_selector != false {
+            my_size += 2;
+        }
+        if self.no_ready_pods != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        if self.ready_endpoints != 0 {
+            os.write_int32(2, self.ready_endpoints)?;
+        }
+        if self.not_ready_endpoints != 0 {
+            os.write_int32(3, self.not_ready_endpoints)?;
+        }
+        if self.terminating_endpoints != 0 {
+            os.write_int32(4, self.terminating_endpoints)?;
+        }
+        if self.has_selector != false {
+            os.write_bool(5, self.has_selector)?;
+        }
+        if self.no_ready_pods != false {
+            os.write_bool(6, self.no_ready_pods)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServiceStatus {
+        ServiceStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type",
+                |m: &ServiceStatus| { &m.field_type },
+                |m: &mut ServiceStatus| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ready_endpoints",
+                |m: &ServiceStatus| { &m.ready_endpoints },
+                |m: &mut ServiceStatus| { &mut m.ready_endpoints },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "not_ready_endpoints",
+                |m: &ServiceStatus| { &m.not_ready_endpoints },
+                |m: &mut ServiceStatus| { &mut m.not_ready_endpoints },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "terminating_endpoints",
+                |m: &ServiceStatus| { &m.terminating_endpoints },
+                |m: &mut ServiceStatus| { &mut m.terminating_endpoints },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_selector",
+                |m: &ServiceStatus| { &m.has_selector },
+                |m: &mut ServiceStatus| { &mut m.has_selector },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "no_ready_pods",
+                |m: &ServiceStatus| { &m.no_ready_pods },
+                |m: &mut ServiceStatus| { &mut m.no_ready_pods },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ServiceStatus>(
+                "ServiceStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ServiceStatus {
+        static instance: ::protobuf::rt::LazyV2<ServiceStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ServiceStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for ServiceStatus {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.ready_endpoints = 0;
+        self.not_ready_endpoints = 0;
+        self.terminating_endpoints = 0;
+        self.has_selector = false;
+        self.no_ready_pods = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServiceStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServiceStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RouteStatus {
+    // message fields
+    pub class_name: ::std::string::String,
+    pub hostnames: ::protobuf::RepeatedField<::std::string::String>,
+    pub parents: ::protobuf::RepeatedField<ResourceReference>,
+    pub backends: ::protobuf::RepeatedField<RouteBackend>,
+    pub backends_without_ready_endpoints: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RouteStatus {
+    fn default() -> &'a RouteStatus {
+        <RouteStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RouteStatus {
+    pub fn new() -> RouteStatus {
+        ::std::default::Default::default()
+    }
+
+    // string class_name = 1;
+
+
+    pub fn get_class_name(&self) -> &str {
+        &self.class_name
+    }
+    pub fn clear_class_name(&mut self) {
+        self.class_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_class_name(&mut self, v: ::std::string::String) {
+        self.class_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_class_name(&mut self) -> &mut ::std::string::String {
+        &mut self.class_name
+    }
+
+    // Take field
+    pub fn take_class_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.class_name, ::std::string::String::new())
+    }
+
+    // repeated string hostnames = 2;
+
+
+    pub fn get_hostnames(&self) -> &[::std::string::String] {
+        &self.hostnames
+    }
+    pub fn clear_hostnames(&mut self) {
+        self.hostnames.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hostnames(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.hostnames = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_hostnames(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.hostnames
+    }
+
+    // Take field
+    pub fn take_hostnames(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.hostnames, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .protocol.kubernetes.ResourceReference parents = 3;
+
+
+    pub fn get_parents(&self) -> &[ResourceReference] {
+        &self.parents
+    }
+    pub fn clear_parents(&mut self) {
+        self.parents.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parents(&mut self, v: ::protobuf::RepeatedField<ResourceReference>) {
+        self.parents = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parents(&mut self) -> &mut ::protobuf::RepeatedField<ResourceReference> {
+        &mut self.parents
+    }
+
+    // Take field
+    pub fn take_parents(&mut self) -> ::protobuf::RepeatedField<ResourceReference> {
+        ::std::mem::replace(&mut self.parents, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .protocol.kubernetes.RouteBackend backends = 4;
+
+
+    pub fn get_backends(&self) -> &[RouteBackend] {
+        &self.backends
+    }
+    pub fn clear_backends(&mut self) {
+        self.backends.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backends(&mut self, v: ::protobuf::RepeatedField<RouteBackend>) {
+        self.backends = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_backends(&mut self) -> &mut ::protobuf::RepeatedField<RouteBackend> {
+        &mut self.backends
+    }
+
+    // Take field
+    pub fn take_backends(&mut self) -> ::protobuf::RepeatedField<RouteBackend> {
+        ::std::mem::replace(&mut self.backends, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 backends_without_ready_endpoints = 5;
+
+
+    pub fn get_backends_without_ready_endpoints(&self) -> i32 {
+        self.backends_without_ready_endpoints
+    }
+    pub fn clear_backends_without_ready_endpoints(&mut self) {
+        self.backends_without_ready_endpoints = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backends_without_ready_endpoints(&mut self, v: i32) {
+        self.backends_without_ready_endpoints = v;
+    }
+}
+
+impl ::protobuf::Message for RouteStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.parents {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.backends {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.class_name)?;
                 },
-                50 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.pod_phase, 50, &mut self.unknown_fields)?
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.hostnames)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.parents)?;
                 },
-                42 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.pod_restart_count = tmp;
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.backends)?;
                 },
-                43 => {
+                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.pod_uptime_seconds = tmp;
-                },
-                44 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.owner_references)?;
-                },
-                49 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.containers)?;
+                    let tmp = is.read_int32()?;
+                    self.backends_without_ready_endpoints = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1195,290 +9264,832 @@ impl ::protobuf::Message for KubernetesMetrics {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.site_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(32, &self.site_id);
-        }
-        if !self.node_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.node_name);
-        }
-        if !self.pod_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.pod_name);
-        }
-        if !self.pod_namespace.is_empty() {
-            my_size += ::protobuf::rt::string_size(33, &self.pod_namespace);
-        }
-        if !self.pod_uuid.is_empty() {
-            my_size += ::protobuf::rt::string_size(34, &self.pod_uuid);
-        }
-        if !self.volume_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(35, &self.volume_name);
-        }
-        if self.timestamp != 0 {
-            my_size += ::protobuf::rt::value_size(31, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
-        }
-        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(41, &self.labels);
-        if self.cpu_usage_nano_cores != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.cpu_usage_nano_cores, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.cpu_usage_core_nano_seconds != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.cpu_usage_core_nano_seconds, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_available_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(5, self.memory_available_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_usage_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(6, self.memory_usage_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_working_set_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(7, self.memory_working_set_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_rss_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(8, self.memory_rss_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_page_faults != 0 {
-            my_size += ::protobuf::rt::value_size(9, self.memory_page_faults, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_major_page_faults != 0 {
-            my_size += ::protobuf::rt::value_size(10, self.memory_major_page_faults, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.memory_usage != 0 {
-            my_size += ::protobuf::rt::value_size(38, self.memory_usage, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.network_rx_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(11, self.network_rx_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.network_rx_errors != 0 {
-            my_size += ::protobuf::rt::value_size(12, self.network_rx_errors, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.network_tx_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(13, self.network_tx_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.network_tx_errors != 0 {
-            my_size += ::protobuf::rt::value_size(14, self.network_tx_errors, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.fs_available_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(15, self.fs_available_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.fs_capacity_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(16, self.fs_capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.fs_used_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(17, self.fs_used_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.fs_inodes_free != 0 {
-            my_size += ::protobuf::rt::value_size(18, self.fs_inodes_free, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.fs_inodes != 0 {
-            my_size += ::protobuf::rt::value_size(19, self.fs_inodes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.fs_inodes_used != 0 {
-            my_size += ::protobuf::rt::value_size(20, self.fs_inodes_used, ::protobuf::wire_format::WireTypeVarint);
+        if !self.class_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.class_name);
         }
-        if self.disk_usage != 0 {
-            my_size += ::protobuf::rt::value_size(37, self.disk_usage, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.rlimit_maxpid != 0 {
-            my_size += ::protobuf::rt::value_size(21, self.rlimit_maxpid, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.rlimit_curproc != 0 {
-            my_size += ::protobuf::rt::value_size(22, self.rlimit_curproc, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.ephemeral_storage_available_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(23, self.ephemeral_storage_available_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.ephemeral_storage_capacity_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(24, self.ephemeral_storage_capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.ephemeral_storage_used_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(25, self.ephemeral_storage_used_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.ephemeral_storage_inodes_free != 0 {
-            my_size += ::protobuf::rt::value_size(26, self.ephemeral_storage_inodes_free, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.ephemeral_storage_inodes != 0 {
-            my_size += ::protobuf::rt::value_size(27, self.ephemeral_storage_inodes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.ephemeral_storage_inodes_used != 0 {
-            my_size += ::protobuf::rt::value_size(28, self.ephemeral_storage_inodes_used, ::protobuf::wire_format::WireTypeVarint);
+        for value in &self.hostnames {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.parents {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.backends {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.backends_without_ready_endpoints != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.backends_without_ready_endpoints, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.process_count != 0 {
-            my_size += ::protobuf::rt::value_size(29, self.process_count, ::protobuf::wire_format::WireTypeVarint);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.class_name.is_empty() {
+            os.write_string(1, &self.class_name)?;
         }
-        if self.swap_usage_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(30, self.swap_usage_bytes, ::protobuf::wire_format::WireTypeVarint);
+        for v in &self.hostnames {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.parents {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.backends {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.backends_without_ready_endpoints != 0 {
+            os.write_int32(5, self.backends_without_ready_endpoints)?;
         }
-        if self.swap_available_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(36, self.swap_available_bytes, ::protobuf::wire_format::WireTypeVarint);
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RouteStatus {
+        RouteStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "class_name",
+                |m: &RouteStatus| { &m.class_name },
+                |m: &mut RouteStatus| { &mut m.class_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "hostnames",
+                |m: &RouteStatus| { &m.hostnames },
+                |m: &mut RouteStatus| { &mut m.hostnames },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceReference>>(
+                "parents",
+                |m: &RouteStatus| { &m.parents },
+                |m: &mut RouteStatus| { &mut m.parents },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RouteBackend>>(
+                "backends",
+                |m: &RouteStatus| { &m.backends },
+                |m: &mut RouteStatus| { &mut m.backends },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "backends_without_ready_endpoints",
+                |m: &RouteStatus| { &m.backends_without_ready_endpoints },
+                |m: &mut RouteStatus| { &mut m.backends_without_ready_endpoints },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RouteStatus>(
+                "RouteStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RouteStatus {
+        static instance: ::protobuf::rt::LazyV2<RouteStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RouteStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for RouteStatus {
+    fn clear(&mut self) {
+        self.class_name.clear();
+        self.hostnames.clear();
+        self.parents.clear();
+        self.backends.clear();
+        self.backends_without_ready_endpoints = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RouteStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RouteStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RouteBackend {
+    // message fields
+    pub service: ::protobuf::SingularPtrField<ResourceReference>,
+    pub port: i32,
+    pub service_missing: bool,
+    pub no_ready_endpoints: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RouteBackend {
+    fn default() -> &'a RouteBackend {
+        <RouteBackend as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RouteBackend {
+    pub fn new() -> RouteBackend {
+        ::std::default::Default::default()
+    }
+
+    // .protocol.kubernetes.ResourceReference service = 1;
+
+
+    pub fn get_service(&self) -> &ResourceReference {
+        self.service.as_ref().unwrap_or_else(|| <ResourceReference as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_service(&mut self) {
+        self.service.clear();
+    }
+
+    pub fn has_service(&self) -> bool {
+        self.service.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ResourceReference) {
+        self.service = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service(&mut self) -> &mut ResourceReference {
+        if self.service.is_none() {
+            self.service.set_default();
         }
-        if self.swap_usage != 0 {
-            my_size += ::protobuf::rt::value_size(39, self.swap_usage, ::protobuf::wire_format::WireTypeVarint);
+        self.service.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> ResourceReference {
+        self.service.take().unwrap_or_else(|| ResourceReference::new())
+    }
+
+    // int32 port = 2;
+
+
+    pub fn get_port(&self) -> i32 {
+        self.port
+    }
+    pub fn clear_port(&mut self) {
+        self.port = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_port(&mut self, v: i32) {
+        self.port = v;
+    }
+
+    // bool service_missing = 3;
+
+
+    pub fn get_service_missing(&self) -> bool {
+        self.service_missing
+    }
+    pub fn clear_service_missing(&mut self) {
+        self.service_missing = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_missing(&mut self, v: bool) {
+        self.service_missing = v;
+    }
+
+    // bool no_ready_endpoints = 4;
+
+
+    pub fn get_no_ready_endpoints(&self) -> bool {
+        self.no_ready_endpoints
+    }
+    pub fn clear_no_ready_endpoints(&mut self) {
+        self.no_ready_endpoints = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_no_ready_endpoints(&mut self, v: bool) {
+        self.no_ready_endpoints = v;
+    }
+}
+
+impl ::protobuf::Message for RouteBackend {
+    fn is_initialized(&self) -> bool {
+        for v in &self.service {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.service)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.port = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.service_missing = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.no_ready_endpoints = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
-        if !self.phase.is_empty() {
-            my_size += ::protobuf::rt::string_size(40, &self.phase);
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.service.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if self.pod_phase != PodPhase::POD_PHASE_UNKNOWN {
-            my_size += ::protobuf::rt::enum_size(50, self.pod_phase);
+        if self.port != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.port, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.pod_restart_count != 0 {
-            my_size += ::protobuf::rt::value_size(42, self.pod_restart_count, ::protobuf::wire_format::WireTypeVarint);
+        if self.service_missing != false {
+            my_size += 2;
         }
-        if self.pod_uptime_seconds != 0 {
-            my_size += ::protobuf::rt::value_size(43, self.pod_uptime_seconds, ::protobuf::wire_format::WireTypeVarint);
+        if self.no_ready_endpoints != false {
+            my_size += 2;
         }
-        for value in &self.owner_references {
-            let len = value.compute_size();
-            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in &self.containers {
-            let len = value.compute_size();
-            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.site_id.is_empty() {
-            os.write_string(32, &self.site_id)?;
-        }
-        if !self.node_name.is_empty() {
-            os.write_string(1, &self.node_name)?;
-        }
-        if !self.pod_name.is_empty() {
-            os.write_string(2, &self.pod_name)?;
-        }
-        if !self.pod_namespace.is_empty() {
-            os.write_string(33, &self.pod_namespace)?;
-        }
-        if !self.pod_uuid.is_empty() {
-            os.write_string(34, &self.pod_uuid)?;
-        }
-        if !self.volume_name.is_empty() {
-            os.write_string(35, &self.volume_name)?;
-        }
-        if self.timestamp != 0 {
-            os.write_int64(31, self.timestamp)?;
-        }
-        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(41, &self.labels, os)?;
-        if self.cpu_usage_nano_cores != 0 {
-            os.write_int64(3, self.cpu_usage_nano_cores)?;
-        }
-        if self.cpu_usage_core_nano_seconds != 0 {
-            os.write_int64(4, self.cpu_usage_core_nano_seconds)?;
-        }
-        if self.memory_available_bytes != 0 {
-            os.write_int64(5, self.memory_available_bytes)?;
-        }
-        if self.memory_usage_bytes != 0 {
-            os.write_int64(6, self.memory_usage_bytes)?;
-        }
-        if self.memory_working_set_bytes != 0 {
-            os.write_int64(7, self.memory_working_set_bytes)?;
-        }
-        if self.memory_rss_bytes != 0 {
-            os.write_int64(8, self.memory_rss_bytes)?;
-        }
-        if self.memory_page_faults != 0 {
-            os.write_int32(9, self.memory_page_faults)?;
-        }
-        if self.memory_major_page_faults != 0 {
-            os.write_int32(10, self.memory_major_page_faults)?;
-        }
-        if self.memory_usage != 0 {
-            os.write_int32(38, self.memory_usage)?;
-        }
-        if self.network_rx_bytes != 0 {
-            os.write_int64(11, self.network_rx_bytes)?;
-        }
-        if self.network_rx_errors != 0 {
-            os.write_int32(12, self.network_rx_errors)?;
-        }
-        if self.network_tx_bytes != 0 {
-            os.write_int64(13, self.network_tx_bytes)?;
-        }
-        if self.network_tx_errors != 0 {
-            os.write_int32(14, self.network_tx_errors)?;
-        }
-        if self.fs_available_bytes != 0 {
-            os.write_int64(15, self.fs_available_bytes)?;
-        }
-        if self.fs_capacity_bytes != 0 {
-            os.write_int64(16, self.fs_capacity_bytes)?;
-        }
-        if self.fs_used_bytes != 0 {
-            os.write_int64(17, self.fs_used_bytes)?;
-        }
-        if self.fs_inodes_free != 0 {
-            os.write_int64(18, self.fs_inodes_free)?;
-        }
-        if self.fs_inodes != 0 {
-            os.write_int64(19, self.fs_inodes)?;
-        }
-        if self.fs_inodes_used != 0 {
-            os.write_int64(20, self.fs_inodes_used)?;
-        }
-        if self.disk_usage != 0 {
-            os.write_int32(37, self.disk_usage)?;
-        }
-        if self.rlimit_maxpid != 0 {
-            os.write_int32(21, self.rlimit_maxpid)?;
-        }
-        if self.rlimit_curproc != 0 {
-            os.write_int32(22, self.rlimit_curproc)?;
-        }
-        if self.ephemeral_storage_available_bytes != 0 {
-            os.write_int64(23, self.ephemeral_storage_available_bytes)?;
+        if let Some(ref v) = self.service.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
-        if self.ephemeral_storage_capacity_bytes != 0 {
-            os.write_int64(24, self.ephemeral_storage_capacity_bytes)?;
+        if self.port != 0 {
+            os.write_int32(2, self.port)?;
         }
-        if self.ephemeral_storage_used_bytes != 0 {
-            os.write_int64(25, self.ephemeral_storage_used_bytes)?;
+        if self.service_missing != false {
+            os.write_bool(3, self.service_missing)?;
         }
-        if self.ephemeral_storage_inodes_free != 0 {
-            os.write_int64(26, self.ephemeral_storage_inodes_free)?;
+        if self.no_ready_endpoints != false {
+            os.write_bool(4, self.no_ready_endpoints)?;
         }
-        if self.ephemeral_storage_inodes != 0 {
-            os.write_int64(27, self.ephemeral_storage_inodes)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RouteBackend {
+        RouteBackend::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceReference>>(
+                "service",
+                |m: &RouteBackend| { &m.service },
+                |m: &mut RouteBackend| { &mut m.service },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "port",
+                |m: &RouteBackend| { &m.port },
+                |m: &mut RouteBackend| { &mut m.port },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "service_missing",
+                |m: &RouteBackend| { &m.service_missing },
+                |m: &mut RouteBackend| { &mut m.service_missing },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "no_ready_endpoints",
+                |m: &RouteBackend| { &m.no_ready_endpoints },
+                |m: &mut RouteBackend| { &mut m.no_ready_endpoints },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RouteBackend>(
+                "RouteBackend",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RouteBackend {
+        static instance: ::protobuf::rt::LazyV2<RouteBackend> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RouteBackend::new)
+    }
+}
+
+impl ::protobuf::Clear for RouteBackend {
+    fn clear(&mut self) {
+        self.service.clear();
+        self.port = 0;
+        self.service_missing = false;
+        self.no_ready_endpoints = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RouteBackend {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RouteBackend {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CustomResourceStatus {
+    // message fields
+    pub api_version: ::std::string::String,
+    pub fields: ::protobuf::RepeatedField<CustomResourceField>,
+    pub conditions: ::protobuf::RepeatedField<CustomResourceCondition>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CustomResourceStatus {
+    fn default() -> &'a CustomResourceStatus {
+        <CustomResourceStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CustomResourceStatus {
+    pub fn new() -> CustomResourceStatus {
+        ::std::default::Default::default()
+    }
+
+    // string api_version = 1;
+
+
+    pub fn get_api_version(&self) -> &str {
+        &self.api_version
+    }
+    pub fn clear_api_version(&mut self) {
+        self.api_version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_api_version(&mut self, v: ::std::string::String) {
+        self.api_version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_api_version(&mut self) -> &mut ::std::string::String {
+        &mut self.api_version
+    }
+
+    // Take field
+    pub fn take_api_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.api_version, ::std::string::String::new())
+    }
+
+    // repeated .protocol.kubernetes.CustomResourceField fields = 2;
+
+
+    pub fn get_fields(&self) -> &[CustomResourceField] {
+        &self.fields
+    }
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::protobuf::RepeatedField<CustomResourceField>) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fields(&mut self) -> &mut ::protobuf::RepeatedField<CustomResourceField> {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::protobuf::RepeatedField<CustomResourceField> {
+        ::std::mem::replace(&mut self.fields, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .protocol.kubernetes.CustomResourceCondition conditions = 3;
+
+
+    pub fn get_conditions(&self) -> &[CustomResourceCondition] {
+        &self.conditions
+    }
+    pub fn clear_conditions(&mut self) {
+        self.conditions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conditions(&mut self, v: ::protobuf::RepeatedField<CustomResourceCondition>) {
+        self.conditions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_conditions(&mut self) -> &mut ::protobuf::RepeatedField<CustomResourceCondition> {
+        &mut self.conditions
+    }
+
+    // Take field
+    pub fn take_conditions(&mut self) -> ::protobuf::RepeatedField<CustomResourceCondition> {
+        ::std::mem::replace(&mut self.conditions, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CustomResourceStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.fields {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.conditions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.api_version)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fields)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.conditions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
-        if self.ephemeral_storage_inodes_used != 0 {
-            os.write_int64(28, self.ephemeral_storage_inodes_used)?;
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.api_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.api_version);
         }
-        if self.process_count != 0 {
-            os.write_int32(29, self.process_count)?;
+        for value in &self.fields {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.conditions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.api_version.is_empty() {
+            os.write_string(1, &self.api_version)?;
         }
-        if self.swap_usage_bytes != 0 {
-            os.write_int64(30, self.swap_usage_bytes)?;
+        for v in &self.fields {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.conditions {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CustomResourceStatus {
+        CustomResourceStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "api_version",
+                |m: &CustomResourceStatus| { &m.api_version },
+                |m: &mut CustomResourceStatus| { &mut m.api_version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CustomResourceField>>(
+                "fields",
+                |m: &CustomResourceStatus| { &m.fields },
+                |m: &mut CustomResourceStatus| { &mut m.fields },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CustomResourceCondition>>(
+                "conditions",
+                |m: &CustomResourceStatus| { &m.conditions },
+                |m: &mut CustomResourceStatus| { &mut m.conditions },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CustomResourceStatus>(
+                "CustomResourceStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CustomResourceStatus {
+        static instance: ::protobuf::rt::LazyV2<CustomResourceStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CustomResourceStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for CustomResourceStatus {
+    fn clear(&mut self) {
+        self.api_version.clear();
+        self.fields.clear();
+        self.conditions.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CustomResourceStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CustomResourceStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CustomResourceField {
+    // message fields
+    pub name: ::std::string::String,
+    pub value: f64,
+    pub string_value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CustomResourceField {
+    fn default() -> &'a CustomResourceField {
+        <CustomResourceField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CustomResourceField {
+    pub fn new() -> CustomResourceField {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // double value = 2;
+
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: f64) {
+        self.value = v;
+    }
+
+    // string string_value = 3;
+
+
+    pub fn get_string_value(&self) -> &str {
+        &self.string_value
+    }
+    pub fn clear_string_value(&mut self) {
+        self.string_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_string_value(&mut self, v: ::std::string::String) {
+        self.string_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_string_value(&mut self) -> &mut ::std::string::String {
+        &mut self.string_value
+    }
+
+    // Take field
+    pub fn take_string_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.string_value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CustomResourceField {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.value = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.string_value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
-        if self.swap_available_bytes != 0 {
-            os.write_int64(36, self.swap_available_bytes)?;
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
         }
-        if self.swap_usage != 0 {
-            os.write_int32(39, self.swap_usage)?;
+        if self.value != 0. {
+            my_size += 9;
         }
-        if !self.phase.is_empty() {
-            os.write_string(40, &self.phase)?;
+        if !self.string_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.string_value);
         }
-        if self.pod_phase != PodPhase::POD_PHASE_UNKNOWN {
-            os.write_enum(50, ::protobuf::ProtobufEnum::value(&self.pod_phase))?;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
         }
-        if self.pod_restart_count != 0 {
-            os.write_int32(42, self.pod_restart_count)?;
+        if self.value != 0. {
+            os.write_double(2, self.value)?;
         }
-        if self.pod_uptime_seconds != 0 {
-            os.write_int64(43, self.pod_uptime_seconds)?;
+        if !self.string_value.is_empty() {
+            os.write_string(3, &self.string_value)?;
         }
-        for v in &self.owner_references {
-            os.write_tag(44, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        for v in &self.containers {
-            os.write_tag(49, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1491,442 +10102,173 @@ impl ::protobuf::Message for KubernetesMetrics {
         &self.unknown_fields
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
-    }
-
-    fn as_any(&self) -> &dyn (::std::any::Any) {
-        self as &dyn (::std::any::Any)
-    }
-    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
-        self as &mut dyn (::std::any::Any)
-    }
-    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
-        self
-    }
-
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
-    }
-
-    fn new() -> KubernetesMetrics {
-        KubernetesMetrics::new()
-    }
-
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
-        descriptor.get(|| {
-            let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "site_id",
-                |m: &KubernetesMetrics| { &m.site_id },
-                |m: &mut KubernetesMetrics| { &mut m.site_id },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "node_name",
-                |m: &KubernetesMetrics| { &m.node_name },
-                |m: &mut KubernetesMetrics| { &mut m.node_name },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "pod_name",
-                |m: &KubernetesMetrics| { &m.pod_name },
-                |m: &mut KubernetesMetrics| { &mut m.pod_name },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "pod_namespace",
-                |m: &KubernetesMetrics| { &m.pod_namespace },
-                |m: &mut KubernetesMetrics| { &mut m.pod_namespace },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "pod_uuid",
-                |m: &KubernetesMetrics| { &m.pod_uuid },
-                |m: &mut KubernetesMetrics| { &mut m.pod_uuid },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "volume_name",
-                |m: &KubernetesMetrics| { &m.volume_name },
-                |m: &mut KubernetesMetrics| { &mut m.volume_name },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "timestamp",
-                |m: &KubernetesMetrics| { &m.timestamp },
-                |m: &mut KubernetesMetrics| { &mut m.timestamp },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
-                "labels",
-                |m: &KubernetesMetrics| { &m.labels },
-                |m: &mut KubernetesMetrics| { &mut m.labels },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "cpu_usage_nano_cores",
-                |m: &KubernetesMetrics| { &m.cpu_usage_nano_cores },
-                |m: &mut KubernetesMetrics| { &mut m.cpu_usage_nano_cores },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "cpu_usage_core_nano_seconds",
-                |m: &KubernetesMetrics| { &m.cpu_usage_core_nano_seconds },
-                |m: &mut KubernetesMetrics| { &mut m.cpu_usage_core_nano_seconds },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "memory_available_bytes",
-                |m: &KubernetesMetrics| { &m.memory_available_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.memory_available_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "memory_usage_bytes",
-                |m: &KubernetesMetrics| { &m.memory_usage_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.memory_usage_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "memory_working_set_bytes",
-                |m: &KubernetesMetrics| { &m.memory_working_set_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.memory_working_set_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "memory_rss_bytes",
-                |m: &KubernetesMetrics| { &m.memory_rss_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.memory_rss_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "memory_page_faults",
-                |m: &KubernetesMetrics| { &m.memory_page_faults },
-                |m: &mut KubernetesMetrics| { &mut m.memory_page_faults },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "memory_major_page_faults",
-                |m: &KubernetesMetrics| { &m.memory_major_page_faults },
-                |m: &mut KubernetesMetrics| { &mut m.memory_major_page_faults },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "memory_usage",
-                |m: &KubernetesMetrics| { &m.memory_usage },
-                |m: &mut KubernetesMetrics| { &mut m.memory_usage },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "network_rx_bytes",
-                |m: &KubernetesMetrics| { &m.network_rx_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.network_rx_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "network_rx_errors",
-                |m: &KubernetesMetrics| { &m.network_rx_errors },
-                |m: &mut KubernetesMetrics| { &mut m.network_rx_errors },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "network_tx_bytes",
-                |m: &KubernetesMetrics| { &m.network_tx_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.network_tx_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "network_tx_errors",
-                |m: &KubernetesMetrics| { &m.network_tx_errors },
-                |m: &mut KubernetesMetrics| { &mut m.network_tx_errors },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "fs_available_bytes",
-                |m: &KubernetesMetrics| { &m.fs_available_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.fs_available_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "fs_capacity_bytes",
-                |m: &KubernetesMetrics| { &m.fs_capacity_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.fs_capacity_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "fs_used_bytes",
-                |m: &KubernetesMetrics| { &m.fs_used_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.fs_used_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "fs_inodes_free",
-                |m: &KubernetesMetrics| { &m.fs_inodes_free },
-                |m: &mut KubernetesMetrics| { &mut m.fs_inodes_free },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "fs_inodes",
-                |m: &KubernetesMetrics| { &m.fs_inodes },
-                |m: &mut KubernetesMetrics| { &mut m.fs_inodes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "fs_inodes_used",
-                |m: &KubernetesMetrics| { &m.fs_inodes_used },
-                |m: &mut KubernetesMetrics| { &mut m.fs_inodes_used },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "disk_usage",
-                |m: &KubernetesMetrics| { &m.disk_usage },
-                |m: &mut KubernetesMetrics| { &mut m.disk_usage },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "rlimit_maxpid",
-                |m: &KubernetesMetrics| { &m.rlimit_maxpid },
-                |m: &mut KubernetesMetrics| { &mut m.rlimit_maxpid },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "rlimit_curproc",
-                |m: &KubernetesMetrics| { &m.rlimit_curproc },
-                |m: &mut KubernetesMetrics| { &mut m.rlimit_curproc },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "ephemeral_storage_available_bytes",
-                |m: &KubernetesMetrics| { &m.ephemeral_storage_available_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_available_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "ephemeral_storage_capacity_bytes",
-                |m: &KubernetesMetrics| { &m.ephemeral_storage_capacity_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_capacity_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "ephemeral_storage_used_bytes",
-                |m: &KubernetesMetrics| { &m.ephemeral_storage_used_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_used_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "ephemeral_storage_inodes_free",
-                |m: &KubernetesMetrics| { &m.ephemeral_storage_inodes_free },
-                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_inodes_free },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "ephemeral_storage_inodes",
-                |m: &KubernetesMetrics| { &m.ephemeral_storage_inodes },
-                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_inodes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "ephemeral_storage_inodes_used",
-                |m: &KubernetesMetrics| { &m.ephemeral_storage_inodes_used },
-                |m: &mut KubernetesMetrics| { &mut m.ephemeral_storage_inodes_used },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "process_count",
-                |m: &KubernetesMetrics| { &m.process_count },
-                |m: &mut KubernetesMetrics| { &mut m.process_count },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "swap_usage_bytes",
-                |m: &KubernetesMetrics| { &m.swap_usage_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.swap_usage_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "swap_available_bytes",
-                |m: &KubernetesMetrics| { &m.swap_available_bytes },
-                |m: &mut KubernetesMetrics| { &mut m.swap_available_bytes },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "swap_usage",
-                |m: &KubernetesMetrics| { &m.swap_usage },
-                |m: &mut KubernetesMetrics| { &mut m.swap_usage },
-            ));
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CustomResourceField {
+        CustomResourceField::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "phase",
-                |m: &KubernetesMetrics| { &m.phase },
-                |m: &mut KubernetesMetrics| { &mut m.phase },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<PodPhase>>(
-                "pod_phase",
-                |m: &KubernetesMetrics| { &m.pod_phase },
-                |m: &mut KubernetesMetrics| { &mut m.pod_phase },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "pod_restart_count",
-                |m: &KubernetesMetrics| { &m.pod_restart_count },
-                |m: &mut KubernetesMetrics| { &mut m.pod_restart_count },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "pod_uptime_seconds",
-                |m: &KubernetesMetrics| { &m.pod_uptime_seconds },
-                |m: &mut KubernetesMetrics| { &mut m.pod_uptime_seconds },
+                "name",
+                |m: &CustomResourceField| { &m.name },
+                |m: &mut CustomResourceField| { &mut m.name },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OwnerReference>>(
-                "owner_references",
-                |m: &KubernetesMetrics| { &m.owner_references },
-                |m: &mut KubernetesMetrics| { &mut m.owner_references },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "value",
+                |m: &CustomResourceField| { &m.value },
+                |m: &mut CustomResourceField| { &mut m.value },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Container>>(
-                "containers",
-                |m: &KubernetesMetrics| { &m.containers },
-                |m: &mut KubernetesMetrics| { &mut m.containers },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "string_value",
+                |m: &CustomResourceField| { &m.string_value },
+                |m: &mut CustomResourceField| { &mut m.string_value },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KubernetesMetrics>(
-                "KubernetesMetrics",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CustomResourceField>(
+                "CustomResourceField",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static KubernetesMetrics {
-        static instance: ::protobuf::rt::LazyV2<KubernetesMetrics> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(KubernetesMetrics::new)
+    fn default_instance() -> &'static CustomResourceField {
+        static instance: ::protobuf::rt::LazyV2<CustomResourceField> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CustomResourceField::new)
     }
 }
 
-impl ::protobuf::Clear for KubernetesMetrics {
+impl ::protobuf::Clear for CustomResourceField {
     fn clear(&mut self) {
-        self.site_id.clear();
-        self.node_name.clear();
-        self.pod_name.clear();
-        self.pod_namespace.clear();
-        self.pod_uuid.clear();
-        self.volume_name.clear();
-        self.timestamp = 0;
-        self.labels.clear();
-        self.cpu_usage_nano_cores = 0;
-        self.cpu_usage_core_nano_seconds = 0;
-        self.memory_available_bytes = 0;
-        self.memory_usage_bytes = 0;
-        self.memory_working_set_bytes = 0;
-        self.memory_rss_bytes = 0;
-        self.memory_page_faults = 0;
-        self.memory_major_page_faults = 0;
-        self.memory_usage = 0;
-        self.network_rx_bytes = 0;
-        self.network_rx_errors = 0;
-        self.network_tx_bytes = 0;
-        self.network_tx_errors = 0;
-        self.fs_available_bytes = 0;
-        self.fs_capacity_bytes = 0;
-        self.fs_used_bytes = 0;
-        self.fs_inodes_free = 0;
-        self.fs_inodes = 0;
-        self.fs_inodes_used = 0;
-        self.disk_usage = 0;
-        self.rlimit_maxpid = 0;
-        self.rlimit_curproc = 0;
-        self.ephemeral_storage_available_bytes = 0;
-        self.ephemeral_storage_capacity_bytes = 0;
-        self.ephemeral_storage_used_bytes = 0;
-        self.ephemeral_storage_inodes_free = 0;
-        self.ephemeral_storage_inodes = 0;
-        self.ephemeral_storage_inodes_used = 0;
-        self.process_count = 0;
-        self.swap_usage_bytes = 0;
-        self.swap_available_bytes = 0;
-        self.swap_usage = 0;
-        self.phase.clear();
-        self.pod_phase = PodPhase::POD_PHASE_UNKNOWN;
-        self.pod_restart_count = 0;
-        self.pod_uptime_seconds = 0;
-        self.owner_references.clear();
-        self.containers.clear();
+        self.name.clear();
+        self.value = 0.;
+        self.string_value.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for KubernetesMetrics {
+impl ::std::fmt::Debug for CustomResourceField {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for KubernetesMetrics {
+impl ::protobuf::reflect::ProtobufValue for CustomResourceField {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OwnerReference {
+pub struct CustomResourceCondition {
     // message fields
-    pub name: ::std::string::String,
-    pub kind: ::std::string::String,
-    pub namespace: ::std::string::String,
+    pub field_type: ::std::string::String,
+    pub status: ConditionStatus,
+    pub reason: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a OwnerReference {
-    fn default() -> &'a OwnerReference {
-        <OwnerReference as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CustomResourceCondition {
+    fn default() -> &'a CustomResourceCondition {
+        <CustomResourceCondition as ::protobuf::Message>::default_instance()
     }
 }
 
-impl OwnerReference {
-    pub fn new() -> OwnerReference {
+impl CustomResourceCondition {
+    pub fn new() -> CustomResourceCondition {
         ::std::default::Default::default()
     }
 
-    // string name = 1;
+    // string type = 1;
 
 
-    pub fn get_name(&self) -> &str {
-        &self.name
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
     }
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = v;
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        &mut self.name
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
     }
 
-    // string kind = 2;
+    // .protocol.kubernetes.ConditionStatus status = 2;
 
 
-    pub fn get_kind(&self) -> &str {
-        &self.kind
+    pub fn get_status(&self) -> ConditionStatus {
+        self.status
     }
-    pub fn clear_kind(&mut self) {
-        self.kind.clear();
+    pub fn clear_status(&mut self) {
+        self.status = ConditionStatus::CONDITION_STATUS_UNKNOWN;
     }
 
     // Param is passed by value, moved
-    pub fn set_kind(&mut self, v: ::std::string::String) {
-        self.kind = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
-        &mut self.kind
-    }
-
-    // Take field
-    pub fn take_kind(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    pub fn set_status(&mut self, v: ConditionStatus) {
+        self.status = v;
     }
 
-    // string namespace = 3;
+    // string reason = 3;
 
 
-    pub fn get_namespace(&self) -> &str {
-        &self.namespace
+    pub fn get_reason(&self) -> &str {
+        &self.reason
     }
-    pub fn clear_namespace(&mut self) {
-        self.namespace.clear();
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_namespace(&mut self, v: ::std::string::String) {
-        self.namespace = v;
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_namespace(&mut self) -> &mut ::std::string::String {
-        &mut self.namespace
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
     }
 
     // Take field
-    pub fn take_namespace(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.namespace, ::std::string::String::new())
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for OwnerReference {
+impl ::protobuf::Message for CustomResourceCondition {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1936,13 +10278,13 @@ impl ::protobuf::Message for OwnerReference {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.namespace)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1956,14 +10298,14 @@ impl ::protobuf::Message for OwnerReference {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.name.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.name);
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
         }
-        if !self.kind.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.kind);
+        if self.status != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(2, self.status);
         }
-        if !self.namespace.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.namespace);
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.reason);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1971,14 +10313,14 @@ impl ::protobuf::Message for OwnerReference {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.name.is_empty() {
-            os.write_string(1, &self.name)?;
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
         }
-        if !self.kind.is_empty() {
-            os.write_string(2, &self.kind)?;
+        if self.status != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.status))?;
         }
-        if !self.namespace.is_empty() {
-            os.write_string(3, &self.namespace)?;
+        if !self.reason.is_empty() {
+            os.write_string(3, &self.reason)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2010,8 +10352,8 @@ impl ::protobuf::Message for OwnerReference {
         Self::descriptor_static()
     }
 
-    fn new() -> OwnerReference {
-        OwnerReference::new()
+    fn new() -> CustomResourceCondition {
+        CustomResourceCondition::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2019,162 +10361,231 @@ impl ::protobuf::Message for OwnerReference {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "name",
-                |m: &OwnerReference| { &m.name },
-                |m: &mut OwnerReference| { &mut m.name },
+                "type",
+                |m: &CustomResourceCondition| { &m.field_type },
+                |m: &mut CustomResourceCondition| { &mut m.field_type },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "kind",
-                |m: &OwnerReference| { &m.kind },
-                |m: &mut OwnerReference| { &mut m.kind },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConditionStatus>>(
+                "status",
+                |m: &CustomResourceCondition| { &m.status },
+                |m: &mut CustomResourceCondition| { &mut m.status },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "namespace",
-                |m: &OwnerReference| { &m.namespace },
-                |m: &mut OwnerReference| { &mut m.namespace },
+                "reason",
+                |m: &CustomResourceCondition| { &m.reason },
+                |m: &mut CustomResourceCondition| { &mut m.reason },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OwnerReference>(
-                "OwnerReference",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CustomResourceCondition>(
+                "CustomResourceCondition",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static OwnerReference {
-        static instance: ::protobuf::rt::LazyV2<OwnerReference> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(OwnerReference::new)
+    fn default_instance() -> &'static CustomResourceCondition {
+        static instance: ::protobuf::rt::LazyV2<CustomResourceCondition> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CustomResourceCondition::new)
     }
 }
 
-impl ::protobuf::Clear for OwnerReference {
+impl ::protobuf::Clear for CustomResourceCondition {
     fn clear(&mut self) {
-        self.name.clear();
-        self.kind.clear();
-        self.namespace.clear();
+        self.field_type.clear();
+        self.status = ConditionStatus::CONDITION_STATUS_UNKNOWN;
+        self.reason.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OwnerReference {
+impl ::std::fmt::Debug for CustomResourceCondition {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OwnerReference {
-    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
+impl ::protobuf::reflect::ProtobufValue for CustomResourceCondition {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CertificateStatus {
+    // message fields
+    pub not_after: i64,
+    pub days_until_expiry: i32,
+    pub common_name: ::std::string::String,
+    pub dns_names: ::protobuf::RepeatedField<::std::string::String>,
+    pub issuer: ::std::string::String,
+    pub secret_name: ::std::string::String,
+    pub ready: ConditionStatus,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CertificateStatus {
+    fn default() -> &'a CertificateStatus {
+        <CertificateStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CertificateStatus {
+    pub fn new() -> CertificateStatus {
+        ::std::default::Default::default()
+    }
+
+    // int64 not_after = 1;
+
+
+    pub fn get_not_after(&self) -> i64 {
+        self.not_after
+    }
+    pub fn clear_not_after(&mut self) {
+        self.not_after = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_not_after(&mut self, v: i64) {
+        self.not_after = v;
+    }
+
+    // int32 days_until_expiry = 2;
+
+
+    pub fn get_days_until_expiry(&self) -> i32 {
+        self.days_until_expiry
+    }
+    pub fn clear_days_until_expiry(&mut self) {
+        self.days_until_expiry = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_days_until_expiry(&mut self, v: i32) {
+        self.days_until_expiry = v;
+    }
+
+    // string common_name = 3;
+
+
+    pub fn get_common_name(&self) -> &str {
+        &self.common_name
+    }
+    pub fn clear_common_name(&mut self) {
+        self.common_name.clear();
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct Container {
-    // message fields
-    pub name: ::std::string::String,
-    pub status: ContainerStatus,
-    pub reason: ::std::string::String,
-    pub exit_code: i32,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
+    // Param is passed by value, moved
+    pub fn set_common_name(&mut self, v: ::std::string::String) {
+        self.common_name = v;
+    }
 
-impl<'a> ::std::default::Default for &'a Container {
-    fn default() -> &'a Container {
-        <Container as ::protobuf::Message>::default_instance()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_common_name(&mut self) -> &mut ::std::string::String {
+        &mut self.common_name
     }
-}
 
-impl Container {
-    pub fn new() -> Container {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_common_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.common_name, ::std::string::String::new())
     }
 
-    // string name = 1;
+    // repeated string dns_names = 4;
 
 
-    pub fn get_name(&self) -> &str {
-        &self.name
+    pub fn get_dns_names(&self) -> &[::std::string::String] {
+        &self.dns_names
     }
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_dns_names(&mut self) {
+        self.dns_names.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = v;
+    pub fn set_dns_names(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.dns_names = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        &mut self.name
+    pub fn mut_dns_names(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.dns_names
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    pub fn take_dns_names(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.dns_names, ::protobuf::RepeatedField::new())
     }
 
-    // .protocol.kubernetes.ContainerStatus status = 2;
+    // string issuer = 5;
 
 
-    pub fn get_status(&self) -> ContainerStatus {
-        self.status
+    pub fn get_issuer(&self) -> &str {
+        &self.issuer
     }
-    pub fn clear_status(&mut self) {
-        self.status = ContainerStatus::CONTAINER_STATUS_UNKNOWN;
+    pub fn clear_issuer(&mut self) {
+        self.issuer.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_status(&mut self, v: ContainerStatus) {
-        self.status = v;
+    pub fn set_issuer(&mut self, v: ::std::string::String) {
+        self.issuer = v;
     }
 
-    // string reason = 3;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_issuer(&mut self) -> &mut ::std::string::String {
+        &mut self.issuer
+    }
+
+    // Take field
+    pub fn take_issuer(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.issuer, ::std::string::String::new())
+    }
 
+    // string secret_name = 6;
 
-    pub fn get_reason(&self) -> &str {
-        &self.reason
+
+    pub fn get_secret_name(&self) -> &str {
+        &self.secret_name
     }
-    pub fn clear_reason(&mut self) {
-        self.reason.clear();
+    pub fn clear_secret_name(&mut self) {
+        self.secret_name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_reason(&mut self, v: ::std::string::String) {
-        self.reason = v;
+    pub fn set_secret_name(&mut self, v: ::std::string::String) {
+        self.secret_name = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
-        &mut self.reason
+    pub fn mut_secret_name(&mut self) -> &mut ::std::string::String {
+        &mut self.secret_name
     }
 
     // Take field
-    pub fn take_reason(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    pub fn take_secret_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret_name, ::std::string::String::new())
     }
 
-    // int32 exit_code = 4;
+    // .protocol.kubernetes.ConditionStatus ready = 7;
 
 
-    pub fn get_exit_code(&self) -> i32 {
-        self.exit_code
+    pub fn get_ready(&self) -> ConditionStatus {
+        self.ready
     }
-    pub fn clear_exit_code(&mut self) {
-        self.exit_code = 0;
+    pub fn clear_ready(&mut self) {
+        self.ready = ConditionStatus::CONDITION_STATUS_UNKNOWN;
     }
 
     // Param is passed by value, moved
-    pub fn set_exit_code(&mut self, v: i32) {
-        self.exit_code = v;
+    pub fn set_ready(&mut self, v: ConditionStatus) {
+        self.ready = v;
     }
 }
 
-impl ::protobuf::Message for Container {
+impl ::protobuf::Message for CertificateStatus {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2184,20 +10595,33 @@ impl ::protobuf::Message for Container {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.not_after = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
-                },
-                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int32()?;
-                    self.exit_code = tmp;
+                    self.days_until_expiry = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.common_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.dns_names)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.issuer)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret_name)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ready, 7, &mut self.unknown_fields)?
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2211,17 +10635,26 @@ impl ::protobuf::Message for Container {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.name.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.name);
+        if self.not_after != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.not_after, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.status != ContainerStatus::CONTAINER_STATUS_UNKNOWN {
-            my_size += ::protobuf::rt::enum_size(2, self.status);
+        if self.days_until_expiry != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.days_until_expiry, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.reason.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.reason);
+        if !self.common_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.common_name);
         }
-        if self.exit_code != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.exit_code, ::protobuf::wire_format::WireTypeVarint);
+        for value in &self.dns_names {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if !self.issuer.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.issuer);
+        }
+        if !self.secret_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.secret_name);
+        }
+        if self.ready != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(7, self.ready);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2229,17 +10662,26 @@ impl ::protobuf::Message for Container {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.name.is_empty() {
-            os.write_string(1, &self.name)?;
+        if self.not_after != 0 {
+            os.write_int64(1, self.not_after)?;
         }
-        if self.status != ContainerStatus::CONTAINER_STATUS_UNKNOWN {
-            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.status))?;
+        if self.days_until_expiry != 0 {
+            os.write_int32(2, self.days_until_expiry)?;
         }
-        if !self.reason.is_empty() {
-            os.write_string(3, &self.reason)?;
+        if !self.common_name.is_empty() {
+            os.write_string(3, &self.common_name)?;
         }
-        if self.exit_code != 0 {
-            os.write_int32(4, self.exit_code)?;
+        for v in &self.dns_names {
+            os.write_string(4, &v)?;
+        };
+        if !self.issuer.is_empty() {
+            os.write_string(5, &self.issuer)?;
+        }
+        if !self.secret_name.is_empty() {
+            os.write_string(6, &self.secret_name)?;
+        }
+        if self.ready != ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            os.write_enum(7, ::protobuf::ProtobufEnum::value(&self.ready))?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2271,65 +10713,83 @@ impl ::protobuf::Message for Container {
         Self::descriptor_static()
     }
 
-    fn new() -> Container {
-        Container::new()
+    fn new() -> CertificateStatus {
+        CertificateStatus::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "not_after",
+                |m: &CertificateStatus| { &m.not_after },
+                |m: &mut CertificateStatus| { &mut m.not_after },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "days_until_expiry",
+                |m: &CertificateStatus| { &m.days_until_expiry },
+                |m: &mut CertificateStatus| { &mut m.days_until_expiry },
+            ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "name",
-                |m: &Container| { &m.name },
-                |m: &mut Container| { &mut m.name },
+                "common_name",
+                |m: &CertificateStatus| { &m.common_name },
+                |m: &mut CertificateStatus| { &mut m.common_name },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ContainerStatus>>(
-                "status",
-                |m: &Container| { &m.status },
-                |m: &mut Container| { &mut m.status },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dns_names",
+                |m: &CertificateStatus| { &m.dns_names },
+                |m: &mut CertificateStatus| { &mut m.dns_names },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "reason",
-                |m: &Container| { &m.reason },
-                |m: &mut Container| { &mut m.reason },
+                "issuer",
+                |m: &CertificateStatus| { &m.issuer },
+                |m: &mut CertificateStatus| { &mut m.issuer },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                "exit_code",
-                |m: &Container| { &m.exit_code },
-                |m: &mut Container| { &mut m.exit_code },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret_name",
+                |m: &CertificateStatus| { &m.secret_name },
+                |m: &mut CertificateStatus| { &mut m.secret_name },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Container>(
-                "Container",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConditionStatus>>(
+                "ready",
+                |m: &CertificateStatus| { &m.ready },
+                |m: &mut CertificateStatus| { &mut m.ready },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CertificateStatus>(
+                "CertificateStatus",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Container {
-        static instance: ::protobuf::rt::LazyV2<Container> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Container::new)
+    fn default_instance() -> &'static CertificateStatus {
+        static instance: ::protobuf::rt::LazyV2<CertificateStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CertificateStatus::new)
     }
 }
 
-impl ::protobuf::Clear for Container {
+impl ::protobuf::Clear for CertificateStatus {
     fn clear(&mut self) {
-        self.name.clear();
-        self.status = ContainerStatus::CONTAINER_STATUS_UNKNOWN;
-        self.reason.clear();
-        self.exit_code = 0;
+        self.not_after = 0;
+        self.days_until_expiry = 0;
+        self.common_name.clear();
+        self.dns_names.clear();
+        self.issuer.clear();
+        self.secret_name.clear();
+        self.ready = ConditionStatus::CONDITION_STATUS_UNKNOWN;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Container {
+impl ::std::fmt::Debug for CertificateStatus {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Container {
+impl ::protobuf::reflect::ProtobufValue for CertificateStatus {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -2616,8 +11076,179 @@ impl ::protobuf::reflect::ProtobufValue for ContainerStatus {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ConditionStatus {
+    CONDITION_STATUS_UNKNOWN = 0,
+    CONDITION_STATUS_TRUE = 1,
+    CONDITION_STATUS_FALSE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ConditionStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ConditionStatus> {
+        match value {
+            0 => ::std::option::Option::Some(ConditionStatus::CONDITION_STATUS_UNKNOWN),
+            1 => ::std::option::Option::Some(ConditionStatus::CONDITION_STATUS_TRUE),
+            2 => ::std::option::Option::Some(ConditionStatus::CONDITION_STATUS_FALSE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ConditionStatus] = &[
+            ConditionStatus::CONDITION_STATUS_UNKNOWN,
+            ConditionStatus::CONDITION_STATUS_TRUE,
+            ConditionStatus::CONDITION_STATUS_FALSE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ConditionStatus>("ConditionStatus", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ConditionStatus {
+}
+
+impl ::std::default::Default for ConditionStatus {
+    fn default() -> Self {
+        ConditionStatus::CONDITION_STATUS_UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConditionStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum PersistentVolumeClaimPhase {
+    PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN = 0,
+    PERSISTENT_VOLUME_CLAIM_PHASE_PENDING = 1,
+    PERSISTENT_VOLUME_CLAIM_PHASE_BOUND = 2,
+    PERSISTENT_VOLUME_CLAIM_PHASE_LOST = 3,
+}
+
+impl ::protobuf::ProtobufEnum for PersistentVolumeClaimPhase {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<PersistentVolumeClaimPhase> {
+        match value {
+            0 => ::std::option::Option::Some(PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN),
+            1 => ::std::option::Option::Some(PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_PENDING),
+            2 => ::std::option::Option::Some(PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_BOUND),
+            3 => ::std::option::Option::Some(PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_LOST),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [PersistentVolumeClaimPhase] = &[
+            PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN,
+            PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_PENDING,
+            PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_BOUND,
+            PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_LOST,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<PersistentVolumeClaimPhase>("PersistentVolumeClaimPhase", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for PersistentVolumeClaimPhase {
+}
+
+impl ::std::default::Default for PersistentVolumeClaimPhase {
+    fn default() -> Self {
+        PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PersistentVolumeClaimPhase {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum PersistentVolumePhase {
+    PERSISTENT_VOLUME_PHASE_UNKNOWN = 0,
+    PERSISTENT_VOLUME_PHASE_PENDING = 1,
+    PERSISTENT_VOLUME_PHASE_AVAILABLE = 2,
+    PERSISTENT_VOLUME_PHASE_BOUND = 3,
+    PERSISTENT_VOLUME_PHASE_RELEASED = 4,
+    PERSISTENT_VOLUME_PHASE_FAILED = 5,
+}
+
+impl ::protobuf::ProtobufEnum for PersistentVolumePhase {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<PersistentVolumePhase> {
+        match value {
+            0 => ::std::option::Option::Some(PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN),
+            1 => ::std::option::Option::Some(PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_PENDING),
+            2 => ::std::option::Option::Some(PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_AVAILABLE),
+            3 => ::std::option::Option::Some(PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_BOUND),
+            4 => ::std::option::Option::Some(PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_RELEASED),
+            5 => ::std::option::Option::Some(PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_FAILED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [PersistentVolumePhase] = &[
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN,
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_PENDING,
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_AVAILABLE,
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_BOUND,
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_RELEASED,
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_FAILED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<PersistentVolumePhase>("PersistentVolumePhase", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for PersistentVolumePhase {
+}
+
+impl ::std::default::Default for PersistentVolumePhase {
+    fn default() -> Self {
+        PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PersistentVolumePhase {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10kubernetes.proto\x12\x13protocol.kubernetes\"\x9d\x11\n\x11Kuberne\
+    \n\x10kubernetes.proto\x12\x13protocol.kubernetes\"\xab\x1d\n\x11Kuberne\
     tesMetrics\x12\x17\n\x07site_id\x18\x20\x20\x01(\tR\x06siteId\x12\x1b\n\
     \tnode_name\x18\x01\x20\x01(\tR\x08nodeName\x12\x19\n\x08pod_name\x18\
     \x02\x20\x01(\tR\x07podName\x12#\n\rpod_namespace\x18!\x20\x01(\tR\x0cpo\
@@ -2663,191 +11294,830 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     nds\x18+\x20\x01(\x03R\x10podUptimeSeconds\x12N\n\x10owner_references\
     \x18,\x20\x03(\x0b2#.protocol.kubernetes.OwnerReferenceR\x0fownerReferen\
     ces\x12>\n\ncontainers\x181\x20\x03(\x0b2\x1e.protocol.kubernetes.Contai\
-    nerR\ncontainers\x1a9\n\x0bLabelsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\
-    \tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"V\
-    \n\x0eOwnerReference\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\
-    \x12\n\x04kind\x18\x02\x20\x01(\tR\x04kind\x12\x1c\n\tnamespace\x18\x03\
-    \x20\x01(\tR\tnamespace\"\x92\x01\n\tContainer\x12\x12\n\x04name\x18\x01\
-    \x20\x01(\tR\x04name\x12<\n\x06status\x18\x02\x20\x01(\x0e2$.protocol.ku\
-    bernetes.ContainerStatusR\x06status\x12\x16\n\x06reason\x18\x03\x20\x01(\
-    \tR\x06reason\x12\x1b\n\texit_code\x18\x04\x20\x01(\x05R\x08exitCode\"Z\
-    \n\x16KubernetesMetricsBatch\x12@\n\x07metrics\x18\x01\x20\x03(\x0b2&.pr\
-    otocol.kubernetes.KubernetesMetricsR\x07metrics*~\n\x08PodPhase\x12\x15\
-    \n\x11POD_PHASE_UNKNOWN\x10\0\x12\x15\n\x11POD_PHASE_PENDING\x10\x01\x12\
-    \x15\n\x11POD_PHASE_RUNNING\x10\x02\x12\x17\n\x13POD_PHASE_SUCCEEDED\x10\
-    \x03\x12\x14\n\x10POD_PHASE_FAILED\x10\x04*\x8c\x01\n\x0fContainerStatus\
-    \x12\x1c\n\x18CONTAINER_STATUS_UNKNOWN\x10\0\x12\x1c\n\x18CONTAINER_STAT\
-    US_WAITING\x10\x01\x12\x1c\n\x18CONTAINER_STATUS_RUNNING\x10\x02\x12\x1f\
-    \n\x1bCONTAINER_STATUS_TERMINATED\x10\x03J\xa3\x1c\n\x06\x12\x04\0\0R\
-    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\x1c\n\
-    \n\n\x02\x05\0\x12\x04\x03\0\t\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\x05\r\
-    \n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\x18\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x04\x02\x13\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x04\x16\x17\n\
-    \x0b\n\x04\x05\0\x02\x01\x12\x03\x05\x02\x18\n\x0c\n\x05\x05\0\x02\x01\
-    \x01\x12\x03\x05\x02\x13\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x05\x16\
-    \x17\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x06\x02\x18\n\x0c\n\x05\x05\0\x02\
-    \x02\x01\x12\x03\x06\x02\x13\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x06\
-    \x16\x17\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x07\x02\x1a\n\x0c\n\x05\x05\0\
-    \x02\x03\x01\x12\x03\x07\x02\x15\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\
-    \x07\x18\x19\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x08\x02\x17\n\x0c\n\x05\
-    \x05\0\x02\x04\x01\x12\x03\x08\x02\x12\n\x0c\n\x05\x05\0\x02\x04\x02\x12\
-    \x03\x08\x15\x16\n\n\n\x02\x05\x01\x12\x04\x0b\0\x10\x01\n\n\n\x03\x05\
-    \x01\x01\x12\x03\x0b\x05\x14\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0c\x02\
-    \x1f\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x1a\n\x0c\n\x05\x05\
-    \x01\x02\0\x02\x12\x03\x0c\x1d\x1e\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\r\
-    \x02\x1f\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\x1a\n\x0c\n\x05\
-    \x05\x01\x02\x01\x02\x12\x03\r\x1d\x1e\n\x0b\n\x04\x05\x01\x02\x02\x12\
-    \x03\x0e\x02\x1f\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x0e\x02\x1a\n\
-    \x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x0e\x1d\x1e\n\x0b\n\x04\x05\x01\
+    nerR\ncontainers\x12B\n\x08resource\x183\x20\x01(\x0b2&.protocol.kuberne\
+    tes.ResourceReferenceR\x08resource\x12?\n\x08workload\x184\x20\x01(\x0b2\
+    #.protocol.kubernetes.WorkloadStatusR\x08workload\x12<\n\x07rollout\x185\
+    \x20\x01(\x0b2\".protocol.kubernetes.RolloutStatusR\x07rollout\x120\n\
+    \x03job\x186\x20\x01(\x0b2\x1e.protocol.kubernetes.JobStatusR\x03job\x12\
+    =\n\x08cron_job\x187\x20\x01(\x0b2\".protocol.kubernetes.CronJobStatusR\
+    \x07cronJob\x12:\n\x05event\x188\x20\x01(\x0b2$.protocol.kubernetes.Kube\
+    rnetesEventR\x05event\x12h\n\x17persistent_volume_claim\x189\x20\x01(\
+    \x0b20.protocol.kubernetes.PersistentVolumeClaimStatusR\x15persistentVol\
+    umeClaim\x12X\n\x11persistent_volume\x18:\x20\x01(\x0b2+.protocol.kubern\
+    etes.PersistentVolumeStatusR\x10persistentVolume\x12n\n\x19horizontal_po\
+    d_autoscaler\x18;\x20\x01(\x0b22.protocol.kubernetes.HorizontalPodAutosc\
+    alerStatusR\x17horizontalPodAutoscaler\x12O\n\x0eresource_quota\x18<\x20\
+    \x01(\x0b2(.protocol.kubernetes.ResourceQuotaStatusR\rresourceQuota\x12F\
+    \n\x0blimit_range\x18=\x20\x01(\x0b2%.protocol.kubernetes.LimitRangeStat\
+    usR\nlimitRange\x12<\n\x07service\x18>\x20\x01(\x0b2\".protocol.kubernet\
+    es.ServiceStatusR\x07service\x126\n\x05route\x18?\x20\x01(\x0b2\x20.prot\
+    ocol.kubernetes.RouteStatusR\x05route\x12R\n\x0fcustom_resource\x18@\x20\
+    \x01(\x0b2).protocol.kubernetes.CustomResourceStatusR\x0ecustomResource\
+    \x12H\n\x0bcertificate\x18A\x20\x01(\x0b2&.protocol.kubernetes.Certifica\
+    teStatusR\x0bcertificate\x12Y\n\x0bannotations\x18B\x20\x03(\x0b27.proto\
+    col.kubernetes.KubernetesMetrics.AnnotationsEntryR\x0bannotations\x12!\n\
+    \x0ccluster_name\x18C\x20\x01(\tR\x0bclusterName\x12\x1d\n\ncluster_id\
+    \x18D\x20\x01(\tR\tclusterId\x12\x14\n\x05shard\x18E\x20\x01(\tR\x05shar\
+    d\x12&\n\x0fcpu_cfs_periods\x18F\x20\x01(\x03R\rcpuCfsPeriods\x129\n\x19\
+    cpu_cfs_throttled_periods\x18G\x20\x01(\x03R\x16cpuCfsThrottledPeriods\
+    \x12%\n\x0ecpu_throttling\x18H\x20\x01(\x05R\rcpuThrottling\x12>\n\x08pr\
+    essure\x18I\x20\x01(\x0b2\".protocol.kubernetes.PressureStallR\x08pressu\
+    re\x1a9\n\x0bLabelsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\
+    \x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\x1a>\n\x10Annotat\
+    ionsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\
+    \x18\x02\x20\x01(\tR\x05value:\x028\x01\"V\n\x0eOwnerReference\x12\x12\n\
+    \x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04kind\x18\x02\x20\x01(\
+    \tR\x04kind\x12\x1c\n\tnamespace\x18\x03\x20\x01(\tR\tnamespace\"\xdc\
+    \x02\n\tContainer\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12<\n\
+    \x06status\x18\x02\x20\x01(\x0e2$.protocol.kubernetes.ContainerStatusR\
+    \x06status\x12\x16\n\x06reason\x18\x03\x20\x01(\tR\x06reason\x12\x1b\n\t\
+    exit_code\x18\x04\x20\x01(\x05R\x08exitCode\x12&\n\x0fcpu_cfs_periods\
+    \x18\x05\x20\x01(\x03R\rcpuCfsPeriods\x129\n\x19cpu_cfs_throttled_period\
+    s\x18\x06\x20\x01(\x03R\x16cpuCfsThrottledPeriods\x12%\n\x0ecpu_throttli\
+    ng\x18\x07\x20\x01(\x05R\rcpuThrottling\x12>\n\x08pressure\x18\x08\x20\
+    \x01(\x0b2\".protocol.kubernetes.PressureStallR\x08pressure\"\xb9\x01\n\
+    \rPressureStall\x12\x19\n\x08cpu_some\x18\x01\x20\x01(\x01R\x07cpuSome\
+    \x12\x19\n\x08cpu_full\x18\x02\x20\x01(\x01R\x07cpuFull\x12\x1f\n\x0bmem\
+    ory_some\x18\x03\x20\x01(\x01R\nmemorySome\x12\x1f\n\x0bmemory_full\x18\
+    \x04\x20\x01(\x01R\nmemoryFull\x12\x17\n\x07io_some\x18\x05\x20\x01(\x01\
+    R\x06ioSome\x12\x17\n\x07io_full\x18\x06\x20\x01(\x01R\x06ioFull\"Y\n\
+    \x11ResourceReference\x12\x12\n\x04kind\x18\x01\x20\x01(\tR\x04kind\x12\
+    \x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1c\n\tnamespace\x18\x03\
+    \x20\x01(\tR\tnamespace\"\x92\x01\n\x0eWorkloadStatus\x12\x12\n\x04pods\
+    \x18\x01\x20\x01(\x05R\x04pods\x12!\n\x0cpods_running\x18\x02\x20\x01(\
+    \x05R\x0bpodsRunning\x12$\n\x0epods_not_ready\x18\x03\x20\x01(\x05R\x0cp\
+    odsNotReady\x12#\n\rrestart_count\x18\x04\x20\x01(\x05R\x0crestartCount\
+    \"\xb6\x03\n\rRolloutStatus\x12)\n\x10desired_replicas\x18\x01\x20\x01(\
+    \x05R\x0fdesiredReplicas\x12)\n\x10updated_replicas\x18\x02\x20\x01(\x05\
+    R\x0fupdatedReplicas\x12%\n\x0eready_replicas\x18\x03\x20\x01(\x05R\rrea\
+    dyReplicas\x12-\n\x12available_replicas\x18\x04\x20\x01(\x05R\x11availab\
+    leReplicas\x121\n\x14unavailable_replicas\x18\x05\x20\x01(\x05R\x13unava\
+    ilableReplicas\x12\x1e\n\ngeneration\x18\x06\x20\x01(\x03R\ngeneration\
+    \x12/\n\x13observed_generation\x18\x07\x20\x01(\x03R\x12observedGenerati\
+    on\x12F\n\x0bprogressing\x18\x08\x20\x01(\x0e2$.protocol.kubernetes.Cond\
+    itionStatusR\x0bprogressing\x12-\n\x12progressing_reason\x18\t\x20\x01(\
+    \tR\x11progressingReason\"\x9c\x02\n\tJobStatus\x12\x1d\n\nstart_time\
+    \x18\x01\x20\x01(\x03R\tstartTime\x12'\n\x0fcompletion_time\x18\x02\x20\
+    \x01(\x03R\x0ecompletionTime\x12)\n\x10duration_seconds\x18\x03\x20\x01(\
+    \x03R\x0fdurationSeconds\x12\x16\n\x06active\x18\x04\x20\x01(\x05R\x06ac\
+    tive\x12\x1c\n\tsucceeded\x18\x05\x20\x01(\x05R\tsucceeded\x12\x16\n\x06\
+    failed\x18\x06\x20\x01(\x05R\x06failed\x12\x1a\n\x08complete\x18\x07\x20\
+    \x01(\x08R\x08complete\x122\n\x15backoff_limit_reached\x18\x08\x20\x01(\
+    \x08R\x13backoffLimitReached\"\xbf\x01\n\rCronJobStatus\x12,\n\x12last_s\
+    chedule_time\x18\x01\x20\x01(\x03R\x10lastScheduleTime\x120\n\x14last_su\
+    ccessful_time\x18\x02\x20\x01(\x03R\x12lastSuccessfulTime\x12\x16\n\x06a\
+    ctive\x18\x03\x20\x01(\x05R\x06active\x12\x1c\n\tsuspended\x18\x04\x20\
+    \x01(\x08R\tsuspended\x12\x18\n\x07overdue\x18\x05\x20\x01(\x08R\x07over\
+    due\"\x82\x02\n\x0fKubernetesEvent\x12\x12\n\x04type\x18\x01\x20\x01(\tR\
+    \x04type\x12\x16\n\x06reason\x18\x02\x20\x01(\tR\x06reason\x12\x12\n\x04\
+    note\x18\x03\x20\x01(\tR\x04note\x12\x16\n\x06action\x18\x04\x20\x01(\tR\
+    \x06action\x121\n\x14reporting_controller\x18\x05\x20\x01(\tR\x13reporti\
+    ngController\x12\x14\n\x05count\x18\x06\x20\x01(\x05R\x05count\x12'\n\
+    \x0ffirst_timestamp\x18\x07\x20\x01(\x03R\x0efirstTimestamp\x12%\n\x0ela\
+    st_timestamp\x18\x08\x20\x01(\x03R\rlastTimestamp\"\xcf\x02\n\x1bPersist\
+    entVolumeClaimStatus\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\
+    \x1c\n\tnamespace\x18\x02\x20\x01(\tR\tnamespace\x12#\n\rstorage_class\
+    \x18\x03\x20\x01(\tR\x0cstorageClass\x12E\n\x05phase\x18\x04\x20\x01(\
+    \x0e2/.protocol.kubernetes.PersistentVolumeClaimPhaseR\x05phase\x12'\n\
+    \x0frequested_bytes\x18\x05\x20\x01(\x03R\x0erequestedBytes\x12%\n\x0eca\
+    pacity_bytes\x18\x06\x20\x01(\x03R\rcapacityBytes\x12!\n\x0caccess_modes\
+    \x18\x07\x20\x03(\tR\x0baccessModes\x12\x1f\n\x0bvolume_name\x18\x08\x20\
+    \x01(\tR\nvolumeName\"\xb8\x02\n\x16PersistentVolumeStatus\x12@\n\x05pha\
+    se\x18\x01\x20\x01(\x0e2*.protocol.kubernetes.PersistentVolumePhaseR\x05\
+    phase\x12%\n\x0ecapacity_bytes\x18\x02\x20\x01(\x03R\rcapacityBytes\x12!\
+    \n\x0caccess_modes\x18\x03\x20\x03(\tR\x0baccessModes\x12%\n\x0ereclaim_\
+    policy\x18\x04\x20\x01(\tR\rreclaimPolicy\x12#\n\rstorage_class\x18\x05\
+    \x20\x01(\tR\x0cstorageClass\x12\x1d\n\nclaim_name\x18\x06\x20\x01(\tR\t\
+    claimName\x12'\n\x0fclaim_namespace\x18\x07\x20\x01(\tR\x0eclaimNamespac\
+    e\"\xf0\x04\n\x1dHorizontalPodAutoscalerStatus\x12I\n\x0cscale_target\
+    \x18\x01\x20\x01(\x0b2&.protocol.kubernetes.ResourceReferenceR\x0bscaleT\
+    arget\x12)\n\x10current_replicas\x18\x02\x20\x01(\x05R\x0fcurrentReplica\
+    s\x12)\n\x10desired_replicas\x18\x03\x20\x01(\x05R\x0fdesiredReplicas\
+    \x12!\n\x0cmin_replicas\x18\x04\x20\x01(\x05R\x0bminReplicas\x12!\n\x0cm\
+    ax_replicas\x18\x05\x20\x01(\x05R\x0bmaxReplicas\x12H\n\rable_to_scale\
+    \x18\x06\x20\x01(\x0e2$.protocol.kubernetes.ConditionStatusR\x0bableToSc\
+    ale\x12K\n\x0escaling_active\x18\x07\x20\x01(\x0e2$.protocol.kubernetes.\
+    ConditionStatusR\rscalingActive\x12M\n\x0fscaling_limited\x18\x08\x20\
+    \x01(\x0e2$.protocol.kubernetes.ConditionStatusR\x0escalingLimited\x124\
+    \n\x16scaling_limited_reason\x18\t\x20\x01(\tR\x14scalingLimitedReason\
+    \x12L\n\x07metrics\x18\n\x20\x03(\x0b22.protocol.kubernetes.HorizontalPo\
+    dAutoscalerMetricR\x07metrics\"\xce\x01\n\x1dHorizontalPodAutoscalerMetr\
+    ic\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04type\x12\x12\n\x04name\x18\
+    \x02\x20\x01(\tR\x04name\x12\x1c\n\tcontainer\x18\x03\x20\x01(\tR\tconta\
+    iner\x12\x1f\n\x0btarget_type\x18\x04\x20\x01(\tR\ntargetType\x12!\n\x0c\
+    target_value\x18\x05\x20\x01(\x01R\x0btargetValue\x12#\n\rcurrent_value\
+    \x18\x06\x20\x01(\x01R\x0ccurrentValue\"|\n\x13ResourceQuotaStatus\x12H\
+    \n\tresources\x18\x01\x20\x03(\x0b2*.protocol.kubernetes.ResourceQuotaRe\
+    sourceR\tresources\x12\x1b\n\tmax_usage\x18\x02\x20\x01(\x05R\x08maxUsag\
+    e\"i\n\x15ResourceQuotaResource\x12\x12\n\x04name\x18\x01\x20\x01(\tR\
+    \x04name\x12\x12\n\x04hard\x18\x02\x20\x01(\x01R\x04hard\x12\x12\n\x04us\
+    ed\x18\x03\x20\x01(\x01R\x04used\x12\x14\n\x05usage\x18\x04\x20\x01(\x05\
+    R\x05usage\"P\n\x10LimitRangeStatus\x12<\n\x06limits\x18\x01\x20\x03(\
+    \x0b2$.protocol.kubernetes.LimitRangeLimitR\x06limits\"\xea\x01\n\x0fLim\
+    itRangeLimit\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04type\x12\x1a\n\x08\
+    resource\x18\x02\x20\x01(\tR\x08resource\x12\x10\n\x03min\x18\x03\x20\
+    \x01(\x01R\x03min\x12\x10\n\x03max\x18\x04\x20\x01(\x01R\x03max\x12#\n\r\
+    default_limit\x18\x05\x20\x01(\x01R\x0cdefaultLimit\x12'\n\x0fdefault_re\
+    quest\x18\x06\x20\x01(\x01R\x0edefaultRequest\x125\n\x17max_limit_reques\
+    t_ratio\x18\x07\x20\x01(\x01R\x14maxLimitRequestRatio\"\xf8\x01\n\rServi\
+    ceStatus\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04type\x12'\n\x0fready_e\
+    ndpoints\x18\x02\x20\x01(\x05R\x0ereadyEndpoints\x12.\n\x13not_ready_end\
+    points\x18\x03\x20\x01(\x05R\x11notReadyEndpoints\x123\n\x15terminating_\
+    endpoints\x18\x04\x20\x01(\x05R\x14terminatingEndpoints\x12!\n\x0chas_se\
+    lector\x18\x05\x20\x01(\x08R\x0bhasSelector\x12\"\n\rno_ready_pods\x18\
+    \x06\x20\x01(\x08R\x0bnoReadyPods\"\x94\x02\n\x0bRouteStatus\x12\x1d\n\n\
+    class_name\x18\x01\x20\x01(\tR\tclassName\x12\x1c\n\thostnames\x18\x02\
+    \x20\x03(\tR\thostnames\x12@\n\x07parents\x18\x03\x20\x03(\x0b2&.protoco\
+    l.kubernetes.ResourceReferenceR\x07parents\x12=\n\x08backends\x18\x04\
+    \x20\x03(\x0b2!.protocol.kubernetes.RouteBackendR\x08backends\x12G\n\x20\
+    backends_without_ready_endpoints\x18\x05\x20\x01(\x05R\x1dbackendsWithou\
+    tReadyEndpoints\"\xbb\x01\n\x0cRouteBackend\x12@\n\x07service\x18\x01\
+    \x20\x01(\x0b2&.protocol.kubernetes.ResourceReferenceR\x07service\x12\
+    \x12\n\x04port\x18\x02\x20\x01(\x05R\x04port\x12'\n\x0fservice_missing\
+    \x18\x03\x20\x01(\x08R\x0eserviceMissing\x12,\n\x12no_ready_endpoints\
+    \x18\x04\x20\x01(\x08R\x10noReadyEndpoints\"\xc7\x01\n\x14CustomResource\
+    Status\x12\x1f\n\x0bapi_version\x18\x01\x20\x01(\tR\napiVersion\x12@\n\
+    \x06fields\x18\x02\x20\x03(\x0b2(.protocol.kubernetes.CustomResourceFiel\
+    dR\x06fields\x12L\n\nconditions\x18\x03\x20\x03(\x0b2,.protocol.kubernet\
+    es.CustomResourceConditionR\nconditions\"b\n\x13CustomResourceField\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05value\x18\x02\
+    \x20\x01(\x01R\x05value\x12!\n\x0cstring_value\x18\x03\x20\x01(\tR\x0bst\
+    ringValue\"\x83\x01\n\x17CustomResourceCondition\x12\x12\n\x04type\x18\
+    \x01\x20\x01(\tR\x04type\x12<\n\x06status\x18\x02\x20\x01(\x0e2$.protoco\
+    l.kubernetes.ConditionStatusR\x06status\x12\x16\n\x06reason\x18\x03\x20\
+    \x01(\tR\x06reason\"\x8f\x02\n\x11CertificateStatus\x12\x1b\n\tnot_after\
+    \x18\x01\x20\x01(\x03R\x08notAfter\x12*\n\x11days_until_expiry\x18\x02\
+    \x20\x01(\x05R\x0fdaysUntilExpiry\x12\x1f\n\x0bcommon_name\x18\x03\x20\
+    \x01(\tR\ncommonName\x12\x1b\n\tdns_names\x18\x04\x20\x03(\tR\x08dnsName\
+    s\x12\x16\n\x06issuer\x18\x05\x20\x01(\tR\x06issuer\x12\x1f\n\x0bsecret_\
+    name\x18\x06\x20\x01(\tR\nsecretName\x12:\n\x05ready\x18\x07\x20\x01(\
+    \x0e2$.protocol.kubernetes.ConditionStatusR\x05ready\"Z\n\x16KubernetesM\
+    etricsBatch\x12@\n\x07metrics\x18\x01\x20\x03(\x0b2&.protocol.kubernetes\
+    .KubernetesMetricsR\x07metrics*~\n\x08PodPhase\x12\x15\n\x11POD_PHASE_UN\
+    KNOWN\x10\0\x12\x15\n\x11POD_PHASE_PENDING\x10\x01\x12\x15\n\x11POD_PHAS\
+    E_RUNNING\x10\x02\x12\x17\n\x13POD_PHASE_SUCCEEDED\x10\x03\x12\x14\n\x10\
+    POD_PHASE_FAILED\x10\x04*\x8c\x01\n\x0fContainerStatus\x12\x1c\n\x18CONT\
+    AINER_STATUS_UNKNOWN\x10\0\x12\x1c\n\x18CONTAINER_STATUS_WAITING\x10\x01\
+    \x12\x1c\n\x18CONTAINER_STATUS_RUNNING\x10\x02\x12\x1f\n\x1bCONTAINER_ST\
+    ATUS_TERMINATED\x10\x03*f\n\x0fConditionStatus\x12\x1c\n\x18CONDITION_ST\
+    ATUS_UNKNOWN\x10\0\x12\x19\n\x15CONDITION_STATUS_TRUE\x10\x01\x12\x1a\n\
+    \x16CONDITION_STATUS_FALSE\x10\x02*\xc3\x01\n\x1aPersistentVolumeClaimPh\
+    ase\x12)\n%PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN\x10\0\x12)\n%PERSISTENT\
+    _VOLUME_CLAIM_PHASE_PENDING\x10\x01\x12'\n#PERSISTENT_VOLUME_CLAIM_PHASE\
+    _BOUND\x10\x02\x12&\n\"PERSISTENT_VOLUME_CLAIM_PHASE_LOST\x10\x03*\xf5\
+    \x01\n\x15PersistentVolumePhase\x12#\n\x1fPERSISTENT_VOLUME_PHASE_UNKNOW\
+    N\x10\0\x12#\n\x1fPERSISTENT_VOLUME_PHASE_PENDING\x10\x01\x12%\n!PERSIST\
+    ENT_VOLUME_PHASE_AVAILABLE\x10\x02\x12!\n\x1dPERSISTENT_VOLUME_PHASE_BOU\
+    ND\x10\x03\x12$\n\x20PERSISTENT_VOLUME_PHASE_RELEASED\x10\x04\x12\"\n\
+    \x1ePERSISTENT_VOLUME_PHASE_FAILED\x10\x05J\xbai\n\x07\x12\x05\0\0\xbc\
+    \x02\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\
+    \x1c\n\n\n\x02\x05\0\x12\x04\x03\0\t\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\
+    \x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\x18\n\x0c\n\x05\x05\0\x02\
+    \0\x01\x12\x03\x04\x02\x13\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x04\x16\
+    \x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x05\x02\x18\n\x0c\n\x05\x05\0\x02\
+    \x01\x01\x12\x03\x05\x02\x13\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x05\
+    \x16\x17\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x06\x02\x18\n\x0c\n\x05\x05\0\
+    \x02\x02\x01\x12\x03\x06\x02\x13\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\
+    \x06\x16\x17\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x07\x02\x1a\n\x0c\n\x05\
+    \x05\0\x02\x03\x01\x12\x03\x07\x02\x15\n\x0c\n\x05\x05\0\x02\x03\x02\x12\
+    \x03\x07\x18\x19\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x08\x02\x17\n\x0c\n\
+    \x05\x05\0\x02\x04\x01\x12\x03\x08\x02\x12\n\x0c\n\x05\x05\0\x02\x04\x02\
+    \x12\x03\x08\x15\x16\n\n\n\x02\x05\x01\x12\x04\x0b\0\x10\x01\n\n\n\x03\
+    \x05\x01\x01\x12\x03\x0b\x05\x14\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0c\
+    \x02\x1f\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x1a\n\x0c\n\x05\
+    \x05\x01\x02\0\x02\x12\x03\x0c\x1d\x1e\n\x0b\n\x04\x05\x01\x02\x01\x12\
+    \x03\r\x02\x1f\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\x1a\n\x0c\n\
+    \x05\x05\x01\x02\x01\x02\x12\x03\r\x1d\x1e\n\x0b\n\x04\x05\x01\x02\x02\
+    \x12\x03\x0e\x02\x1f\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x0e\x02\x1a\
+    \n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x0e\x1d\x1e\n\x0b\n\x04\x05\x01\
     \x02\x03\x12\x03\x0f\x02\"\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x0f\
-    \x02\x1d\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x0f\x20!\n\n\n\x02\x04\
-    \0\x12\x04\x12\0A\x01\n\n\n\x03\x04\0\x01\x12\x03\x12\x08\x19\n\x0b\n\
-    \x04\x04\0\x02\0\x12\x03\x13\x02\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
-    \x13\x02\x08\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x13\t\x10\n\x0c\n\x05\
-    \x04\0\x02\0\x03\x12\x03\x13\x13\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
-    \x14\x02\x17\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x14\x02\x08\n\x0c\n\
-    \x05\x04\0\x02\x01\x01\x12\x03\x14\t\x12\n\x0c\n\x05\x04\0\x02\x01\x03\
-    \x12\x03\x14\x15\x16\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x15\x02\x16\n\x0c\
-    \n\x05\x04\0\x02\x02\x05\x12\x03\x15\x02\x08\n\x0c\n\x05\x04\0\x02\x02\
-    \x01\x12\x03\x15\t\x11\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x15\x14\x15\
-    \n\x0b\n\x04\x04\0\x02\x03\x12\x03\x16\x02\x1c\n\x0c\n\x05\x04\0\x02\x03\
-    \x05\x12\x03\x16\x02\x08\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x16\t\x16\
-    \n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x16\x19\x1b\n\x0b\n\x04\x04\0\x02\
-    \x04\x12\x03\x17\x02\x17\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x17\x02\
-    \x08\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x17\t\x11\n\x0c\n\x05\x04\0\
-    \x02\x04\x03\x12\x03\x17\x14\x16\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x18\
-    \x02\x1a\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x18\x02\x08\n\x0c\n\x05\
-    \x04\0\x02\x05\x01\x12\x03\x18\t\x14\n\x0c\n\x05\x04\0\x02\x05\x03\x12\
-    \x03\x18\x17\x19\n\x0b\n\x04\x04\0\x02\x06\x12\x03\x19\x02\x17\n\x0c\n\
-    \x05\x04\0\x02\x06\x05\x12\x03\x19\x02\x07\n\x0c\n\x05\x04\0\x02\x06\x01\
-    \x12\x03\x19\x08\x11\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\x19\x14\x16\n\
-    \x0b\n\x04\x04\0\x02\x07\x12\x03\x1a\x02\"\n\x0c\n\x05\x04\0\x02\x07\x06\
-    \x12\x03\x1a\x02\x15\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\x1a\x16\x1c\n\
-    \x0c\n\x05\x04\0\x02\x07\x03\x12\x03\x1a\x1f!\n\x0b\n\x04\x04\0\x02\x08\
-    \x12\x03\x1b\x02!\n\x0c\n\x05\x04\0\x02\x08\x05\x12\x03\x1b\x02\x07\n\
-    \x0c\n\x05\x04\0\x02\x08\x01\x12\x03\x1b\x08\x1c\n\x0c\n\x05\x04\0\x02\
-    \x08\x03\x12\x03\x1b\x1f\x20\n\x0b\n\x04\x04\0\x02\t\x12\x03\x1c\x02(\n\
-    \x0c\n\x05\x04\0\x02\t\x05\x12\x03\x1c\x02\x07\n\x0c\n\x05\x04\0\x02\t\
-    \x01\x12\x03\x1c\x08#\n\x0c\n\x05\x04\0\x02\t\x03\x12\x03\x1c&'\n\x0b\n\
-    \x04\x04\0\x02\n\x12\x03\x1d\x02#\n\x0c\n\x05\x04\0\x02\n\x05\x12\x03\
-    \x1d\x02\x07\n\x0c\n\x05\x04\0\x02\n\x01\x12\x03\x1d\x08\x1e\n\x0c\n\x05\
-    \x04\0\x02\n\x03\x12\x03\x1d!\"\n\x0b\n\x04\x04\0\x02\x0b\x12\x03\x1e\
-    \x02\x1f\n\x0c\n\x05\x04\0\x02\x0b\x05\x12\x03\x1e\x02\x07\n\x0c\n\x05\
-    \x04\0\x02\x0b\x01\x12\x03\x1e\x08\x1a\n\x0c\n\x05\x04\0\x02\x0b\x03\x12\
-    \x03\x1e\x1d\x1e\n\x0b\n\x04\x04\0\x02\x0c\x12\x03\x1f\x02%\n\x0c\n\x05\
-    \x04\0\x02\x0c\x05\x12\x03\x1f\x02\x07\n\x0c\n\x05\x04\0\x02\x0c\x01\x12\
-    \x03\x1f\x08\x20\n\x0c\n\x05\x04\0\x02\x0c\x03\x12\x03\x1f#$\n\x0b\n\x04\
-    \x04\0\x02\r\x12\x03\x20\x02\x1d\n\x0c\n\x05\x04\0\x02\r\x05\x12\x03\x20\
-    \x02\x07\n\x0c\n\x05\x04\0\x02\r\x01\x12\x03\x20\x08\x18\n\x0c\n\x05\x04\
-    \0\x02\r\x03\x12\x03\x20\x1b\x1c\n\x0b\n\x04\x04\0\x02\x0e\x12\x03!\x02\
-    \x1f\n\x0c\n\x05\x04\0\x02\x0e\x05\x12\x03!\x02\x07\n\x0c\n\x05\x04\0\
-    \x02\x0e\x01\x12\x03!\x08\x1a\n\x0c\n\x05\x04\0\x02\x0e\x03\x12\x03!\x1d\
-    \x1e\n\x0b\n\x04\x04\0\x02\x0f\x12\x03\"\x02&\n\x0c\n\x05\x04\0\x02\x0f\
-    \x05\x12\x03\"\x02\x07\n\x0c\n\x05\x04\0\x02\x0f\x01\x12\x03\"\x08\x20\n\
-    \x0c\n\x05\x04\0\x02\x0f\x03\x12\x03\"#%\n\x0b\n\x04\x04\0\x02\x10\x12\
-    \x03#\x02\x1a\n\x0c\n\x05\x04\0\x02\x10\x05\x12\x03#\x02\x07\n\x0c\n\x05\
-    \x04\0\x02\x10\x01\x12\x03#\x08\x14\n\x0c\n\x05\x04\0\x02\x10\x03\x12\
-    \x03#\x17\x19\n\x0b\n\x04\x04\0\x02\x11\x12\x03$\x02\x1e\n\x0c\n\x05\x04\
-    \0\x02\x11\x05\x12\x03$\x02\x07\n\x0c\n\x05\x04\0\x02\x11\x01\x12\x03$\
-    \x08\x18\n\x0c\n\x05\x04\0\x02\x11\x03\x12\x03$\x1b\x1d\n\x0b\n\x04\x04\
-    \0\x02\x12\x12\x03%\x02\x1f\n\x0c\n\x05\x04\0\x02\x12\x05\x12\x03%\x02\
-    \x07\n\x0c\n\x05\x04\0\x02\x12\x01\x12\x03%\x08\x19\n\x0c\n\x05\x04\0\
-    \x02\x12\x03\x12\x03%\x1c\x1e\n\x0b\n\x04\x04\0\x02\x13\x12\x03&\x02\x1e\
-    \n\x0c\n\x05\x04\0\x02\x13\x05\x12\x03&\x02\x07\n\x0c\n\x05\x04\0\x02\
-    \x13\x01\x12\x03&\x08\x18\n\x0c\n\x05\x04\0\x02\x13\x03\x12\x03&\x1b\x1d\
-    \n\x0b\n\x04\x04\0\x02\x14\x12\x03'\x02\x1f\n\x0c\n\x05\x04\0\x02\x14\
-    \x05\x12\x03'\x02\x07\n\x0c\n\x05\x04\0\x02\x14\x01\x12\x03'\x08\x19\n\
-    \x0c\n\x05\x04\0\x02\x14\x03\x12\x03'\x1c\x1e\n\x0b\n\x04\x04\0\x02\x15\
-    \x12\x03(\x02\x20\n\x0c\n\x05\x04\0\x02\x15\x05\x12\x03(\x02\x07\n\x0c\n\
-    \x05\x04\0\x02\x15\x01\x12\x03(\x08\x1a\n\x0c\n\x05\x04\0\x02\x15\x03\
-    \x12\x03(\x1d\x1f\n\x0b\n\x04\x04\0\x02\x16\x12\x03)\x02\x1f\n\x0c\n\x05\
-    \x04\0\x02\x16\x05\x12\x03)\x02\x07\n\x0c\n\x05\x04\0\x02\x16\x01\x12\
-    \x03)\x08\x19\n\x0c\n\x05\x04\0\x02\x16\x03\x12\x03)\x1c\x1e\n\x0b\n\x04\
-    \x04\0\x02\x17\x12\x03*\x02\x1b\n\x0c\n\x05\x04\0\x02\x17\x05\x12\x03*\
-    \x02\x07\n\x0c\n\x05\x04\0\x02\x17\x01\x12\x03*\x08\x15\n\x0c\n\x05\x04\
-    \0\x02\x17\x03\x12\x03*\x18\x1a\n\x0b\n\x04\x04\0\x02\x18\x12\x03+\x02\
-    \x1c\n\x0c\n\x05\x04\0\x02\x18\x05\x12\x03+\x02\x07\n\x0c\n\x05\x04\0\
-    \x02\x18\x01\x12\x03+\x08\x16\n\x0c\n\x05\x04\0\x02\x18\x03\x12\x03+\x19\
-    \x1b\n\x0b\n\x04\x04\0\x02\x19\x12\x03,\x02\x17\n\x0c\n\x05\x04\0\x02\
-    \x19\x05\x12\x03,\x02\x07\n\x0c\n\x05\x04\0\x02\x19\x01\x12\x03,\x08\x11\
-    \n\x0c\n\x05\x04\0\x02\x19\x03\x12\x03,\x14\x16\n\x0b\n\x04\x04\0\x02\
-    \x1a\x12\x03-\x02\x1c\n\x0c\n\x05\x04\0\x02\x1a\x05\x12\x03-\x02\x07\n\
-    \x0c\n\x05\x04\0\x02\x1a\x01\x12\x03-\x08\x16\n\x0c\n\x05\x04\0\x02\x1a\
-    \x03\x12\x03-\x19\x1b\n\x0b\n\x04\x04\0\x02\x1b\x12\x03.\x02\x18\n\x0c\n\
-    \x05\x04\0\x02\x1b\x05\x12\x03.\x02\x07\n\x0c\n\x05\x04\0\x02\x1b\x01\
-    \x12\x03.\x08\x12\n\x0c\n\x05\x04\0\x02\x1b\x03\x12\x03.\x15\x17\n\x0b\n\
-    \x04\x04\0\x02\x1c\x12\x03/\x02\x1b\n\x0c\n\x05\x04\0\x02\x1c\x05\x12\
-    \x03/\x02\x07\n\x0c\n\x05\x04\0\x02\x1c\x01\x12\x03/\x08\x15\n\x0c\n\x05\
-    \x04\0\x02\x1c\x03\x12\x03/\x18\x1a\n\x0b\n\x04\x04\0\x02\x1d\x12\x030\
-    \x02\x1c\n\x0c\n\x05\x04\0\x02\x1d\x05\x12\x030\x02\x07\n\x0c\n\x05\x04\
-    \0\x02\x1d\x01\x12\x030\x08\x16\n\x0c\n\x05\x04\0\x02\x1d\x03\x12\x030\
-    \x19\x1b\n\x0b\n\x04\x04\0\x02\x1e\x12\x031\x02/\n\x0c\n\x05\x04\0\x02\
-    \x1e\x05\x12\x031\x02\x07\n\x0c\n\x05\x04\0\x02\x1e\x01\x12\x031\x08)\n\
-    \x0c\n\x05\x04\0\x02\x1e\x03\x12\x031,.\n\x0b\n\x04\x04\0\x02\x1f\x12\
-    \x032\x02.\n\x0c\n\x05\x04\0\x02\x1f\x05\x12\x032\x02\x07\n\x0c\n\x05\
-    \x04\0\x02\x1f\x01\x12\x032\x08(\n\x0c\n\x05\x04\0\x02\x1f\x03\x12\x032+\
-    -\n\x0b\n\x04\x04\0\x02\x20\x12\x033\x02*\n\x0c\n\x05\x04\0\x02\x20\x05\
-    \x12\x033\x02\x07\n\x0c\n\x05\x04\0\x02\x20\x01\x12\x033\x08$\n\x0c\n\
-    \x05\x04\0\x02\x20\x03\x12\x033')\n\x0b\n\x04\x04\0\x02!\x12\x034\x02+\n\
-    \x0c\n\x05\x04\0\x02!\x05\x12\x034\x02\x07\n\x0c\n\x05\x04\0\x02!\x01\
-    \x12\x034\x08%\n\x0c\n\x05\x04\0\x02!\x03\x12\x034(*\n\x0b\n\x04\x04\0\
-    \x02\"\x12\x035\x02&\n\x0c\n\x05\x04\0\x02\"\x05\x12\x035\x02\x07\n\x0c\
-    \n\x05\x04\0\x02\"\x01\x12\x035\x08\x20\n\x0c\n\x05\x04\0\x02\"\x03\x12\
-    \x035#%\n\x0b\n\x04\x04\0\x02#\x12\x036\x02+\n\x0c\n\x05\x04\0\x02#\x05\
-    \x12\x036\x02\x07\n\x0c\n\x05\x04\0\x02#\x01\x12\x036\x08%\n\x0c\n\x05\
-    \x04\0\x02#\x03\x12\x036(*\n\x0b\n\x04\x04\0\x02$\x12\x037\x02\x1b\n\x0c\
-    \n\x05\x04\0\x02$\x05\x12\x037\x02\x07\n\x0c\n\x05\x04\0\x02$\x01\x12\
-    \x037\x08\x15\n\x0c\n\x05\x04\0\x02$\x03\x12\x037\x18\x1a\n\x0b\n\x04\
-    \x04\0\x02%\x12\x038\x02\x1e\n\x0c\n\x05\x04\0\x02%\x05\x12\x038\x02\x07\
-    \n\x0c\n\x05\x04\0\x02%\x01\x12\x038\x08\x18\n\x0c\n\x05\x04\0\x02%\x03\
-    \x12\x038\x1b\x1d\n\x0b\n\x04\x04\0\x02&\x12\x039\x02\"\n\x0c\n\x05\x04\
-    \0\x02&\x05\x12\x039\x02\x07\n\x0c\n\x05\x04\0\x02&\x01\x12\x039\x08\x1c\
-    \n\x0c\n\x05\x04\0\x02&\x03\x12\x039\x1f!\n\x0b\n\x04\x04\0\x02'\x12\x03\
-    :\x02\x18\n\x0c\n\x05\x04\0\x02'\x05\x12\x03:\x02\x07\n\x0c\n\x05\x04\0\
-    \x02'\x01\x12\x03:\x08\x12\n\x0c\n\x05\x04\0\x02'\x03\x12\x03:\x15\x17\n\
-    0\n\x04\x04\0\x02(\x12\x03;\x02(\"#\x20Deprecated:\x20use\x20pod_phase\
-    \x20instead\n\n\x0c\n\x05\x04\0\x02(\x05\x12\x03;\x02\x08\n\x0c\n\x05\
-    \x04\0\x02(\x01\x12\x03;\t\x0e\n\x0c\n\x05\x04\0\x02(\x03\x12\x03;\x11\
-    \x13\n\x0c\n\x05\x04\0\x02(\x08\x12\x03;\x14'\n\r\n\x06\x04\0\x02(\x08\
-    \x03\x12\x03;\x15&\n\x0b\n\x04\x04\0\x02)\x12\x03<\x02\x1a\n\x0c\n\x05\
-    \x04\0\x02)\x06\x12\x03<\x02\n\n\x0c\n\x05\x04\0\x02)\x01\x12\x03<\x0b\
-    \x14\n\x0c\n\x05\x04\0\x02)\x03\x12\x03<\x17\x19\n\x0b\n\x04\x04\0\x02*\
-    \x12\x03=\x02\x1f\n\x0c\n\x05\x04\0\x02*\x05\x12\x03=\x02\x07\n\x0c\n\
-    \x05\x04\0\x02*\x01\x12\x03=\x08\x19\n\x0c\n\x05\x04\0\x02*\x03\x12\x03=\
-    \x1c\x1e\n\x0b\n\x04\x04\0\x02+\x12\x03>\x02\x20\n\x0c\n\x05\x04\0\x02+\
-    \x05\x12\x03>\x02\x07\n\x0c\n\x05\x04\0\x02+\x01\x12\x03>\x08\x1a\n\x0c\
-    \n\x05\x04\0\x02+\x03\x12\x03>\x1d\x1f\n\x0b\n\x04\x04\0\x02,\x12\x03?\
-    \x020\n\x0c\n\x05\x04\0\x02,\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\0\x02,\
-    \x06\x12\x03?\x0b\x19\n\x0c\n\x05\x04\0\x02,\x01\x12\x03?\x1a*\n\x0c\n\
-    \x05\x04\0\x02,\x03\x12\x03?-/\n\x0b\n\x04\x04\0\x02-\x12\x03@\x02%\n\
-    \x0c\n\x05\x04\0\x02-\x04\x12\x03@\x02\n\n\x0c\n\x05\x04\0\x02-\x06\x12\
-    \x03@\x0b\x14\n\x0c\n\x05\x04\0\x02-\x01\x12\x03@\x15\x1f\n\x0c\n\x05\
-    \x04\0\x02-\x03\x12\x03@\"$\n\n\n\x02\x04\x01\x12\x04C\0G\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03C\x08\x16\n\x0b\n\x04\x04\x01\x02\0\x12\x03D\x02\x12\
-    \n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03D\x02\x08\n\x0c\n\x05\x04\x01\x02\
-    \0\x01\x12\x03D\t\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03D\x10\x11\n\x0b\
-    \n\x04\x04\x01\x02\x01\x12\x03E\x02\x12\n\x0c\n\x05\x04\x01\x02\x01\x05\
-    \x12\x03E\x02\x08\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03E\t\r\n\x0c\n\
-    \x05\x04\x01\x02\x01\x03\x12\x03E\x10\x11\n\x0b\n\x04\x04\x01\x02\x02\
-    \x12\x03F\x02\x17\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03F\x02\x08\n\x0c\
-    \n\x05\x04\x01\x02\x02\x01\x12\x03F\t\x12\n\x0c\n\x05\x04\x01\x02\x02\
-    \x03\x12\x03F\x15\x16\n\n\n\x02\x04\x02\x12\x04I\0N\x01\n\n\n\x03\x04\
-    \x02\x01\x12\x03I\x08\x11\n\x0b\n\x04\x04\x02\x02\0\x12\x03J\x02\x12\n\
-    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03J\x02\x08\n\x0c\n\x05\x04\x02\x02\0\
-    \x01\x12\x03J\t\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03J\x10\x11\n\x0b\n\
-    \x04\x04\x02\x02\x01\x12\x03K\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x06\
-    \x12\x03K\x02\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03K\x12\x18\n\x0c\
-    \n\x05\x04\x02\x02\x01\x03\x12\x03K\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\
-    \x12\x03L\x02\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03L\x02\x08\n\x0c\
-    \n\x05\x04\x02\x02\x02\x01\x12\x03L\t\x0f\n\x0c\n\x05\x04\x02\x02\x02\
-    \x03\x12\x03L\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03M\x02\x16\n\x0c\
-    \n\x05\x04\x02\x02\x03\x05\x12\x03M\x02\x07\n\x0c\n\x05\x04\x02\x02\x03\
-    \x01\x12\x03M\x08\x11\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03M\x14\x15\n\
-    \n\n\x02\x04\x03\x12\x04P\0R\x01\n\n\n\x03\x04\x03\x01\x12\x03P\x08\x1e\
-    \n\x0b\n\x04\x04\x03\x02\0\x12\x03Q\x02)\n\x0c\n\x05\x04\x03\x02\0\x04\
-    \x12\x03Q\x02\n\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03Q\x0b\x1c\n\x0c\n\
-    \x05\x04\x03\x02\0\x01\x12\x03Q\x1d$\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
-    \x03Q'(b\x06proto3\
+    \x02\x1d\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x0f\x20!\n\n\n\x02\x05\
+    \x02\x12\x04\x12\0\x16\x01\n\n\n\x03\x05\x02\x01\x12\x03\x12\x05\x14\n\
+    \x0b\n\x04\x05\x02\x02\0\x12\x03\x13\x02\x1f\n\x0c\n\x05\x05\x02\x02\0\
+    \x01\x12\x03\x13\x02\x1a\n\x0c\n\x05\x05\x02\x02\0\x02\x12\x03\x13\x1d\
+    \x1e\n\x0b\n\x04\x05\x02\x02\x01\x12\x03\x14\x02\x1c\n\x0c\n\x05\x05\x02\
+    \x02\x01\x01\x12\x03\x14\x02\x17\n\x0c\n\x05\x05\x02\x02\x01\x02\x12\x03\
+    \x14\x1a\x1b\n\x0b\n\x04\x05\x02\x02\x02\x12\x03\x15\x02\x1d\n\x0c\n\x05\
+    \x05\x02\x02\x02\x01\x12\x03\x15\x02\x18\n\x0c\n\x05\x05\x02\x02\x02\x02\
+    \x12\x03\x15\x1b\x1c\n\n\n\x02\x05\x03\x12\x04\x18\0\x1d\x01\n\n\n\x03\
+    \x05\x03\x01\x12\x03\x18\x05\x1f\n\x0b\n\x04\x05\x03\x02\0\x12\x03\x19\
+    \x02,\n\x0c\n\x05\x05\x03\x02\0\x01\x12\x03\x19\x02'\n\x0c\n\x05\x05\x03\
+    \x02\0\x02\x12\x03\x19*+\n\x0b\n\x04\x05\x03\x02\x01\x12\x03\x1a\x02,\n\
+    \x0c\n\x05\x05\x03\x02\x01\x01\x12\x03\x1a\x02'\n\x0c\n\x05\x05\x03\x02\
+    \x01\x02\x12\x03\x1a*+\n\x0b\n\x04\x05\x03\x02\x02\x12\x03\x1b\x02*\n\
+    \x0c\n\x05\x05\x03\x02\x02\x01\x12\x03\x1b\x02%\n\x0c\n\x05\x05\x03\x02\
+    \x02\x02\x12\x03\x1b()\n\x0b\n\x04\x05\x03\x02\x03\x12\x03\x1c\x02)\n\
+    \x0c\n\x05\x05\x03\x02\x03\x01\x12\x03\x1c\x02$\n\x0c\n\x05\x05\x03\x02\
+    \x03\x02\x12\x03\x1c'(\n\n\n\x02\x05\x04\x12\x04\x1f\0&\x01\n\n\n\x03\
+    \x05\x04\x01\x12\x03\x1f\x05\x1a\n\x0b\n\x04\x05\x04\x02\0\x12\x03\x20\
+    \x02&\n\x0c\n\x05\x05\x04\x02\0\x01\x12\x03\x20\x02!\n\x0c\n\x05\x05\x04\
+    \x02\0\x02\x12\x03\x20$%\n\x0b\n\x04\x05\x04\x02\x01\x12\x03!\x02&\n\x0c\
+    \n\x05\x05\x04\x02\x01\x01\x12\x03!\x02!\n\x0c\n\x05\x05\x04\x02\x01\x02\
+    \x12\x03!$%\n\x0b\n\x04\x05\x04\x02\x02\x12\x03\"\x02(\n\x0c\n\x05\x05\
+    \x04\x02\x02\x01\x12\x03\"\x02#\n\x0c\n\x05\x05\x04\x02\x02\x02\x12\x03\
+    \"&'\n\x0b\n\x04\x05\x04\x02\x03\x12\x03#\x02$\n\x0c\n\x05\x05\x04\x02\
+    \x03\x01\x12\x03#\x02\x1f\n\x0c\n\x05\x05\x04\x02\x03\x02\x12\x03#\"#\n\
+    \x0b\n\x04\x05\x04\x02\x04\x12\x03$\x02'\n\x0c\n\x05\x05\x04\x02\x04\x01\
+    \x12\x03$\x02\"\n\x0c\n\x05\x05\x04\x02\x04\x02\x12\x03$%&\n\x0b\n\x04\
+    \x05\x04\x02\x05\x12\x03%\x02%\n\x0c\n\x05\x05\x04\x02\x05\x01\x12\x03%\
+    \x02\x20\n\x0c\n\x05\x05\x04\x02\x05\x02\x12\x03%#$\n\n\n\x02\x04\0\x12\
+    \x04(\0n\x01\n\n\n\x03\x04\0\x01\x12\x03(\x08\x19\n\x0b\n\x04\x04\0\x02\
+    \0\x12\x03)\x02\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03)\x02\x08\n\x0c\n\
+    \x05\x04\0\x02\0\x01\x12\x03)\t\x10\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03)\
+    \x13\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03*\x02\x17\n\x0c\n\x05\x04\0\
+    \x02\x01\x05\x12\x03*\x02\x08\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03*\t\
+    \x12\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03*\x15\x16\n\x0b\n\x04\x04\0\
+    \x02\x02\x12\x03+\x02\x16\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03+\x02\x08\
+    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03+\t\x11\n\x0c\n\x05\x04\0\x02\x02\
+    \x03\x12\x03+\x14\x15\n\x0b\n\x04\x04\0\x02\x03\x12\x03,\x02\x1c\n\x0c\n\
+    \x05\x04\0\x02\x03\x05\x12\x03,\x02\x08\n\x0c\n\x05\x04\0\x02\x03\x01\
+    \x12\x03,\t\x16\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03,\x19\x1b\n\x0b\n\
+    \x04\x04\0\x02\x04\x12\x03-\x02\x17\n\x0c\n\x05\x04\0\x02\x04\x05\x12\
+    \x03-\x02\x08\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03-\t\x11\n\x0c\n\x05\
+    \x04\0\x02\x04\x03\x12\x03-\x14\x16\n\x0b\n\x04\x04\0\x02\x05\x12\x03.\
+    \x02\x1a\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03.\x02\x08\n\x0c\n\x05\x04\
+    \0\x02\x05\x01\x12\x03.\t\x14\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03.\x17\
+    \x19\n\x0b\n\x04\x04\0\x02\x06\x12\x03/\x02\x17\n\x0c\n\x05\x04\0\x02\
+    \x06\x05\x12\x03/\x02\x07\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03/\x08\x11\
+    \n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03/\x14\x16\n\x0b\n\x04\x04\0\x02\
+    \x07\x12\x030\x02\"\n\x0c\n\x05\x04\0\x02\x07\x06\x12\x030\x02\x15\n\x0c\
+    \n\x05\x04\0\x02\x07\x01\x12\x030\x16\x1c\n\x0c\n\x05\x04\0\x02\x07\x03\
+    \x12\x030\x1f!\n\x0b\n\x04\x04\0\x02\x08\x12\x031\x02!\n\x0c\n\x05\x04\0\
+    \x02\x08\x05\x12\x031\x02\x07\n\x0c\n\x05\x04\0\x02\x08\x01\x12\x031\x08\
+    \x1c\n\x0c\n\x05\x04\0\x02\x08\x03\x12\x031\x1f\x20\n\x0b\n\x04\x04\0\
+    \x02\t\x12\x032\x02(\n\x0c\n\x05\x04\0\x02\t\x05\x12\x032\x02\x07\n\x0c\
+    \n\x05\x04\0\x02\t\x01\x12\x032\x08#\n\x0c\n\x05\x04\0\x02\t\x03\x12\x03\
+    2&'\n\x0b\n\x04\x04\0\x02\n\x12\x033\x02#\n\x0c\n\x05\x04\0\x02\n\x05\
+    \x12\x033\x02\x07\n\x0c\n\x05\x04\0\x02\n\x01\x12\x033\x08\x1e\n\x0c\n\
+    \x05\x04\0\x02\n\x03\x12\x033!\"\n\x0b\n\x04\x04\0\x02\x0b\x12\x034\x02\
+    \x1f\n\x0c\n\x05\x04\0\x02\x0b\x05\x12\x034\x02\x07\n\x0c\n\x05\x04\0\
+    \x02\x0b\x01\x12\x034\x08\x1a\n\x0c\n\x05\x04\0\x02\x0b\x03\x12\x034\x1d\
+    \x1e\n\x0b\n\x04\x04\0\x02\x0c\x12\x035\x02%\n\x0c\n\x05\x04\0\x02\x0c\
+    \x05\x12\x035\x02\x07\n\x0c\n\x05\x04\0\x02\x0c\x01\x12\x035\x08\x20\n\
+    \x0c\n\x05\x04\0\x02\x0c\x03\x12\x035#$\n\x0b\n\x04\x04\0\x02\r\x12\x036\
+    \x02\x1d\n\x0c\n\x05\x04\0\x02\r\x05\x12\x036\x02\x07\n\x0c\n\x05\x04\0\
+    \x02\r\x01\x12\x036\x08\x18\n\x0c\n\x05\x04\0\x02\r\x03\x12\x036\x1b\x1c\
+    \n\x0b\n\x04\x04\0\x02\x0e\x12\x037\x02\x1f\n\x0c\n\x05\x04\0\x02\x0e\
+    \x05\x12\x037\x02\x07\n\x0c\n\x05\x04\0\x02\x0e\x01\x12\x037\x08\x1a\n\
+    \x0c\n\x05\x04\0\x02\x0e\x03\x12\x037\x1d\x1e\n\x0b\n\x04\x04\0\x02\x0f\
+    \x12\x038\x02&\n\x0c\n\x05\x04\0\x02\x0f\x05\x12\x038\x02\x07\n\x0c\n\
+    \x05\x04\0\x02\x0f\x01\x12\x038\x08\x20\n\x0c\n\x05\x04\0\x02\x0f\x03\
+    \x12\x038#%\n\x0b\n\x04\x04\0\x02\x10\x12\x039\x02\x1a\n\x0c\n\x05\x04\0\
+    \x02\x10\x05\x12\x039\x02\x07\n\x0c\n\x05\x04\0\x02\x10\x01\x12\x039\x08\
+    \x14\n\x0c\n\x05\x04\0\x02\x10\x03\x12\x039\x17\x19\n\x0b\n\x04\x04\0\
+    \x02\x11\x12\x03:\x02\x1e\n\x0c\n\x05\x04\0\x02\x11\x05\x12\x03:\x02\x07\
+    \n\x0c\n\x05\x04\0\x02\x11\x01\x12\x03:\x08\x18\n\x0c\n\x05\x04\0\x02\
+    \x11\x03\x12\x03:\x1b\x1d\n\x0b\n\x04\x04\0\x02\x12\x12\x03;\x02\x1f\n\
+    \x0c\n\x05\x04\0\x02\x12\x05\x12\x03;\x02\x07\n\x0c\n\x05\x04\0\x02\x12\
+    \x01\x12\x03;\x08\x19\n\x0c\n\x05\x04\0\x02\x12\x03\x12\x03;\x1c\x1e\n\
+    \x0b\n\x04\x04\0\x02\x13\x12\x03<\x02\x1e\n\x0c\n\x05\x04\0\x02\x13\x05\
+    \x12\x03<\x02\x07\n\x0c\n\x05\x04\0\x02\x13\x01\x12\x03<\x08\x18\n\x0c\n\
+    \x05\x04\0\x02\x13\x03\x12\x03<\x1b\x1d\n\x0b\n\x04\x04\0\x02\x14\x12\
+    \x03=\x02\x1f\n\x0c\n\x05\x04\0\x02\x14\x05\x12\x03=\x02\x07\n\x0c\n\x05\
+    \x04\0\x02\x14\x01\x12\x03=\x08\x19\n\x0c\n\x05\x04\0\x02\x14\x03\x12\
+    \x03=\x1c\x1e\n\x0b\n\x04\x04\0\x02\x15\x12\x03>\x02\x20\n\x0c\n\x05\x04\
+    \0\x02\x15\x05\x12\x03>\x02\x07\n\x0c\n\x05\x04\0\x02\x15\x01\x12\x03>\
+    \x08\x1a\n\x0c\n\x05\x04\0\x02\x15\x03\x12\x03>\x1d\x1f\n\x0b\n\x04\x04\
+    \0\x02\x16\x12\x03?\x02\x1f\n\x0c\n\x05\x04\0\x02\x16\x05\x12\x03?\x02\
+    \x07\n\x0c\n\x05\x04\0\x02\x16\x01\x12\x03?\x08\x19\n\x0c\n\x05\x04\0\
+    \x02\x16\x03\x12\x03?\x1c\x1e\n\x0b\n\x04\x04\0\x02\x17\x12\x03@\x02\x1b\
+    \n\x0c\n\x05\x04\0\x02\x17\x05\x12\x03@\x02\x07\n\x0c\n\x05\x04\0\x02\
+    \x17\x01\x12\x03@\x08\x15\n\x0c\n\x05\x04\0\x02\x17\x03\x12\x03@\x18\x1a\
+    \n\x0b\n\x04\x04\0\x02\x18\x12\x03A\x02\x1c\n\x0c\n\x05\x04\0\x02\x18\
+    \x05\x12\x03A\x02\x07\n\x0c\n\x05\x04\0\x02\x18\x01\x12\x03A\x08\x16\n\
+    \x0c\n\x05\x04\0\x02\x18\x03\x12\x03A\x19\x1b\n\x0b\n\x04\x04\0\x02\x19\
+    \x12\x03B\x02\x17\n\x0c\n\x05\x04\0\x02\x19\x05\x12\x03B\x02\x07\n\x0c\n\
+    \x05\x04\0\x02\x19\x01\x12\x03B\x08\x11\n\x0c\n\x05\x04\0\x02\x19\x03\
+    \x12\x03B\x14\x16\n\x0b\n\x04\x04\0\x02\x1a\x12\x03C\x02\x1c\n\x0c\n\x05\
+    \x04\0\x02\x1a\x05\x12\x03C\x02\x07\n\x0c\n\x05\x04\0\x02\x1a\x01\x12\
+    \x03C\x08\x16\n\x0c\n\x05\x04\0\x02\x1a\x03\x12\x03C\x19\x1b\n\x0b\n\x04\
+    \x04\0\x02\x1b\x12\x03D\x02\x18\n\x0c\n\x05\x04\0\x02\x1b\x05\x12\x03D\
+    \x02\x07\n\x0c\n\x05\x04\0\x02\x1b\x01\x12\x03D\x08\x12\n\x0c\n\x05\x04\
+    \0\x02\x1b\x03\x12\x03D\x15\x17\n\x0b\n\x04\x04\0\x02\x1c\x12\x03E\x02\
+    \x1b\n\x0c\n\x05\x04\0\x02\x1c\x05\x12\x03E\x02\x07\n\x0c\n\x05\x04\0\
+    \x02\x1c\x01\x12\x03E\x08\x15\n\x0c\n\x05\x04\0\x02\x1c\x03\x12\x03E\x18\
+    \x1a\n\x0b\n\x04\x04\0\x02\x1d\x12\x03F\x02\x1c\n\x0c\n\x05\x04\0\x02\
+    \x1d\x05\x12\x03F\x02\x07\n\x0c\n\x05\x04\0\x02\x1d\x01\x12\x03F\x08\x16\
+    \n\x0c\n\x05\x04\0\x02\x1d\x03\x12\x03F\x19\x1b\n\x0b\n\x04\x04\0\x02\
+    \x1e\x12\x03G\x02/\n\x0c\n\x05\x04\0\x02\x1e\x05\x12\x03G\x02\x07\n\x0c\
+    \n\x05\x04\0\x02\x1e\x01\x12\x03G\x08)\n\x0c\n\x05\x04\0\x02\x1e\x03\x12\
+    \x03G,.\n\x0b\n\x04\x04\0\x02\x1f\x12\x03H\x02.\n\x0c\n\x05\x04\0\x02\
+    \x1f\x05\x12\x03H\x02\x07\n\x0c\n\x05\x04\0\x02\x1f\x01\x12\x03H\x08(\n\
+    \x0c\n\x05\x04\0\x02\x1f\x03\x12\x03H+-\n\x0b\n\x04\x04\0\x02\x20\x12\
+    \x03I\x02*\n\x0c\n\x05\x04\0\x02\x20\x05\x12\x03I\x02\x07\n\x0c\n\x05\
+    \x04\0\x02\x20\x01\x12\x03I\x08$\n\x0c\n\x05\x04\0\x02\x20\x03\x12\x03I'\
+    )\n\x0b\n\x04\x04\0\x02!\x12\x03J\x02+\n\x0c\n\x05\x04\0\x02!\x05\x12\
+    \x03J\x02\x07\n\x0c\n\x05\x04\0\x02!\x01\x12\x03J\x08%\n\x0c\n\x05\x04\0\
+    \x02!\x03\x12\x03J(*\n\x0b\n\x04\x04\0\x02\"\x12\x03K\x02&\n\x0c\n\x05\
+    \x04\0\x02\"\x05\x12\x03K\x02\x07\n\x0c\n\x05\x04\0\x02\"\x01\x12\x03K\
+    \x08\x20\n\x0c\n\x05\x04\0\x02\"\x03\x12\x03K#%\n\x0b\n\x04\x04\0\x02#\
+    \x12\x03L\x02+\n\x0c\n\x05\x04\0\x02#\x05\x12\x03L\x02\x07\n\x0c\n\x05\
+    \x04\0\x02#\x01\x12\x03L\x08%\n\x0c\n\x05\x04\0\x02#\x03\x12\x03L(*\n\
+    \x0b\n\x04\x04\0\x02$\x12\x03M\x02\x1b\n\x0c\n\x05\x04\0\x02$\x05\x12\
+    \x03M\x02\x07\n\x0c\n\x05\x04\0\x02$\x01\x12\x03M\x08\x15\n\x0c\n\x05\
+    \x04\0\x02$\x03\x12\x03M\x18\x1a\n\x0b\n\x04\x04\0\x02%\x12\x03N\x02\x1e\
+    \n\x0c\n\x05\x04\0\x02%\x05\x12\x03N\x02\x07\n\x0c\n\x05\x04\0\x02%\x01\
+    \x12\x03N\x08\x18\n\x0c\n\x05\x04\0\x02%\x03\x12\x03N\x1b\x1d\n\x0b\n\
+    \x04\x04\0\x02&\x12\x03O\x02\"\n\x0c\n\x05\x04\0\x02&\x05\x12\x03O\x02\
+    \x07\n\x0c\n\x05\x04\0\x02&\x01\x12\x03O\x08\x1c\n\x0c\n\x05\x04\0\x02&\
+    \x03\x12\x03O\x1f!\n\x0b\n\x04\x04\0\x02'\x12\x03P\x02\x18\n\x0c\n\x05\
+    \x04\0\x02'\x05\x12\x03P\x02\x07\n\x0c\n\x05\x04\0\x02'\x01\x12\x03P\x08\
+    \x12\n\x0c\n\x05\x04\0\x02'\x03\x12\x03P\x15\x17\n0\n\x04\x04\0\x02(\x12\
+    \x03Q\x02(\"#\x20Deprecated:\x20use\x20pod_phase\x20instead\n\n\x0c\n\
+    \x05\x04\0\x02(\x05\x12\x03Q\x02\x08\n\x0c\n\x05\x04\0\x02(\x01\x12\x03Q\
+    \t\x0e\n\x0c\n\x05\x04\0\x02(\x03\x12\x03Q\x11\x13\n\x0c\n\x05\x04\0\x02\
+    (\x08\x12\x03Q\x14'\n\r\n\x06\x04\0\x02(\x08\x03\x12\x03Q\x15&\n\x0b\n\
+    \x04\x04\0\x02)\x12\x03R\x02\x1a\n\x0c\n\x05\x04\0\x02)\x06\x12\x03R\x02\
+    \n\n\x0c\n\x05\x04\0\x02)\x01\x12\x03R\x0b\x14\n\x0c\n\x05\x04\0\x02)\
+    \x03\x12\x03R\x17\x19\n\x0b\n\x04\x04\0\x02*\x12\x03S\x02\x1f\n\x0c\n\
+    \x05\x04\0\x02*\x05\x12\x03S\x02\x07\n\x0c\n\x05\x04\0\x02*\x01\x12\x03S\
+    \x08\x19\n\x0c\n\x05\x04\0\x02*\x03\x12\x03S\x1c\x1e\n\x0b\n\x04\x04\0\
+    \x02+\x12\x03T\x02\x20\n\x0c\n\x05\x04\0\x02+\x05\x12\x03T\x02\x07\n\x0c\
+    \n\x05\x04\0\x02+\x01\x12\x03T\x08\x1a\n\x0c\n\x05\x04\0\x02+\x03\x12\
+    \x03T\x1d\x1f\n\x0b\n\x04\x04\0\x02,\x12\x03U\x020\n\x0c\n\x05\x04\0\x02\
+    ,\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\0\x02,\x06\x12\x03U\x0b\x19\n\x0c\n\
+    \x05\x04\0\x02,\x01\x12\x03U\x1a*\n\x0c\n\x05\x04\0\x02,\x03\x12\x03U-/\
+    \n\x0b\n\x04\x04\0\x02-\x12\x03V\x02%\n\x0c\n\x05\x04\0\x02-\x04\x12\x03\
+    V\x02\n\n\x0c\n\x05\x04\0\x02-\x06\x12\x03V\x0b\x14\n\x0c\n\x05\x04\0\
+    \x02-\x01\x12\x03V\x15\x1f\n\x0c\n\x05\x04\0\x02-\x03\x12\x03V\"$\n\x0b\
+    \n\x04\x04\0\x02.\x12\x03W\x02\"\n\x0c\n\x05\x04\0\x02.\x06\x12\x03W\x02\
+    \x13\n\x0c\n\x05\x04\0\x02.\x01\x12\x03W\x14\x1c\n\x0c\n\x05\x04\0\x02.\
+    \x03\x12\x03W\x1f!\n\x0b\n\x04\x04\0\x02/\x12\x03X\x02\x1f\n\x0c\n\x05\
+    \x04\0\x02/\x06\x12\x03X\x02\x10\n\x0c\n\x05\x04\0\x02/\x01\x12\x03X\x11\
+    \x19\n\x0c\n\x05\x04\0\x02/\x03\x12\x03X\x1c\x1e\n\x0b\n\x04\x04\0\x020\
+    \x12\x03Y\x02\x1d\n\x0c\n\x05\x04\0\x020\x06\x12\x03Y\x02\x0f\n\x0c\n\
+    \x05\x04\0\x020\x01\x12\x03Y\x10\x17\n\x0c\n\x05\x04\0\x020\x03\x12\x03Y\
+    \x1a\x1c\n\x0b\n\x04\x04\0\x021\x12\x03Z\x02\x15\n\x0c\n\x05\x04\0\x021\
+    \x06\x12\x03Z\x02\x0b\n\x0c\n\x05\x04\0\x021\x01\x12\x03Z\x0c\x0f\n\x0c\
+    \n\x05\x04\0\x021\x03\x12\x03Z\x12\x14\n\x0b\n\x04\x04\0\x022\x12\x03[\
+    \x02\x1e\n\x0c\n\x05\x04\0\x022\x06\x12\x03[\x02\x0f\n\x0c\n\x05\x04\0\
+    \x022\x01\x12\x03[\x10\x18\n\x0c\n\x05\x04\0\x022\x03\x12\x03[\x1b\x1d\n\
+    \x0b\n\x04\x04\0\x023\x12\x03\\\x02\x1d\n\x0c\n\x05\x04\0\x023\x06\x12\
+    \x03\\\x02\x11\n\x0c\n\x05\x04\0\x023\x01\x12\x03\\\x12\x17\n\x0c\n\x05\
+    \x04\0\x023\x03\x12\x03\\\x1a\x1c\n\x0b\n\x04\x04\0\x024\x12\x03]\x02;\n\
+    \x0c\n\x05\x04\0\x024\x06\x12\x03]\x02\x1d\n\x0c\n\x05\x04\0\x024\x01\
+    \x12\x03]\x1e5\n\x0c\n\x05\x04\0\x024\x03\x12\x03]8:\n\x0b\n\x04\x04\0\
+    \x025\x12\x03^\x020\n\x0c\n\x05\x04\0\x025\x06\x12\x03^\x02\x18\n\x0c\n\
+    \x05\x04\0\x025\x01\x12\x03^\x19*\n\x0c\n\x05\x04\0\x025\x03\x12\x03^-/\
+    \n\x0b\n\x04\x04\0\x026\x12\x03_\x02?\n\x0c\n\x05\x04\0\x026\x06\x12\x03\
+    _\x02\x1f\n\x0c\n\x05\x04\0\x026\x01\x12\x03_\x209\n\x0c\n\x05\x04\0\x02\
+    6\x03\x12\x03_<>\n\x0b\n\x04\x04\0\x027\x12\x03`\x02*\n\x0c\n\x05\x04\0\
+    \x027\x06\x12\x03`\x02\x15\n\x0c\n\x05\x04\0\x027\x01\x12\x03`\x16$\n\
+    \x0c\n\x05\x04\0\x027\x03\x12\x03`')\n\x0b\n\x04\x04\0\x028\x12\x03a\x02\
+    $\n\x0c\n\x05\x04\0\x028\x06\x12\x03a\x02\x12\n\x0c\n\x05\x04\0\x028\x01\
+    \x12\x03a\x13\x1e\n\x0c\n\x05\x04\0\x028\x03\x12\x03a!#\n\x0b\n\x04\x04\
+    \0\x029\x12\x03b\x02\x1d\n\x0c\n\x05\x04\0\x029\x06\x12\x03b\x02\x0f\n\
+    \x0c\n\x05\x04\0\x029\x01\x12\x03b\x10\x17\n\x0c\n\x05\x04\0\x029\x03\
+    \x12\x03b\x1a\x1c\n\x0b\n\x04\x04\0\x02:\x12\x03c\x02\x19\n\x0c\n\x05\
+    \x04\0\x02:\x06\x12\x03c\x02\r\n\x0c\n\x05\x04\0\x02:\x01\x12\x03c\x0e\
+    \x13\n\x0c\n\x05\x04\0\x02:\x03\x12\x03c\x16\x18\n\x0b\n\x04\x04\0\x02;\
+    \x12\x03d\x02,\n\x0c\n\x05\x04\0\x02;\x06\x12\x03d\x02\x16\n\x0c\n\x05\
+    \x04\0\x02;\x01\x12\x03d\x17&\n\x0c\n\x05\x04\0\x02;\x03\x12\x03d)+\n\
+    \x0b\n\x04\x04\0\x02<\x12\x03e\x02%\n\x0c\n\x05\x04\0\x02<\x06\x12\x03e\
+    \x02\x13\n\x0c\n\x05\x04\0\x02<\x01\x12\x03e\x14\x1f\n\x0c\n\x05\x04\0\
+    \x02<\x03\x12\x03e\"$\n\x0b\n\x04\x04\0\x02=\x12\x03f\x02'\n\x0c\n\x05\
+    \x04\0\x02=\x06\x12\x03f\x02\x15\n\x0c\n\x05\x04\0\x02=\x01\x12\x03f\x16\
+    !\n\x0c\n\x05\x04\0\x02=\x03\x12\x03f$&\n\x0b\n\x04\x04\0\x02>\x12\x03g\
+    \x02\x1b\n\x0c\n\x05\x04\0\x02>\x05\x12\x03g\x02\x08\n\x0c\n\x05\x04\0\
+    \x02>\x01\x12\x03g\t\x15\n\x0c\n\x05\x04\0\x02>\x03\x12\x03g\x18\x1a\n\
+    \x0b\n\x04\x04\0\x02?\x12\x03h\x02\x19\n\x0c\n\x05\x04\0\x02?\x05\x12\
+    \x03h\x02\x08\n\x0c\n\x05\x04\0\x02?\x01\x12\x03h\t\x13\n\x0c\n\x05\x04\
+    \0\x02?\x03\x12\x03h\x16\x18\n\x0b\n\x04\x04\0\x02@\x12\x03i\x02\x14\n\
+    \x0c\n\x05\x04\0\x02@\x05\x12\x03i\x02\x08\n\x0c\n\x05\x04\0\x02@\x01\
+    \x12\x03i\t\x0e\n\x0c\n\x05\x04\0\x02@\x03\x12\x03i\x11\x13\n\x0b\n\x04\
+    \x04\0\x02A\x12\x03j\x02\x1d\n\x0c\n\x05\x04\0\x02A\x05\x12\x03j\x02\x07\
+    \n\x0c\n\x05\x04\0\x02A\x01\x12\x03j\x08\x17\n\x0c\n\x05\x04\0\x02A\x03\
+    \x12\x03j\x1a\x1c\n\x0b\n\x04\x04\0\x02B\x12\x03k\x02'\n\x0c\n\x05\x04\0\
+    \x02B\x05\x12\x03k\x02\x07\n\x0c\n\x05\x04\0\x02B\x01\x12\x03k\x08!\n\
+    \x0c\n\x05\x04\0\x02B\x03\x12\x03k$&\n\x0b\n\x04\x04\0\x02C\x12\x03l\x02\
+    \x1c\n\x0c\n\x05\x04\0\x02C\x05\x12\x03l\x02\x07\n\x0c\n\x05\x04\0\x02C\
+    \x01\x12\x03l\x08\x16\n\x0c\n\x05\x04\0\x02C\x03\x12\x03l\x19\x1b\n\x0b\
+    \n\x04\x04\0\x02D\x12\x03m\x02\x1e\n\x0c\n\x05\x04\0\x02D\x06\x12\x03m\
+    \x02\x0f\n\x0c\n\x05\x04\0\x02D\x01\x12\x03m\x10\x18\n\x0c\n\x05\x04\0\
+    \x02D\x03\x12\x03m\x1b\x1d\n\n\n\x02\x04\x01\x12\x04p\0t\x01\n\n\n\x03\
+    \x04\x01\x01\x12\x03p\x08\x16\n\x0b\n\x04\x04\x01\x02\0\x12\x03q\x02\x12\
+    \n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03q\x02\x08\n\x0c\n\x05\x04\x01\x02\
+    \0\x01\x12\x03q\t\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03q\x10\x11\n\x0b\
+    \n\x04\x04\x01\x02\x01\x12\x03r\x02\x12\n\x0c\n\x05\x04\x01\x02\x01\x05\
+    \x12\x03r\x02\x08\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03r\t\r\n\x0c\n\
+    \x05\x04\x01\x02\x01\x03\x12\x03r\x10\x11\n\x0b\n\x04\x04\x01\x02\x02\
+    \x12\x03s\x02\x17\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03s\x02\x08\n\x0c\
+    \n\x05\x04\x01\x02\x02\x01\x12\x03s\t\x12\n\x0c\n\x05\x04\x01\x02\x02\
+    \x03\x12\x03s\x15\x16\n\n\n\x02\x04\x02\x12\x04v\0\x7f\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03v\x08\x11\n\x0b\n\x04\x04\x02\x02\0\x12\x03w\x02\x12\n\
+    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03w\x02\x08\n\x0c\n\x05\x04\x02\x02\0\
+    \x01\x12\x03w\t\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03w\x10\x11\n\x0b\n\
+    \x04\x04\x02\x02\x01\x12\x03x\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x06\
+    \x12\x03x\x02\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03x\x12\x18\n\x0c\
+    \n\x05\x04\x02\x02\x01\x03\x12\x03x\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\
+    \x12\x03y\x02\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03y\x02\x08\n\x0c\
+    \n\x05\x04\x02\x02\x02\x01\x12\x03y\t\x0f\n\x0c\n\x05\x04\x02\x02\x02\
+    \x03\x12\x03y\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03z\x02\x16\n\x0c\
+    \n\x05\x04\x02\x02\x03\x05\x12\x03z\x02\x07\n\x0c\n\x05\x04\x02\x02\x03\
+    \x01\x12\x03z\x08\x11\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03z\x14\x15\n\
+    \x0b\n\x04\x04\x02\x02\x04\x12\x03{\x02\x1c\n\x0c\n\x05\x04\x02\x02\x04\
+    \x05\x12\x03{\x02\x07\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03{\x08\x17\n\
+    \x0c\n\x05\x04\x02\x02\x04\x03\x12\x03{\x1a\x1b\n\x0b\n\x04\x04\x02\x02\
+    \x05\x12\x03|\x02&\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03|\x02\x07\n\
+    \x0c\n\x05\x04\x02\x02\x05\x01\x12\x03|\x08!\n\x0c\n\x05\x04\x02\x02\x05\
+    \x03\x12\x03|$%\n\x0b\n\x04\x04\x02\x02\x06\x12\x03}\x02\x1b\n\x0c\n\x05\
+    \x04\x02\x02\x06\x05\x12\x03}\x02\x07\n\x0c\n\x05\x04\x02\x02\x06\x01\
+    \x12\x03}\x08\x16\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03}\x19\x1a\n\x0b\
+    \n\x04\x04\x02\x02\x07\x12\x03~\x02\x1d\n\x0c\n\x05\x04\x02\x02\x07\x06\
+    \x12\x03~\x02\x0f\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03~\x10\x18\n\x0c\
+    \n\x05\x04\x02\x02\x07\x03\x12\x03~\x1b\x1c\n\x0c\n\x02\x04\x03\x12\x06\
+    \x81\x01\0\x88\x01\x01\n\x0b\n\x03\x04\x03\x01\x12\x04\x81\x01\x08\x15\n\
+    \x0c\n\x04\x04\x03\x02\0\x12\x04\x82\x01\x02\x16\n\r\n\x05\x04\x03\x02\0\
+    \x05\x12\x04\x82\x01\x02\x08\n\r\n\x05\x04\x03\x02\0\x01\x12\x04\x82\x01\
+    \t\x11\n\r\n\x05\x04\x03\x02\0\x03\x12\x04\x82\x01\x14\x15\n\x0c\n\x04\
+    \x04\x03\x02\x01\x12\x04\x83\x01\x02\x16\n\r\n\x05\x04\x03\x02\x01\x05\
+    \x12\x04\x83\x01\x02\x08\n\r\n\x05\x04\x03\x02\x01\x01\x12\x04\x83\x01\t\
+    \x11\n\r\n\x05\x04\x03\x02\x01\x03\x12\x04\x83\x01\x14\x15\n\x0c\n\x04\
+    \x04\x03\x02\x02\x12\x04\x84\x01\x02\x19\n\r\n\x05\x04\x03\x02\x02\x05\
+    \x12\x04\x84\x01\x02\x08\n\r\n\x05\x04\x03\x02\x02\x01\x12\x04\x84\x01\t\
+    \x14\n\r\n\x05\x04\x03\x02\x02\x03\x12\x04\x84\x01\x17\x18\n\x0c\n\x04\
+    \x04\x03\x02\x03\x12\x04\x85\x01\x02\x19\n\r\n\x05\x04\x03\x02\x03\x05\
+    \x12\x04\x85\x01\x02\x08\n\r\n\x05\x04\x03\x02\x03\x01\x12\x04\x85\x01\t\
+    \x14\n\r\n\x05\x04\x03\x02\x03\x03\x12\x04\x85\x01\x17\x18\n\x0c\n\x04\
+    \x04\x03\x02\x04\x12\x04\x86\x01\x02\x15\n\r\n\x05\x04\x03\x02\x04\x05\
+    \x12\x04\x86\x01\x02\x08\n\r\n\x05\x04\x03\x02\x04\x01\x12\x04\x86\x01\t\
+    \x10\n\r\n\x05\x04\x03\x02\x04\x03\x12\x04\x86\x01\x13\x14\n\x0c\n\x04\
+    \x04\x03\x02\x05\x12\x04\x87\x01\x02\x15\n\r\n\x05\x04\x03\x02\x05\x05\
+    \x12\x04\x87\x01\x02\x08\n\r\n\x05\x04\x03\x02\x05\x01\x12\x04\x87\x01\t\
+    \x10\n\r\n\x05\x04\x03\x02\x05\x03\x12\x04\x87\x01\x13\x14\n\x0c\n\x02\
+    \x04\x04\x12\x06\x8a\x01\0\x8e\x01\x01\n\x0b\n\x03\x04\x04\x01\x12\x04\
+    \x8a\x01\x08\x19\n\x0c\n\x04\x04\x04\x02\0\x12\x04\x8b\x01\x02\x12\n\r\n\
+    \x05\x04\x04\x02\0\x05\x12\x04\x8b\x01\x02\x08\n\r\n\x05\x04\x04\x02\0\
+    \x01\x12\x04\x8b\x01\t\r\n\r\n\x05\x04\x04\x02\0\x03\x12\x04\x8b\x01\x10\
+    \x11\n\x0c\n\x04\x04\x04\x02\x01\x12\x04\x8c\x01\x02\x12\n\r\n\x05\x04\
+    \x04\x02\x01\x05\x12\x04\x8c\x01\x02\x08\n\r\n\x05\x04\x04\x02\x01\x01\
+    \x12\x04\x8c\x01\t\r\n\r\n\x05\x04\x04\x02\x01\x03\x12\x04\x8c\x01\x10\
+    \x11\n\x0c\n\x04\x04\x04\x02\x02\x12\x04\x8d\x01\x02\x17\n\r\n\x05\x04\
+    \x04\x02\x02\x05\x12\x04\x8d\x01\x02\x08\n\r\n\x05\x04\x04\x02\x02\x01\
+    \x12\x04\x8d\x01\t\x12\n\r\n\x05\x04\x04\x02\x02\x03\x12\x04\x8d\x01\x15\
+    \x16\n\x0c\n\x02\x04\x05\x12\x06\x90\x01\0\x95\x01\x01\n\x0b\n\x03\x04\
+    \x05\x01\x12\x04\x90\x01\x08\x16\n\x0c\n\x04\x04\x05\x02\0\x12\x04\x91\
+    \x01\x02\x11\n\r\n\x05\x04\x05\x02\0\x05\x12\x04\x91\x01\x02\x07\n\r\n\
+    \x05\x04\x05\x02\0\x01\x12\x04\x91\x01\x08\x0c\n\r\n\x05\x04\x05\x02\0\
+    \x03\x12\x04\x91\x01\x0f\x10\n\x0c\n\x04\x04\x05\x02\x01\x12\x04\x92\x01\
+    \x02\x19\n\r\n\x05\x04\x05\x02\x01\x05\x12\x04\x92\x01\x02\x07\n\r\n\x05\
+    \x04\x05\x02\x01\x01\x12\x04\x92\x01\x08\x14\n\r\n\x05\x04\x05\x02\x01\
+    \x03\x12\x04\x92\x01\x17\x18\n\x0c\n\x04\x04\x05\x02\x02\x12\x04\x93\x01\
+    \x02\x1b\n\r\n\x05\x04\x05\x02\x02\x05\x12\x04\x93\x01\x02\x07\n\r\n\x05\
+    \x04\x05\x02\x02\x01\x12\x04\x93\x01\x08\x16\n\r\n\x05\x04\x05\x02\x02\
+    \x03\x12\x04\x93\x01\x19\x1a\n\x0c\n\x04\x04\x05\x02\x03\x12\x04\x94\x01\
+    \x02\x1a\n\r\n\x05\x04\x05\x02\x03\x05\x12\x04\x94\x01\x02\x07\n\r\n\x05\
+    \x04\x05\x02\x03\x01\x12\x04\x94\x01\x08\x15\n\r\n\x05\x04\x05\x02\x03\
+    \x03\x12\x04\x94\x01\x18\x19\n\x0c\n\x02\x04\x06\x12\x06\x97\x01\0\xa1\
+    \x01\x01\n\x0b\n\x03\x04\x06\x01\x12\x04\x97\x01\x08\x15\n\x0c\n\x04\x04\
+    \x06\x02\0\x12\x04\x98\x01\x02\x1d\n\r\n\x05\x04\x06\x02\0\x05\x12\x04\
+    \x98\x01\x02\x07\n\r\n\x05\x04\x06\x02\0\x01\x12\x04\x98\x01\x08\x18\n\r\
+    \n\x05\x04\x06\x02\0\x03\x12\x04\x98\x01\x1b\x1c\n\x0c\n\x04\x04\x06\x02\
+    \x01\x12\x04\x99\x01\x02\x1d\n\r\n\x05\x04\x06\x02\x01\x05\x12\x04\x99\
+    \x01\x02\x07\n\r\n\x05\x04\x06\x02\x01\x01\x12\x04\x99\x01\x08\x18\n\r\n\
+    \x05\x04\x06\x02\x01\x03\x12\x04\x99\x01\x1b\x1c\n\x0c\n\x04\x04\x06\x02\
+    \x02\x12\x04\x9a\x01\x02\x1b\n\r\n\x05\x04\x06\x02\x02\x05\x12\x04\x9a\
+    \x01\x02\x07\n\r\n\x05\x04\x06\x02\x02\x01\x12\x04\x9a\x01\x08\x16\n\r\n\
+    \x05\x04\x06\x02\x02\x03\x12\x04\x9a\x01\x19\x1a\n\x0c\n\x04\x04\x06\x02\
+    \x03\x12\x04\x9b\x01\x02\x1f\n\r\n\x05\x04\x06\x02\x03\x05\x12\x04\x9b\
+    \x01\x02\x07\n\r\n\x05\x04\x06\x02\x03\x01\x12\x04\x9b\x01\x08\x1a\n\r\n\
+    \x05\x04\x06\x02\x03\x03\x12\x04\x9b\x01\x1d\x1e\n\x0c\n\x04\x04\x06\x02\
+    \x04\x12\x04\x9c\x01\x02!\n\r\n\x05\x04\x06\x02\x04\x05\x12\x04\x9c\x01\
+    \x02\x07\n\r\n\x05\x04\x06\x02\x04\x01\x12\x04\x9c\x01\x08\x1c\n\r\n\x05\
+    \x04\x06\x02\x04\x03\x12\x04\x9c\x01\x1f\x20\n\x0c\n\x04\x04\x06\x02\x05\
+    \x12\x04\x9d\x01\x02\x17\n\r\n\x05\x04\x06\x02\x05\x05\x12\x04\x9d\x01\
+    \x02\x07\n\r\n\x05\x04\x06\x02\x05\x01\x12\x04\x9d\x01\x08\x12\n\r\n\x05\
+    \x04\x06\x02\x05\x03\x12\x04\x9d\x01\x15\x16\n\x0c\n\x04\x04\x06\x02\x06\
+    \x12\x04\x9e\x01\x02\x20\n\r\n\x05\x04\x06\x02\x06\x05\x12\x04\x9e\x01\
+    \x02\x07\n\r\n\x05\x04\x06\x02\x06\x01\x12\x04\x9e\x01\x08\x1b\n\r\n\x05\
+    \x04\x06\x02\x06\x03\x12\x04\x9e\x01\x1e\x1f\n\x0c\n\x04\x04\x06\x02\x07\
+    \x12\x04\x9f\x01\x02\"\n\r\n\x05\x04\x06\x02\x07\x06\x12\x04\x9f\x01\x02\
+    \x11\n\r\n\x05\x04\x06\x02\x07\x01\x12\x04\x9f\x01\x12\x1d\n\r\n\x05\x04\
+    \x06\x02\x07\x03\x12\x04\x9f\x01\x20!\n\x0c\n\x04\x04\x06\x02\x08\x12\
+    \x04\xa0\x01\x02\x20\n\r\n\x05\x04\x06\x02\x08\x05\x12\x04\xa0\x01\x02\
+    \x08\n\r\n\x05\x04\x06\x02\x08\x01\x12\x04\xa0\x01\t\x1b\n\r\n\x05\x04\
+    \x06\x02\x08\x03\x12\x04\xa0\x01\x1e\x1f\n\x0c\n\x02\x04\x07\x12\x06\xa3\
+    \x01\0\xac\x01\x01\n\x0b\n\x03\x04\x07\x01\x12\x04\xa3\x01\x08\x11\n\x0c\
+    \n\x04\x04\x07\x02\0\x12\x04\xa4\x01\x02\x17\n\r\n\x05\x04\x07\x02\0\x05\
+    \x12\x04\xa4\x01\x02\x07\n\r\n\x05\x04\x07\x02\0\x01\x12\x04\xa4\x01\x08\
+    \x12\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\xa4\x01\x15\x16\n\x0c\n\x04\x04\
+    \x07\x02\x01\x12\x04\xa5\x01\x02\x1c\n\r\n\x05\x04\x07\x02\x01\x05\x12\
+    \x04\xa5\x01\x02\x07\n\r\n\x05\x04\x07\x02\x01\x01\x12\x04\xa5\x01\x08\
+    \x17\n\r\n\x05\x04\x07\x02\x01\x03\x12\x04\xa5\x01\x1a\x1b\n\x0c\n\x04\
+    \x04\x07\x02\x02\x12\x04\xa6\x01\x02\x1d\n\r\n\x05\x04\x07\x02\x02\x05\
+    \x12\x04\xa6\x01\x02\x07\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\xa6\x01\
+    \x08\x18\n\r\n\x05\x04\x07\x02\x02\x03\x12\x04\xa6\x01\x1b\x1c\n\x0c\n\
+    \x04\x04\x07\x02\x03\x12\x04\xa7\x01\x02\x13\n\r\n\x05\x04\x07\x02\x03\
+    \x05\x12\x04\xa7\x01\x02\x07\n\r\n\x05\x04\x07\x02\x03\x01\x12\x04\xa7\
+    \x01\x08\x0e\n\r\n\x05\x04\x07\x02\x03\x03\x12\x04\xa7\x01\x11\x12\n\x0c\
+    \n\x04\x04\x07\x02\x04\x12\x04\xa8\x01\x02\x16\n\r\n\x05\x04\x07\x02\x04\
+    \x05\x12\x04\xa8\x01\x02\x07\n\r\n\x05\x04\x07\x02\x04\x01\x12\x04\xa8\
+    \x01\x08\x11\n\r\n\x05\x04\x07\x02\x04\x03\x12\x04\xa8\x01\x14\x15\n\x0c\
+    \n\x04\x04\x07\x02\x05\x12\x04\xa9\x01\x02\x13\n\r\n\x05\x04\x07\x02\x05\
+    \x05\x12\x04\xa9\x01\x02\x07\n\r\n\x05\x04\x07\x02\x05\x01\x12\x04\xa9\
+    \x01\x08\x0e\n\r\n\x05\x04\x07\x02\x05\x03\x12\x04\xa9\x01\x11\x12\n\x0c\
+    \n\x04\x04\x07\x02\x06\x12\x04\xaa\x01\x02\x14\n\r\n\x05\x04\x07\x02\x06\
+    \x05\x12\x04\xaa\x01\x02\x06\n\r\n\x05\x04\x07\x02\x06\x01\x12\x04\xaa\
+    \x01\x07\x0f\n\r\n\x05\x04\x07\x02\x06\x03\x12\x04\xaa\x01\x12\x13\n\x0c\
+    \n\x04\x04\x07\x02\x07\x12\x04\xab\x01\x02!\n\r\n\x05\x04\x07\x02\x07\
+    \x05\x12\x04\xab\x01\x02\x06\n\r\n\x05\x04\x07\x02\x07\x01\x12\x04\xab\
+    \x01\x07\x1c\n\r\n\x05\x04\x07\x02\x07\x03\x12\x04\xab\x01\x1f\x20\n\x0c\
+    \n\x02\x04\x08\x12\x06\xae\x01\0\xb4\x01\x01\n\x0b\n\x03\x04\x08\x01\x12\
+    \x04\xae\x01\x08\x15\n\x0c\n\x04\x04\x08\x02\0\x12\x04\xaf\x01\x02\x1f\n\
+    \r\n\x05\x04\x08\x02\0\x05\x12\x04\xaf\x01\x02\x07\n\r\n\x05\x04\x08\x02\
+    \0\x01\x12\x04\xaf\x01\x08\x1a\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\xaf\
+    \x01\x1d\x1e\n\x0c\n\x04\x04\x08\x02\x01\x12\x04\xb0\x01\x02!\n\r\n\x05\
+    \x04\x08\x02\x01\x05\x12\x04\xb0\x01\x02\x07\n\r\n\x05\x04\x08\x02\x01\
+    \x01\x12\x04\xb0\x01\x08\x1c\n\r\n\x05\x04\x08\x02\x01\x03\x12\x04\xb0\
+    \x01\x1f\x20\n\x0c\n\x04\x04\x08\x02\x02\x12\x04\xb1\x01\x02\x13\n\r\n\
+    \x05\x04\x08\x02\x02\x05\x12\x04\xb1\x01\x02\x07\n\r\n\x05\x04\x08\x02\
+    \x02\x01\x12\x04\xb1\x01\x08\x0e\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\
+    \xb1\x01\x11\x12\n\x0c\n\x04\x04\x08\x02\x03\x12\x04\xb2\x01\x02\x15\n\r\
+    \n\x05\x04\x08\x02\x03\x05\x12\x04\xb2\x01\x02\x06\n\r\n\x05\x04\x08\x02\
+    \x03\x01\x12\x04\xb2\x01\x07\x10\n\r\n\x05\x04\x08\x02\x03\x03\x12\x04\
+    \xb2\x01\x13\x14\n\x0c\n\x04\x04\x08\x02\x04\x12\x04\xb3\x01\x02\x13\n\r\
+    \n\x05\x04\x08\x02\x04\x05\x12\x04\xb3\x01\x02\x06\n\r\n\x05\x04\x08\x02\
+    \x04\x01\x12\x04\xb3\x01\x07\x0e\n\r\n\x05\x04\x08\x02\x04\x03\x12\x04\
+    \xb3\x01\x11\x12\n\x0c\n\x02\x04\t\x12\x06\xb6\x01\0\xbf\x01\x01\n\x0b\n\
+    \x03\x04\t\x01\x12\x04\xb6\x01\x08\x17\n\x0c\n\x04\x04\t\x02\0\x12\x04\
+    \xb7\x01\x02\x12\n\r\n\x05\x04\t\x02\0\x05\x12\x04\xb7\x01\x02\x08\n\r\n\
+    \x05\x04\t\x02\0\x01\x12\x04\xb7\x01\t\r\n\r\n\x05\x04\t\x02\0\x03\x12\
+    \x04\xb7\x01\x10\x11\n\x0c\n\x04\x04\t\x02\x01\x12\x04\xb8\x01\x02\x14\n\
+    \r\n\x05\x04\t\x02\x01\x05\x12\x04\xb8\x01\x02\x08\n\r\n\x05\x04\t\x02\
+    \x01\x01\x12\x04\xb8\x01\t\x0f\n\r\n\x05\x04\t\x02\x01\x03\x12\x04\xb8\
+    \x01\x12\x13\n\x0c\n\x04\x04\t\x02\x02\x12\x04\xb9\x01\x02\x12\n\r\n\x05\
+    \x04\t\x02\x02\x05\x12\x04\xb9\x01\x02\x08\n\r\n\x05\x04\t\x02\x02\x01\
+    \x12\x04\xb9\x01\t\r\n\r\n\x05\x04\t\x02\x02\x03\x12\x04\xb9\x01\x10\x11\
+    \n\x0c\n\x04\x04\t\x02\x03\x12\x04\xba\x01\x02\x14\n\r\n\x05\x04\t\x02\
+    \x03\x05\x12\x04\xba\x01\x02\x08\n\r\n\x05\x04\t\x02\x03\x01\x12\x04\xba\
+    \x01\t\x0f\n\r\n\x05\x04\t\x02\x03\x03\x12\x04\xba\x01\x12\x13\n\x0c\n\
+    \x04\x04\t\x02\x04\x12\x04\xbb\x01\x02\"\n\r\n\x05\x04\t\x02\x04\x05\x12\
+    \x04\xbb\x01\x02\x08\n\r\n\x05\x04\t\x02\x04\x01\x12\x04\xbb\x01\t\x1d\n\
+    \r\n\x05\x04\t\x02\x04\x03\x12\x04\xbb\x01\x20!\n\x0c\n\x04\x04\t\x02\
+    \x05\x12\x04\xbc\x01\x02\x12\n\r\n\x05\x04\t\x02\x05\x05\x12\x04\xbc\x01\
+    \x02\x07\n\r\n\x05\x04\t\x02\x05\x01\x12\x04\xbc\x01\x08\r\n\r\n\x05\x04\
+    \t\x02\x05\x03\x12\x04\xbc\x01\x10\x11\n\x0c\n\x04\x04\t\x02\x06\x12\x04\
+    \xbd\x01\x02\x1c\n\r\n\x05\x04\t\x02\x06\x05\x12\x04\xbd\x01\x02\x07\n\r\
+    \n\x05\x04\t\x02\x06\x01\x12\x04\xbd\x01\x08\x17\n\r\n\x05\x04\t\x02\x06\
+    \x03\x12\x04\xbd\x01\x1a\x1b\n\x0c\n\x04\x04\t\x02\x07\x12\x04\xbe\x01\
+    \x02\x1b\n\r\n\x05\x04\t\x02\x07\x05\x12\x04\xbe\x01\x02\x07\n\r\n\x05\
+    \x04\t\x02\x07\x01\x12\x04\xbe\x01\x08\x16\n\r\n\x05\x04\t\x02\x07\x03\
+    \x12\x04\xbe\x01\x19\x1a\n\x0c\n\x02\x04\n\x12\x06\xc1\x01\0\xca\x01\x01\
+    \n\x0b\n\x03\x04\n\x01\x12\x04\xc1\x01\x08#\n\x0c\n\x04\x04\n\x02\0\x12\
+    \x04\xc2\x01\x02\x12\n\r\n\x05\x04\n\x02\0\x05\x12\x04\xc2\x01\x02\x08\n\
+    \r\n\x05\x04\n\x02\0\x01\x12\x04\xc2\x01\t\r\n\r\n\x05\x04\n\x02\0\x03\
+    \x12\x04\xc2\x01\x10\x11\n\x0c\n\x04\x04\n\x02\x01\x12\x04\xc3\x01\x02\
+    \x17\n\r\n\x05\x04\n\x02\x01\x05\x12\x04\xc3\x01\x02\x08\n\r\n\x05\x04\n\
+    \x02\x01\x01\x12\x04\xc3\x01\t\x12\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\
+    \xc3\x01\x15\x16\n\x0c\n\x04\x04\n\x02\x02\x12\x04\xc4\x01\x02\x1b\n\r\n\
+    \x05\x04\n\x02\x02\x05\x12\x04\xc4\x01\x02\x08\n\r\n\x05\x04\n\x02\x02\
+    \x01\x12\x04\xc4\x01\t\x16\n\r\n\x05\x04\n\x02\x02\x03\x12\x04\xc4\x01\
+    \x19\x1a\n\x0c\n\x04\x04\n\x02\x03\x12\x04\xc5\x01\x02'\n\r\n\x05\x04\n\
+    \x02\x03\x06\x12\x04\xc5\x01\x02\x1c\n\r\n\x05\x04\n\x02\x03\x01\x12\x04\
+    \xc5\x01\x1d\"\n\r\n\x05\x04\n\x02\x03\x03\x12\x04\xc5\x01%&\n\x0c\n\x04\
+    \x04\n\x02\x04\x12\x04\xc6\x01\x02\x1c\n\r\n\x05\x04\n\x02\x04\x05\x12\
+    \x04\xc6\x01\x02\x07\n\r\n\x05\x04\n\x02\x04\x01\x12\x04\xc6\x01\x08\x17\
+    \n\r\n\x05\x04\n\x02\x04\x03\x12\x04\xc6\x01\x1a\x1b\n\x0c\n\x04\x04\n\
+    \x02\x05\x12\x04\xc7\x01\x02\x1b\n\r\n\x05\x04\n\x02\x05\x05\x12\x04\xc7\
+    \x01\x02\x07\n\r\n\x05\x04\n\x02\x05\x01\x12\x04\xc7\x01\x08\x16\n\r\n\
+    \x05\x04\n\x02\x05\x03\x12\x04\xc7\x01\x19\x1a\n\x0c\n\x04\x04\n\x02\x06\
+    \x12\x04\xc8\x01\x02#\n\r\n\x05\x04\n\x02\x06\x04\x12\x04\xc8\x01\x02\n\
+    \n\r\n\x05\x04\n\x02\x06\x05\x12\x04\xc8\x01\x0b\x11\n\r\n\x05\x04\n\x02\
+    \x06\x01\x12\x04\xc8\x01\x12\x1e\n\r\n\x05\x04\n\x02\x06\x03\x12\x04\xc8\
+    \x01!\"\n\x0c\n\x04\x04\n\x02\x07\x12\x04\xc9\x01\x02\x19\n\r\n\x05\x04\
+    \n\x02\x07\x05\x12\x04\xc9\x01\x02\x08\n\r\n\x05\x04\n\x02\x07\x01\x12\
+    \x04\xc9\x01\t\x14\n\r\n\x05\x04\n\x02\x07\x03\x12\x04\xc9\x01\x17\x18\n\
+    \x0c\n\x02\x04\x0b\x12\x06\xcc\x01\0\xd4\x01\x01\n\x0b\n\x03\x04\x0b\x01\
+    \x12\x04\xcc\x01\x08\x1e\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\xcd\x01\x02\"\
+    \n\r\n\x05\x04\x0b\x02\0\x06\x12\x04\xcd\x01\x02\x17\n\r\n\x05\x04\x0b\
+    \x02\0\x01\x12\x04\xcd\x01\x18\x1d\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\
+    \xcd\x01\x20!\n\x0c\n\x04\x04\x0b\x02\x01\x12\x04\xce\x01\x02\x1b\n\r\n\
+    \x05\x04\x0b\x02\x01\x05\x12\x04\xce\x01\x02\x07\n\r\n\x05\x04\x0b\x02\
+    \x01\x01\x12\x04\xce\x01\x08\x16\n\r\n\x05\x04\x0b\x02\x01\x03\x12\x04\
+    \xce\x01\x19\x1a\n\x0c\n\x04\x04\x0b\x02\x02\x12\x04\xcf\x01\x02#\n\r\n\
+    \x05\x04\x0b\x02\x02\x04\x12\x04\xcf\x01\x02\n\n\r\n\x05\x04\x0b\x02\x02\
+    \x05\x12\x04\xcf\x01\x0b\x11\n\r\n\x05\x04\x0b\x02\x02\x01\x12\x04\xcf\
+    \x01\x12\x1e\n\r\n\x05\x04\x0b\x02\x02\x03\x12\x04\xcf\x01!\"\n\x0c\n\
+    \x04\x04\x0b\x02\x03\x12\x04\xd0\x01\x02\x1c\n\r\n\x05\x04\x0b\x02\x03\
+    \x05\x12\x04\xd0\x01\x02\x08\n\r\n\x05\x04\x0b\x02\x03\x01\x12\x04\xd0\
+    \x01\t\x17\n\r\n\x05\x04\x0b\x02\x03\x03\x12\x04\xd0\x01\x1a\x1b\n\x0c\n\
+    \x04\x04\x0b\x02\x04\x12\x04\xd1\x01\x02\x1b\n\r\n\x05\x04\x0b\x02\x04\
+    \x05\x12\x04\xd1\x01\x02\x08\n\r\n\x05\x04\x0b\x02\x04\x01\x12\x04\xd1\
+    \x01\t\x16\n\r\n\x05\x04\x0b\x02\x04\x03\x12\x04\xd1\x01\x19\x1a\n\x0c\n\
+    \x04\x04\x0b\x02\x05\x12\x04\xd2\x01\x02\x18\n\r\n\x05\x04\x0b\x02\x05\
+    \x05\x12\x04\xd2\x01\x02\x08\n\r\n\x05\x04\x0b\x02\x05\x01\x12\x04\xd2\
+    \x01\t\x13\n\r\n\x05\x04\x0b\x02\x05\x03\x12\x04\xd2\x01\x16\x17\n\x0c\n\
+    \x04\x04\x0b\x02\x06\x12\x04\xd3\x01\x02\x1d\n\r\n\x05\x04\x0b\x02\x06\
+    \x05\x12\x04\xd3\x01\x02\x08\n\r\n\x05\x04\x0b\x02\x06\x01\x12\x04\xd3\
+    \x01\t\x18\n\r\n\x05\x04\x0b\x02\x06\x03\x12\x04\xd3\x01\x1b\x1c\n\x0c\n\
+    \x02\x04\x0c\x12\x06\xd6\x01\0\xe1\x01\x01\n\x0b\n\x03\x04\x0c\x01\x12\
+    \x04\xd6\x01\x08%\n\x0c\n\x04\x04\x0c\x02\0\x12\x04\xd7\x01\x02%\n\r\n\
+    \x05\x04\x0c\x02\0\x06\x12\x04\xd7\x01\x02\x13\n\r\n\x05\x04\x0c\x02\0\
+    \x01\x12\x04\xd7\x01\x14\x20\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\xd7\x01\
+    #$\n\x0c\n\x04\x04\x0c\x02\x01\x12\x04\xd8\x01\x02\x1d\n\r\n\x05\x04\x0c\
+    \x02\x01\x05\x12\x04\xd8\x01\x02\x07\n\r\n\x05\x04\x0c\x02\x01\x01\x12\
+    \x04\xd8\x01\x08\x18\n\r\n\x05\x04\x0c\x02\x01\x03\x12\x04\xd8\x01\x1b\
+    \x1c\n\x0c\n\x04\x04\x0c\x02\x02\x12\x04\xd9\x01\x02\x1d\n\r\n\x05\x04\
+    \x0c\x02\x02\x05\x12\x04\xd9\x01\x02\x07\n\r\n\x05\x04\x0c\x02\x02\x01\
+    \x12\x04\xd9\x01\x08\x18\n\r\n\x05\x04\x0c\x02\x02\x03\x12\x04\xd9\x01\
+    \x1b\x1c\n\x0c\n\x04\x04\x0c\x02\x03\x12\x04\xda\x01\x02\x19\n\r\n\x05\
+    \x04\x0c\x02\x03\x05\x12\x04\xda\x01\x02\x07\n\r\n\x05\x04\x0c\x02\x03\
+    \x01\x12\x04\xda\x01\x08\x14\n\r\n\x05\x04\x0c\x02\x03\x03\x12\x04\xda\
+    \x01\x17\x18\n\x0c\n\x04\x04\x0c\x02\x04\x12\x04\xdb\x01\x02\x19\n\r\n\
+    \x05\x04\x0c\x02\x04\x05\x12\x04\xdb\x01\x02\x07\n\r\n\x05\x04\x0c\x02\
+    \x04\x01\x12\x04\xdb\x01\x08\x14\n\r\n\x05\x04\x0c\x02\x04\x03\x12\x04\
+    \xdb\x01\x17\x18\n\x0c\n\x04\x04\x0c\x02\x05\x12\x04\xdc\x01\x02$\n\r\n\
+    \x05\x04\x0c\x02\x05\x06\x12\x04\xdc\x01\x02\x11\n\r\n\x05\x04\x0c\x02\
+    \x05\x01\x12\x04\xdc\x01\x12\x1f\n\r\n\x05\x04\x0c\x02\x05\x03\x12\x04\
+    \xdc\x01\"#\n\x0c\n\x04\x04\x0c\x02\x06\x12\x04\xdd\x01\x02%\n\r\n\x05\
+    \x04\x0c\x02\x06\x06\x12\x04\xdd\x01\x02\x11\n\r\n\x05\x04\x0c\x02\x06\
+    \x01\x12\x04\xdd\x01\x12\x20\n\r\n\x05\x04\x0c\x02\x06\x03\x12\x04\xdd\
+    \x01#$\n\x0c\n\x04\x04\x0c\x02\x07\x12\x04\xde\x01\x02&\n\r\n\x05\x04\
+    \x0c\x02\x07\x06\x12\x04\xde\x01\x02\x11\n\r\n\x05\x04\x0c\x02\x07\x01\
+    \x12\x04\xde\x01\x12!\n\r\n\x05\x04\x0c\x02\x07\x03\x12\x04\xde\x01$%\n\
+    \x0c\n\x04\x04\x0c\x02\x08\x12\x04\xdf\x01\x02$\n\r\n\x05\x04\x0c\x02\
+    \x08\x05\x12\x04\xdf\x01\x02\x08\n\r\n\x05\x04\x0c\x02\x08\x01\x12\x04\
+    \xdf\x01\t\x1f\n\r\n\x05\x04\x0c\x02\x08\x03\x12\x04\xdf\x01\"#\n\x0c\n\
+    \x04\x04\x0c\x02\t\x12\x04\xe0\x01\x026\n\r\n\x05\x04\x0c\x02\t\x04\x12\
+    \x04\xe0\x01\x02\n\n\r\n\x05\x04\x0c\x02\t\x06\x12\x04\xe0\x01\x0b(\n\r\
+    \n\x05\x04\x0c\x02\t\x01\x12\x04\xe0\x01)0\n\r\n\x05\x04\x0c\x02\t\x03\
+    \x12\x04\xe0\x0135\n\x0c\n\x02\x04\r\x12\x06\xe3\x01\0\xea\x01\x01\n\x0b\
+    \n\x03\x04\r\x01\x12\x04\xe3\x01\x08%\n\x0c\n\x04\x04\r\x02\0\x12\x04\
+    \xe4\x01\x02\x12\n\r\n\x05\x04\r\x02\0\x05\x12\x04\xe4\x01\x02\x08\n\r\n\
+    \x05\x04\r\x02\0\x01\x12\x04\xe4\x01\t\r\n\r\n\x05\x04\r\x02\0\x03\x12\
+    \x04\xe4\x01\x10\x11\n\x0c\n\x04\x04\r\x02\x01\x12\x04\xe5\x01\x02\x12\n\
+    \r\n\x05\x04\r\x02\x01\x05\x12\x04\xe5\x01\x02\x08\n\r\n\x05\x04\r\x02\
+    \x01\x01\x12\x04\xe5\x01\t\r\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\xe5\x01\
+    \x10\x11\n\x0c\n\x04\x04\r\x02\x02\x12\x04\xe6\x01\x02\x17\n\r\n\x05\x04\
+    \r\x02\x02\x05\x12\x04\xe6\x01\x02\x08\n\r\n\x05\x04\r\x02\x02\x01\x12\
+    \x04\xe6\x01\t\x12\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\xe6\x01\x15\x16\n\
+    \x0c\n\x04\x04\r\x02\x03\x12\x04\xe7\x01\x02\x19\n\r\n\x05\x04\r\x02\x03\
+    \x05\x12\x04\xe7\x01\x02\x08\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\xe7\x01\
+    \t\x14\n\r\n\x05\x04\r\x02\x03\x03\x12\x04\xe7\x01\x17\x18\n\x0c\n\x04\
+    \x04\r\x02\x04\x12\x04\xe8\x01\x02\x1a\n\r\n\x05\x04\r\x02\x04\x05\x12\
+    \x04\xe8\x01\x02\x08\n\r\n\x05\x04\r\x02\x04\x01\x12\x04\xe8\x01\t\x15\n\
+    \r\n\x05\x04\r\x02\x04\x03\x12\x04\xe8\x01\x18\x19\n\x0c\n\x04\x04\r\x02\
+    \x05\x12\x04\xe9\x01\x02\x1b\n\r\n\x05\x04\r\x02\x05\x05\x12\x04\xe9\x01\
+    \x02\x08\n\r\n\x05\x04\r\x02\x05\x01\x12\x04\xe9\x01\t\x16\n\r\n\x05\x04\
+    \r\x02\x05\x03\x12\x04\xe9\x01\x19\x1a\n\x0c\n\x02\x04\x0e\x12\x06\xec\
+    \x01\0\xef\x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\x04\xec\x01\x08\x1b\n\x0c\
+    \n\x04\x04\x0e\x02\0\x12\x04\xed\x01\x02/\n\r\n\x05\x04\x0e\x02\0\x04\
+    \x12\x04\xed\x01\x02\n\n\r\n\x05\x04\x0e\x02\0\x06\x12\x04\xed\x01\x0b\
+    \x20\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xed\x01!*\n\r\n\x05\x04\x0e\x02\
+    \0\x03\x12\x04\xed\x01-.\n\x0c\n\x04\x04\x0e\x02\x01\x12\x04\xee\x01\x02\
+    \x16\n\r\n\x05\x04\x0e\x02\x01\x05\x12\x04\xee\x01\x02\x07\n\r\n\x05\x04\
+    \x0e\x02\x01\x01\x12\x04\xee\x01\x08\x11\n\r\n\x05\x04\x0e\x02\x01\x03\
+    \x12\x04\xee\x01\x14\x15\n\x0c\n\x02\x04\x0f\x12\x06\xf1\x01\0\xf6\x01\
+    \x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\xf1\x01\x08\x1d\n\x0c\n\x04\x04\x0f\
+    \x02\0\x12\x04\xf2\x01\x02\x12\n\r\n\x05\x04\x0f\x02\0\x05\x12\x04\xf2\
+    \x01\x02\x08\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xf2\x01\t\r\n\r\n\x05\
+    \x04\x0f\x02\0\x03\x12\x04\xf2\x01\x10\x11\n\x0c\n\x04\x04\x0f\x02\x01\
+    \x12\x04\xf3\x01\x02\x12\n\r\n\x05\x04\x0f\x02\x01\x05\x12\x04\xf3\x01\
+    \x02\x08\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xf3\x01\t\r\n\r\n\x05\x04\
+    \x0f\x02\x01\x03\x12\x04\xf3\x01\x10\x11\n\x0c\n\x04\x04\x0f\x02\x02\x12\
+    \x04\xf4\x01\x02\x12\n\r\n\x05\x04\x0f\x02\x02\x05\x12\x04\xf4\x01\x02\
+    \x08\n\r\n\x05\x04\x0f\x02\x02\x01\x12\x04\xf4\x01\t\r\n\r\n\x05\x04\x0f\
+    \x02\x02\x03\x12\x04\xf4\x01\x10\x11\n\x0c\n\x04\x04\x0f\x02\x03\x12\x04\
+    \xf5\x01\x02\x12\n\r\n\x05\x04\x0f\x02\x03\x05\x12\x04\xf5\x01\x02\x07\n\
+    \r\n\x05\x04\x0f\x02\x03\x01\x12\x04\xf5\x01\x08\r\n\r\n\x05\x04\x0f\x02\
+    \x03\x03\x12\x04\xf5\x01\x10\x11\n\x0c\n\x02\x04\x10\x12\x06\xf8\x01\0\
+    \xfa\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\x04\xf8\x01\x08\x18\n\x0c\n\x04\
+    \x04\x10\x02\0\x12\x04\xf9\x01\x02&\n\r\n\x05\x04\x10\x02\0\x04\x12\x04\
+    \xf9\x01\x02\n\n\r\n\x05\x04\x10\x02\0\x06\x12\x04\xf9\x01\x0b\x1a\n\r\n\
+    \x05\x04\x10\x02\0\x01\x12\x04\xf9\x01\x1b!\n\r\n\x05\x04\x10\x02\0\x03\
+    \x12\x04\xf9\x01$%\n\x0c\n\x02\x04\x11\x12\x06\xfc\x01\0\x84\x02\x01\n\
+    \x0b\n\x03\x04\x11\x01\x12\x04\xfc\x01\x08\x17\n\x0c\n\x04\x04\x11\x02\0\
+    \x12\x04\xfd\x01\x02\x12\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\xfd\x01\x02\
+    \x08\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xfd\x01\t\r\n\r\n\x05\x04\x11\
+    \x02\0\x03\x12\x04\xfd\x01\x10\x11\n\x0c\n\x04\x04\x11\x02\x01\x12\x04\
+    \xfe\x01\x02\x16\n\r\n\x05\x04\x11\x02\x01\x05\x12\x04\xfe\x01\x02\x08\n\
+    \r\n\x05\x04\x11\x02\x01\x01\x12\x04\xfe\x01\t\x11\n\r\n\x05\x04\x11\x02\
+    \x01\x03\x12\x04\xfe\x01\x14\x15\n\x0c\n\x04\x04\x11\x02\x02\x12\x04\xff\
+    \x01\x02\x11\n\r\n\x05\x04\x11\x02\x02\x05\x12\x04\xff\x01\x02\x08\n\r\n\
+    \x05\x04\x11\x02\x02\x01\x12\x04\xff\x01\t\x0c\n\r\n\x05\x04\x11\x02\x02\
+    \x03\x12\x04\xff\x01\x0f\x10\n\x0c\n\x04\x04\x11\x02\x03\x12\x04\x80\x02\
+    \x02\x11\n\r\n\x05\x04\x11\x02\x03\x05\x12\x04\x80\x02\x02\x08\n\r\n\x05\
+    \x04\x11\x02\x03\x01\x12\x04\x80\x02\t\x0c\n\r\n\x05\x04\x11\x02\x03\x03\
+    \x12\x04\x80\x02\x0f\x10\n\x0c\n\x04\x04\x11\x02\x04\x12\x04\x81\x02\x02\
+    \x1b\n\r\n\x05\x04\x11\x02\x04\x05\x12\x04\x81\x02\x02\x08\n\r\n\x05\x04\
+    \x11\x02\x04\x01\x12\x04\x81\x02\t\x16\n\r\n\x05\x04\x11\x02\x04\x03\x12\
+    \x04\x81\x02\x19\x1a\n\x0c\n\x04\x04\x11\x02\x05\x12\x04\x82\x02\x02\x1d\
+    \n\r\n\x05\x04\x11\x02\x05\x05\x12\x04\x82\x02\x02\x08\n\r\n\x05\x04\x11\
+    \x02\x05\x01\x12\x04\x82\x02\t\x18\n\r\n\x05\x04\x11\x02\x05\x03\x12\x04\
+    \x82\x02\x1b\x1c\n\x0c\n\x04\x04\x11\x02\x06\x12\x04\x83\x02\x02%\n\r\n\
+    \x05\x04\x11\x02\x06\x05\x12\x04\x83\x02\x02\x08\n\r\n\x05\x04\x11\x02\
+    \x06\x01\x12\x04\x83\x02\t\x20\n\r\n\x05\x04\x11\x02\x06\x03\x12\x04\x83\
+    \x02#$\n\x0c\n\x02\x04\x12\x12\x06\x86\x02\0\x8d\x02\x01\n\x0b\n\x03\x04\
+    \x12\x01\x12\x04\x86\x02\x08\x15\n\x0c\n\x04\x04\x12\x02\0\x12\x04\x87\
+    \x02\x02\x12\n\r\n\x05\x04\x12\x02\0\x05\x12\x04\x87\x02\x02\x08\n\r\n\
+    \x05\x04\x12\x02\0\x01\x12\x04\x87\x02\t\r\n\r\n\x05\x04\x12\x02\0\x03\
+    \x12\x04\x87\x02\x10\x11\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\x88\x02\x02\
+    \x1c\n\r\n\x05\x04\x12\x02\x01\x05\x12\x04\x88\x02\x02\x07\n\r\n\x05\x04\
+    \x12\x02\x01\x01\x12\x04\x88\x02\x08\x17\n\r\n\x05\x04\x12\x02\x01\x03\
+    \x12\x04\x88\x02\x1a\x1b\n\x0c\n\x04\x04\x12\x02\x02\x12\x04\x89\x02\x02\
+    \x20\n\r\n\x05\x04\x12\x02\x02\x05\x12\x04\x89\x02\x02\x07\n\r\n\x05\x04\
+    \x12\x02\x02\x01\x12\x04\x89\x02\x08\x1b\n\r\n\x05\x04\x12\x02\x02\x03\
+    \x12\x04\x89\x02\x1e\x1f\n\x0c\n\x04\x04\x12\x02\x03\x12\x04\x8a\x02\x02\
+    \"\n\r\n\x05\x04\x12\x02\x03\x05\x12\x04\x8a\x02\x02\x07\n\r\n\x05\x04\
+    \x12\x02\x03\x01\x12\x04\x8a\x02\x08\x1d\n\r\n\x05\x04\x12\x02\x03\x03\
+    \x12\x04\x8a\x02\x20!\n\x0c\n\x04\x04\x12\x02\x04\x12\x04\x8b\x02\x02\
+    \x18\n\r\n\x05\x04\x12\x02\x04\x05\x12\x04\x8b\x02\x02\x06\n\r\n\x05\x04\
+    \x12\x02\x04\x01\x12\x04\x8b\x02\x07\x13\n\r\n\x05\x04\x12\x02\x04\x03\
+    \x12\x04\x8b\x02\x16\x17\n\x0c\n\x04\x04\x12\x02\x05\x12\x04\x8c\x02\x02\
+    \x19\n\r\n\x05\x04\x12\x02\x05\x05\x12\x04\x8c\x02\x02\x06\n\r\n\x05\x04\
+    \x12\x02\x05\x01\x12\x04\x8c\x02\x07\x14\n\r\n\x05\x04\x12\x02\x05\x03\
+    \x12\x04\x8c\x02\x17\x18\n\x0c\n\x02\x04\x13\x12\x06\x8f\x02\0\x95\x02\
+    \x01\n\x0b\n\x03\x04\x13\x01\x12\x04\x8f\x02\x08\x13\n\x0c\n\x04\x04\x13\
+    \x02\0\x12\x04\x90\x02\x02\x18\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\x90\
+    \x02\x02\x08\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\x90\x02\t\x13\n\r\n\x05\
+    \x04\x13\x02\0\x03\x12\x04\x90\x02\x16\x17\n\x0c\n\x04\x04\x13\x02\x01\
+    \x12\x04\x91\x02\x02\x20\n\r\n\x05\x04\x13\x02\x01\x04\x12\x04\x91\x02\
+    \x02\n\n\r\n\x05\x04\x13\x02\x01\x05\x12\x04\x91\x02\x0b\x11\n\r\n\x05\
+    \x04\x13\x02\x01\x01\x12\x04\x91\x02\x12\x1b\n\r\n\x05\x04\x13\x02\x01\
+    \x03\x12\x04\x91\x02\x1e\x1f\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\x92\x02\
+    \x02)\n\r\n\x05\x04\x13\x02\x02\x04\x12\x04\x92\x02\x02\n\n\r\n\x05\x04\
+    \x13\x02\x02\x06\x12\x04\x92\x02\x0b\x1c\n\r\n\x05\x04\x13\x02\x02\x01\
+    \x12\x04\x92\x02\x1d$\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\x92\x02'(\n\
+    \x0c\n\x04\x04\x13\x02\x03\x12\x04\x93\x02\x02%\n\r\n\x05\x04\x13\x02\
+    \x03\x04\x12\x04\x93\x02\x02\n\n\r\n\x05\x04\x13\x02\x03\x06\x12\x04\x93\
+    \x02\x0b\x17\n\r\n\x05\x04\x13\x02\x03\x01\x12\x04\x93\x02\x18\x20\n\r\n\
+    \x05\x04\x13\x02\x03\x03\x12\x04\x93\x02#$\n\x0c\n\x04\x04\x13\x02\x04\
+    \x12\x04\x94\x02\x02-\n\r\n\x05\x04\x13\x02\x04\x05\x12\x04\x94\x02\x02\
+    \x07\n\r\n\x05\x04\x13\x02\x04\x01\x12\x04\x94\x02\x08(\n\r\n\x05\x04\
+    \x13\x02\x04\x03\x12\x04\x94\x02+,\n\x0c\n\x02\x04\x14\x12\x06\x97\x02\0\
+    \x9c\x02\x01\n\x0b\n\x03\x04\x14\x01\x12\x04\x97\x02\x08\x14\n\x0c\n\x04\
+    \x04\x14\x02\0\x12\x04\x98\x02\x02\x20\n\r\n\x05\x04\x14\x02\0\x06\x12\
+    \x04\x98\x02\x02\x13\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\x98\x02\x14\x1b\
+    \n\r\n\x05\x04\x14\x02\0\x03\x12\x04\x98\x02\x1e\x1f\n\x0c\n\x04\x04\x14\
+    \x02\x01\x12\x04\x99\x02\x02\x11\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\
+    \x99\x02\x02\x07\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\x99\x02\x08\x0c\n\
+    \r\n\x05\x04\x14\x02\x01\x03\x12\x04\x99\x02\x0f\x10\n\x0c\n\x04\x04\x14\
+    \x02\x02\x12\x04\x9a\x02\x02\x1b\n\r\n\x05\x04\x14\x02\x02\x05\x12\x04\
+    \x9a\x02\x02\x06\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\x9a\x02\x07\x16\n\
+    \r\n\x05\x04\x14\x02\x02\x03\x12\x04\x9a\x02\x19\x1a\n\x0c\n\x04\x04\x14\
+    \x02\x03\x12\x04\x9b\x02\x02\x1e\n\r\n\x05\x04\x14\x02\x03\x05\x12\x04\
+    \x9b\x02\x02\x06\n\r\n\x05\x04\x14\x02\x03\x01\x12\x04\x9b\x02\x07\x19\n\
+    \r\n\x05\x04\x14\x02\x03\x03\x12\x04\x9b\x02\x1c\x1d\n\x0c\n\x02\x04\x15\
+    \x12\x06\x9e\x02\0\xa2\x02\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\x9e\x02\
+    \x08\x1c\n\x0c\n\x04\x04\x15\x02\0\x12\x04\x9f\x02\x02\x19\n\r\n\x05\x04\
+    \x15\x02\0\x05\x12\x04\x9f\x02\x02\x08\n\r\n\x05\x04\x15\x02\0\x01\x12\
+    \x04\x9f\x02\t\x14\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\x9f\x02\x17\x18\n\
+    \x0c\n\x04\x04\x15\x02\x01\x12\x04\xa0\x02\x02*\n\r\n\x05\x04\x15\x02\
+    \x01\x04\x12\x04\xa0\x02\x02\n\n\r\n\x05\x04\x15\x02\x01\x06\x12\x04\xa0\
+    \x02\x0b\x1e\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\xa0\x02\x1f%\n\r\n\
+    \x05\x04\x15\x02\x01\x03\x12\x04\xa0\x02()\n\x0c\n\x04\x04\x15\x02\x02\
+    \x12\x04\xa1\x02\x022\n\r\n\x05\x04\x15\x02\x02\x04\x12\x04\xa1\x02\x02\
+    \n\n\r\n\x05\x04\x15\x02\x02\x06\x12\x04\xa1\x02\x0b\"\n\r\n\x05\x04\x15\
+    \x02\x02\x01\x12\x04\xa1\x02#-\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\xa1\
+    \x0201\n\x0c\n\x02\x04\x16\x12\x06\xa4\x02\0\xa8\x02\x01\n\x0b\n\x03\x04\
+    \x16\x01\x12\x04\xa4\x02\x08\x1b\n\x0c\n\x04\x04\x16\x02\0\x12\x04\xa5\
+    \x02\x02\x12\n\r\n\x05\x04\x16\x02\0\x05\x12\x04\xa5\x02\x02\x08\n\r\n\
+    \x05\x04\x16\x02\0\x01\x12\x04\xa5\x02\t\r\n\r\n\x05\x04\x16\x02\0\x03\
+    \x12\x04\xa5\x02\x10\x11\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xa6\x02\x02\
+    \x13\n\r\n\x05\x04\x16\x02\x01\x05\x12\x04\xa6\x02\x02\x08\n\r\n\x05\x04\
+    \x16\x02\x01\x01\x12\x04\xa6\x02\t\x0e\n\r\n\x05\x04\x16\x02\x01\x03\x12\
+    \x04\xa6\x02\x11\x12\n\x0c\n\x04\x04\x16\x02\x02\x12\x04\xa7\x02\x02\x1a\
+    \n\r\n\x05\x04\x16\x02\x02\x05\x12\x04\xa7\x02\x02\x08\n\r\n\x05\x04\x16\
+    \x02\x02\x01\x12\x04\xa7\x02\t\x15\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\
+    \xa7\x02\x18\x19\n\x0c\n\x02\x04\x17\x12\x06\xaa\x02\0\xae\x02\x01\n\x0b\
+    \n\x03\x04\x17\x01\x12\x04\xaa\x02\x08\x1f\n\x0c\n\x04\x04\x17\x02\0\x12\
+    \x04\xab\x02\x02\x12\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xab\x02\x02\x08\
+    \n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xab\x02\t\r\n\r\n\x05\x04\x17\x02\0\
+    \x03\x12\x04\xab\x02\x10\x11\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xac\x02\
+    \x02\x1d\n\r\n\x05\x04\x17\x02\x01\x06\x12\x04\xac\x02\x02\x11\n\r\n\x05\
+    \x04\x17\x02\x01\x01\x12\x04\xac\x02\x12\x18\n\r\n\x05\x04\x17\x02\x01\
+    \x03\x12\x04\xac\x02\x1b\x1c\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xad\x02\
+    \x02\x14\n\r\n\x05\x04\x17\x02\x02\x05\x12\x04\xad\x02\x02\x08\n\r\n\x05\
+    \x04\x17\x02\x02\x01\x12\x04\xad\x02\t\x0f\n\r\n\x05\x04\x17\x02\x02\x03\
+    \x12\x04\xad\x02\x12\x13\n\x0c\n\x02\x04\x18\x12\x06\xb0\x02\0\xb8\x02\
+    \x01\n\x0b\n\x03\x04\x18\x01\x12\x04\xb0\x02\x08\x19\n\x0c\n\x04\x04\x18\
+    \x02\0\x12\x04\xb1\x02\x02\x16\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\xb1\
+    \x02\x02\x07\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xb1\x02\x08\x11\n\r\n\
+    \x05\x04\x18\x02\0\x03\x12\x04\xb1\x02\x14\x15\n\x0c\n\x04\x04\x18\x02\
+    \x01\x12\x04\xb2\x02\x02\x1e\n\r\n\x05\x04\x18\x02\x01\x05\x12\x04\xb2\
+    \x02\x02\x07\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xb2\x02\x08\x19\n\r\n\
+    \x05\x04\x18\x02\x01\x03\x12\x04\xb2\x02\x1c\x1d\n\x0c\n\x04\x04\x18\x02\
+    \x02\x12\x04\xb3\x02\x02\x19\n\r\n\x05\x04\x18\x02\x02\x05\x12\x04\xb3\
+    \x02\x02\x08\n\r\n\x05\x04\x18\x02\x02\x01\x12\x04\xb3\x02\t\x14\n\r\n\
+    \x05\x04\x18\x02\x02\x03\x12\x04\xb3\x02\x17\x18\n\x0c\n\x04\x04\x18\x02\
+    \x03\x12\x04\xb4\x02\x02\x20\n\r\n\x05\x04\x18\x02\x03\x04\x12\x04\xb4\
+    \x02\x02\n\n\r\n\x05\x04\x18\x02\x03\x05\x12\x04\xb4\x02\x0b\x11\n\r\n\
+    \x05\x04\x18\x02\x03\x01\x12\x04\xb4\x02\x12\x1b\n\r\n\x05\x04\x18\x02\
+    \x03\x03\x12\x04\xb4\x02\x1e\x1f\n\x0c\n\x04\x04\x18\x02\x04\x12\x04\xb5\
+    \x02\x02\x14\n\r\n\x05\x04\x18\x02\x04\x05\x12\x04\xb5\x02\x02\x08\n\r\n\
+    \x05\x04\x18\x02\x04\x01\x12\x04\xb5\x02\t\x0f\n\r\n\x05\x04\x18\x02\x04\
+    \x03\x12\x04\xb5\x02\x12\x13\n\x0c\n\x04\x04\x18\x02\x05\x12\x04\xb6\x02\
+    \x02\x19\n\r\n\x05\x04\x18\x02\x05\x05\x12\x04\xb6\x02\x02\x08\n\r\n\x05\
+    \x04\x18\x02\x05\x01\x12\x04\xb6\x02\t\x14\n\r\n\x05\x04\x18\x02\x05\x03\
+    \x12\x04\xb6\x02\x17\x18\n\x0c\n\x04\x04\x18\x02\x06\x12\x04\xb7\x02\x02\
+    \x1c\n\r\n\x05\x04\x18\x02\x06\x06\x12\x04\xb7\x02\x02\x11\n\r\n\x05\x04\
+    \x18\x02\x06\x01\x12\x04\xb7\x02\x12\x17\n\r\n\x05\x04\x18\x02\x06\x03\
+    \x12\x04\xb7\x02\x1a\x1b\n\x0c\n\x02\x04\x19\x12\x06\xba\x02\0\xbc\x02\
+    \x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xba\x02\x08\x1e\n\x0c\n\x04\x04\x19\
+    \x02\0\x12\x04\xbb\x02\x02)\n\r\n\x05\x04\x19\x02\0\x04\x12\x04\xbb\x02\
+    \x02\n\n\r\n\x05\x04\x19\x02\0\x06\x12\x04\xbb\x02\x0b\x1c\n\r\n\x05\x04\
+    \x19\x02\0\x01\x12\x04\xbb\x02\x1d$\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\
+    \xbb\x02'(b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
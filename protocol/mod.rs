@@ -1,3 +1,4 @@
 // @generated
 
 pub mod kubernetes;
+pub mod otlp;
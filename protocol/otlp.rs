@@ -0,0 +1,3382 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `otlp.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportMetricsServiceRequest {
+    // message fields
+    pub resource_metrics: ::protobuf::RepeatedField<ResourceMetrics>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportMetricsServiceRequest {
+    fn default() -> &'a ExportMetricsServiceRequest {
+        <ExportMetricsServiceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportMetricsServiceRequest {
+    pub fn new() -> ExportMetricsServiceRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.ResourceMetrics resource_metrics = 1;
+
+
+    pub fn get_resource_metrics(&self) -> &[ResourceMetrics] {
+        &self.resource_metrics
+    }
+    pub fn clear_resource_metrics(&mut self) {
+        self.resource_metrics.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource_metrics(&mut self, v: ::protobuf::RepeatedField<ResourceMetrics>) {
+        self.resource_metrics = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_resource_metrics(&mut self) -> &mut ::protobuf::RepeatedField<ResourceMetrics> {
+        &mut self.resource_metrics
+    }
+
+    // Take field
+    pub fn take_resource_metrics(&mut self) -> ::protobuf::RepeatedField<ResourceMetrics> {
+        ::std::mem::replace(&mut self.resource_metrics, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ExportMetricsServiceRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.resource_metrics {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.resource_metrics)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.resource_metrics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.resource_metrics {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportMetricsServiceRequest {
+        ExportMetricsServiceRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceMetrics>>(
+                "resource_metrics",
+                |m: &ExportMetricsServiceRequest| { &m.resource_metrics },
+                |m: &mut ExportMetricsServiceRequest| { &mut m.resource_metrics },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportMetricsServiceRequest>(
+                "ExportMetricsServiceRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportMetricsServiceRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportMetricsServiceRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportMetricsServiceRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportMetricsServiceRequest {
+    fn clear(&mut self) {
+        self.resource_metrics.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportMetricsServiceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportMetricsServiceRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportMetricsServiceResponse {
+    // message fields
+    pub partial_success: ::protobuf::SingularPtrField<ExportMetricsPartialSuccess>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportMetricsServiceResponse {
+    fn default() -> &'a ExportMetricsServiceResponse {
+        <ExportMetricsServiceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportMetricsServiceResponse {
+    pub fn new() -> ExportMetricsServiceResponse {
+        ::std::default::Default::default()
+    }
+
+    // .opentelemetry.proto.metrics.v1.ExportMetricsPartialSuccess partial_success = 1;
+
+
+    pub fn get_partial_success(&self) -> &ExportMetricsPartialSuccess {
+        self.partial_success.as_ref().unwrap_or_else(|| <ExportMetricsPartialSuccess as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_partial_success(&mut self) {
+        self.partial_success.clear();
+    }
+
+    pub fn has_partial_success(&self) -> bool {
+        self.partial_success.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_partial_success(&mut self, v: ExportMetricsPartialSuccess) {
+        self.partial_success = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_partial_success(&mut self) -> &mut ExportMetricsPartialSuccess {
+        if self.partial_success.is_none() {
+            self.partial_success.set_default();
+        }
+        self.partial_success.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_partial_success(&mut self) -> ExportMetricsPartialSuccess {
+        self.partial_success.take().unwrap_or_else(|| ExportMetricsPartialSuccess::new())
+    }
+}
+
+impl ::protobuf::Message for ExportMetricsServiceResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.partial_success {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.partial_success)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.partial_success.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.partial_success.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportMetricsServiceResponse {
+        ExportMetricsServiceResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExportMetricsPartialSuccess>>(
+                "partial_success",
+                |m: &ExportMetricsServiceResponse| { &m.partial_success },
+                |m: &mut ExportMetricsServiceResponse| { &mut m.partial_success },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportMetricsServiceResponse>(
+                "ExportMetricsServiceResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportMetricsServiceResponse {
+        static instance: ::protobuf::rt::LazyV2<ExportMetricsServiceResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportMetricsServiceResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportMetricsServiceResponse {
+    fn clear(&mut self) {
+        self.partial_success.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportMetricsServiceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportMetricsServiceResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportMetricsPartialSuccess {
+    // message fields
+    pub rejected_data_points: i64,
+    pub error_message: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportMetricsPartialSuccess {
+    fn default() -> &'a ExportMetricsPartialSuccess {
+        <ExportMetricsPartialSuccess as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportMetricsPartialSuccess {
+    pub fn new() -> ExportMetricsPartialSuccess {
+        ::std::default::Default::default()
+    }
+
+    // int64 rejected_data_points = 1;
+
+
+    pub fn get_rejected_data_points(&self) -> i64 {
+        self.rejected_data_points
+    }
+    pub fn clear_rejected_data_points(&mut self) {
+        self.rejected_data_points = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rejected_data_points(&mut self, v: i64) {
+        self.rejected_data_points = v;
+    }
+
+    // string error_message = 2;
+
+
+    pub fn get_error_message(&self) -> &str {
+        &self.error_message
+    }
+    pub fn clear_error_message(&mut self) {
+        self.error_message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_message(&mut self, v: ::std::string::String) {
+        self.error_message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error_message(&mut self) -> &mut ::std::string::String {
+        &mut self.error_message
+    }
+
+    // Take field
+    pub fn take_error_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error_message, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ExportMetricsPartialSuccess {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rejected_data_points = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error_message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rejected_data_points != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.rejected_data_points, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error_message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.rejected_data_points != 0 {
+            os.write_int64(1, self.rejected_data_points)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(2, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportMetricsPartialSuccess {
+        ExportMetricsPartialSuccess::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rejected_data_points",
+                |m: &ExportMetricsPartialSuccess| { &m.rejected_data_points },
+                |m: &mut ExportMetricsPartialSuccess| { &mut m.rejected_data_points },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error_message",
+                |m: &ExportMetricsPartialSuccess| { &m.error_message },
+                |m: &mut ExportMetricsPartialSuccess| { &mut m.error_message },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportMetricsPartialSuccess>(
+                "ExportMetricsPartialSuccess",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportMetricsPartialSuccess {
+        static instance: ::protobuf::rt::LazyV2<ExportMetricsPartialSuccess> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportMetricsPartialSuccess::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportMetricsPartialSuccess {
+    fn clear(&mut self) {
+        self.rejected_data_points = 0;
+        self.error_message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportMetricsPartialSuccess {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportMetricsPartialSuccess {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AnyValue {
+    // message oneof groups
+    pub value: ::std::option::Option<AnyValue_oneof_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AnyValue {
+    fn default() -> &'a AnyValue {
+        <AnyValue as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum AnyValue_oneof_value {
+    string_value(::std::string::String),
+    bool_value(bool),
+    int_value(i64),
+    double_value(f64),
+}
+
+impl AnyValue {
+    pub fn new() -> AnyValue {
+        ::std::default::Default::default()
+    }
+
+    // string string_value = 1;
+
+
+    pub fn get_string_value(&self) -> &str {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::string_value(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_string_value(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_string_value(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::string_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_string_value(&mut self, v: ::std::string::String) {
+        self.value = ::std::option::Option::Some(AnyValue_oneof_value::string_value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_string_value(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(AnyValue_oneof_value::string_value(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(AnyValue_oneof_value::string_value(::std::string::String::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::string_value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_string_value(&mut self) -> ::std::string::String {
+        if self.has_string_value() {
+            match self.value.take() {
+                ::std::option::Option::Some(AnyValue_oneof_value::string_value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bool bool_value = 2;
+
+
+    pub fn get_bool_value(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::bool_value(v)) => v,
+            _ => false,
+        }
+    }
+    pub fn clear_bool_value(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_bool_value(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::bool_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool_value(&mut self, v: bool) {
+        self.value = ::std::option::Option::Some(AnyValue_oneof_value::bool_value(v))
+    }
+
+    // int64 int_value = 3;
+
+
+    pub fn get_int_value(&self) -> i64 {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::int_value(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_int_value(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_int_value(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::int_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_int_value(&mut self, v: i64) {
+        self.value = ::std::option::Option::Some(AnyValue_oneof_value::int_value(v))
+    }
+
+    // double double_value = 4;
+
+
+    pub fn get_double_value(&self) -> f64 {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::double_value(v)) => v,
+            _ => 0.,
+        }
+    }
+    pub fn clear_double_value(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_double_value(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(AnyValue_oneof_value::double_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_double_value(&mut self, v: f64) {
+        self.value = ::std::option::Option::Some(AnyValue_oneof_value::double_value(v))
+    }
+}
+
+impl ::protobuf::Message for AnyValue {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(AnyValue_oneof_value::string_value(is.read_string()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(AnyValue_oneof_value::bool_value(is.read_bool()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(AnyValue_oneof_value::int_value(is.read_int64()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(AnyValue_oneof_value::double_value(is.read_double()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &AnyValue_oneof_value::string_value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+                &AnyValue_oneof_value::bool_value(v) => {
+                    my_size += 2;
+                },
+                &AnyValue_oneof_value::int_value(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &AnyValue_oneof_value::double_value(v) => {
+                    my_size += 9;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &AnyValue_oneof_value::string_value(ref v) => {
+                    os.write_string(1, v)?;
+                },
+                &AnyValue_oneof_value::bool_value(v) => {
+                    os.write_bool(2, v)?;
+                },
+                &AnyValue_oneof_value::int_value(v) => {
+                    os.write_int64(3, v)?;
+                },
+                &AnyValue_oneof_value::double_value(v) => {
+                    os.write_double(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AnyValue {
+        AnyValue::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "string_value",
+                AnyValue::has_string_value,
+                AnyValue::get_string_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                "bool_value",
+                AnyValue::has_bool_value,
+                AnyValue::get_bool_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "int_value",
+                AnyValue::has_int_value,
+                AnyValue::get_int_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                "double_value",
+                AnyValue::has_double_value,
+                AnyValue::get_double_value,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AnyValue>(
+                "AnyValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AnyValue {
+        static instance: ::protobuf::rt::LazyV2<AnyValue> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AnyValue::new)
+    }
+}
+
+impl ::protobuf::Clear for AnyValue {
+    fn clear(&mut self) {
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AnyValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AnyValue {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyValue {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::protobuf::SingularPtrField<AnyValue>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyValue {
+    fn default() -> &'a KeyValue {
+        <KeyValue as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyValue {
+    pub fn new() -> KeyValue {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .opentelemetry.proto.metrics.v1.AnyValue value = 2;
+
+
+    pub fn get_value(&self) -> &AnyValue {
+        self.value.as_ref().unwrap_or_else(|| <AnyValue as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: AnyValue) {
+        self.value = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut AnyValue {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> AnyValue {
+        self.value.take().unwrap_or_else(|| AnyValue::new())
+    }
+}
+
+impl ::protobuf::Message for KeyValue {
+    fn is_initialized(&self) -> bool {
+        for v in &self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KeyValue {
+        KeyValue::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &KeyValue| { &m.key },
+                |m: &mut KeyValue| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AnyValue>>(
+                "value",
+                |m: &KeyValue| { &m.value },
+                |m: &mut KeyValue| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KeyValue>(
+                "KeyValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static KeyValue {
+        static instance: ::protobuf::rt::LazyV2<KeyValue> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KeyValue::new)
+    }
+}
+
+impl ::protobuf::Clear for KeyValue {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyValue {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InstrumentationScope {
+    // message fields
+    pub name: ::std::string::String,
+    pub version: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InstrumentationScope {
+    fn default() -> &'a InstrumentationScope {
+        <InstrumentationScope as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InstrumentationScope {
+    pub fn new() -> InstrumentationScope {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string version = 2;
+
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for InstrumentationScope {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InstrumentationScope {
+        InstrumentationScope::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &InstrumentationScope| { &m.name },
+                |m: &mut InstrumentationScope| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "version",
+                |m: &InstrumentationScope| { &m.version },
+                |m: &mut InstrumentationScope| { &mut m.version },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InstrumentationScope>(
+                "InstrumentationScope",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InstrumentationScope {
+        static instance: ::protobuf::rt::LazyV2<InstrumentationScope> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InstrumentationScope::new)
+    }
+}
+
+impl ::protobuf::Clear for InstrumentationScope {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.version.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InstrumentationScope {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InstrumentationScope {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Resource {
+    // message fields
+    pub attributes: ::protobuf::RepeatedField<KeyValue>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Resource {
+    fn default() -> &'a Resource {
+        <Resource as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Resource {
+    pub fn new() -> Resource {
+        ::std::default::Default::default()
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.KeyValue attributes = 1;
+
+
+    pub fn get_attributes(&self) -> &[KeyValue] {
+        &self.attributes
+    }
+    pub fn clear_attributes(&mut self) {
+        self.attributes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attributes(&mut self, v: ::protobuf::RepeatedField<KeyValue>) {
+        self.attributes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attributes(&mut self) -> &mut ::protobuf::RepeatedField<KeyValue> {
+        &mut self.attributes
+    }
+
+    // Take field
+    pub fn take_attributes(&mut self) -> ::protobuf::RepeatedField<KeyValue> {
+        ::std::mem::replace(&mut self.attributes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Resource {
+    fn is_initialized(&self) -> bool {
+        for v in &self.attributes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attributes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.attributes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.attributes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Resource {
+        Resource::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KeyValue>>(
+                "attributes",
+                |m: &Resource| { &m.attributes },
+                |m: &mut Resource| { &mut m.attributes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Resource>(
+                "Resource",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Resource {
+        static instance: ::protobuf::rt::LazyV2<Resource> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Resource::new)
+    }
+}
+
+impl ::protobuf::Clear for Resource {
+    fn clear(&mut self) {
+        self.attributes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Resource {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Resource {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceMetrics {
+    // message fields
+    pub resource: ::protobuf::SingularPtrField<Resource>,
+    pub scope_metrics: ::protobuf::RepeatedField<ScopeMetrics>,
+    pub schema_url: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResourceMetrics {
+    fn default() -> &'a ResourceMetrics {
+        <ResourceMetrics as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResourceMetrics {
+    pub fn new() -> ResourceMetrics {
+        ::std::default::Default::default()
+    }
+
+    // .opentelemetry.proto.metrics.v1.Resource resource = 1;
+
+
+    pub fn get_resource(&self) -> &Resource {
+        self.resource.as_ref().unwrap_or_else(|| <Resource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    pub fn has_resource(&self) -> bool {
+        self.resource.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: Resource) {
+        self.resource = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut Resource {
+        if self.resource.is_none() {
+            self.resource.set_default();
+        }
+        self.resource.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> Resource {
+        self.resource.take().unwrap_or_else(|| Resource::new())
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.ScopeMetrics scope_metrics = 2;
+
+
+    pub fn get_scope_metrics(&self) -> &[ScopeMetrics] {
+        &self.scope_metrics
+    }
+    pub fn clear_scope_metrics(&mut self) {
+        self.scope_metrics.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scope_metrics(&mut self, v: ::protobuf::RepeatedField<ScopeMetrics>) {
+        self.scope_metrics = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scope_metrics(&mut self) -> &mut ::protobuf::RepeatedField<ScopeMetrics> {
+        &mut self.scope_metrics
+    }
+
+    // Take field
+    pub fn take_scope_metrics(&mut self) -> ::protobuf::RepeatedField<ScopeMetrics> {
+        ::std::mem::replace(&mut self.scope_metrics, ::protobuf::RepeatedField::new())
+    }
+
+    // string schema_url = 3;
+
+
+    pub fn get_schema_url(&self) -> &str {
+        &self.schema_url
+    }
+    pub fn clear_schema_url(&mut self) {
+        self.schema_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schema_url(&mut self, v: ::std::string::String) {
+        self.schema_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_schema_url(&mut self) -> &mut ::std::string::String {
+        &mut self.schema_url
+    }
+
+    // Take field
+    pub fn take_schema_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.schema_url, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ResourceMetrics {
+    fn is_initialized(&self) -> bool {
+        for v in &self.resource {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.scope_metrics {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.resource)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.scope_metrics)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.schema_url)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.resource.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.scope_metrics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.schema_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.schema_url);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.resource.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.scope_metrics {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.schema_url.is_empty() {
+            os.write_string(3, &self.schema_url)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResourceMetrics {
+        ResourceMetrics::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Resource>>(
+                "resource",
+                |m: &ResourceMetrics| { &m.resource },
+                |m: &mut ResourceMetrics| { &mut m.resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ScopeMetrics>>(
+                "scope_metrics",
+                |m: &ResourceMetrics| { &m.scope_metrics },
+                |m: &mut ResourceMetrics| { &mut m.scope_metrics },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "schema_url",
+                |m: &ResourceMetrics| { &m.schema_url },
+                |m: &mut ResourceMetrics| { &mut m.schema_url },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResourceMetrics>(
+                "ResourceMetrics",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResourceMetrics {
+        static instance: ::protobuf::rt::LazyV2<ResourceMetrics> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResourceMetrics::new)
+    }
+}
+
+impl ::protobuf::Clear for ResourceMetrics {
+    fn clear(&mut self) {
+        self.resource.clear();
+        self.scope_metrics.clear();
+        self.schema_url.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceMetrics {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceMetrics {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScopeMetrics {
+    // message fields
+    pub scope: ::protobuf::SingularPtrField<InstrumentationScope>,
+    pub metrics: ::protobuf::RepeatedField<Metric>,
+    pub schema_url: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScopeMetrics {
+    fn default() -> &'a ScopeMetrics {
+        <ScopeMetrics as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScopeMetrics {
+    pub fn new() -> ScopeMetrics {
+        ::std::default::Default::default()
+    }
+
+    // .opentelemetry.proto.metrics.v1.InstrumentationScope scope = 1;
+
+
+    pub fn get_scope(&self) -> &InstrumentationScope {
+        self.scope.as_ref().unwrap_or_else(|| <InstrumentationScope as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_scope(&mut self) {
+        self.scope.clear();
+    }
+
+    pub fn has_scope(&self) -> bool {
+        self.scope.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scope(&mut self, v: InstrumentationScope) {
+        self.scope = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_scope(&mut self) -> &mut InstrumentationScope {
+        if self.scope.is_none() {
+            self.scope.set_default();
+        }
+        self.scope.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_scope(&mut self) -> InstrumentationScope {
+        self.scope.take().unwrap_or_else(|| InstrumentationScope::new())
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.Metric metrics = 2;
+
+
+    pub fn get_metrics(&self) -> &[Metric] {
+        &self.metrics
+    }
+    pub fn clear_metrics(&mut self) {
+        self.metrics.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metrics(&mut self, v: ::protobuf::RepeatedField<Metric>) {
+        self.metrics = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_metrics(&mut self) -> &mut ::protobuf::RepeatedField<Metric> {
+        &mut self.metrics
+    }
+
+    // Take field
+    pub fn take_metrics(&mut self) -> ::protobuf::RepeatedField<Metric> {
+        ::std::mem::replace(&mut self.metrics, ::protobuf::RepeatedField::new())
+    }
+
+    // string schema_url = 3;
+
+
+    pub fn get_schema_url(&self) -> &str {
+        &self.schema_url
+    }
+    pub fn clear_schema_url(&mut self) {
+        self.schema_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schema_url(&mut self, v: ::std::string::String) {
+        self.schema_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_schema_url(&mut self) -> &mut ::std::string::String {
+        &mut self.schema_url
+    }
+
+    // Take field
+    pub fn take_schema_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.schema_url, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ScopeMetrics {
+    fn is_initialized(&self) -> bool {
+        for v in &self.scope {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.metrics {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.scope)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.metrics)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.schema_url)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.scope.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.metrics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.schema_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.schema_url);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.scope.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.metrics {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.schema_url.is_empty() {
+            os.write_string(3, &self.schema_url)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScopeMetrics {
+        ScopeMetrics::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstrumentationScope>>(
+                "scope",
+                |m: &ScopeMetrics| { &m.scope },
+                |m: &mut ScopeMetrics| { &mut m.scope },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Metric>>(
+                "metrics",
+                |m: &ScopeMetrics| { &m.metrics },
+                |m: &mut ScopeMetrics| { &mut m.metrics },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "schema_url",
+                |m: &ScopeMetrics| { &m.schema_url },
+                |m: &mut ScopeMetrics| { &mut m.schema_url },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScopeMetrics>(
+                "ScopeMetrics",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ScopeMetrics {
+        static instance: ::protobuf::rt::LazyV2<ScopeMetrics> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ScopeMetrics::new)
+    }
+}
+
+impl ::protobuf::Clear for ScopeMetrics {
+    fn clear(&mut self) {
+        self.scope.clear();
+        self.metrics.clear();
+        self.schema_url.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScopeMetrics {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScopeMetrics {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Metric {
+    // message fields
+    pub name: ::std::string::String,
+    pub description: ::std::string::String,
+    pub unit: ::std::string::String,
+    // message oneof groups
+    pub data: ::std::option::Option<Metric_oneof_data>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Metric {
+    fn default() -> &'a Metric {
+        <Metric as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Metric_oneof_data {
+    gauge(Gauge),
+    sum(Sum),
+}
+
+impl Metric {
+    pub fn new() -> Metric {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string description = 2;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+
+    // string unit = 3;
+
+
+    pub fn get_unit(&self) -> &str {
+        &self.unit
+    }
+    pub fn clear_unit(&mut self) {
+        self.unit.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unit(&mut self, v: ::std::string::String) {
+        self.unit = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_unit(&mut self) -> &mut ::std::string::String {
+        &mut self.unit
+    }
+
+    // Take field
+    pub fn take_unit(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.unit, ::std::string::String::new())
+    }
+
+    // .opentelemetry.proto.metrics.v1.Gauge gauge = 5;
+
+
+    pub fn get_gauge(&self) -> &Gauge {
+        match self.data {
+            ::std::option::Option::Some(Metric_oneof_data::gauge(ref v)) => v,
+            _ => <Gauge as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gauge(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_gauge(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Metric_oneof_data::gauge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gauge(&mut self, v: Gauge) {
+        self.data = ::std::option::Option::Some(Metric_oneof_data::gauge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gauge(&mut self) -> &mut Gauge {
+        if let ::std::option::Option::Some(Metric_oneof_data::gauge(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Metric_oneof_data::gauge(Gauge::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Metric_oneof_data::gauge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gauge(&mut self) -> Gauge {
+        if self.has_gauge() {
+            match self.data.take() {
+                ::std::option::Option::Some(Metric_oneof_data::gauge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Gauge::new()
+        }
+    }
+
+    // .opentelemetry.proto.metrics.v1.Sum sum = 7;
+
+
+    pub fn get_sum(&self) -> &Sum {
+        match self.data {
+            ::std::option::Option::Some(Metric_oneof_data::sum(ref v)) => v,
+            _ => <Sum as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_sum(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_sum(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Metric_oneof_data::sum(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sum(&mut self, v: Sum) {
+        self.data = ::std::option::Option::Some(Metric_oneof_data::sum(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sum(&mut self) -> &mut Sum {
+        if let ::std::option::Option::Some(Metric_oneof_data::sum(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Metric_oneof_data::sum(Sum::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Metric_oneof_data::sum(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sum(&mut self) -> Sum {
+        if self.has_sum() {
+            match self.data.take() {
+                ::std::option::Option::Some(Metric_oneof_data::sum(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Sum::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Metric {
+    fn is_initialized(&self) -> bool {
+        if let Some(Metric_oneof_data::gauge(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Metric_oneof_data::sum(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.unit)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Metric_oneof_data::gauge(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Metric_oneof_data::sum(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.description);
+        }
+        if !self.unit.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.unit);
+        }
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &Metric_oneof_data::gauge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Metric_oneof_data::sum(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(2, &self.description)?;
+        }
+        if !self.unit.is_empty() {
+            os.write_string(3, &self.unit)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &Metric_oneof_data::gauge(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Metric_oneof_data::sum(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Metric {
+        Metric::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Metric| { &m.name },
+                |m: &mut Metric| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &Metric| { &m.description },
+                |m: &mut Metric| { &mut m.description },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "unit",
+                |m: &Metric| { &m.unit },
+                |m: &mut Metric| { &mut m.unit },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Gauge>(
+                "gauge",
+                Metric::has_gauge,
+                Metric::get_gauge,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Sum>(
+                "sum",
+                Metric::has_sum,
+                Metric::get_sum,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Metric>(
+                "Metric",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Metric {
+        static instance: ::protobuf::rt::LazyV2<Metric> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Metric::new)
+    }
+}
+
+impl ::protobuf::Clear for Metric {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.description.clear();
+        self.unit.clear();
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Metric {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Metric {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Gauge {
+    // message fields
+    pub data_points: ::protobuf::RepeatedField<NumberDataPoint>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Gauge {
+    fn default() -> &'a Gauge {
+        <Gauge as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Gauge {
+    pub fn new() -> Gauge {
+        ::std::default::Default::default()
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.NumberDataPoint data_points = 1;
+
+
+    pub fn get_data_points(&self) -> &[NumberDataPoint] {
+        &self.data_points
+    }
+    pub fn clear_data_points(&mut self) {
+        self.data_points.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_points(&mut self, v: ::protobuf::RepeatedField<NumberDataPoint>) {
+        self.data_points = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data_points(&mut self) -> &mut ::protobuf::RepeatedField<NumberDataPoint> {
+        &mut self.data_points
+    }
+
+    // Take field
+    pub fn take_data_points(&mut self) -> ::protobuf::RepeatedField<NumberDataPoint> {
+        ::std::mem::replace(&mut self.data_points, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Gauge {
+    fn is_initialized(&self) -> bool {
+        for v in &self.data_points {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.data_points)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.data_points {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.data_points {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Gauge {
+        Gauge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<NumberDataPoint>>(
+                "data_points",
+                |m: &Gauge| { &m.data_points },
+                |m: &mut Gauge| { &mut m.data_points },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Gauge>(
+                "Gauge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Gauge {
+        static instance: ::protobuf::rt::LazyV2<Gauge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Gauge::new)
+    }
+}
+
+impl ::protobuf::Clear for Gauge {
+    fn clear(&mut self) {
+        self.data_points.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Gauge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Gauge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Sum {
+    // message fields
+    pub data_points: ::protobuf::RepeatedField<NumberDataPoint>,
+    pub aggregation_temporality: AggregationTemporality,
+    pub is_monotonic: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Sum {
+    fn default() -> &'a Sum {
+        <Sum as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Sum {
+    pub fn new() -> Sum {
+        ::std::default::Default::default()
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.NumberDataPoint data_points = 1;
+
+
+    pub fn get_data_points(&self) -> &[NumberDataPoint] {
+        &self.data_points
+    }
+    pub fn clear_data_points(&mut self) {
+        self.data_points.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_points(&mut self, v: ::protobuf::RepeatedField<NumberDataPoint>) {
+        self.data_points = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data_points(&mut self) -> &mut ::protobuf::RepeatedField<NumberDataPoint> {
+        &mut self.data_points
+    }
+
+    // Take field
+    pub fn take_data_points(&mut self) -> ::protobuf::RepeatedField<NumberDataPoint> {
+        ::std::mem::replace(&mut self.data_points, ::protobuf::RepeatedField::new())
+    }
+
+    // .opentelemetry.proto.metrics.v1.AggregationTemporality aggregation_temporality = 2;
+
+
+    pub fn get_aggregation_temporality(&self) -> AggregationTemporality {
+        self.aggregation_temporality
+    }
+    pub fn clear_aggregation_temporality(&mut self) {
+        self.aggregation_temporality = AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregation_temporality(&mut self, v: AggregationTemporality) {
+        self.aggregation_temporality = v;
+    }
+
+    // bool is_monotonic = 3;
+
+
+    pub fn get_is_monotonic(&self) -> bool {
+        self.is_monotonic
+    }
+    pub fn clear_is_monotonic(&mut self) {
+        self.is_monotonic = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_monotonic(&mut self, v: bool) {
+        self.is_monotonic = v;
+    }
+}
+
+impl ::protobuf::Message for Sum {
+    fn is_initialized(&self) -> bool {
+        for v in &self.data_points {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.data_points)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.aggregation_temporality, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_monotonic = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.data_points {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.aggregation_temporality != AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED {
+            my_size += ::protobuf::rt::enum_size(2, self.aggregation_temporality);
+        }
+        if self.is_monotonic != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.data_points {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.aggregation_temporality != AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.aggregation_temporality))?;
+        }
+        if self.is_monotonic != false {
+            os.write_bool(3, self.is_monotonic)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Sum {
+        Sum::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<NumberDataPoint>>(
+                "data_points",
+                |m: &Sum| { &m.data_points },
+                |m: &mut Sum| { &mut m.data_points },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AggregationTemporality>>(
+                "aggregation_temporality",
+                |m: &Sum| { &m.aggregation_temporality },
+                |m: &mut Sum| { &mut m.aggregation_temporality },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_monotonic",
+                |m: &Sum| { &m.is_monotonic },
+                |m: &mut Sum| { &mut m.is_monotonic },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Sum>(
+                "Sum",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Sum {
+        static instance: ::protobuf::rt::LazyV2<Sum> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Sum::new)
+    }
+}
+
+impl ::protobuf::Clear for Sum {
+    fn clear(&mut self) {
+        self.data_points.clear();
+        self.aggregation_temporality = AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED;
+        self.is_monotonic = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Sum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Sum {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NumberDataPoint {
+    // message fields
+    pub attributes: ::protobuf::RepeatedField<KeyValue>,
+    pub start_time_unix_nano: u64,
+    pub time_unix_nano: u64,
+    // message oneof groups
+    pub value: ::std::option::Option<NumberDataPoint_oneof_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NumberDataPoint {
+    fn default() -> &'a NumberDataPoint {
+        <NumberDataPoint as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum NumberDataPoint_oneof_value {
+    as_double(f64),
+    as_int(i64),
+}
+
+impl NumberDataPoint {
+    pub fn new() -> NumberDataPoint {
+        ::std::default::Default::default()
+    }
+
+    // repeated .opentelemetry.proto.metrics.v1.KeyValue attributes = 7;
+
+
+    pub fn get_attributes(&self) -> &[KeyValue] {
+        &self.attributes
+    }
+    pub fn clear_attributes(&mut self) {
+        self.attributes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attributes(&mut self, v: ::protobuf::RepeatedField<KeyValue>) {
+        self.attributes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attributes(&mut self) -> &mut ::protobuf::RepeatedField<KeyValue> {
+        &mut self.attributes
+    }
+
+    // Take field
+    pub fn take_attributes(&mut self) -> ::protobuf::RepeatedField<KeyValue> {
+        ::std::mem::replace(&mut self.attributes, ::protobuf::RepeatedField::new())
+    }
+
+    // fixed64 start_time_unix_nano = 2;
+
+
+    pub fn get_start_time_unix_nano(&self) -> u64 {
+        self.start_time_unix_nano
+    }
+    pub fn clear_start_time_unix_nano(&mut self) {
+        self.start_time_unix_nano = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time_unix_nano(&mut self, v: u64) {
+        self.start_time_unix_nano = v;
+    }
+
+    // fixed64 time_unix_nano = 3;
+
+
+    pub fn get_time_unix_nano(&self) -> u64 {
+        self.time_unix_nano
+    }
+    pub fn clear_time_unix_nano(&mut self) {
+        self.time_unix_nano = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time_unix_nano(&mut self, v: u64) {
+        self.time_unix_nano = v;
+    }
+
+    // double as_double = 4;
+
+
+    pub fn get_as_double(&self) -> f64 {
+        match self.value {
+            ::std::option::Option::Some(NumberDataPoint_oneof_value::as_double(v)) => v,
+            _ => 0.,
+        }
+    }
+    pub fn clear_as_double(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_as_double(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(NumberDataPoint_oneof_value::as_double(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_as_double(&mut self, v: f64) {
+        self.value = ::std::option::Option::Some(NumberDataPoint_oneof_value::as_double(v))
+    }
+
+    // sfixed64 as_int = 6;
+
+
+    pub fn get_as_int(&self) -> i64 {
+        match self.value {
+            ::std::option::Option::Some(NumberDataPoint_oneof_value::as_int(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_as_int(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_as_int(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(NumberDataPoint_oneof_value::as_int(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_as_int(&mut self, v: i64) {
+        self.value = ::std::option::Option::Some(NumberDataPoint_oneof_value::as_int(v))
+    }
+}
+
+impl ::protobuf::Message for NumberDataPoint {
+    fn is_initialized(&self) -> bool {
+        for v in &self.attributes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attributes)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.start_time_unix_nano = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.time_unix_nano = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(NumberDataPoint_oneof_value::as_double(is.read_double()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(NumberDataPoint_oneof_value::as_int(is.read_sfixed64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.attributes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.start_time_unix_nano != 0 {
+            my_size += 9;
+        }
+        if self.time_unix_nano != 0 {
+            my_size += 9;
+        }
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &NumberDataPoint_oneof_value::as_double(v) => {
+                    my_size += 9;
+                },
+                &NumberDataPoint_oneof_value::as_int(v) => {
+                    my_size += 9;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.attributes {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.start_time_unix_nano != 0 {
+            os.write_fixed64(2, self.start_time_unix_nano)?;
+        }
+        if self.time_unix_nano != 0 {
+            os.write_fixed64(3, self.time_unix_nano)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &NumberDataPoint_oneof_value::as_double(v) => {
+                    os.write_double(4, v)?;
+                },
+                &NumberDataPoint_oneof_value::as_int(v) => {
+                    os.write_sfixed64(6, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NumberDataPoint {
+        NumberDataPoint::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KeyValue>>(
+                "attributes",
+                |m: &NumberDataPoint| { &m.attributes },
+                |m: &mut NumberDataPoint| { &mut m.attributes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "start_time_unix_nano",
+                |m: &NumberDataPoint| { &m.start_time_unix_nano },
+                |m: &mut NumberDataPoint| { &mut m.start_time_unix_nano },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "time_unix_nano",
+                |m: &NumberDataPoint| { &m.time_unix_nano },
+                |m: &mut NumberDataPoint| { &mut m.time_unix_nano },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                "as_double",
+                NumberDataPoint::has_as_double,
+                NumberDataPoint::get_as_double,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "as_int",
+                NumberDataPoint::has_as_int,
+                NumberDataPoint::get_as_int,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NumberDataPoint>(
+                "NumberDataPoint",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NumberDataPoint {
+        static instance: ::protobuf::rt::LazyV2<NumberDataPoint> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NumberDataPoint::new)
+    }
+}
+
+impl ::protobuf::Clear for NumberDataPoint {
+    fn clear(&mut self) {
+        self.attributes.clear();
+        self.start_time_unix_nano = 0;
+        self.time_unix_nano = 0;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NumberDataPoint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NumberDataPoint {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AggregationTemporality {
+    AGGREGATION_TEMPORALITY_UNSPECIFIED = 0,
+    AGGREGATION_TEMPORALITY_DELTA = 1,
+    AGGREGATION_TEMPORALITY_CUMULATIVE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for AggregationTemporality {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AggregationTemporality> {
+        match value {
+            0 => ::std::option::Option::Some(AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED),
+            1 => ::std::option::Option::Some(AggregationTemporality::AGGREGATION_TEMPORALITY_DELTA),
+            2 => ::std::option::Option::Some(AggregationTemporality::AGGREGATION_TEMPORALITY_CUMULATIVE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AggregationTemporality] = &[
+            AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED,
+            AggregationTemporality::AGGREGATION_TEMPORALITY_DELTA,
+            AggregationTemporality::AGGREGATION_TEMPORALITY_CUMULATIVE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<AggregationTemporality>("AggregationTemporality", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for AggregationTemporality {
+}
+
+impl ::std::default::Default for AggregationTemporality {
+    fn default() -> Self {
+        AggregationTemporality::AGGREGATION_TEMPORALITY_UNSPECIFIED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AggregationTemporality {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\notlp.proto\x12\x1eopentelemetry.proto.metrics.v1\"y\n\x1bExportMetri\
+    csServiceRequest\x12Z\n\x10resource_metrics\x18\x01\x20\x03(\x0b2/.opent\
+    elemetry.proto.metrics.v1.ResourceMetricsR\x0fresourceMetrics\"\x84\x01\
+    \n\x1cExportMetricsServiceResponse\x12d\n\x0fpartial_success\x18\x01\x20\
+    \x01(\x0b2;.opentelemetry.proto.metrics.v1.ExportMetricsPartialSuccessR\
+    \x0epartialSuccess\"t\n\x1bExportMetricsPartialSuccess\x120\n\x14rejecte\
+    d_data_points\x18\x01\x20\x01(\x03R\x12rejectedDataPoints\x12#\n\rerror_\
+    message\x18\x02\x20\x01(\tR\x0cerrorMessage\"\x9d\x01\n\x08AnyValue\x12#\
+    \n\x0cstring_value\x18\x01\x20\x01(\tH\0R\x0bstringValue\x12\x1f\n\nbool\
+    _value\x18\x02\x20\x01(\x08H\0R\tboolValue\x12\x1d\n\tint_value\x18\x03\
+    \x20\x01(\x03H\0R\x08intValue\x12#\n\x0cdouble_value\x18\x04\x20\x01(\
+    \x01H\0R\x0bdoubleValueB\x07\n\x05value\"\\\n\x08KeyValue\x12\x10\n\x03k\
+    ey\x18\x01\x20\x01(\tR\x03key\x12>\n\x05value\x18\x02\x20\x01(\x0b2(.ope\
+    ntelemetry.proto.metrics.v1.AnyValueR\x05value\"D\n\x14InstrumentationSc\
+    ope\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07version\
+    \x18\x02\x20\x01(\tR\x07version\"T\n\x08Resource\x12H\n\nattributes\x18\
+    \x01\x20\x03(\x0b2(.opentelemetry.proto.metrics.v1.KeyValueR\nattributes\
+    \"\xc9\x01\n\x0fResourceMetrics\x12D\n\x08resource\x18\x01\x20\x01(\x0b2\
+    (.opentelemetry.proto.metrics.v1.ResourceR\x08resource\x12Q\n\rscope_met\
+    rics\x18\x02\x20\x03(\x0b2,.opentelemetry.proto.metrics.v1.ScopeMetricsR\
+    \x0cscopeMetrics\x12\x1d\n\nschema_url\x18\x03\x20\x01(\tR\tschemaUrl\"\
+    \xbb\x01\n\x0cScopeMetrics\x12J\n\x05scope\x18\x01\x20\x01(\x0b24.opente\
+    lemetry.proto.metrics.v1.InstrumentationScopeR\x05scope\x12@\n\x07metric\
+    s\x18\x02\x20\x03(\x0b2&.opentelemetry.proto.metrics.v1.MetricR\x07metri\
+    cs\x12\x1d\n\nschema_url\x18\x03\x20\x01(\tR\tschemaUrl\"\xd2\x01\n\x06M\
+    etric\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x20\n\x0bdescrip\
+    tion\x18\x02\x20\x01(\tR\x0bdescription\x12\x12\n\x04unit\x18\x03\x20\
+    \x01(\tR\x04unit\x12=\n\x05gauge\x18\x05\x20\x01(\x0b2%.opentelemetry.pr\
+    oto.metrics.v1.GaugeH\0R\x05gauge\x127\n\x03sum\x18\x07\x20\x01(\x0b2#.o\
+    pentelemetry.proto.metrics.v1.SumH\0R\x03sumB\x06\n\x04data\"Y\n\x05Gaug\
+    e\x12P\n\x0bdata_points\x18\x01\x20\x03(\x0b2/.opentelemetry.proto.metri\
+    cs.v1.NumberDataPointR\ndataPoints\"\xeb\x01\n\x03Sum\x12P\n\x0bdata_poi\
+    nts\x18\x01\x20\x03(\x0b2/.opentelemetry.proto.metrics.v1.NumberDataPoin\
+    tR\ndataPoints\x12o\n\x17aggregation_temporality\x18\x02\x20\x01(\x0e26.\
+    opentelemetry.proto.metrics.v1.AggregationTemporalityR\x16aggregationTem\
+    porality\x12!\n\x0cis_monotonic\x18\x03\x20\x01(\x08R\x0bisMonotonic\"\
+    \xf3\x01\n\x0fNumberDataPoint\x12H\n\nattributes\x18\x07\x20\x03(\x0b2(.\
+    opentelemetry.proto.metrics.v1.KeyValueR\nattributes\x12/\n\x14start_tim\
+    e_unix_nano\x18\x02\x20\x01(\x06R\x11startTimeUnixNano\x12$\n\x0etime_un\
+    ix_nano\x18\x03\x20\x01(\x06R\x0ctimeUnixNano\x12\x1d\n\tas_double\x18\
+    \x04\x20\x01(\x01H\0R\x08asDouble\x12\x17\n\x06as_int\x18\x06\x20\x01(\
+    \x10H\0R\x05asIntB\x07\n\x05value*\x8c\x01\n\x16AggregationTemporality\
+    \x12'\n#AGGREGATION_TEMPORALITY_UNSPECIFIED\x10\0\x12!\n\x1dAGGREGATION_\
+    TEMPORALITY_DELTA\x10\x01\x12&\n\"AGGREGATION_TEMPORALITY_CUMULATIVE\x10\
+    \x02J\x9e\x16\n\x06\x12\x04\x05\0Z\x01\n\xf1\x02\n\x01\x0c\x12\x03\x05\0\
+    \x12\x1a\xe6\x02\x20The\x20messages\x20of\x20the\x20OpenTelemetry\x20pro\
+    tocol\x20(OTLP)\x20that\x20are\x20used\x20to\x20export\n\x20metrics,\x20\
+    from\x20the\x20`common`,\x20`resource`,\x20`metrics`\x20and\x20`metrics_\
+    service`\n\x20protos\x20of\x20https://github.com/open-telemetry/opentele\
+    metry-proto\x20(v1.5.0).\n\x20Fields\x20that\x20are\x20not\x20exported\
+    \x20are\x20omitted,\x20and\x20the\x20messages\x20are\x20combined\n\x20in\
+    \x20one\x20package.\x20The\x20field\x20numbers\x20match\x20the\x20origin\
+    al\x20protos.\n\n\x08\n\x01\x02\x12\x03\x06\0'\n\n\n\x02\x04\0\x12\x04\
+    \x08\0\n\x01\n\n\n\x03\x04\0\x01\x12\x03\x08\x08#\n\x0b\n\x04\x04\0\x02\
+    \0\x12\x03\t\x020\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\t\x02\n\n\x0c\n\
+    \x05\x04\0\x02\0\x06\x12\x03\t\x0b\x1a\n\x0c\n\x05\x04\0\x02\0\x01\x12\
+    \x03\t\x1b+\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\t./\n\n\n\x02\x04\x01\
+    \x12\x04\x0c\0\x0e\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0c\x08$\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\r\x022\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\
+    \r\x02\x1d\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x1e-\n\x0c\n\x05\x04\
+    \x01\x02\0\x03\x12\x03\r01\n\n\n\x02\x04\x02\x12\x04\x10\0\x13\x01\n\n\n\
+    \x03\x04\x02\x01\x12\x03\x10\x08#\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x11\
+    \x02!\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x11\x02\x07\n\x0c\n\x05\x04\
+    \x02\x02\0\x01\x12\x03\x11\x08\x1c\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
+    \x11\x1f\x20\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x12\x02\x1b\n\x0c\n\x05\
+    \x04\x02\x02\x01\x05\x12\x03\x12\x02\x08\n\x0c\n\x05\x04\x02\x02\x01\x01\
+    \x12\x03\x12\t\x16\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x12\x19\x1a\n\
+    \n\n\x02\x04\x03\x12\x04\x15\0\x1c\x01\n\n\n\x03\x04\x03\x01\x12\x03\x15\
+    \x08\x10\n\x0c\n\x04\x04\x03\x08\0\x12\x04\x16\x02\x1b\x03\n\x0c\n\x05\
+    \x04\x03\x08\0\x01\x12\x03\x16\x08\r\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
+    \x17\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x17\x04\n\n\x0c\n\x05\
+    \x04\x03\x02\0\x01\x12\x03\x17\x0b\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
+    \x03\x17\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x18\x04\x18\n\x0c\n\
+    \x05\x04\x03\x02\x01\x05\x12\x03\x18\x04\x08\n\x0c\n\x05\x04\x03\x02\x01\
+    \x01\x12\x03\x18\t\x13\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x18\x16\
+    \x17\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x19\x04\x18\n\x0c\n\x05\x04\x03\
+    \x02\x02\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\
+    \x19\n\x13\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x19\x16\x17\n\x0b\n\
+    \x04\x04\x03\x02\x03\x12\x03\x1a\x04\x1c\n\x0c\n\x05\x04\x03\x02\x03\x05\
+    \x12\x03\x1a\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x1a\x0b\x17\n\
+    \x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x1a\x1a\x1b\n\n\n\x02\x04\x04\x12\
+    \x04\x1e\0!\x01\n\n\n\x03\x04\x04\x01\x12\x03\x1e\x08\x10\n\x0b\n\x04\
+    \x04\x04\x02\0\x12\x03\x1f\x02\x11\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\
+    \x1f\x02\x08\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1f\t\x0c\n\x0c\n\x05\
+    \x04\x04\x02\0\x03\x12\x03\x1f\x0f\x10\n\x0b\n\x04\x04\x04\x02\x01\x12\
+    \x03\x20\x02\x15\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\x20\x02\n\n\x0c\
+    \n\x05\x04\x04\x02\x01\x01\x12\x03\x20\x0b\x10\n\x0c\n\x05\x04\x04\x02\
+    \x01\x03\x12\x03\x20\x13\x14\n\n\n\x02\x04\x05\x12\x04#\0&\x01\n\n\n\x03\
+    \x04\x05\x01\x12\x03#\x08\x1c\n\x0b\n\x04\x04\x05\x02\0\x12\x03$\x02\x12\
+    \n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03$\x02\x08\n\x0c\n\x05\x04\x05\x02\
+    \0\x01\x12\x03$\t\r\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03$\x10\x11\n\x0b\
+    \n\x04\x04\x05\x02\x01\x12\x03%\x02\x15\n\x0c\n\x05\x04\x05\x02\x01\x05\
+    \x12\x03%\x02\x08\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03%\t\x10\n\x0c\n\
+    \x05\x04\x05\x02\x01\x03\x12\x03%\x13\x14\n\n\n\x02\x04\x06\x12\x04(\0*\
+    \x01\n\n\n\x03\x04\x06\x01\x12\x03(\x08\x10\n\x0b\n\x04\x04\x06\x02\0\
+    \x12\x03)\x02#\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03)\x02\n\n\x0c\n\x05\
+    \x04\x06\x02\0\x06\x12\x03)\x0b\x13\n\x0c\n\x05\x04\x06\x02\0\x01\x12\
+    \x03)\x14\x1e\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03)!\"\n\n\n\x02\x04\
+    \x07\x12\x04,\00\x01\n\n\n\x03\x04\x07\x01\x12\x03,\x08\x17\n\x0b\n\x04\
+    \x04\x07\x02\0\x12\x03-\x02\x18\n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03-\
+    \x02\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03-\x0b\x13\n\x0c\n\x05\x04\
+    \x07\x02\0\x03\x12\x03-\x16\x17\n\x0b\n\x04\x04\x07\x02\x01\x12\x03.\x02\
+    *\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x01\x06\x12\x03.\x0b\x17\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03.\x18%\
+    \n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03.()\n\x0b\n\x04\x04\x07\x02\x02\
+    \x12\x03/\x02\x18\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03/\x02\x08\n\x0c\
+    \n\x05\x04\x07\x02\x02\x01\x12\x03/\t\x13\n\x0c\n\x05\x04\x07\x02\x02\
+    \x03\x12\x03/\x16\x17\n\n\n\x02\x04\x08\x12\x042\06\x01\n\n\n\x03\x04\
+    \x08\x01\x12\x032\x08\x14\n\x0b\n\x04\x04\x08\x02\0\x12\x033\x02!\n\x0c\
+    \n\x05\x04\x08\x02\0\x06\x12\x033\x02\x16\n\x0c\n\x05\x04\x08\x02\0\x01\
+    \x12\x033\x17\x1c\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x033\x1f\x20\n\x0b\n\
+    \x04\x04\x08\x02\x01\x12\x034\x02\x1e\n\x0c\n\x05\x04\x08\x02\x01\x04\
+    \x12\x034\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x06\x12\x034\x0b\x11\n\x0c\n\
+    \x05\x04\x08\x02\x01\x01\x12\x034\x12\x19\n\x0c\n\x05\x04\x08\x02\x01\
+    \x03\x12\x034\x1c\x1d\n\x0b\n\x04\x04\x08\x02\x02\x12\x035\x02\x18\n\x0c\
+    \n\x05\x04\x08\x02\x02\x05\x12\x035\x02\x08\n\x0c\n\x05\x04\x08\x02\x02\
+    \x01\x12\x035\t\x13\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x035\x16\x17\n\n\
+    \n\x02\x04\t\x12\x048\0@\x01\n\n\n\x03\x04\t\x01\x12\x038\x08\x0e\n\x0b\
+    \n\x04\x04\t\x02\0\x12\x039\x02\x12\n\x0c\n\x05\x04\t\x02\0\x05\x12\x039\
+    \x02\x08\n\x0c\n\x05\x04\t\x02\0\x01\x12\x039\t\r\n\x0c\n\x05\x04\t\x02\
+    \0\x03\x12\x039\x10\x11\n\x0b\n\x04\x04\t\x02\x01\x12\x03:\x02\x19\n\x0c\
+    \n\x05\x04\t\x02\x01\x05\x12\x03:\x02\x08\n\x0c\n\x05\x04\t\x02\x01\x01\
+    \x12\x03:\t\x14\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03:\x17\x18\n\x0b\n\
+    \x04\x04\t\x02\x02\x12\x03;\x02\x12\n\x0c\n\x05\x04\t\x02\x02\x05\x12\
+    \x03;\x02\x08\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03;\t\r\n\x0c\n\x05\x04\
+    \t\x02\x02\x03\x12\x03;\x10\x11\n\x0c\n\x04\x04\t\x08\0\x12\x04<\x02?\
+    \x03\n\x0c\n\x05\x04\t\x08\0\x01\x12\x03<\x08\x0c\n\x0b\n\x04\x04\t\x02\
+    \x03\x12\x03=\x04\x14\n\x0c\n\x05\x04\t\x02\x03\x06\x12\x03=\x04\t\n\x0c\
+    \n\x05\x04\t\x02\x03\x01\x12\x03=\n\x0f\n\x0c\n\x05\x04\t\x02\x03\x03\
+    \x12\x03=\x12\x13\n\x0b\n\x04\x04\t\x02\x04\x12\x03>\x04\x10\n\x0c\n\x05\
+    \x04\t\x02\x04\x06\x12\x03>\x04\x07\n\x0c\n\x05\x04\t\x02\x04\x01\x12\
+    \x03>\x08\x0b\n\x0c\n\x05\x04\t\x02\x04\x03\x12\x03>\x0e\x0f\n\n\n\x02\
+    \x04\n\x12\x04B\0D\x01\n\n\n\x03\x04\n\x01\x12\x03B\x08\r\n\x0b\n\x04\
+    \x04\n\x02\0\x12\x03C\x02+\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03C\x02\n\n\
+    \x0c\n\x05\x04\n\x02\0\x06\x12\x03C\x0b\x1a\n\x0c\n\x05\x04\n\x02\0\x01\
+    \x12\x03C\x1b&\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03C)*\n\n\n\x02\x04\x0b\
+    \x12\x04F\0J\x01\n\n\n\x03\x04\x0b\x01\x12\x03F\x08\x0b\n\x0b\n\x04\x04\
+    \x0b\x02\0\x12\x03G\x02+\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03G\x02\n\n\
+    \x0c\n\x05\x04\x0b\x02\0\x06\x12\x03G\x0b\x1a\n\x0c\n\x05\x04\x0b\x02\0\
+    \x01\x12\x03G\x1b&\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03G)*\n\x0b\n\x04\
+    \x04\x0b\x02\x01\x12\x03H\x025\n\x0c\n\x05\x04\x0b\x02\x01\x06\x12\x03H\
+    \x02\x18\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03H\x190\n\x0c\n\x05\x04\
+    \x0b\x02\x01\x03\x12\x03H34\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03I\x02\x18\
+    \n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\x03I\x02\x06\n\x0c\n\x05\x04\x0b\
+    \x02\x02\x01\x12\x03I\x07\x13\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03I\
+    \x16\x17\n\n\n\x02\x05\0\x12\x04L\0P\x01\n\n\n\x03\x05\0\x01\x12\x03L\
+    \x05\x1b\n\x0b\n\x04\x05\0\x02\0\x12\x03M\x02*\n\x0c\n\x05\x05\0\x02\0\
+    \x01\x12\x03M\x02%\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03M()\n\x0b\n\x04\
+    \x05\0\x02\x01\x12\x03N\x02$\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03N\x02\
+    \x1f\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03N\"#\n\x0b\n\x04\x05\0\x02\x02\
+    \x12\x03O\x02)\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03O\x02$\n\x0c\n\x05\
+    \x05\0\x02\x02\x02\x12\x03O'(\n\n\n\x02\x04\x0c\x12\x04R\0Z\x01\n\n\n\
+    \x03\x04\x0c\x01\x12\x03R\x08\x17\n\x0b\n\x04\x04\x0c\x02\0\x12\x03S\x02\
+    #\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\x0c\x02\0\
+    \x06\x12\x03S\x0b\x13\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03S\x14\x1e\n\
+    \x0c\n\x05\x04\x0c\x02\0\x03\x12\x03S!\"\n\x0b\n\x04\x04\x0c\x02\x01\x12\
+    \x03T\x02#\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03T\x02\t\n\x0c\n\x05\
+    \x04\x0c\x02\x01\x01\x12\x03T\n\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\
+    \x03T!\"\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03U\x02\x1d\n\x0c\n\x05\x04\
+    \x0c\x02\x02\x05\x12\x03U\x02\t\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03U\
+    \n\x18\n\x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03U\x1b\x1c\n\x0c\n\x04\x04\
+    \x0c\x08\0\x12\x04V\x02Y\x03\n\x0c\n\x05\x04\x0c\x08\0\x01\x12\x03V\x08\
+    \r\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03W\x04\x19\n\x0c\n\x05\x04\x0c\x02\
+    \x03\x05\x12\x03W\x04\n\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03W\x0b\x14\
+    \n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03W\x17\x18\n\x0b\n\x04\x04\x0c\
+    \x02\x04\x12\x03X\x04\x18\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03X\x04\
+    \x0c\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03X\r\x13\n\x0c\n\x05\x04\x0c\
+    \x02\x04\x03\x12\x03X\x16\x17b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
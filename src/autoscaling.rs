@@ -0,0 +1,389 @@
+use k8s_openapi::api::autoscaling::v2::{
+    HorizontalPodAutoscaler, MetricSpec, MetricStatus, MetricTarget, MetricValueStatus,
+};
+use kube::api::ListParams;
+use kube::{Api, ResourceExt};
+
+use crate::ownership::ResourceIdentifier;
+use crate::protocol::kubernetes::{
+    ConditionStatus, HorizontalPodAutoscalerMetric, HorizontalPodAutoscalerStatus,
+    KubernetesMetrics, ResourceReference,
+};
+use crate::quantity::parse_quantity;
+use crate::Error;
+
+// Extract the status of every HorizontalPodAutoscaler in the cluster.
+pub async fn extract_horizontal_pod_autoscalers(
+    client: &kube::Client,
+) -> Result<Vec<KubernetesMetrics>, Error> {
+    let horizontal_pod_autoscalers: Api<HorizontalPodAutoscaler> = Api::all(client.clone());
+
+    Ok(horizontal_pod_autoscalers
+        .list(&ListParams::default())
+        .await?
+        .iter()
+        .map(KubernetesMetrics::from_horizontal_pod_autoscaler)
+        .collect())
+}
+
+impl KubernetesMetrics {
+    pub fn from_horizontal_pod_autoscaler(
+        horizontal_pod_autoscaler: &HorizontalPodAutoscaler,
+    ) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(horizontal_pod_autoscaler),
+        ));
+
+        metric.set_horizontal_pod_autoscaler(
+            HorizontalPodAutoscalerStatus::from_horizontal_pod_autoscaler(
+                horizontal_pod_autoscaler,
+            ),
+        );
+
+        metric
+    }
+
+    pub fn is_horizontal_pod_autoscaler(&self) -> bool {
+        self.has_horizontal_pod_autoscaler()
+    }
+}
+
+impl HorizontalPodAutoscalerStatus {
+    pub fn from_horizontal_pod_autoscaler(
+        horizontal_pod_autoscaler: &HorizontalPodAutoscaler,
+    ) -> HorizontalPodAutoscalerStatus {
+        let mut autoscaler = HorizontalPodAutoscalerStatus::new();
+
+        let current_metrics = horizontal_pod_autoscaler
+            .status
+            .as_ref()
+            .and_then(|status| status.current_metrics.as_deref())
+            .unwrap_or_default();
+
+        if let Some(spec) = &horizontal_pod_autoscaler.spec {
+            if let Some(scale_target) = ResourceIdentifier::from_cross_version_object_reference(
+                &spec.scale_target_ref,
+                horizontal_pod_autoscaler.namespace(),
+            ) {
+                autoscaler.set_scale_target(ResourceReference::from(&scale_target));
+            }
+
+            autoscaler.set_min_replicas(spec.min_replicas.unwrap_or(1));
+            autoscaler.set_max_replicas(spec.max_replicas);
+
+            for metric_spec in spec.metrics.iter().flatten() {
+                if let Some(metric) =
+                    HorizontalPodAutoscalerMetric::from_metric_spec(metric_spec, current_metrics)
+                {
+                    autoscaler.mut_metrics().push(metric);
+                }
+            }
+        }
+
+        if let Some(status) = &horizontal_pod_autoscaler.status {
+            autoscaler.set_current_replicas(status.current_replicas.unwrap_or_default());
+            autoscaler.set_desired_replicas(status.desired_replicas);
+
+            for condition in status.conditions.iter().flatten() {
+                let condition_status = ConditionStatus::from(condition.status.as_str());
+
+                match condition.type_.as_str() {
+                    "AbleToScale" => autoscaler.set_able_to_scale(condition_status),
+                    "ScalingActive" => autoscaler.set_scaling_active(condition_status),
+                    "ScalingLimited" => {
+                        autoscaler.set_scaling_limited(condition_status);
+                        if let Some(reason) = &condition.reason {
+                            autoscaler.set_scaling_limited_reason(reason.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        autoscaler
+    }
+}
+
+impl HorizontalPodAutoscalerMetric {
+    // Build a metric from the target in an autoscaler's spec, and its
+    // current value from the matching metric in the autoscaler's status.
+    pub fn from_metric_spec(
+        metric_spec: &MetricSpec,
+        current_metrics: &[MetricStatus],
+    ) -> Option<HorizontalPodAutoscalerMetric> {
+        let (name, container, target) = metric_spec_target(metric_spec)?;
+
+        let mut metric = HorizontalPodAutoscalerMetric::new();
+        metric.set_field_type(metric_spec.type_.clone());
+        metric.set_name(name.clone());
+        metric.set_container(container.clone());
+        metric.set_target_type(target.type_.clone());
+
+        if let Some(target_value) = target_value(target) {
+            metric.set_target_value(target_value);
+        }
+
+        if let Some(current_value) = current_metrics
+            .iter()
+            .filter(|current_metric| current_metric.type_ == metric_spec.type_)
+            .filter_map(metric_status_current)
+            .find(|(current_name, current_container, _)| {
+                current_name == &name && current_container == &container
+            })
+            .and_then(|(_, _, current)| current_value(current, &target.type_))
+        {
+            metric.set_current_value(current_value);
+        }
+
+        Some(metric)
+    }
+}
+
+// The name, container and target of a metric in an autoscaler's spec.
+// The container is only set for container resource metrics.
+fn metric_spec_target(metric_spec: &MetricSpec) -> Option<(String, String, &MetricTarget)> {
+    match metric_spec.type_.as_str() {
+        "Resource" => metric_spec
+            .resource
+            .as_ref()
+            .map(|source| (source.name.clone(), String::new(), &source.target)),
+        "ContainerResource" => metric_spec.container_resource.as_ref().map(|source| {
+            (
+                source.name.clone(),
+                source.container.clone(),
+                &source.target,
+            )
+        }),
+        "Pods" => metric_spec
+            .pods
+            .as_ref()
+            .map(|source| (source.metric.name.clone(), String::new(), &source.target)),
+        "Object" => metric_spec
+            .object
+            .as_ref()
+            .map(|source| (source.metric.name.clone(), String::new(), &source.target)),
+        "External" => metric_spec
+            .external
+            .as_ref()
+            .map(|source| (source.metric.name.clone(), String::new(), &source.target)),
+        _ => None,
+    }
+}
+
+// The name, container and current value of a metric in an autoscaler's
+// status, identified in the same way as in `metric_spec_target`.
+fn metric_status_current(
+    metric_status: &MetricStatus,
+) -> Option<(String, String, &MetricValueStatus)> {
+    match metric_status.type_.as_str() {
+        "Resource" => metric_status
+            .resource
+            .as_ref()
+            .map(|status| (status.name.clone(), String::new(), &status.current)),
+        "ContainerResource" => metric_status.container_resource.as_ref().map(|status| {
+            (
+                status.name.clone(),
+                status.container.clone(),
+                &status.current,
+            )
+        }),
+        "Pods" => metric_status
+            .pods
+            .as_ref()
+            .map(|status| (status.metric.name.clone(), String::new(), &status.current)),
+        "Object" => metric_status
+            .object
+            .as_ref()
+            .map(|status| (status.metric.name.clone(), String::new(), &status.current)),
+        "External" => metric_status
+            .external
+            .as_ref()
+            .map(|status| (status.metric.name.clone(), String::new(), &status.current)),
+        _ => None,
+    }
+}
+
+fn target_value(target: &MetricTarget) -> Option<f64> {
+    match target.type_.as_str() {
+        "Utilization" => target.average_utilization.map(f64::from),
+        "AverageValue" => target.average_value.as_ref().and_then(parse_quantity),
+        "Value" => target.value.as_ref().and_then(parse_quantity),
+        _ => None,
+    }
+}
+
+// The current value of a metric, in the same form as its target, so that
+// the two can be compared.
+fn current_value(current: &MetricValueStatus, target_type: &str) -> Option<f64> {
+    match target_type {
+        "Utilization" => current.average_utilization.map(f64::from),
+        "AverageValue" => current.average_value.as_ref().and_then(parse_quantity),
+        "Value" => current.value.as_ref().and_then(parse_quantity),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::kubernetes::{ConditionStatus, HorizontalPodAutoscalerStatus};
+    use k8s_openapi::api::autoscaling::v2::{
+        CrossVersionObjectReference, HorizontalPodAutoscaler, HorizontalPodAutoscalerCondition,
+        HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus as KubernetesStatus,
+        MetricIdentifier, MetricSpec, MetricStatus, MetricTarget, MetricValueStatus,
+        PodsMetricSource, PodsMetricStatus, ResourceMetricSource, ResourceMetricStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    fn horizontal_pod_autoscaler() -> HorizontalPodAutoscaler {
+        let mut horizontal_pod_autoscaler = HorizontalPodAutoscaler::default();
+        horizontal_pod_autoscaler.metadata.name = Some("web".to_string());
+        horizontal_pod_autoscaler.metadata.namespace = Some("default".to_string());
+        horizontal_pod_autoscaler.spec = Some(HorizontalPodAutoscalerSpec {
+            scale_target_ref: CrossVersionObjectReference {
+                api_version: Some("apps/v1".to_string()),
+                kind: "Deployment".to_string(),
+                name: "web".to_string(),
+            },
+            min_replicas: Some(2),
+            max_replicas: 10,
+            metrics: Some(vec![
+                MetricSpec {
+                    type_: "Resource".to_string(),
+                    resource: Some(ResourceMetricSource {
+                        name: "cpu".to_string(),
+                        target: MetricTarget {
+                            type_: "Utilization".to_string(),
+                            average_utilization: Some(70),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                },
+                MetricSpec {
+                    type_: "Pods".to_string(),
+                    pods: Some(PodsMetricSource {
+                        metric: MetricIdentifier {
+                            name: "requests_per_second".to_string(),
+                            selector: None,
+                        },
+                        target: MetricTarget {
+                            type_: "AverageValue".to_string(),
+                            average_value: Some(Quantity("500m".to_string())),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+        horizontal_pod_autoscaler
+    }
+
+    #[test]
+    fn horizontal_pod_autoscaler_status_at_max_replicas() {
+        let mut horizontal_pod_autoscaler = horizontal_pod_autoscaler();
+        horizontal_pod_autoscaler.status = Some(KubernetesStatus {
+            current_replicas: Some(10),
+            desired_replicas: 10,
+            current_metrics: Some(vec![
+                MetricStatus {
+                    type_: "Pods".to_string(),
+                    pods: Some(PodsMetricStatus {
+                        metric: MetricIdentifier {
+                            name: "requests_per_second".to_string(),
+                            selector: None,
+                        },
+                        current: MetricValueStatus {
+                            average_value: Some(Quantity("1200m".to_string())),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                },
+                MetricStatus {
+                    type_: "Resource".to_string(),
+                    resource: Some(ResourceMetricStatus {
+                        name: "cpu".to_string(),
+                        current: MetricValueStatus {
+                            average_utilization: Some(95),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                },
+            ]),
+            conditions: Some(vec![
+                HorizontalPodAutoscalerCondition {
+                    type_: "AbleToScale".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                },
+                HorizontalPodAutoscalerCondition {
+                    type_: "ScalingLimited".to_string(),
+                    status: "True".to_string(),
+                    reason: Some("TooManyReplicas".to_string()),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        let autoscaler = HorizontalPodAutoscalerStatus::from_horizontal_pod_autoscaler(
+            &horizontal_pod_autoscaler,
+        );
+
+        assert_eq!("Deployment", autoscaler.get_scale_target().get_kind());
+        assert_eq!("web", autoscaler.get_scale_target().get_name());
+        assert_eq!("default", autoscaler.get_scale_target().get_namespace());
+        assert_eq!(10, autoscaler.get_current_replicas());
+        assert_eq!(10, autoscaler.get_desired_replicas());
+        assert_eq!(2, autoscaler.get_min_replicas());
+        assert_eq!(10, autoscaler.get_max_replicas());
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_TRUE,
+            autoscaler.get_able_to_scale()
+        );
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_UNKNOWN,
+            autoscaler.get_scaling_active()
+        );
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_TRUE,
+            autoscaler.get_scaling_limited()
+        );
+        assert_eq!("TooManyReplicas", autoscaler.get_scaling_limited_reason());
+
+        let metrics = autoscaler.get_metrics();
+        assert_eq!(2, metrics.len());
+
+        assert_eq!("Resource", metrics[0].get_field_type());
+        assert_eq!("cpu", metrics[0].get_name());
+        assert_eq!("Utilization", metrics[0].get_target_type());
+        assert_eq!(70.0, metrics[0].get_target_value());
+        assert_eq!(95.0, metrics[0].get_current_value());
+
+        assert_eq!("Pods", metrics[1].get_field_type());
+        assert_eq!("requests_per_second", metrics[1].get_name());
+        assert_eq!("AverageValue", metrics[1].get_target_type());
+        assert_eq!(0.5, metrics[1].get_target_value());
+        assert_eq!(1.2, metrics[1].get_current_value());
+    }
+
+    #[test]
+    fn horizontal_pod_autoscaler_status_without_status() {
+        let autoscaler = HorizontalPodAutoscalerStatus::from_horizontal_pod_autoscaler(
+            &horizontal_pod_autoscaler(),
+        );
+
+        assert_eq!(0, autoscaler.get_current_replicas());
+        assert_eq!(10, autoscaler.get_max_replicas());
+        assert_eq!(2, autoscaler.get_metrics().len());
+        assert_eq!(0.0, autoscaler.get_metrics()[0].get_current_value());
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_UNKNOWN,
+            autoscaler.get_able_to_scale()
+        );
+    }
+}
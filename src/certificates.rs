@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Secret;
+use kube::api::{DynamicObject, GroupVersionKind, ListParams};
+use kube::{Api, ResourceExt};
+use log::trace;
+
+use crate::ownership::{OwnershipResolver, ResourceIdentifier};
+use crate::protocol::kubernetes::{
+    CertificateStatus, ConditionStatus, KubernetesMetrics, ResourceReference,
+};
+use crate::x509::Certificate;
+use crate::Error;
+
+const TLS_SECRET_TYPE: &str = "kubernetes.io/tls";
+const TLS_SECRET_CERTIFICATE_KEY: &str = "tls.crt";
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// The certificates of the TLS Secrets in the cluster, by the namespace and
+// name of their Secret, with the resource version of the Secret they were
+// parsed from. Scanning TLS Secrets is opt-in, as reading them also reads
+// their private keys. Every version of a Secret is only read and parsed
+// once.
+pub struct TlsSecretCache {
+    enabled: bool,
+    certificates: HashMap<(String, String), (String, Option<Certificate>)>,
+}
+
+impl TlsSecretCache {
+    pub fn from_env() -> Self {
+        TlsSecretCache {
+            enabled: env::var("APPSIGNAL_CERTIFICATES_TLS_SECRETS").as_deref() == Ok("true"),
+            certificates: HashMap::new(),
+        }
+    }
+
+    // The certificates of the TLS Secrets that are not in the skipped
+    // Secrets. Only the metadata of the Secrets is listed, and the Secrets
+    // that changed since they were last read are read again.
+    async fn extract(
+        &mut self,
+        client: &kube::Client,
+        skipped: &HashSet<(String, String)>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<KubernetesMetrics>, Error> {
+        let mut metrics = Vec::new();
+
+        let secrets: Api<Secret> = Api::all(client.clone());
+        let secrets_list = secrets
+            .list_metadata(&ListParams::default().fields(&format!("type={}", TLS_SECRET_TYPE)))
+            .await?;
+
+        let mut certificates = HashMap::new();
+        for secret in &secrets_list {
+            let key = (secret.namespace().unwrap_or_default(), secret.name_any());
+            if skipped.contains(&key) {
+                continue;
+            }
+
+            let resource_version = secret.resource_version().unwrap_or_default();
+            let certificate = match self.certificates.remove(&key) {
+                Some((cached_version, certificate)) if cached_version == resource_version => {
+                    certificate
+                }
+                _ => {
+                    let secrets: Api<Secret> = Api::namespaced(client.clone(), &key.0);
+                    secrets
+                        .get_opt(&key.1)
+                        .await?
+                        .and_then(|secret| parse_tls_secret(&secret))
+                }
+            };
+
+            match &certificate {
+                Some(certificate) => metrics.push(KubernetesMetrics::from_secret_certificate(
+                    &key.0,
+                    &key.1,
+                    certificate,
+                    now,
+                )),
+                None => trace!("Could not parse certificate in Secret {}/{}", key.0, key.1),
+            }
+
+            certificates.insert(key, (resource_version, certificate));
+        }
+
+        // Forget the certificates of deleted Secrets.
+        self.certificates = certificates;
+
+        Ok(metrics)
+    }
+}
+
+// Extract the expiry of every cert-manager Certificate in the cluster, if
+// cert-manager is installed, and of every TLS Secret that is not managed
+// by an issued Certificate, when scanning TLS Secrets is enabled.
+pub async fn extract_certificates(
+    client: &kube::Client,
+    resolver: &mut OwnershipResolver,
+    tls_secrets: &mut TlsSecretCache,
+) -> Result<Vec<KubernetesMetrics>, Error> {
+    let now = Utc::now();
+    let mut metrics = Vec::new();
+
+    // The Secrets of issued Certificates, by their namespace and name.
+    let mut certificate_secrets = HashSet::new();
+
+    let gvk = GroupVersionKind::gvk("cert-manager.io", "v1", "Certificate");
+    for certificate in resolver.list_objects(&gvk).await?.into_iter().flatten() {
+        match KubernetesMetrics::from_cert_manager_certificate(&certificate, &gvk, now) {
+            Some(metric) => {
+                certificate_secrets.insert((
+                    certificate.namespace().unwrap_or_default(),
+                    metric.get_certificate().get_secret_name().to_string(),
+                ));
+                metrics.push(metric);
+            }
+            // A Certificate that has not been issued yet has no expiry.
+            // Its Secret may still contain a previously issued certificate.
+            None => trace!(
+                "Skipping Certificate {} without expiry",
+                certificate.name_any()
+            ),
+        }
+    }
+
+    if tls_secrets.enabled {
+        metrics.extend(
+            tls_secrets
+                .extract(client, &certificate_secrets, now)
+                .await?,
+        );
+    }
+
+    Ok(metrics)
+}
+
+fn parse_tls_secret(secret: &Secret) -> Option<Certificate> {
+    let pem = secret.data.as_ref()?.get(TLS_SECRET_CERTIFICATE_KEY)?;
+    Certificate::from_pem(&pem.0)
+}
+
+impl KubernetesMetrics {
+    pub fn from_tls_secret(secret: &Secret, now: DateTime<Utc>) -> Option<KubernetesMetrics> {
+        let certificate = parse_tls_secret(secret)?;
+
+        Some(KubernetesMetrics::from_secret_certificate(
+            &secret.namespace().unwrap_or_default(),
+            &secret.name_any(),
+            &certificate,
+            now,
+        ))
+    }
+
+    fn from_secret_certificate(
+        namespace: &str,
+        name: &str,
+        certificate: &Certificate,
+        now: DateTime<Utc>,
+    ) -> KubernetesMetrics {
+        let mut status = CertificateStatus::new();
+        status.set_not_after(certificate.not_after.timestamp());
+        status.set_days_until_expiry(days_until(certificate.not_after, now));
+        status.set_common_name(certificate.common_name.clone().unwrap_or_default());
+        status.set_dns_names(certificate.dns_names.clone().into());
+        status.set_issuer(certificate.issuer_common_name.clone().unwrap_or_default());
+        status.set_secret_name(name.to_string());
+
+        let mut metric =
+            KubernetesMetrics::from_resource(ResourceReference::from(&ResourceIdentifier {
+                gvk: GroupVersionKind::gvk("", "v1", "Secret"),
+                name: name.to_string(),
+                namespace: Some(namespace.to_string()),
+            }));
+        metric.set_certificate(status);
+
+        metric
+    }
+
+    pub fn from_cert_manager_certificate(
+        certificate: &DynamicObject,
+        gvk: &GroupVersionKind,
+        now: DateTime<Utc>,
+    ) -> Option<KubernetesMetrics> {
+        let spec = &certificate.data["spec"];
+        let certificate_status = &certificate.data["status"];
+
+        let not_after: DateTime<Utc> = certificate_status["notAfter"].as_str()?.parse().ok()?;
+
+        let mut status = CertificateStatus::new();
+        status.set_not_after(not_after.timestamp());
+        status.set_days_until_expiry(days_until(not_after, now));
+        status.set_common_name(spec["commonName"].as_str().unwrap_or_default().to_string());
+        for dns_name in spec["dnsNames"].as_array().into_iter().flatten() {
+            if let Some(dns_name) = dns_name.as_str() {
+                status.mut_dns_names().push(dns_name.to_string());
+            }
+        }
+        status.set_issuer(
+            spec["issuerRef"]["name"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        );
+        status.set_secret_name(spec["secretName"].as_str().unwrap_or_default().to_string());
+
+        if let Some(ready) = certificate_status["conditions"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|condition| condition["type"] == "Ready")
+        {
+            status.set_ready(ConditionStatus::from(
+                ready["status"].as_str().unwrap_or_default(),
+            ));
+        }
+
+        let mut metric =
+            KubernetesMetrics::from_resource(ResourceReference::from(&ResourceIdentifier {
+                gvk: gvk.clone(),
+                name: certificate.name_any(),
+                namespace: certificate.namespace(),
+            }));
+        metric.set_certificate(status);
+
+        Some(metric)
+    }
+
+    pub fn is_certificate(&self) -> bool {
+        self.has_certificate()
+    }
+}
+
+// The number of whole days until a certificate expires. Expired
+// certificates have a negative number of days until expiry.
+fn days_until(not_after: DateTime<Utc>, now: DateTime<Utc>) -> i32 {
+    (not_after.timestamp() - now.timestamp()).div_euclid(SECONDS_PER_DAY) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::kubernetes::{ConditionStatus, KubernetesMetrics};
+    use chrono::{TimeZone, Utc};
+    use k8s_openapi::api::core::v1::Secret;
+    use k8s_openapi::ByteString;
+    use kube::api::{DynamicObject, GroupVersionKind};
+
+    #[test]
+    fn certificate_metric_from_tls_secret() {
+        let pem =
+            std::fs::read("test/fixtures/certificate.pem").expect("Could not open example file");
+
+        let mut secret = Secret::default();
+        secret.metadata.name = Some("example-tls".to_string());
+        secret.metadata.namespace = Some("web".to_string());
+        secret.type_ = Some("kubernetes.io/tls".to_string());
+        secret.data = Some([("tls.crt".to_string(), ByteString(pem))].into());
+
+        let now = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let metric = KubernetesMetrics::from_tls_secret(&secret, now).unwrap();
+
+        assert!(metric.is_certificate());
+        assert_eq!("Secret", metric.get_resource().get_kind());
+        assert_eq!("example-tls", metric.get_resource().get_name());
+        assert_eq!("web", metric.get_resource().get_namespace());
+
+        let status = metric.get_certificate();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0)
+                .unwrap()
+                .timestamp(),
+            status.get_not_after()
+        );
+        assert_eq!(11, status.get_days_until_expiry());
+        assert_eq!("example.com", status.get_common_name());
+        assert_eq!(
+            &["example.com".to_string(), "www.example.com".to_string()],
+            status.get_dns_names()
+        );
+
+        let expired = KubernetesMetrics::from_tls_secret(
+            &secret,
+            Utc.with_ymd_and_hms(2025, 4, 1, 1, 0, 0).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(-1, expired.get_certificate().get_days_until_expiry());
+    }
+
+    #[test]
+    fn certificate_metric_from_cert_manager_certificate() {
+        let gvk = GroupVersionKind::gvk("cert-manager.io", "v1", "Certificate");
+        let certificate: DynamicObject = serde_json::from_value(serde_json::json!({
+            "apiVersion": "cert-manager.io/v1",
+            "kind": "Certificate",
+            "metadata": { "name": "example", "namespace": "web" },
+            "spec": {
+                "secretName": "example-tls",
+                "dnsNames": ["example.com"],
+                "issuerRef": { "name": "letsencrypt", "kind": "ClusterIssuer" }
+            },
+            "status": {
+                "notAfter": "2025-04-01T00:00:00Z",
+                "conditions": [{ "type": "Ready", "status": "True" }]
+            }
+        }))
+        .unwrap();
+
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let metric =
+            KubernetesMetrics::from_cert_manager_certificate(&certificate, &gvk, now).unwrap();
+
+        assert_eq!("Certificate", metric.get_resource().get_kind());
+
+        let status = metric.get_certificate();
+        assert_eq!(31, status.get_days_until_expiry());
+        assert_eq!("letsencrypt", status.get_issuer());
+        assert_eq!("example-tls", status.get_secret_name());
+        assert_eq!(&["example.com".to_string()], status.get_dns_names());
+        assert_eq!(ConditionStatus::CONDITION_STATUS_TRUE, status.get_ready());
+
+        let mut pending = certificate.clone();
+        pending.data["status"] = serde_json::json!({});
+        assert!(KubernetesMetrics::from_cert_manager_certificate(&pending, &gvk, now).is_none());
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::{DynamicObject, GroupVersionKind};
+use kube::core::GroupVersion;
+use kube::ResourceExt;
+use log::warn;
+use serde::Deserialize;
+
+use crate::ownership::{OwnershipResolver, ResourceIdentifier};
+use crate::protocol::kubernetes::{
+    ConditionStatus, CustomResourceCondition, CustomResourceField, CustomResourceStatus,
+    KubernetesMetrics, ResourceReference,
+};
+use crate::quantity::parse_quantity;
+
+// The configuration of a kind of custom resource to report the status of,
+// such as:
+//
+//     {
+//       "apiVersion": "postgresql.cnpg.io/v1",
+//       "kind": "Cluster",
+//       "fields": { "ready_instances": "/status/readyInstances" },
+//       "conditions": ["Ready"]
+//     }
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomResourceConfig {
+    pub api_version: String,
+    pub kind: String,
+    // The names of the fields to report, and the JSON pointers to their
+    // values in the custom resource.
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    // The types of the conditions to report. All conditions are reported
+    // when not set.
+    #[serde(default)]
+    pub conditions: Option<Vec<String>>,
+}
+
+impl CustomResourceConfig {
+    pub fn parse_list(json: &str) -> Result<Vec<CustomResourceConfig>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn gvk(&self) -> Option<GroupVersionKind> {
+        let group_version: GroupVersion = self.api_version.parse().ok()?;
+
+        Some(group_version.with_kind(&self.kind))
+    }
+}
+
+// Extract the configured status fields and conditions of every custom
+// resource of the configured kinds. Kinds that are not available in the
+// cluster, or that cannot be listed, are skipped.
+pub async fn extract_custom_resources(
+    resolver: &mut OwnershipResolver,
+    configs: &[CustomResourceConfig],
+) -> Vec<KubernetesMetrics> {
+    let mut metrics = Vec::new();
+
+    for config in configs {
+        let gvk = match config.gvk() {
+            Some(gvk) => gvk,
+            None => {
+                warn!(
+                    "Invalid apiVersion {:?} for custom resource {}",
+                    config.api_version, config.kind
+                );
+                continue;
+            }
+        };
+
+        match resolver.list_objects(&gvk).await {
+            Ok(Some(objects)) => {
+                for object in objects {
+                    metrics.push(KubernetesMetrics::from_custom_resource(
+                        &object, &gvk, config,
+                    ));
+                }
+            }
+            Ok(None) => warn!(
+                "Custom resource {} {} is not available in the cluster",
+                config.api_version, config.kind
+            ),
+            Err(err) => warn!(
+                "Failed to extract custom resource {} {}: {}",
+                config.api_version, config.kind, err
+            ),
+        }
+    }
+
+    metrics
+}
+
+impl KubernetesMetrics {
+    pub fn from_custom_resource(
+        object: &DynamicObject,
+        gvk: &GroupVersionKind,
+        config: &CustomResourceConfig,
+    ) -> KubernetesMetrics {
+        let mut metric =
+            KubernetesMetrics::from_resource(ResourceReference::from(&ResourceIdentifier {
+                gvk: gvk.clone(),
+                name: object.name_any(),
+                namespace: object.namespace(),
+            }));
+
+        metric.set_custom_resource(CustomResourceStatus::from_custom_resource(object, config));
+
+        metric
+    }
+
+    pub fn is_custom_resource(&self) -> bool {
+        self.has_custom_resource()
+    }
+}
+
+impl CustomResourceStatus {
+    pub fn from_custom_resource(
+        object: &DynamicObject,
+        config: &CustomResourceConfig,
+    ) -> CustomResourceStatus {
+        let mut status = CustomResourceStatus::new();
+        status.set_api_version(config.api_version.clone());
+
+        for (name, pointer) in &config.fields {
+            if let Some(field) = object
+                .data
+                .pointer(pointer)
+                .and_then(|value| CustomResourceField::from_json(name, value))
+            {
+                status.mut_fields().push(field);
+            }
+        }
+
+        for condition in object.data["status"]["conditions"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            let condition_type = match condition["type"].as_str() {
+                Some(condition_type) => condition_type,
+                None => continue,
+            };
+
+            if let Some(condition_types) = &config.conditions {
+                if !condition_types.iter().any(|t| t == condition_type) {
+                    continue;
+                }
+            }
+
+            let mut custom_resource_condition = CustomResourceCondition::new();
+            custom_resource_condition.set_field_type(condition_type.to_string());
+            custom_resource_condition.set_status(ConditionStatus::from(
+                condition["status"].as_str().unwrap_or_default(),
+            ));
+            custom_resource_condition
+                .set_reason(condition["reason"].as_str().unwrap_or_default().to_string());
+            status.mut_conditions().push(custom_resource_condition);
+        }
+
+        status
+    }
+}
+
+impl CustomResourceField {
+    // Build a field from a number, boolean or string value. Strings that
+    // are resource quantities, such as "10Gi", are also reported as numbers.
+    pub fn from_json(name: &str, value: &serde_json::Value) -> Option<CustomResourceField> {
+        let mut field = CustomResourceField::new();
+        field.set_name(name.to_string());
+
+        match value {
+            serde_json::Value::Number(number) => field.set_value(number.as_f64()?),
+            serde_json::Value::Bool(boolean) => field.set_value(if *boolean { 1.0 } else { 0.0 }),
+            serde_json::Value::String(string) => {
+                if let Some(value) = parse_quantity(&Quantity(string.clone())) {
+                    field.set_value(value);
+                }
+                field.set_string_value(string.clone());
+            }
+            _ => return None,
+        }
+
+        Some(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomResourceConfig;
+    use crate::protocol::kubernetes::{ConditionStatus, CustomResourceStatus};
+    use kube::api::DynamicObject;
+
+    fn config(conditions: Option<Vec<String>>) -> CustomResourceConfig {
+        CustomResourceConfig {
+            api_version: "postgresql.cnpg.io/v1".to_string(),
+            kind: "Cluster".to_string(),
+            fields: [
+                ("instances", "/status/instances"),
+                ("phase", "/status/phase"),
+                ("storage", "/spec/storage/size"),
+                ("fenced", "/status/fenced"),
+                ("missing", "/status/missing"),
+                ("topology", "/status/topology"),
+            ]
+            .iter()
+            .map(|(name, pointer)| (name.to_string(), pointer.to_string()))
+            .collect(),
+            conditions,
+        }
+    }
+
+    fn cluster() -> DynamicObject {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "postgresql.cnpg.io/v1",
+            "kind": "Cluster",
+            "metadata": { "name": "postgres", "namespace": "database" },
+            "spec": { "storage": { "size": "1Gi" } },
+            "status": {
+                "instances": 3,
+                "phase": "Cluster in healthy state",
+                "fenced": false,
+                "topology": { "successfullyExtracted": true },
+                "conditions": [
+                    { "type": "Ready", "status": "True", "reason": "ClusterIsReady" },
+                    { "type": "ContinuousArchiving", "status": "False", "reason": "Failing" }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_custom_resource_config_list() {
+        let configs = CustomResourceConfig::parse_list(
+            r#"[{"apiVersion": "cert-manager.io/v1", "kind": "Certificate"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(1, configs.len());
+        assert_eq!("cert-manager.io/v1", configs[0].api_version);
+        assert_eq!("Certificate", configs[0].kind);
+        assert!(configs[0].fields.is_empty());
+        assert_eq!(None, configs[0].conditions);
+
+        assert!(CustomResourceConfig::parse_list(r#"[{"kind": "Certificate"}]"#).is_err());
+    }
+
+    #[test]
+    fn custom_resource_status_from_fields_and_conditions() {
+        let status = CustomResourceStatus::from_custom_resource(&cluster(), &config(None));
+
+        assert_eq!("postgresql.cnpg.io/v1", status.get_api_version());
+
+        // Fields are reported by name, skipping missing and object values.
+        let fields = status.get_fields();
+        assert_eq!(4, fields.len());
+        assert_eq!("fenced", fields[0].get_name());
+        assert_eq!(0.0, fields[0].get_value());
+        assert_eq!("instances", fields[1].get_name());
+        assert_eq!(3.0, fields[1].get_value());
+        assert_eq!("phase", fields[2].get_name());
+        assert_eq!("Cluster in healthy state", fields[2].get_string_value());
+        assert_eq!(0.0, fields[2].get_value());
+        assert_eq!("storage", fields[3].get_name());
+        assert_eq!(1024.0 * 1024.0 * 1024.0, fields[3].get_value());
+
+        let conditions = status.get_conditions();
+        assert_eq!(2, conditions.len());
+        assert_eq!("Ready", conditions[0].get_field_type());
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_TRUE,
+            conditions[0].get_status()
+        );
+        assert_eq!("ClusterIsReady", conditions[0].get_reason());
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_FALSE,
+            conditions[1].get_status()
+        );
+    }
+
+    #[test]
+    fn custom_resource_status_with_selected_conditions() {
+        let status = CustomResourceStatus::from_custom_resource(
+            &cluster(),
+            &config(Some(vec!["ContinuousArchiving".to_string()])),
+        );
+
+        let conditions = status.get_conditions();
+        assert_eq!(1, conditions.len());
+        assert_eq!("ContinuousArchiving", conditions[0].get_field_type());
+    }
+}
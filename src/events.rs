@@ -0,0 +1,396 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::events::v1::Event;
+use kube::runtime::{watcher, WatchStreamExt};
+use kube::{Api, ResourceExt};
+use log::{trace, warn};
+use tokio::task::JoinHandle;
+
+use crate::now_timestamp;
+use crate::ownership::{OwnershipResolver, ResourceIdentifier};
+use crate::protocol::kubernetes::{
+    KubernetesEvent, KubernetesMetrics, OwnerReference, ResourceReference,
+};
+
+// Events are deduplicated by the kind, namespace and name of the object
+// they are about, and by their reason.
+type EventKey = (String, String, String, String);
+
+struct PendingEvent {
+    resource: ResourceIdentifier,
+    metric: KubernetesMetrics,
+}
+
+struct EventStore {
+    started_at: DateTime<Utc>,
+    // The last seen occurrence count of each event, by its UID.
+    counts: HashMap<String, i32>,
+    // The Warning events that occurred since the store was last drained.
+    pending: BTreeMap<EventKey, PendingEvent>,
+}
+
+impl EventStore {
+    fn new(started_at: DateTime<Utc>) -> Self {
+        EventStore {
+            started_at,
+            counts: HashMap::new(),
+            pending: BTreeMap::new(),
+        }
+    }
+
+    fn record(&mut self, event: &Event) {
+        if event.type_.as_deref() != Some("Warning") {
+            return;
+        }
+
+        let uid = match &event.metadata.uid {
+            Some(uid) => uid.clone(),
+            None => return,
+        };
+
+        // Events that repeat are updated with an increased count, rather
+        // than reported again, so only the new occurrences are counted.
+        let count = event
+            .series
+            .as_ref()
+            .map(|series| series.count)
+            .or(event.deprecated_count)
+            .unwrap_or(1)
+            .max(1);
+        let previous_count = self.counts.insert(uid, count);
+        let occurrences = count - previous_count.unwrap_or(0);
+
+        if occurrences <= 0 {
+            return;
+        }
+
+        let last_timestamp = match last_timestamp(event) {
+            Some(last_timestamp) => last_timestamp,
+            None => return,
+        };
+
+        // When the watch starts, it lists all existing events. Skip those
+        // that last occurred before the collector was started.
+        if previous_count.is_none() && last_timestamp < self.started_at {
+            return;
+        }
+
+        let resource = match event
+            .regarding
+            .as_ref()
+            .and_then(ResourceIdentifier::from_object_reference)
+        {
+            Some(resource) => resource,
+            None => return,
+        };
+
+        let reason = event.reason.clone().unwrap_or_default();
+        let key = (
+            resource.gvk.kind.clone(),
+            resource.namespace.clone().unwrap_or_default(),
+            resource.name.clone(),
+            reason,
+        );
+
+        let pending_event = self.pending.entry(key).or_insert_with(|| PendingEvent {
+            metric: KubernetesMetrics::from_event(event, &resource),
+            resource,
+        });
+
+        let kubernetes_event = pending_event.metric.mut_event();
+        kubernetes_event.set_count(kubernetes_event.get_count() + occurrences);
+
+        if last_timestamp.timestamp() >= kubernetes_event.get_last_timestamp() {
+            kubernetes_event.set_last_timestamp(last_timestamp.timestamp());
+            if let Some(note) = &event.note {
+                kubernetes_event.set_note(note.clone());
+            }
+        }
+    }
+
+    fn forget(&mut self, event: &Event) {
+        if let Some(uid) = &event.metadata.uid {
+            self.counts.remove(uid);
+        }
+    }
+}
+
+// Watches Kubernetes Warning events in the background, and keeps the
+// events that occurred since they were last extracted.
+pub struct EventCollector {
+    client: kube::Client,
+    store: Arc<Mutex<EventStore>>,
+    watcher: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl EventCollector {
+    pub fn new(client: kube::Client) -> Self {
+        EventCollector {
+            client,
+            store: Arc::new(Mutex::new(EventStore::new(Utc::now()))),
+            watcher: Mutex::new(None),
+        }
+    }
+
+    // Watch the events only while this replica extracts them, as the watch
+    // is cluster-wide. The events are forgotten when the watch stops, and
+    // only the events that occur after the watch starts are extracted.
+    pub fn set_watching(&self, watching: bool) {
+        let mut watcher = self.watcher.lock().expect("Event watcher lock poisoned");
+
+        if watching == watcher.is_some() {
+            return;
+        }
+
+        if let Some(watcher) = watcher.take() {
+            watcher.abort();
+        }
+
+        *self.store.lock().expect("Event store lock poisoned") = EventStore::new(Utc::now());
+
+        if watching {
+            *watcher = Some(self.watch());
+        }
+    }
+
+    fn watch(&self) -> JoinHandle<()> {
+        let watcher_store = self.store.clone();
+
+        let events: Api<Event> = Api::all(self.client.clone());
+        let config = watcher::Config::default().fields("type=Warning");
+
+        tokio::spawn(async move {
+            let mut stream = watcher(events, config).default_backoff().boxed();
+
+            while let Some(result) = stream.next().await {
+                let mut store = watcher_store.lock().expect("Event store lock poisoned");
+
+                match result {
+                    Ok(watcher::Event::Apply(event)) | Ok(watcher::Event::InitApply(event)) => {
+                        store.record(&event)
+                    }
+                    Ok(watcher::Event::Delete(event)) => store.forget(&event),
+                    Ok(_) => {}
+                    Err(err) => warn!("Failed to watch Kubernetes events: {}", err),
+                }
+            }
+        })
+    }
+
+    // Take the Warning events that occurred since the last extraction, and
+    // attribute them to the node and top-level owners of their object.
+    pub async fn extract_events(
+        &self,
+        resolver: &mut OwnershipResolver,
+        pods: &kube::api::ObjectList<Pod>,
+    ) -> Vec<KubernetesMetrics> {
+        let pending = std::mem::take(
+            &mut self
+                .store
+                .lock()
+                .expect("Event store lock poisoned")
+                .pending,
+        );
+
+        let mut metrics = Vec::new();
+
+        for PendingEvent {
+            resource,
+            mut metric,
+        } in pending.into_values()
+        {
+            if !metric.pod_name.is_empty() {
+                if let Some(pod) = pods.iter().find(|pod| {
+                    pod.name_any() == metric.pod_name
+                        && pod.namespace().as_deref() == Some(metric.get_pod_namespace())
+                }) {
+                    if let Some(node_name) =
+                        pod.spec.as_ref().and_then(|spec| spec.node_name.as_ref())
+                    {
+                        metric.set_node_name(node_name.clone());
+                    }
+                }
+            }
+
+            match resolver.resolve_top_level_owners(&resource).await {
+                Ok(owners) => {
+                    for owner in owners.into_iter().filter(|owner| owner != &resource) {
+                        metric
+                            .mut_owner_references()
+                            .push(OwnerReference::from(owner));
+                    }
+                }
+                // The object the event is about may no longer exist, such as
+                // an evicted pod.
+                Err(err) => trace!(
+                    "Failed to extract owner references for event about {:?}: {}",
+                    resource,
+                    err
+                ),
+            }
+
+            metrics.push(metric);
+        }
+
+        metrics
+    }
+}
+
+impl KubernetesMetrics {
+    pub fn from_event(event: &Event, resource: &ResourceIdentifier) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::new();
+
+        metric.set_resource(ResourceReference::from(resource));
+        metric.set_timestamp(now_timestamp());
+
+        // Attribute the event to the same pod or node identity that the
+        // pod and node metrics are reported with.
+        match resource.gvk.kind.as_str() {
+            "Pod" => {
+                metric.set_pod_name(resource.name.clone());
+                metric.set_pod_namespace(resource.namespace.clone().unwrap_or_default());
+                if let Some(uid) = event.regarding.as_ref().and_then(|r| r.uid.as_ref()) {
+                    metric.set_pod_uuid(uid.clone());
+                }
+            }
+            "Node" => metric.set_node_name(resource.name.clone()),
+            _ => {}
+        }
+
+        let mut kubernetes_event = KubernetesEvent::new();
+        kubernetes_event.set_field_type(event.type_.clone().unwrap_or_default());
+        kubernetes_event.set_reason(event.reason.clone().unwrap_or_default());
+        kubernetes_event.set_action(event.action.clone().unwrap_or_default());
+        kubernetes_event
+            .set_reporting_controller(event.reporting_controller.clone().unwrap_or_default());
+        if let Some(first_timestamp) = first_timestamp(event) {
+            kubernetes_event.set_first_timestamp(first_timestamp.timestamp());
+        }
+        metric.set_event(kubernetes_event);
+
+        metric
+    }
+
+    pub fn is_event(&self) -> bool {
+        self.has_event()
+    }
+}
+
+fn first_timestamp(event: &Event) -> Option<DateTime<Utc>> {
+    event
+        .event_time
+        .as_ref()
+        .map(|time| time.0)
+        .or(event.deprecated_first_timestamp.as_ref().map(|time| time.0))
+        .or(event
+            .metadata
+            .creation_timestamp
+            .as_ref()
+            .map(|time| time.0))
+}
+
+fn last_timestamp(event: &Event) -> Option<DateTime<Utc>> {
+    event
+        .series
+        .as_ref()
+        .map(|series| series.last_observed_time.0)
+        .or(event.deprecated_last_timestamp.as_ref().map(|time| time.0))
+        .or_else(|| first_timestamp(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventStore;
+    use chrono::{DateTime, TimeZone, Utc};
+    use k8s_openapi::api::core::v1::ObjectReference;
+    use k8s_openapi::api::events::v1::{Event, EventSeries};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+
+    fn time(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, minute, 0).unwrap()
+    }
+
+    fn event(uid: &str, type_: &str, reason: &str, pod_name: &str) -> Event {
+        let mut event = Event::default();
+        event.metadata.uid = Some(uid.to_string());
+        event.type_ = Some(type_.to_string());
+        event.reason = Some(reason.to_string());
+        event.note = Some(format!("{} happened", reason));
+        event.event_time = Some(MicroTime(time(5)));
+        event.regarding = Some(ObjectReference {
+            api_version: Some("v1".to_string()),
+            kind: Some("Pod".to_string()),
+            name: Some(pod_name.to_string()),
+            namespace: Some("default".to_string()),
+            uid: Some(format!("{}-uid", pod_name)),
+            ..Default::default()
+        });
+        event
+    }
+
+    #[test]
+    fn records_warning_events_deduplicated_by_object_and_reason() {
+        let mut store = EventStore::new(time(0));
+        store.record(&event("1", "Warning", "BackOff", "web"));
+        store.record(&event("2", "Warning", "BackOff", "web"));
+        store.record(&event("3", "Warning", "FailedMount", "web"));
+        store.record(&event("4", "Warning", "BackOff", "worker"));
+        store.record(&event("5", "Normal", "Pulled", "web"));
+
+        assert_eq!(3, store.pending.len());
+
+        let metrics: Vec<_> = store.pending.into_values().map(|e| e.metric).collect();
+        let back_off = &metrics[0];
+
+        assert!(back_off.is_event());
+        assert!(back_off.is_resource());
+        assert!(!back_off.is_pod());
+        assert!(!back_off.is_node());
+        assert_eq!("web", back_off.pod_name);
+        assert_eq!("default", back_off.pod_namespace);
+        assert_eq!("web-uid", back_off.pod_uuid);
+        assert_eq!("Pod", back_off.get_resource().get_kind());
+        assert_eq!("Warning", back_off.get_event().get_field_type());
+        assert_eq!("BackOff", back_off.get_event().get_reason());
+        assert_eq!("BackOff happened", back_off.get_event().get_note());
+        assert_eq!(2, back_off.get_event().get_count());
+        assert_eq!(
+            time(5).timestamp(),
+            back_off.get_event().get_last_timestamp()
+        );
+    }
+
+    #[test]
+    fn counts_only_new_occurrences_of_repeated_events() {
+        let mut store = EventStore::new(time(0));
+
+        let mut repeated = event("1", "Warning", "BackOff", "web");
+        store.record(&repeated);
+
+        repeated.series = Some(EventSeries {
+            count: 5,
+            last_observed_time: MicroTime(time(9)),
+        });
+        store.record(&repeated);
+        store.record(&repeated);
+
+        let pending = store.pending.into_values().next().unwrap();
+        assert_eq!(5, pending.metric.get_event().get_count());
+        assert_eq!(
+            time(9).timestamp(),
+            pending.metric.get_event().get_last_timestamp()
+        );
+    }
+
+    #[test]
+    fn skips_events_from_before_the_collector_started() {
+        let mut store = EventStore::new(time(30));
+        store.record(&event("1", "Warning", "BackOff", "web"));
+
+        assert!(store.pending.is_empty());
+    }
+}
@@ -0,0 +1,993 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+
+use futures::future::{self, BoxFuture};
+use futures::FutureExt;
+use http::{header, Method, Request, Response, StatusCode};
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use log::{debug, info, warn};
+use protobuf::ProtobufEnum;
+use reqwest::Client;
+use tokio::net::TcpListener;
+
+use crate::protocol::kubernetes::{
+    ConditionStatus, Container, ContainerStatus, KubernetesMetrics, PressureStall,
+};
+use crate::sinks::{Report, Sink};
+use crate::Error;
+
+const PREFIX: &str = "appsignal_kubernetes";
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+// Serves the metrics of the latest run on `/metrics` in the Prometheus text
+// exposition format, for an in-cluster Prometheus to scrape.
+pub struct MetricsEndpoint {
+    exposition: Arc<RwLock<String>>,
+}
+
+impl MetricsEndpoint {
+    pub async fn start(address: SocketAddr) -> Result<Self, Error> {
+        let listener = TcpListener::bind(address).await?;
+        let exposition = Arc::new(RwLock::new(String::new()));
+
+        info!("Serving Prometheus metrics on http://{}/metrics", address);
+
+        let serve_exposition = exposition.clone();
+        tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(err) => {
+                        warn!("Failed to accept metrics connection: {}", err);
+                        continue;
+                    }
+                };
+
+                let exposition = serve_exposition.clone();
+                tokio::spawn(async move {
+                    let service = service_fn(move |request| {
+                        let response = respond(&request, &exposition);
+                        async move { Ok::<_, Infallible>(response) }
+                    });
+
+                    if let Err(err) = http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await
+                    {
+                        debug!("Failed to serve metrics connection: {}", err);
+                    }
+                });
+            }
+        });
+
+        Ok(MetricsEndpoint { exposition })
+    }
+
+    pub fn update(&self, metrics: &[KubernetesMetrics]) {
+        *self.exposition.write().expect("Exposition lock poisoned") = render(metrics);
+    }
+}
+
+// Serves the metrics with their cumulative counters. With leader election,
+// standbys report no metrics, so that they are not served twice. Failed and
+// skipped runs report no metrics either, so that stale metrics are not
+// served.
+impl Sink for MetricsEndpoint {
+    fn name(&self) -> &'static str {
+        "Prometheus"
+    }
+
+    fn deliver<'a>(
+        &'a self,
+        report: &'a Report,
+        _client: &'a Client,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        self.update(&report.metrics);
+        future::ready(Ok(())).boxed()
+    }
+}
+
+fn respond(request: &Request<Incoming>, exposition: &RwLock<String>) -> Response<Full<Bytes>> {
+    let (status, content_type, body) = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/metrics") => (
+            StatusCode::OK,
+            CONTENT_TYPE,
+            exposition.read().expect("Exposition lock poisoned").clone(),
+        ),
+        (_, "/metrics") => (StatusCode::METHOD_NOT_ALLOWED, "text/plain", String::new()),
+        _ => (StatusCode::NOT_FOUND, "text/plain", String::new()),
+    };
+
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static(content_type),
+    );
+    response
+}
+
+type Labels = BTreeMap<String, String>;
+
+struct Family {
+    metric_type: &'static str,
+    samples: Vec<(Labels, f64)>,
+}
+
+// The metric families of an exposition, by name.
+#[derive(Default)]
+struct Exposition {
+    families: BTreeMap<String, Family>,
+}
+
+impl Exposition {
+    fn add(&mut self, metric_type: &'static str, name: &str, labels: &Labels, value: f64) {
+        self.families
+            .entry(format!("{}_{}", PREFIX, name))
+            .or_insert_with(|| Family {
+                metric_type,
+                samples: Vec::new(),
+            })
+            .samples
+            .push((labels.clone(), value));
+    }
+
+    fn gauge(&mut self, name: &str, labels: &Labels, value: f64) {
+        self.add("gauge", name, labels, value);
+    }
+
+    fn counter(&mut self, name: &str, labels: &Labels, value: f64) {
+        self.add("counter", &format!("{}_total", name), labels, value);
+    }
+
+    // A gauge with a value of 1 for the current state, which is given as
+    // a label, and 0 for the other states.
+    fn state<E: ProtobufEnum>(&mut self, name: &str, label: &str, labels: &Labels, current: E) {
+        for &state in E::values() {
+            let mut labels = labels.clone();
+            labels.insert(label.to_string(), enum_name(state));
+            let value = if state.value() == current.value() {
+                1.0
+            } else {
+                0.0
+            };
+            self.gauge(name, &labels, value);
+        }
+    }
+
+    // A condition, with its status as a label. Unknown conditions, which
+    // are not set, are skipped.
+    fn condition(&mut self, name: &str, labels: &Labels, status: ConditionStatus, reason: &str) {
+        if status == ConditionStatus::CONDITION_STATUS_UNKNOWN {
+            return;
+        }
+
+        let mut labels = labels.clone();
+        labels.insert("status".to_string(), enum_name(status));
+        if !reason.is_empty() {
+            labels.insert("reason".to_string(), reason.to_string());
+        }
+        self.gauge(name, &labels, 1.0);
+    }
+
+    fn pressure(&mut self, name: &str, labels: &Labels, pressure: &PressureStall) {
+        for (resource, some, full) in [
+            ("cpu", pressure.cpu_some, pressure.cpu_full),
+            ("memory", pressure.memory_some, pressure.memory_full),
+            ("io", pressure.io_some, pressure.io_full),
+        ] {
+            self.gauge(&format!("{}_{}_some", name, resource), labels, some);
+            self.gauge(&format!("{}_{}_full", name, resource), labels, full);
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut output = String::new();
+
+        for (name, family) in &self.families {
+            let _ = writeln!(output, "# TYPE {} {}", name, family.metric_type);
+
+            for (labels, value) in &family.samples {
+                output.push_str(name);
+
+                if !labels.is_empty() {
+                    let labels: Vec<String> = labels
+                        .iter()
+                        .map(|(key, value)| format!("{}=\"{}\"", key, escape(value)))
+                        .collect();
+                    let _ = write!(output, "{{{}}}", labels.join(","));
+                }
+
+                let _ = writeln!(output, " {}", format_value(*value));
+            }
+        }
+
+        output
+    }
+}
+
+// Render the metrics in the Prometheus text exposition format. Metric names
+// are prefixed with `appsignal_kubernetes_` and the kind of object they are
+// about, such as `appsignal_kubernetes_pod_memory_working_set_bytes`. The
+// labels, annotations and owners of an object are reported as the
+// `label_*`, `annotation_*` and `owner_*` labels of its metrics.
+pub fn render(metrics: &[KubernetesMetrics]) -> String {
+    let mut exposition = Exposition::default();
+
+    for metric in metrics {
+        if metric.has_event() {
+            continue;
+        }
+
+        let mut labels = Labels::new();
+        insert_label(&mut labels, "cluster", &metric.cluster_name);
+
+        if metric.is_resource() {
+            add_resource(&mut exposition, metric, labels);
+        } else if metric.is_volume() {
+            add_volume(&mut exposition, metric, labels);
+        } else if metric.is_pod() {
+            add_pod(&mut exposition, metric, labels);
+        } else {
+            add_node(&mut exposition, metric, labels);
+        }
+    }
+
+    exposition.render()
+}
+
+fn add_node(exposition: &mut Exposition, metric: &KubernetesMetrics, mut labels: Labels) {
+    insert_label(&mut labels, "node", &metric.node_name);
+    insert_object_labels(&mut labels, metric);
+
+    for (name, value) in [
+        ("cpu_usage_nano_cores", metric.cpu_usage_nano_cores as f64),
+        (
+            "memory_available_bytes",
+            metric.memory_available_bytes as f64,
+        ),
+        ("memory_usage_bytes", metric.memory_usage_bytes as f64),
+        (
+            "memory_working_set_bytes",
+            metric.memory_working_set_bytes as f64,
+        ),
+        ("memory_rss_bytes", metric.memory_rss_bytes as f64),
+        ("memory_usage_percent", metric.memory_usage as f64),
+        ("fs_available_bytes", metric.fs_available_bytes as f64),
+        ("fs_capacity_bytes", metric.fs_capacity_bytes as f64),
+        ("fs_used_bytes", metric.fs_used_bytes as f64),
+        ("fs_inodes_free", metric.fs_inodes_free as f64),
+        ("fs_inodes", metric.fs_inodes as f64),
+        ("fs_inodes_used", metric.fs_inodes_used as f64),
+        ("disk_usage_percent", metric.disk_usage as f64),
+        ("rlimit_maxpid", metric.rlimit_maxpid as f64),
+        ("rlimit_curproc", metric.rlimit_curproc as f64),
+        ("swap_usage_bytes", metric.swap_usage_bytes as f64),
+        ("swap_available_bytes", metric.swap_available_bytes as f64),
+        ("swap_usage_percent", metric.swap_usage as f64),
+    ] {
+        exposition.gauge(&format!("node_{}", name), &labels, value);
+    }
+
+    add_counters(exposition, "node", metric, &labels);
+}
+
+fn add_pod(exposition: &mut Exposition, metric: &KubernetesMetrics, mut labels: Labels) {
+    insert_label(&mut labels, "namespace", &metric.pod_namespace);
+    insert_label(&mut labels, "pod", &metric.pod_name);
+    insert_label(&mut labels, "uid", &metric.pod_uuid);
+    insert_label(&mut labels, "node", &metric.node_name);
+    let container_labels = labels.clone();
+    insert_object_labels(&mut labels, metric);
+
+    for (name, value) in [
+        ("cpu_usage_nano_cores", metric.cpu_usage_nano_cores as f64),
+        ("memory_usage_bytes", metric.memory_usage_bytes as f64),
+        (
+            "memory_working_set_bytes",
+            metric.memory_working_set_bytes as f64,
+        ),
+        ("memory_rss_bytes", metric.memory_rss_bytes as f64),
+        (
+            "ephemeral_storage_available_bytes",
+            metric.ephemeral_storage_available_bytes as f64,
+        ),
+        (
+            "ephemeral_storage_capacity_bytes",
+            metric.ephemeral_storage_capacity_bytes as f64,
+        ),
+        (
+            "ephemeral_storage_used_bytes",
+            metric.ephemeral_storage_used_bytes as f64,
+        ),
+        (
+            "ephemeral_storage_inodes_free",
+            metric.ephemeral_storage_inodes_free as f64,
+        ),
+        (
+            "ephemeral_storage_inodes",
+            metric.ephemeral_storage_inodes as f64,
+        ),
+        (
+            "ephemeral_storage_inodes_used",
+            metric.ephemeral_storage_inodes_used as f64,
+        ),
+        ("process_count", metric.process_count as f64),
+        ("swap_usage_bytes", metric.swap_usage_bytes as f64),
+        ("uptime_seconds", metric.pod_uptime_seconds as f64),
+    ] {
+        exposition.gauge(&format!("pod_{}", name), &labels, value);
+    }
+
+    add_counters(exposition, "pod", metric, &labels);
+    exposition.counter("pod_restarts", &labels, metric.pod_restart_count as f64);
+    add_throttling(
+        exposition,
+        "pod",
+        &labels,
+        metric.cpu_cfs_periods,
+        metric.cpu_cfs_throttled_periods,
+        metric.cpu_throttling,
+    );
+    if metric.has_pressure() {
+        exposition.pressure("pod_pressure", &labels, metric.get_pressure());
+    }
+
+    exposition.state("pod_phase", "phase", &labels, metric.get_pod_phase());
+
+    for container in metric.get_containers() {
+        add_container(exposition, container, container_labels.clone());
+    }
+}
+
+fn add_container(exposition: &mut Exposition, container: &Container, mut labels: Labels) {
+    insert_label(&mut labels, "container", &container.name);
+
+    let mut status_labels = labels.clone();
+    status_labels.insert("status".to_string(), enum_name(container.get_status()));
+    insert_label(&mut status_labels, "reason", &container.reason);
+    exposition.gauge("container_status", &status_labels, 1.0);
+
+    if container.get_status() == ContainerStatus::CONTAINER_STATUS_TERMINATED {
+        exposition.gauge("container_exit_code", &labels, container.exit_code as f64);
+    }
+
+    add_throttling(
+        exposition,
+        "container",
+        &labels,
+        container.cpu_cfs_periods,
+        container.cpu_cfs_throttled_periods,
+        container.cpu_throttling,
+    );
+    if container.has_pressure() {
+        exposition.pressure("container_pressure", &labels, container.get_pressure());
+    }
+}
+
+fn add_volume(exposition: &mut Exposition, metric: &KubernetesMetrics, mut labels: Labels) {
+    insert_label(&mut labels, "node", &metric.node_name);
+    insert_label(&mut labels, "namespace", &metric.pod_namespace);
+    insert_label(&mut labels, "pod", &metric.pod_name);
+    insert_label(&mut labels, "volume", &metric.volume_name);
+
+    if metric.has_persistent_volume_claim() {
+        let claim = metric.get_persistent_volume_claim();
+        insert_label(&mut labels, "persistent_volume_claim", &claim.name);
+        insert_label(&mut labels, "storage_class", &claim.storage_class);
+    }
+
+    for (name, value) in [
+        ("available_bytes", metric.fs_available_bytes as f64),
+        ("capacity_bytes", metric.fs_capacity_bytes as f64),
+        ("used_bytes", metric.fs_used_bytes as f64),
+        ("inodes_free", metric.fs_inodes_free as f64),
+        ("inodes", metric.fs_inodes as f64),
+        ("inodes_used", metric.fs_inodes_used as f64),
+        ("usage_percent", metric.disk_usage as f64),
+    ] {
+        exposition.gauge(&format!("volume_{}", name), &labels, value);
+    }
+}
+
+fn add_resource(exposition: &mut Exposition, metric: &KubernetesMetrics, mut labels: Labels) {
+    let resource = metric.get_resource();
+    insert_label(&mut labels, "kind", &resource.kind);
+    insert_label(&mut labels, "namespace", &resource.namespace);
+    insert_label(&mut labels, "name", &resource.name);
+    insert_object_labels(&mut labels, metric);
+
+    if metric.has_workload() {
+        let workload = metric.get_workload();
+        for (name, value) in [
+            ("pods", workload.pods),
+            ("pods_running", workload.pods_running),
+            ("pods_not_ready", workload.pods_not_ready),
+            ("restart_count", workload.restart_count),
+        ] {
+            exposition.gauge(&format!("workload_{}", name), &labels, value as f64);
+        }
+    }
+
+    if metric.has_rollout() {
+        let rollout = metric.get_rollout();
+        for (name, value) in [
+            ("desired_replicas", rollout.desired_replicas as f64),
+            ("updated_replicas", rollout.updated_replicas as f64),
+            ("ready_replicas", rollout.ready_replicas as f64),
+            ("available_replicas", rollout.available_replicas as f64),
+            ("unavailable_replicas", rollout.unavailable_replicas as f64),
+            ("generation", rollout.generation as f64),
+            ("observed_generation", rollout.observed_generation as f64),
+        ] {
+            exposition.gauge(&format!("rollout_{}", name), &labels, value);
+        }
+        exposition.condition(
+            "rollout_progressing",
+            &labels,
+            rollout.progressing,
+            &rollout.progressing_reason,
+        );
+    }
+
+    if metric.has_job() {
+        let job = metric.get_job();
+        for (name, value) in [
+            ("start_time_seconds", job.start_time as f64),
+            ("completion_time_seconds", job.completion_time as f64),
+            ("duration_seconds", job.duration_seconds as f64),
+            ("active", job.active as f64),
+            ("succeeded", job.succeeded as f64),
+            ("failed", job.failed as f64),
+            ("complete", bool_value(job.complete)),
+            (
+                "backoff_limit_reached",
+                bool_value(job.backoff_limit_reached),
+            ),
+        ] {
+            exposition.gauge(&format!("job_{}", name), &labels, value);
+        }
+    }
+
+    if metric.has_cron_job() {
+        let cron_job = metric.get_cron_job();
+        for (name, value) in [
+            (
+                "last_schedule_time_seconds",
+                cron_job.last_schedule_time as f64,
+            ),
+            (
+                "last_successful_time_seconds",
+                cron_job.last_successful_time as f64,
+            ),
+            ("active", cron_job.active as f64),
+            ("suspended", bool_value(cron_job.suspended)),
+            ("overdue", bool_value(cron_job.overdue)),
+        ] {
+            exposition.gauge(&format!("cron_job_{}", name), &labels, value);
+        }
+    }
+
+    if metric.has_persistent_volume_claim() {
+        let claim = metric.get_persistent_volume_claim();
+        let mut labels = labels.clone();
+        insert_label(&mut labels, "storage_class", &claim.storage_class);
+        insert_label(&mut labels, "volume", &claim.volume_name);
+
+        exposition.gauge(
+            "persistent_volume_claim_requested_bytes",
+            &labels,
+            claim.requested_bytes as f64,
+        );
+        exposition.gauge(
+            "persistent_volume_claim_capacity_bytes",
+            &labels,
+            claim.capacity_bytes as f64,
+        );
+        exposition.state(
+            "persistent_volume_claim_phase",
+            "phase",
+            &labels,
+            claim.get_phase(),
+        );
+    }
+
+    if metric.has_persistent_volume() {
+        let volume = metric.get_persistent_volume();
+        let mut labels = labels.clone();
+        insert_label(&mut labels, "storage_class", &volume.storage_class);
+        insert_label(&mut labels, "reclaim_policy", &volume.reclaim_policy);
+        insert_label(&mut labels, "claim_namespace", &volume.claim_namespace);
+        insert_label(&mut labels, "claim_name", &volume.claim_name);
+
+        exposition.gauge(
+            "persistent_volume_capacity_bytes",
+            &labels,
+            volume.capacity_bytes as f64,
+        );
+        exposition.state(
+            "persistent_volume_phase",
+            "phase",
+            &labels,
+            volume.get_phase(),
+        );
+    }
+
+    if metric.has_horizontal_pod_autoscaler() {
+        let autoscaler = metric.get_horizontal_pod_autoscaler();
+        for (name, value) in [
+            ("current_replicas", autoscaler.current_replicas),
+            ("desired_replicas", autoscaler.desired_replicas),
+            ("min_replicas", autoscaler.min_replicas),
+            ("max_replicas", autoscaler.max_replicas),
+        ] {
+            exposition.gauge(
+                &format!("horizontal_pod_autoscaler_{}", name),
+                &labels,
+                value as f64,
+            );
+        }
+        for (name, status, reason) in [
+            ("able_to_scale", autoscaler.able_to_scale, ""),
+            ("scaling_active", autoscaler.scaling_active, ""),
+            (
+                "scaling_limited",
+                autoscaler.scaling_limited,
+                autoscaler.scaling_limited_reason.as_str(),
+            ),
+        ] {
+            exposition.condition(
+                &format!("horizontal_pod_autoscaler_{}", name),
+                &labels,
+                status,
+                reason,
+            );
+        }
+        for autoscaler_metric in autoscaler.get_metrics() {
+            let mut labels = labels.clone();
+            insert_label(&mut labels, "metric_type", &autoscaler_metric.field_type);
+            insert_label(&mut labels, "metric_name", &autoscaler_metric.name);
+            insert_label(&mut labels, "container", &autoscaler_metric.container);
+            insert_label(&mut labels, "target_type", &autoscaler_metric.target_type);
+
+            exposition.gauge(
+                "horizontal_pod_autoscaler_metric_target_value",
+                &labels,
+                autoscaler_metric.target_value,
+            );
+            exposition.gauge(
+                "horizontal_pod_autoscaler_metric_current_value",
+                &labels,
+                autoscaler_metric.current_value,
+            );
+        }
+    }
+
+    if metric.has_resource_quota() {
+        let quota = metric.get_resource_quota();
+        exposition.gauge(
+            "resource_quota_max_usage_percent",
+            &labels,
+            quota.max_usage as f64,
+        );
+        for quota_resource in quota.get_resources() {
+            let mut labels = labels.clone();
+            insert_label(&mut labels, "resource", &quota_resource.name);
+
+            exposition.gauge("resource_quota_hard", &labels, quota_resource.hard);
+            exposition.gauge("resource_quota_used", &labels, quota_resource.used);
+            exposition.gauge(
+                "resource_quota_usage_percent",
+                &labels,
+                quota_resource.usage as f64,
+            );
+        }
+    }
+
+    if metric.has_limit_range() {
+        for limit in metric.get_limit_range().get_limits() {
+            let mut labels = labels.clone();
+            insert_label(&mut labels, "type", &limit.field_type);
+            insert_label(&mut labels, "resource", &limit.resource);
+
+            for (name, value) in [
+                ("min", limit.min),
+                ("max", limit.max),
+                ("default_limit", limit.default_limit),
+                ("default_request", limit.default_request),
+                ("max_limit_request_ratio", limit.max_limit_request_ratio),
+            ] {
+                exposition.gauge(&format!("limit_range_{}", name), &labels, value);
+            }
+        }
+    }
+
+    if metric.has_service() {
+        let service = metric.get_service();
+        let mut labels = labels.clone();
+        insert_label(&mut labels, "type", &service.field_type);
+
+        for (name, value) in [
+            ("ready_endpoints", service.ready_endpoints as f64),
+            ("not_ready_endpoints", service.not_ready_endpoints as f64),
+            (
+                "terminating_endpoints",
+                service.terminating_endpoints as f64,
+            ),
+            ("has_selector", bool_value(service.has_selector)),
+            ("no_ready_pods", bool_value(service.no_ready_pods)),
+        ] {
+            exposition.gauge(&format!("service_{}", name), &labels, value);
+        }
+    }
+
+    if metric.has_route() {
+        let route = metric.get_route();
+        exposition.gauge(
+            "route_backends_without_ready_endpoints",
+            &labels,
+            route.backends_without_ready_endpoints as f64,
+        );
+        for backend in route.get_backends() {
+            let mut labels = labels.clone();
+            insert_label(&mut labels, "service", &backend.get_service().name);
+            insert_label(&mut labels, "port", &backend.port.to_string());
+
+            exposition.gauge(
+                "route_backend_service_missing",
+                &labels,
+                bool_value(backend.service_missing),
+            );
+            exposition.gauge(
+                "route_backend_no_ready_endpoints",
+                &labels,
+                bool_value(backend.no_ready_endpoints),
+            );
+        }
+    }
+
+    if metric.has_custom_resource() {
+        let custom_resource = metric.get_custom_resource();
+        let mut labels = labels.clone();
+        insert_label(&mut labels, "api_version", &custom_resource.api_version);
+
+        for field in custom_resource.get_fields() {
+            if !field.string_value.is_empty() {
+                continue;
+            }
+
+            let mut labels = labels.clone();
+            insert_label(&mut labels, "field", &field.name);
+            exposition.gauge("custom_resource_field", &labels, field.value);
+        }
+        for condition in custom_resource.get_conditions() {
+            let mut labels = labels.clone();
+            insert_label(&mut labels, "type", &condition.field_type);
+            exposition.condition(
+                "custom_resource_condition",
+                &labels,
+                condition.status,
+                &condition.reason,
+            );
+        }
+    }
+
+    if metric.has_certificate() {
+        let certificate = metric.get_certificate();
+        let mut labels = labels.clone();
+        insert_label(&mut labels, "common_name", &certificate.common_name);
+        insert_label(&mut labels, "issuer", &certificate.issuer);
+        insert_label(&mut labels, "secret_name", &certificate.secret_name);
+
+        exposition.gauge(
+            "certificate_not_after_seconds",
+            &labels,
+            certificate.not_after as f64,
+        );
+        exposition.gauge(
+            "certificate_days_until_expiry",
+            &labels,
+            certificate.days_until_expiry as f64,
+        );
+        exposition.condition("certificate_ready", &labels, certificate.ready, "");
+    }
+}
+
+// The cumulative CPU, page fault and network counters of nodes and pods.
+fn add_counters(
+    exposition: &mut Exposition,
+    kind: &str,
+    metric: &KubernetesMetrics,
+    labels: &Labels,
+) {
+    for (name, value) in [
+        (
+            "cpu_usage_core_nano_seconds",
+            metric.cpu_usage_core_nano_seconds as f64,
+        ),
+        ("memory_page_faults", metric.memory_page_faults as f64),
+        (
+            "memory_major_page_faults",
+            metric.memory_major_page_faults as f64,
+        ),
+        ("network_rx_bytes", metric.network_rx_bytes as f64),
+        ("network_rx_errors", metric.network_rx_errors as f64),
+        ("network_tx_bytes", metric.network_tx_bytes as f64),
+        ("network_tx_errors", metric.network_tx_errors as f64),
+    ] {
+        exposition.counter(&format!("{}_{}", kind, name), labels, value);
+    }
+}
+
+// The CPU throttling of the last minute, when cAdvisor is scraped.
+fn add_throttling(
+    exposition: &mut Exposition,
+    kind: &str,
+    labels: &Labels,
+    periods: i64,
+    throttled_periods: i64,
+    throttling: i32,
+) {
+    if periods == 0 {
+        return;
+    }
+
+    exposition.gauge(&format!("{}_cpu_cfs_periods", kind), labels, periods as f64);
+    exposition.gauge(
+        &format!("{}_cpu_cfs_throttled_periods", kind),
+        labels,
+        throttled_periods as f64,
+    );
+    exposition.gauge(
+        &format!("{}_cpu_throttling_percent", kind),
+        labels,
+        throttling as f64,
+    );
+}
+
+// The owners, labels and annotations of the object a metric is about.
+fn insert_object_labels(labels: &mut Labels, metric: &KubernetesMetrics) {
+    for owner_reference in metric.get_owner_references() {
+        labels.insert(
+            label_name("owner_", &owner_reference.kind.to_lowercase()),
+            owner_reference.name.clone(),
+        );
+    }
+
+    insert_prefixed_labels(labels, "label_", &metric.labels);
+    insert_prefixed_labels(labels, "annotation_", &metric.annotations);
+}
+
+// Insert the labels or annotations with their keys as label names. Keys
+// that only differ in the characters that are replaced in label names, such
+// as `app.kubernetes.io/name` and `app_kubernetes_io_name`, have the same
+// label name. Only the first of those keys, in sorted order, is inserted.
+fn insert_prefixed_labels(
+    labels: &mut Labels,
+    prefix: &str,
+    pairs: &std::collections::HashMap<String, String>,
+) {
+    let mut pairs: Vec<_> = pairs.iter().collect();
+    pairs.sort();
+
+    for (key, value) in pairs {
+        match labels.entry(label_name(prefix, key)) {
+            Entry::Vacant(entry) => {
+                entry.insert(value.clone());
+            }
+            Entry::Occupied(entry) => {
+                debug!(
+                    "Skipping `{}`, as its label name `{}` is already used",
+                    key,
+                    entry.key()
+                );
+            }
+        }
+    }
+}
+
+fn insert_label(labels: &mut Labels, name: &str, value: &str) {
+    if !value.is_empty() {
+        labels.insert(name.to_string(), value.to_string());
+    }
+}
+
+// A valid Prometheus label name for the key with the prefix, with every
+// character other than ASCII letters, digits and underscores replaced by an
+// underscore. Label names cannot start with a digit, so those are prefixed
+// with an underscore.
+fn label_name(prefix: &str, key: &str) -> String {
+    let mut name: String = prefix
+        .chars()
+        .chain(key.chars())
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+// The name of an enum value without the prefix of its type, such as
+// `running` for `POD_PHASE_RUNNING`.
+pub(crate) fn enum_name<E: ProtobufEnum>(value: E) -> String {
+    let name = value.descriptor().name();
+    let type_name = E::enum_descriptor_static().name();
+
+    let mut prefix = String::new();
+    for (index, c) in type_name.chars().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            prefix.push('_');
+        }
+        prefix.push(c.to_ascii_uppercase());
+    }
+    prefix.push('_');
+
+    name.strip_prefix(&prefix).unwrap_or(name).to_lowercase()
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn bool_value(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{label_name, render};
+    use crate::protocol::kubernetes::{
+        ConditionStatus, Container, ContainerStatus, KubernetesMetrics, OwnerReference, PodPhase,
+        ResourceReference,
+    };
+
+    #[test]
+    fn render_colliding_label_names_once() {
+        let mut pod = KubernetesMetrics::new();
+        pod.set_pod_name("web-1".to_string());
+        pod.set_pod_uuid("uid-1".to_string());
+        pod.set_pod_phase(PodPhase::POD_PHASE_RUNNING);
+        for (key, value) in [
+            ("app_kubernetes_io_name", "underscores"),
+            ("app.kubernetes.io/name", "dots"),
+        ] {
+            pod.mut_labels().insert(key.to_string(), value.to_string());
+        }
+
+        let exposition = render(&[pod]);
+
+        assert!(exposition.contains(r#"label_app_kubernetes_io_name="dots""#));
+        assert!(!exposition.contains("underscores"));
+    }
+
+    #[test]
+    fn valid_label_names() {
+        assert_eq!(
+            "label_app_kubernetes_io_name",
+            label_name("label_", "app.kubernetes.io/name")
+        );
+        assert_eq!("label_8080", label_name("label_", "8080"));
+        assert_eq!("_8080", label_name("", "8080"));
+        assert_eq!("_", label_name("", ""));
+    }
+
+    #[test]
+    fn render_pod_metrics() {
+        let mut pod = KubernetesMetrics::new();
+        pod.set_cluster_name("production".to_string());
+        pod.set_node_name("node-1".to_string());
+        pod.set_pod_name("web-1".to_string());
+        pod.set_pod_namespace("shop".to_string());
+        pod.set_pod_uuid("uid-1".to_string());
+        pod.set_pod_phase(PodPhase::POD_PHASE_RUNNING);
+        pod.set_memory_working_set_bytes(1048576);
+        pod.set_network_rx_bytes(2048);
+        pod.set_pod_restart_count(3);
+        pod.mut_labels()
+            .insert("app.kubernetes.io/name".to_string(), "web".to_string());
+        pod.mut_annotations()
+            .insert("team".to_string(), "say \"hi\"".to_string());
+
+        let mut owner_reference = OwnerReference::new();
+        owner_reference.set_kind("Deployment".to_string());
+        owner_reference.set_name("web".to_string());
+        pod.mut_owner_references().push(owner_reference);
+
+        let mut container = Container::new();
+        container.set_name("web".to_string());
+        container.set_status(ContainerStatus::CONTAINER_STATUS_WAITING);
+        container.set_reason("CrashLoopBackOff".to_string());
+        pod.mut_containers().push(container);
+
+        let exposition = render(&[pod]);
+        let labels = r#"annotation_team="say \"hi\"",cluster="production",label_app_kubernetes_io_name="web",namespace="shop",node="node-1",owner_deployment="web",pod="web-1",uid="uid-1""#;
+
+        assert!(exposition.contains(&format!(
+            "# TYPE appsignal_kubernetes_pod_memory_working_set_bytes gauge\nappsignal_kubernetes_pod_memory_working_set_bytes{{{}}} 1048576\n",
+            labels
+        )));
+        assert!(exposition.contains(&format!(
+            "# TYPE appsignal_kubernetes_pod_network_rx_bytes_total counter\nappsignal_kubernetes_pod_network_rx_bytes_total{{{}}} 2048\n",
+            labels
+        )));
+        assert!(exposition.contains(&format!(
+            "# TYPE appsignal_kubernetes_pod_restarts_total counter\nappsignal_kubernetes_pod_restarts_total{{{}}} 3\n",
+            labels
+        )));
+        assert!(exposition.contains(
+            r#"appsignal_kubernetes_pod_phase{annotation_team="say \"hi\"",cluster="production",label_app_kubernetes_io_name="web",namespace="shop",node="node-1",owner_deployment="web",phase="running",pod="web-1",uid="uid-1"} 1"#
+        ));
+        assert!(exposition.contains(
+            r#"appsignal_kubernetes_pod_phase{annotation_team="say \"hi\"",cluster="production",label_app_kubernetes_io_name="web",namespace="shop",node="node-1",owner_deployment="web",phase="failed",pod="web-1",uid="uid-1"} 0"#
+        ));
+        assert!(exposition.contains(
+            r#"appsignal_kubernetes_container_status{cluster="production",container="web",namespace="shop",node="node-1",pod="web-1",reason="CrashLoopBackOff",status="waiting",uid="uid-1"} 1"#
+        ));
+        assert!(!exposition.contains("appsignal_kubernetes_pod_cpu_cfs_periods"));
+    }
+
+    #[test]
+    fn render_resource_metrics() {
+        let mut reference = ResourceReference::new();
+        reference.set_kind("Deployment".to_string());
+        reference.set_namespace("shop".to_string());
+        reference.set_name("web".to_string());
+
+        let mut deployment = KubernetesMetrics::new();
+        deployment.set_resource(reference);
+        deployment.mut_rollout().set_ready_replicas(2);
+        deployment
+            .mut_rollout()
+            .set_progressing(ConditionStatus::CONDITION_STATUS_FALSE);
+        deployment
+            .mut_rollout()
+            .set_progressing_reason("ProgressDeadlineExceeded".to_string());
+
+        let mut event = deployment.clone();
+        event.mut_event().set_reason("BackOff".to_string());
+
+        let exposition = render(&[deployment, event]);
+
+        assert_eq!(
+            "# TYPE appsignal_kubernetes_rollout_ready_replicas gauge\n\
+             appsignal_kubernetes_rollout_ready_replicas{kind=\"Deployment\",name=\"web\",namespace=\"shop\"} 2\n",
+            exposition
+                .split_inclusive('\n')
+                .skip_while(|line| !line.contains("rollout_ready_replicas"))
+                .take(2)
+                .collect::<String>()
+        );
+        assert!(exposition.contains(
+            "appsignal_kubernetes_rollout_progressing{kind=\"Deployment\",name=\"web\",namespace=\"shop\",reason=\"ProgressDeadlineExceeded\",status=\"false\"} 1\n"
+        ));
+        assert!(!exposition.contains("BackOff"));
+    }
+}
@@ -0,0 +1,250 @@
+use std::env;
+
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{ListParams, ObjectList};
+
+use crate::protocol::kubernetes::KubernetesMetrics;
+
+// Filters the namespaces and pods that metrics are reported for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    // Glob patterns of the namespaces to report. All namespaces are
+    // reported when empty.
+    pub include_namespaces: Vec<String>,
+    // Glob patterns of the namespaces not to report.
+    pub exclude_namespaces: Vec<String>,
+    // A Kubernetes label selector for the pods to report.
+    pub pod_label_selector: Option<String>,
+}
+
+impl Filter {
+    pub fn from_env() -> Filter {
+        Filter {
+            include_namespaces: list_from_env("APPSIGNAL_INCLUDE_NAMESPACES"),
+            exclude_namespaces: list_from_env("APPSIGNAL_EXCLUDE_NAMESPACES"),
+            pod_label_selector: env::var("APPSIGNAL_POD_LABEL_SELECTOR")
+                .ok()
+                .map(|selector| selector.trim().to_string())
+                .filter(|selector| !selector.is_empty()),
+        }
+    }
+
+    pub fn includes_namespace(&self, namespace: &str) -> bool {
+        (self.include_namespaces.is_empty()
+            || self
+                .include_namespaces
+                .iter()
+                .any(|pattern| glob_match(pattern, namespace)))
+            && !self
+                .exclude_namespaces
+                .iter()
+                .any(|pattern| glob_match(pattern, namespace))
+    }
+
+    // The parameters to list namespaced objects with, filtering them by
+    // namespace server-side where a field selector allows it. Namespaces
+    // matched by glob patterns are filtered with `includes_namespace`.
+    pub fn list_params(&self) -> ListParams {
+        list_params(self.field_selectors())
+    }
+
+    // The parameters to list pods with, filtering them by namespace and by
+    // the pod label selector server-side. When a node name is given, only
+    // the pods on that node are listed.
+    pub fn pod_list_params(&self, node_name: Option<&str>) -> ListParams {
+        let mut field_selectors = self.field_selectors();
+        if let Some(node_name) = node_name {
+            field_selectors.push(format!("spec.nodeName={}", node_name));
+        }
+
+        let list_params = list_params(field_selectors);
+
+        match &self.pod_label_selector {
+            Some(selector) => list_params.labels(selector),
+            None => list_params,
+        }
+    }
+
+    fn field_selectors(&self) -> Vec<String> {
+        let mut field_selectors: Vec<String> = self
+            .exclude_namespaces
+            .iter()
+            .filter(|pattern| !is_glob(pattern))
+            .map(|namespace| format!("metadata.namespace!={}", namespace))
+            .collect();
+
+        // Field selectors cannot match one of several values, so only a
+        // single included namespace can be selected server-side.
+        if let [namespace] = self.include_namespaces.as_slice() {
+            if !is_glob(namespace) {
+                field_selectors.push(format!("metadata.namespace={}", namespace));
+            }
+        }
+
+        field_selectors
+    }
+
+    // Whether to report the kubelet stats of a pod, as identified by the
+    // `podRef` in the kubelet's summary. When a pod label selector is set,
+    // only the stats of the pods that were listed with it are reported.
+    pub fn includes_stats_pod(&self, pod_ref: &serde_json::Value, pods: &ObjectList<Pod>) -> bool {
+        if !self.includes_namespace(pod_ref["namespace"].as_str().unwrap_or_default()) {
+            return false;
+        }
+
+        if self.pod_label_selector.is_none() {
+            return true;
+        }
+
+        let uid = pod_ref["uid"].as_str();
+        pods.iter().any(|pod| pod.metadata.uid.as_deref() == uid)
+    }
+
+    // Whether to report a metric, based on the namespace of the pod or
+    // resource it is about. Metrics that are not about a namespaced object,
+    // such as node metrics, are always reported.
+    pub fn includes_metric(&self, metric: &KubernetesMetrics) -> bool {
+        let namespace = if metric.is_resource() {
+            metric.get_resource().get_namespace()
+        } else {
+            metric.get_pod_namespace()
+        };
+
+        namespace.is_empty() || self.includes_namespace(namespace)
+    }
+}
+
+fn list_params(field_selectors: Vec<String>) -> ListParams {
+    let list_params = ListParams::default();
+
+    if field_selectors.is_empty() {
+        list_params
+    } else {
+        list_params.fields(&field_selectors.join(","))
+    }
+}
+
+pub(crate) fn list_from_env(key: &str) -> Vec<String> {
+    env::var(key)
+        .unwrap_or_default()
+        .split(',')
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+// Match a value against a glob pattern, in which `*` matches any number of
+// characters and `?` matches a single character.
+pub(crate) fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+
+    let (mut p, mut v) = (0, 0);
+    // The position of the last `*` in the pattern, and the position in the
+    // value it was matched from, to backtrack to on a mismatch.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if let Some((star_p, star_v)) = backtrack {
+            p = star_p + 1;
+            v = star_v + 1;
+            backtrack = Some((star_p, star_v + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob_match, Filter};
+    use crate::protocol::kubernetes::{KubernetesMetrics, ResourceReference};
+
+    fn filter(include: &[&str], exclude: &[&str]) -> Filter {
+        Filter {
+            include_namespaces: include.iter().map(|s| s.to_string()).collect(),
+            exclude_namespaces: exclude.iter().map(|s| s.to_string()).collect(),
+            pod_label_selector: None,
+        }
+    }
+
+    #[test]
+    fn match_globs() {
+        assert!(glob_match("kube-system", "kube-system"));
+        assert!(glob_match("kube-*", "kube-public"));
+        assert!(glob_match("*-staging", "shop-staging"));
+        assert!(glob_match("team-?", "team-a"));
+        assert!(glob_match("*a*b*", "xaybzb"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("team-?", "team-ab"));
+        assert!(!glob_match("kube-*", "default"));
+        assert!(!glob_match("kube-system", "kube-system2"));
+    }
+
+    #[test]
+    fn include_and_exclude_namespaces() {
+        let all = filter(&[], &[]);
+        assert!(all.includes_namespace("kube-system"));
+
+        let filter = filter(&["team-*", "default"], &["team-internal", "kube-*"]);
+        assert!(filter.includes_namespace("team-a"));
+        assert!(filter.includes_namespace("default"));
+        assert!(!filter.includes_namespace("team-internal"));
+        assert!(!filter.includes_namespace("kube-system"));
+        assert!(!filter.includes_namespace("monitoring"));
+    }
+
+    #[test]
+    fn list_params_with_field_and_label_selectors() {
+        let mut filter = filter(&["shop"], &["kube-system", "kube-*"]);
+        filter.pod_label_selector = Some("app=web".to_string());
+
+        let list_params = filter.pod_list_params(None);
+        assert_eq!(
+            Some("metadata.namespace!=kube-system,metadata.namespace=shop".to_string()),
+            list_params.field_selector
+        );
+        assert_eq!(Some("app=web".to_string()), list_params.label_selector);
+
+        let list_params = filter.pod_list_params(Some("node-1"));
+        assert_eq!(
+            Some(
+                "metadata.namespace!=kube-system,metadata.namespace=shop,spec.nodeName=node-1"
+                    .to_string()
+            ),
+            list_params.field_selector
+        );
+
+        let list_params = super::Filter::default().list_params();
+        assert_eq!(None, list_params.field_selector);
+    }
+
+    #[test]
+    fn includes_metrics_by_namespace() {
+        let filter = filter(&[], &["kube-system"]);
+
+        let mut pod = KubernetesMetrics::new();
+        pod.set_pod_namespace("kube-system".to_string());
+        assert!(!filter.includes_metric(&pod));
+
+        let mut reference = ResourceReference::new();
+        reference.set_namespace("default".to_string());
+        assert!(filter.includes_metric(&KubernetesMetrics::from_resource(reference)));
+
+        let mut node = KubernetesMetrics::new();
+        node.set_node_name("node-1".to_string());
+        assert!(filter.includes_metric(&node));
+    }
+}
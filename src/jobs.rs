@@ -0,0 +1,491 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use kube::api::ListParams;
+use kube::Api;
+use log::{trace, warn};
+
+use crate::ownership::{OwnershipResolver, ResourceIdentifier};
+use crate::protocol::kubernetes::{CronJobStatus, JobStatus, OwnerReference};
+use crate::workloads::WorkloadAggregator;
+use crate::Error;
+
+// The time after its scheduled time that a CronJob run is considered
+// overdue, unless the CronJob sets a starting deadline.
+const DEFAULT_STARTING_DEADLINE_SECONDS: i64 = 60;
+
+// Extract the execution status of every Job and CronJob in the cluster,
+// and add it to its workload metric.
+pub async fn extract_jobs(
+    client: &kube::Client,
+    resolver: &mut OwnershipResolver,
+    workloads: &mut WorkloadAggregator,
+) -> Result<(), Error> {
+    let now = Utc::now();
+
+    let jobs: Api<Job> = Api::all(client.clone());
+    for job in jobs.list(&ListParams::default()).await? {
+        let resource = ResourceIdentifier::from_object(&job);
+
+        // Report the top-level owners of the Job, such as its CronJob,
+        // except for the Job itself when it is not owned by anything.
+        let owner_references = match resolver.resolve_top_level_owners(&resource).await {
+            Ok(owners) => owners
+                .into_iter()
+                .filter(|owner| owner != &resource)
+                .map(OwnerReference::from)
+                .collect(),
+            Err(err) => {
+                warn!(
+                    "Failed to extract owner references for job {}: {}",
+                    resource.name, err
+                );
+                Vec::new()
+            }
+        };
+
+        let metric = workloads.resource(&resource);
+        metric.set_job(JobStatus::from_job(&job, now));
+        metric.set_owner_references(owner_references.into());
+    }
+
+    let cron_jobs: Api<CronJob> = Api::all(client.clone());
+    for cron_job in cron_jobs.list(&ListParams::default()).await? {
+        workloads
+            .resource(&ResourceIdentifier::from_object(&cron_job))
+            .set_cron_job(CronJobStatus::from_cron_job(&cron_job, now));
+    }
+
+    Ok(())
+}
+
+impl JobStatus {
+    pub fn from_job(job: &Job, now: DateTime<Utc>) -> JobStatus {
+        let mut job_status = JobStatus::new();
+
+        let status = match &job.status {
+            Some(status) => status,
+            None => return job_status,
+        };
+
+        job_status.set_active(status.active.unwrap_or_default());
+        job_status.set_succeeded(status.succeeded.unwrap_or_default());
+        job_status.set_failed(status.failed.unwrap_or_default());
+
+        // Failed Jobs have no completion time. They ended when they failed.
+        let mut failed_time = None;
+
+        for condition in status.conditions.iter().flatten() {
+            if condition.status != "True" {
+                continue;
+            }
+
+            match condition.type_.as_str() {
+                "Complete" => job_status.set_complete(true),
+                "Failed" => {
+                    if condition.reason.as_deref() == Some("BackoffLimitExceeded") {
+                        job_status.set_backoff_limit_reached(true);
+                    }
+                    failed_time = condition.last_transition_time.as_ref().map(|time| time.0);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(start_time) = &status.start_time {
+            job_status.set_start_time(start_time.0.timestamp());
+
+            // The duration of a Job that has not completed or failed yet is
+            // the time it has been running for so far.
+            let end_time = match &status.completion_time {
+                Some(completion_time) => {
+                    job_status.set_completion_time(completion_time.0.timestamp());
+                    completion_time.0
+                }
+                None => failed_time.unwrap_or(now),
+            };
+
+            job_status.set_duration_seconds(
+                end_time
+                    .signed_duration_since(start_time.0)
+                    .num_seconds()
+                    .max(0),
+            );
+        }
+
+        job_status
+    }
+}
+
+impl CronJobStatus {
+    pub fn from_cron_job(cron_job: &CronJob, now: DateTime<Utc>) -> CronJobStatus {
+        let mut cron_job_status = CronJobStatus::new();
+
+        if let Some(status) = &cron_job.status {
+            if let Some(last_schedule_time) = &status.last_schedule_time {
+                cron_job_status.set_last_schedule_time(last_schedule_time.0.timestamp());
+            }
+
+            if let Some(last_successful_time) = &status.last_successful_time {
+                cron_job_status.set_last_successful_time(last_successful_time.0.timestamp());
+            }
+
+            cron_job_status
+                .set_active(status.active.as_ref().map_or(0, |active| active.len()) as i32);
+        }
+
+        if let Some(spec) = &cron_job.spec {
+            cron_job_status.set_suspended(spec.suspend.unwrap_or(false));
+        }
+
+        cron_job_status.set_overdue(is_overdue(cron_job, now));
+
+        cron_job_status
+    }
+}
+
+// A CronJob is overdue when its next run after the last scheduled run
+// (or after its creation, if it never ran) should have started by now.
+fn is_overdue(cron_job: &CronJob, now: DateTime<Utc>) -> bool {
+    let spec = match &cron_job.spec {
+        Some(spec) if !spec.suspend.unwrap_or(false) => spec,
+        _ => return false,
+    };
+
+    // Schedules are evaluated in UTC. CronJobs that are scheduled in a
+    // different time zone are never considered overdue.
+    if let Some(time_zone) = &spec.time_zone {
+        if time_zone != "UTC" && time_zone != "Etc/UTC" {
+            trace!(
+                "Skipping overdue check for CronJob {:?} in time zone {}",
+                cron_job.metadata.name,
+                time_zone
+            );
+            return false;
+        }
+    }
+
+    let schedule = match parse_schedule(&spec.schedule) {
+        Some(schedule) => schedule,
+        None => {
+            warn!(
+                "Could not parse schedule {:?} of CronJob {:?}",
+                spec.schedule, cron_job.metadata.name
+            );
+            return false;
+        }
+    };
+
+    let last_run = cron_job
+        .status
+        .as_ref()
+        .and_then(|status| status.last_schedule_time.as_ref())
+        .or(cron_job.metadata.creation_timestamp.as_ref());
+
+    let next_run = match last_run.and_then(|last_run| schedule.next_after(&last_run.0)) {
+        Some(next_run) => next_run,
+        None => return false,
+    };
+
+    let starting_deadline_seconds = spec
+        .starting_deadline_seconds
+        .unwrap_or(DEFAULT_STARTING_DEADLINE_SECONDS);
+
+    now.timestamp() > next_run.timestamp() + starting_deadline_seconds
+}
+
+const DAYS_OF_WEEK: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+// A Kubernetes CronJob schedule. Like in cron, a schedule that restricts
+// both the day of the month and the day of the week runs on the days that
+// match either of them, so it is evaluated as one schedule for each.
+struct Schedule(Vec<cron::Schedule>);
+
+impl Schedule {
+    fn next_after(&self, time: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.0
+            .iter()
+            .filter_map(|schedule| schedule.after(time).next())
+            .min()
+    }
+}
+
+// Parse a Kubernetes CronJob schedule, which uses the standard five-field
+// cron format, into schedules with a leading seconds field.
+fn parse_schedule(schedule: &str) -> Option<Schedule> {
+    let schedule = schedule.trim();
+
+    if schedule.starts_with('@') {
+        return cron::Schedule::from_str(schedule)
+            .ok()
+            .map(|schedule| Schedule(vec![schedule]));
+    }
+
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+        return None;
+    };
+    let day_of_month = if is_wildcard(day_of_month) {
+        "*"
+    } else {
+        day_of_month
+    };
+    let day_of_week = parse_days_of_week(day_of_week)?;
+
+    let parse = |day_of_month: &str, day_of_week: &str| {
+        cron::Schedule::from_str(&format!(
+            "0 {} {} {} {} {}",
+            minute, hour, day_of_month, month, day_of_week
+        ))
+        .ok()
+    };
+
+    if day_of_month == "*" || day_of_week == "*" {
+        Some(Schedule(vec![parse(day_of_month, &day_of_week)?]))
+    } else {
+        Some(Schedule(vec![
+            parse(day_of_month, "*")?,
+            parse("*", &day_of_week)?,
+        ]))
+    }
+}
+
+fn is_wildcard(field: &str) -> bool {
+    field == "*" || field == "?"
+}
+
+// Translate the day of the week field, in which cron numbers the days from
+// Sunday as 0 (or 7) to Saturday as 6, to a list of day names, as the cron
+// crate numbers the days from Sunday as 1.
+fn parse_days_of_week(field: &str) -> Option<String> {
+    if is_wildcard(field) {
+        return Some("*".to_string());
+    }
+
+    let mut days = [false; 7];
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse().ok().filter(|step| *step > 0)?),
+            None => (item, 1),
+        };
+
+        let (start, end) = if is_wildcard(range) {
+            (0, 6)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_day_of_week(start)?, parse_day_of_week(end)?)
+        } else if step > 1 {
+            (parse_day_of_week(range)?, 6)
+        } else {
+            let day = parse_day_of_week(range)?;
+            (day, day)
+        };
+
+        if start > end {
+            return None;
+        }
+
+        for day in (start..=end).step_by(step) {
+            days[day % 7] = true;
+        }
+    }
+
+    Some(
+        DAYS_OF_WEEK
+            .iter()
+            .zip(days)
+            .filter(|(_, included)| *included)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+fn parse_day_of_week(value: &str) -> Option<usize> {
+    match value.parse::<usize>() {
+        Ok(day) => (day <= 7).then_some(day),
+        Err(_) => DAYS_OF_WEEK
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_schedule;
+    use crate::protocol::kubernetes::{CronJobStatus, JobStatus};
+    use chrono::{DateTime, TimeZone, Utc};
+    use k8s_openapi::api::batch::v1::{
+        CronJob, CronJobSpec, CronJobStatus as KubernetesCronJobStatus, Job, JobCondition,
+        JobStatus as KubernetesJobStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    fn time(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    fn cron_job(schedule: &str, last_schedule_time: DateTime<Utc>) -> CronJob {
+        CronJob {
+            spec: Some(CronJobSpec {
+                schedule: schedule.to_string(),
+                ..Default::default()
+            }),
+            status: Some(KubernetesCronJobStatus {
+                last_schedule_time: Some(Time(last_schedule_time)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn job_status_from_completed_job() {
+        let job = Job {
+            status: Some(KubernetesJobStatus {
+                start_time: Some(Time(time(10, 0))),
+                completion_time: Some(Time(time(10, 5))),
+                succeeded: Some(1),
+                conditions: Some(vec![JobCondition {
+                    type_: "Complete".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let job_status = JobStatus::from_job(&job, time(11, 0));
+
+        assert_eq!(time(10, 0).timestamp(), job_status.get_start_time());
+        assert_eq!(time(10, 5).timestamp(), job_status.get_completion_time());
+        assert_eq!(300, job_status.get_duration_seconds());
+        assert_eq!(1, job_status.get_succeeded());
+        assert_eq!(0, job_status.get_failed());
+        assert!(job_status.get_complete());
+        assert!(!job_status.get_backoff_limit_reached());
+    }
+
+    #[test]
+    fn job_status_from_failed_job() {
+        let job = Job {
+            status: Some(KubernetesJobStatus {
+                start_time: Some(Time(time(10, 0))),
+                failed: Some(6),
+                conditions: Some(vec![JobCondition {
+                    type_: "Failed".to_string(),
+                    status: "True".to_string(),
+                    reason: Some("BackoffLimitExceeded".to_string()),
+                    last_transition_time: Some(Time(time(10, 12))),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // The duration ends when the Job failed, and does not grow for as
+        // long as the Job exists.
+        for now in [time(10, 30), time(11, 30)] {
+            let job_status = JobStatus::from_job(&job, now);
+            assert_eq!(720, job_status.get_duration_seconds());
+        }
+
+        let job_status = JobStatus::from_job(&job, time(10, 30));
+
+        assert_eq!(0, job_status.get_completion_time());
+        assert_eq!(6, job_status.get_failed());
+        assert!(!job_status.get_complete());
+        assert!(job_status.get_backoff_limit_reached());
+    }
+
+    #[test]
+    fn cron_job_status_is_not_overdue_before_next_run() {
+        let cron_job = cron_job("0 * * * *", time(10, 0));
+
+        let cron_job_status = CronJobStatus::from_cron_job(&cron_job, time(11, 0));
+
+        assert_eq!(
+            time(10, 0).timestamp(),
+            cron_job_status.get_last_schedule_time()
+        );
+        assert!(!cron_job_status.get_overdue());
+    }
+
+    #[test]
+    fn cron_job_status_is_overdue_after_missed_run() {
+        let cron_job = cron_job("0 * * * *", time(10, 0));
+
+        let cron_job_status = CronJobStatus::from_cron_job(&cron_job, time(11, 5));
+
+        assert!(cron_job_status.get_overdue());
+    }
+
+    #[test]
+    fn cron_job_status_is_not_overdue_when_suspended() {
+        let mut cron_job = cron_job("0 * * * *", time(10, 0));
+        cron_job.spec.as_mut().unwrap().suspend = Some(true);
+
+        let cron_job_status = CronJobStatus::from_cron_job(&cron_job, time(11, 5));
+
+        assert!(cron_job_status.get_suspended());
+        assert!(!cron_job_status.get_overdue());
+    }
+
+    #[test]
+    fn parse_schedule_with_standard_and_macro_formats() {
+        assert!(parse_schedule("*/5 * * * *").is_some());
+        assert!(parse_schedule("@hourly").is_some());
+        assert!(parse_schedule("not a schedule").is_none());
+        assert!(parse_schedule("0 9 * * 8").is_none());
+    }
+
+    // 2025-01-03 is a Friday.
+    fn date(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, day, hour, 0, 0).unwrap()
+    }
+
+    fn next_run(schedule: &str, after: DateTime<Utc>) -> DateTime<Utc> {
+        parse_schedule(schedule)
+            .unwrap()
+            .next_after(&after)
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_schedule_on_weekdays() {
+        assert_eq!(date(3, 9), next_run("0 9 * * 1-5", date(3, 0)));
+        assert_eq!(date(6, 9), next_run("0 9 * * 1-5", date(3, 10)));
+        assert_eq!(date(6, 9), next_run("0 9 * * MON-FRI", date(3, 10)));
+        assert_eq!(date(4, 9), next_run("0 9 * * 5-7", date(3, 10)));
+        assert_eq!(date(7, 9), next_run("0 9 * * 0/2", date(5, 10)));
+    }
+
+    #[test]
+    fn parse_schedule_on_sunday() {
+        assert_eq!(date(5, 0), next_run("* * * * 0", date(1, 0)));
+        assert_eq!(date(5, 0), next_run("0 0 * * 7", date(1, 0)));
+        assert_eq!(date(5, 0), next_run("0 0 * * sun", date(1, 0)));
+    }
+
+    #[test]
+    fn parse_schedule_on_day_of_month_or_day_of_week() {
+        // On the 13th, and on Fridays.
+        assert_eq!(date(3, 0), next_run("0 0 13 * 5", date(1, 0)));
+        assert_eq!(date(10, 0), next_run("0 0 13 * 5", date(4, 0)));
+        assert_eq!(date(13, 0), next_run("0 0 13 * 5", date(10, 1)));
+
+        // A wildcard day of the week restricts nothing.
+        assert_eq!(date(13, 0), next_run("0 0 13 * ?", date(1, 0)));
+    }
+
+    #[test]
+    fn cron_job_status_is_not_overdue_on_weekend() {
+        let cron_job = cron_job("0 9 * * 1-5", date(3, 9));
+
+        let cron_job_status = CronJobStatus::from_cron_job(&cron_job, date(5, 10));
+
+        assert!(!cron_job_status.get_overdue());
+    }
+}
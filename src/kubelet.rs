@@ -0,0 +1,143 @@
+use std::env;
+use std::fs;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use http::Request;
+use reqwest::{Certificate, Client};
+
+use crate::Error;
+
+const KUBELET_PORT: u16 = 10250;
+
+const SERVICE_ACCOUNT_TOKEN_PATH: &str = "/var/run/secrets/kubernetes.io/serviceaccount/token";
+const SERVICE_ACCOUNT_CA_PATH: &str = "/var/run/secrets/kubernetes.io/serviceaccount/ca.crt";
+
+// How the kubelets are queried for their stats.
+pub enum Kubelet {
+    // Through the API server's node proxy, for every node in the cluster.
+    Proxy,
+    // Directly, for the kubelet on the node this replica runs on, when
+    // running as a DaemonSet.
+    Local {
+        node_name: String,
+        address: SocketAddr,
+        client: Client,
+    },
+}
+
+impl Kubelet {
+    // Query the local kubelet directly when running as a DaemonSet, with
+    // the node's name and IP address set from the downward API.
+    pub fn from_env(daemonset: bool) -> Result<Kubelet, Error> {
+        if !daemonset {
+            return Ok(Kubelet::Proxy);
+        }
+
+        let node_name = env::var("NODE_NAME").map_err(|_| "NODE_NAME not set")?;
+        let node_ip: IpAddr = env::var("NODE_IP")
+            .map_err(|_| "NODE_IP not set")?
+            .parse()
+            .map_err(|err| format!("Could not parse NODE_IP: {}", err))?;
+
+        let mut builder = Client::builder().timeout(Duration::from_secs(30));
+
+        // Kubelet serving certificates are often self-signed, rather than
+        // signed by the cluster's certificate authority.
+        if env::var("APPSIGNAL_KUBELET_INSECURE_SKIP_TLS_VERIFY").as_deref() == Ok("true") {
+            builder = builder.danger_accept_invalid_certs(true);
+        } else if let Ok(ca) = fs::read(SERVICE_ACCOUNT_CA_PATH) {
+            builder = builder.add_root_certificate(Certificate::from_pem(&ca)?);
+        }
+
+        Ok(Kubelet::Local {
+            node_name,
+            address: SocketAddr::new(node_ip, KUBELET_PORT),
+            client: builder.build()?,
+        })
+    }
+
+    // The name of the node this replica runs on, when only its kubelet is
+    // queried.
+    pub fn local_node_name(&self) -> Option<&str> {
+        match self {
+            Kubelet::Proxy => None,
+            Kubelet::Local { node_name, .. } => Some(node_name),
+        }
+    }
+
+    // Request a path from the kubelet on a node, such as `/stats/summary`.
+    pub async fn get_text(
+        &self,
+        client: &kube::Client,
+        node_name: &str,
+        path: &str,
+    ) -> Result<String, Error> {
+        match self {
+            Kubelet::Proxy => {
+                let url = format!("/api/v1/nodes/{}/proxy{}", node_name, path);
+                let request = Request::get(url).body(Default::default())?;
+
+                Ok(client.request_text(request).await?)
+            }
+            Kubelet::Local {
+                address, client, ..
+            } => {
+                // The service account token is read for every request, as it
+                // is rotated by the kubelet.
+                let token = fs::read_to_string(SERVICE_ACCOUNT_TOKEN_PATH)?;
+                Ok(client
+                    .get(url(*address, path))
+                    .bearer_auth(token.trim())
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?)
+            }
+        }
+    }
+
+    pub async fn get_json(
+        &self,
+        client: &kube::Client,
+        node_name: &str,
+        path: &str,
+    ) -> Result<serde_json::Value, Error> {
+        Ok(serde_json::from_str(
+            &self.get_text(client, node_name, path).await?,
+        )?)
+    }
+}
+
+// The URL of a path on the kubelet. IPv6 addresses are enclosed in
+// brackets.
+fn url(address: SocketAddr, path: &str) -> String {
+    format!("https://{}{}", address, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{url, KUBELET_PORT};
+    use std::net::SocketAddr;
+
+    fn kubelet_url(node_ip: &str) -> String {
+        url(
+            SocketAddr::new(node_ip.parse().unwrap(), KUBELET_PORT),
+            "/stats/summary",
+        )
+    }
+
+    #[test]
+    fn kubelet_url_for_ipv4_and_ipv6_addresses() {
+        assert_eq!(
+            "https://10.0.0.1:10250/stats/summary",
+            kubelet_url("10.0.0.1")
+        );
+        assert_eq!(
+            "https://[fd00::1]:10250/stats/summary",
+            kubelet_url("fd00::1")
+        );
+        assert!(reqwest::Url::parse(&kubelet_url("fd00::1")).is_ok());
+    }
+}
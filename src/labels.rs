@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+
+use k8s_openapi::api::core::v1::Namespace;
+use log::warn;
+
+use crate::filter::{glob_match, list_from_env};
+
+// Labels that Kubernetes sets to identify revisions of a workload. Their
+// values change on every rollout, without describing the pod.
+const DEFAULT_DENIED_LABELS: [&str; 5] = [
+    "pod-template-hash",
+    "controller-revision-hash",
+    "pod-template-generation",
+    "controller-uid",
+    "batch.kubernetes.io/controller-uid",
+];
+
+const DEFAULT_NAMESPACE_LABEL_PREFIX: &str = "namespace_";
+
+// Filters the labels of pods and nodes that are reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelFilter {
+    // Glob patterns of the label keys to report. All labels are reported
+    // when empty.
+    pub allow: Vec<String>,
+    // Glob patterns of the label keys not to report.
+    pub deny: Vec<String>,
+    // The maximum number of labels to report per metric.
+    pub max_labels: Option<usize>,
+    // Glob patterns of the namespace label keys to add to the labels of
+    // the pods in the namespace.
+    pub namespace_labels: Vec<String>,
+    // The prefix of the keys of the labels inherited from namespaces.
+    pub namespace_label_prefix: String,
+}
+
+impl Default for LabelFilter {
+    fn default() -> LabelFilter {
+        LabelFilter {
+            allow: Vec::new(),
+            deny: DEFAULT_DENIED_LABELS
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+            max_labels: None,
+            namespace_labels: Vec::new(),
+            namespace_label_prefix: DEFAULT_NAMESPACE_LABEL_PREFIX.to_string(),
+        }
+    }
+}
+
+impl LabelFilter {
+    pub fn from_env() -> LabelFilter {
+        let default = LabelFilter::default();
+
+        LabelFilter {
+            allow: list_from_env("APPSIGNAL_LABELS_ALLOW"),
+            // Setting the denied labels replaces the default ones, so an
+            // empty value reports the hash labels as well.
+            deny: match env::var("APPSIGNAL_LABELS_DENY") {
+                Ok(_) => list_from_env("APPSIGNAL_LABELS_DENY"),
+                Err(_) => default.deny,
+            },
+            max_labels: env::var("APPSIGNAL_LABELS_MAX").ok().and_then(|max| {
+                match max.trim().parse() {
+                    Ok(max) => Some(max),
+                    Err(err) => {
+                        warn!("Could not parse APPSIGNAL_LABELS_MAX: {}", err);
+                        None
+                    }
+                }
+            }),
+            namespace_labels: list_from_env("APPSIGNAL_NAMESPACE_LABELS"),
+            namespace_label_prefix: env::var("APPSIGNAL_NAMESPACE_LABEL_PREFIX")
+                .unwrap_or(default.namespace_label_prefix),
+        }
+    }
+
+    pub fn includes(&self, key: &str) -> bool {
+        (self.allow.is_empty() || self.allow.iter().any(|pattern| glob_match(pattern, key)))
+            && !self.deny.iter().any(|pattern| glob_match(pattern, key))
+    }
+
+    // Filter the labels to report. When there are more labels than the
+    // maximum, the labels with the first keys in alphabetical order are
+    // reported, so the same labels are reported on every run.
+    pub fn filter(&self, labels: &BTreeMap<String, String>) -> HashMap<String, String> {
+        labels
+            .iter()
+            .filter(|(key, _)| self.includes(key))
+            .take(self.max_labels.unwrap_or(usize::MAX))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    // Add the selected labels of a pod's namespace to the pod's labels,
+    // with their keys prefixed. These are not limited by the maximum
+    // number of labels, as they are selected explicitly.
+    pub fn inherit_namespace_labels(
+        &self,
+        labels: &mut HashMap<String, String>,
+        namespace: Option<&Namespace>,
+    ) {
+        let namespace_labels = match namespace.and_then(|ns| ns.metadata.labels.as_ref()) {
+            Some(namespace_labels) => namespace_labels,
+            None => return,
+        };
+
+        for (key, value) in select_annotations(&self.namespace_labels, namespace_labels) {
+            labels
+                .entry(format!("{}{}", self.namespace_label_prefix, key))
+                .or_insert(value);
+        }
+    }
+}
+
+// Select the annotations, or labels, with keys that match any of the glob
+// patterns.
+pub fn select_annotations(
+    patterns: &[String],
+    annotations: &BTreeMap<String, String>,
+) -> HashMap<String, String> {
+    annotations
+        .iter()
+        .filter(|(key, _)| patterns.iter().any(|pattern| glob_match(pattern, key)))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select_annotations, LabelFilter};
+    use k8s_openapi::api::core::v1::Namespace;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn example_labels() -> BTreeMap<String, String> {
+        [
+            ("app", "web"),
+            ("app.kubernetes.io/name", "shop"),
+            ("pod-template-hash", "5d8f7c9b4"),
+            ("team", "payments"),
+        ]
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+    }
+
+    #[test]
+    fn filter_labels_with_default_denied_labels() {
+        let labels = LabelFilter::default().filter(&example_labels());
+
+        assert_eq!(3, labels.len());
+        assert!(!labels.contains_key("pod-template-hash"));
+        assert_eq!(Some(&"payments".to_string()), labels.get("team"));
+    }
+
+    #[test]
+    fn filter_labels_with_allowed_labels_and_maximum() {
+        let filter = LabelFilter {
+            allow: vec!["app*".to_string(), "team".to_string()],
+            deny: vec!["app.kubernetes.io/*".to_string()],
+            ..LabelFilter::default()
+        };
+        let labels = filter.filter(&example_labels());
+        assert_eq!(2, labels.len());
+        assert!(labels.contains_key("app"));
+        assert!(labels.contains_key("team"));
+
+        let filter = LabelFilter {
+            max_labels: Some(2),
+            ..LabelFilter::default()
+        };
+        let labels = filter.filter(&example_labels());
+        assert_eq!(2, labels.len());
+        assert!(labels.contains_key("app"));
+        assert!(labels.contains_key("app.kubernetes.io/name"));
+    }
+
+    #[test]
+    fn select_annotations_by_pattern() {
+        let annotations: BTreeMap<String, String> = [
+            ("team", "payments"),
+            ("prometheus.io/scrape", "true"),
+            ("kubectl.kubernetes.io/last-applied-configuration", "{}"),
+        ]
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+        let selected = select_annotations(
+            &["team".to_string(), "prometheus.io/*".to_string()],
+            &annotations,
+        );
+        assert_eq!(2, selected.len());
+        assert_eq!(
+            Some(&"true".to_string()),
+            selected.get("prometheus.io/scrape")
+        );
+
+        assert!(select_annotations(&[], &annotations).is_empty());
+    }
+
+    #[test]
+    fn inherit_selected_namespace_labels() {
+        let mut namespace = Namespace::default();
+        namespace.metadata.labels = Some(
+            [
+                ("team", "payments"),
+                ("env", "prod"),
+                ("kubernetes.io/metadata.name", "shop"),
+            ]
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        );
+
+        let filter = LabelFilter {
+            namespace_labels: vec!["team".to_string(), "env".to_string()],
+            max_labels: Some(1),
+            ..LabelFilter::default()
+        };
+
+        let mut labels: HashMap<String, String> = [("app".to_string(), "web".to_string())].into();
+        filter.inherit_namespace_labels(&mut labels, Some(&namespace));
+
+        assert_eq!(3, labels.len());
+        assert_eq!(Some(&"payments".to_string()), labels.get("namespace_team"));
+        assert_eq!(Some(&"prod".to_string()), labels.get("namespace_env"));
+
+        let mut labels = HashMap::new();
+        LabelFilter::default().inherit_namespace_labels(&mut labels, Some(&namespace));
+        assert!(labels.is_empty());
+    }
+}
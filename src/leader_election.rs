@@ -0,0 +1,342 @@
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::coordination::v1::{Lease, LeaseSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+use kube::api::{ObjectMeta, PostParams};
+use kube::Api;
+use log::{info, warn};
+
+use crate::Error;
+
+const DEFAULT_LEASE_NAME: &str = "appsignal-kubernetes";
+
+// The annotation on the Lease with the timestamp of the last minute that
+// the leader reported metrics for, so a new leader does not report the
+// same minute again after a failover.
+const LAST_REPORTED_ANNOTATION: &str = "appsignal.com/last-reported-timestamp";
+
+// How long the leader holds the Lease without renewing it. Standbys take
+// over when the leader has not renewed the Lease for this long.
+pub(crate) const LEASE_DURATION: Duration = Duration::from_secs(15);
+pub(crate) const RENEW_INTERVAL: Duration = Duration::from_secs(5);
+
+// How often an update of a Lease is attempted when it conflicts with a
+// concurrent update.
+const CONFLICT_ATTEMPTS: usize = 3;
+
+struct LeaderState {
+    is_leader: bool,
+    // When the Lease was last acquired or renewed by this replica.
+    renewed_at: Option<Instant>,
+    last_reported_timestamp: Option<i64>,
+}
+
+// Elects a single replica to report metrics, using a `coordination.k8s.io`
+// Lease in the namespace of the replicas.
+pub struct LeaderElection {
+    leases: Api<Lease>,
+    name: String,
+    identity: String,
+    state: Arc<Mutex<LeaderState>>,
+}
+
+impl LeaderElection {
+    pub fn start(client: kube::Client) -> Self {
+        let leases: Api<Lease> = Api::default_namespaced(client);
+        let name = lease_name();
+        let identity = identity();
+
+        let state = Arc::new(Mutex::new(LeaderState {
+            is_leader: false,
+            renewed_at: None,
+            last_reported_timestamp: None,
+        }));
+
+        let renew_leases = leases.clone();
+        let renew_name = name.clone();
+        let renew_state = state.clone();
+        let renew_identity = identity.clone();
+
+        tokio::spawn(async move {
+            let identity = renew_identity;
+            let mut interval = tokio::time::interval(RENEW_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let metadata = ObjectMeta {
+                    name: Some(renew_name.clone()),
+                    ..Default::default()
+                };
+                let result = acquire_or_renew(&renew_leases, metadata, &identity).await;
+                let mut state = renew_state.lock().expect("Leader state lock poisoned");
+
+                match result {
+                    Ok((is_leader, lease)) => {
+                        if is_leader && !state.is_leader {
+                            info!("Became the leader as {}", identity);
+                        } else if !is_leader && state.is_leader {
+                            info!(
+                                "Lost leadership to {}",
+                                holder_identity(&lease).unwrap_or("another replica")
+                            );
+                        }
+
+                        state.is_leader = is_leader;
+                        if is_leader {
+                            state.renewed_at = Some(Instant::now());
+                        }
+                        state.last_reported_timestamp = last_reported_timestamp(&lease);
+                    }
+                    Err(err) => warn!("Failed to acquire or renew leader lease: {}", err),
+                }
+            }
+        });
+
+        LeaderElection {
+            leases,
+            name,
+            identity,
+            state,
+        }
+    }
+
+    // Whether this replica is the leader. A leader that has failed to renew
+    // the Lease steps down before the Lease expires, so that it no longer
+    // reports once a standby can take over.
+    pub fn is_leader(&self) -> bool {
+        let state = self.state.lock().expect("Leader state lock poisoned");
+
+        state.is_leader
+            && state
+                .renewed_at
+                .is_some_and(|renewed_at| renewed_at.elapsed() < LEASE_DURATION - RENEW_INTERVAL)
+    }
+
+    // Whether metrics were already reported for the minute of the
+    // timestamp, by this replica or by a previous leader.
+    pub fn has_reported(&self, timestamp: i64) -> bool {
+        let state = self.state.lock().expect("Leader state lock poisoned");
+
+        state
+            .last_reported_timestamp
+            .is_some_and(|last_reported_timestamp| last_reported_timestamp >= timestamp)
+    }
+
+    // Claim the minute of the timestamp to report metrics for, before they
+    // are reported. The claim is recorded in the Lease, guarded by its
+    // resource version, and only succeeds while this replica holds the
+    // Lease and no leader has claimed the minute yet. A minute that is
+    // claimed by a leader that fails to report it is not reported again.
+    pub async fn claim_report(&self, timestamp: i64) -> Result<bool, Error> {
+        for _ in 0..CONFLICT_ATTEMPTS {
+            let mut lease = self.leases.get(&self.name).await?;
+
+            let last_reported_timestamp = last_reported_timestamp(&lease);
+            self.state
+                .lock()
+                .expect("Leader state lock poisoned")
+                .last_reported_timestamp = last_reported_timestamp;
+
+            if holder_identity(&lease) != Some(self.identity.as_str())
+                || last_reported_timestamp.is_some_and(|last_reported| last_reported >= timestamp)
+            {
+                return Ok(false);
+            }
+
+            lease
+                .metadata
+                .annotations
+                .get_or_insert_with(Default::default)
+                .insert(LAST_REPORTED_ANNOTATION.to_owned(), timestamp.to_string());
+
+            match self
+                .leases
+                .replace(&self.name, &PostParams::default(), &lease)
+                .await
+            {
+                Ok(_) => {
+                    self.state
+                        .lock()
+                        .expect("Leader state lock poisoned")
+                        .last_reported_timestamp = Some(timestamp);
+                    return Ok(true);
+                }
+                // The Lease was renewed, or taken over, since it was read.
+                Err(kube::Error::Api(response)) if response.code == 409 => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Err("Lease was updated while claiming the minute to report".into())
+    }
+}
+
+// The name of the Lease to elect the leader with, which is also the prefix
+// of the names of other Leases of the replicas.
+pub(crate) fn lease_name() -> String {
+    env::var("APPSIGNAL_LEADER_ELECTION_LEASE").unwrap_or(DEFAULT_LEASE_NAME.to_owned())
+}
+
+// The identity of this replica. Pods use their name as their hostname.
+pub(crate) fn identity() -> String {
+    env::var("POD_NAME")
+        .or_else(|_| env::var("HOSTNAME"))
+        .unwrap_or(DEFAULT_LEASE_NAME.to_owned())
+}
+
+// Acquire the Lease, creating it with the given metadata if it does not
+// exist, or renew it when this replica holds it. Returns whether this
+// replica holds the Lease, and the Lease as last seen.
+pub(crate) async fn acquire_or_renew(
+    leases: &Api<Lease>,
+    metadata: ObjectMeta,
+    identity: &str,
+) -> Result<(bool, Lease), Error> {
+    let name = metadata.name.clone().unwrap_or_default();
+
+    for _ in 0..CONFLICT_ATTEMPTS {
+        let now = Utc::now();
+
+        let result = match leases.get_opt(&name).await? {
+            Some(lease) => match lease_spec(lease.spec.as_ref(), identity, now) {
+                Some(spec) => {
+                    let mut renewed = lease;
+                    renewed.spec = Some(spec);
+                    leases
+                        .replace(&name, &PostParams::default(), &renewed)
+                        .await
+                }
+                None => return Ok((false, lease)),
+            },
+            None => {
+                let lease = Lease {
+                    metadata: metadata.clone(),
+                    spec: lease_spec(None, identity, now),
+                };
+                leases.create(&PostParams::default(), &lease).await
+            }
+        };
+
+        match result {
+            Ok(lease) => return Ok((true, lease)),
+            // The Lease was updated since it was read: by another replica
+            // that acquired it, or by this replica, such as when claiming a
+            // minute to report. It is read again to tell which.
+            Err(kube::Error::Api(response)) if response.code == 409 => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Err(format!("Lease {} was updated while acquiring or renewing it", name).into())
+}
+
+// The spec to acquire or renew a Lease with, or `None` if the Lease is held
+// by another replica that renewed it within the Lease's duration.
+fn lease_spec(spec: Option<&LeaseSpec>, identity: &str, now: DateTime<Utc>) -> Option<LeaseSpec> {
+    let lease_duration_seconds = LEASE_DURATION.as_secs() as i32;
+
+    let spec = match spec {
+        Some(spec) => spec,
+        None => {
+            return Some(LeaseSpec {
+                holder_identity: Some(identity.to_owned()),
+                acquire_time: Some(MicroTime(now)),
+                renew_time: Some(MicroTime(now)),
+                lease_duration_seconds: Some(lease_duration_seconds),
+                lease_transitions: Some(0),
+            })
+        }
+    };
+
+    if spec.holder_identity.as_deref() == Some(identity) {
+        return Some(LeaseSpec {
+            renew_time: Some(MicroTime(now)),
+            lease_duration_seconds: Some(lease_duration_seconds),
+            ..spec.clone()
+        });
+    }
+
+    if !is_expired(spec, now) {
+        return None;
+    }
+
+    Some(LeaseSpec {
+        holder_identity: Some(identity.to_owned()),
+        acquire_time: Some(MicroTime(now)),
+        renew_time: Some(MicroTime(now)),
+        lease_duration_seconds: Some(lease_duration_seconds),
+        lease_transitions: Some(spec.lease_transitions.unwrap_or_default() + 1),
+    })
+}
+
+// Whether the holder of a Lease has not renewed it within its duration.
+pub(crate) fn is_expired(spec: &LeaseSpec, now: DateTime<Utc>) -> bool {
+    match (&spec.renew_time, spec.lease_duration_seconds) {
+        (Some(renew_time), Some(duration)) => {
+            renew_time.0.timestamp() + i64::from(duration) < now.timestamp()
+        }
+        _ => true,
+    }
+}
+
+fn holder_identity(lease: &Lease) -> Option<&str> {
+    lease.spec.as_ref()?.holder_identity.as_deref()
+}
+
+fn last_reported_timestamp(lease: &Lease) -> Option<i64> {
+    lease
+        .metadata
+        .annotations
+        .as_ref()?
+        .get(LAST_REPORTED_ANNOTATION)?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{last_reported_timestamp, lease_spec, LAST_REPORTED_ANNOTATION};
+    use chrono::{TimeZone, Utc};
+    use k8s_openapi::api::coordination::v1::Lease;
+
+    #[test]
+    fn acquire_renew_and_take_over_lease() {
+        let at = |seconds| Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, seconds).unwrap();
+        let now = at(0);
+
+        let acquired = lease_spec(None, "agent-a", now).unwrap();
+        assert_eq!(Some("agent-a".to_string()), acquired.holder_identity);
+        assert_eq!(Some(15), acquired.lease_duration_seconds);
+        assert_eq!(Some(0), acquired.lease_transitions);
+
+        let renewed = lease_spec(Some(&acquired), "agent-a", at(5)).unwrap();
+        assert_eq!(acquired.acquire_time, renewed.acquire_time);
+        assert_eq!(at(5), renewed.renew_time.unwrap().0);
+
+        // Another replica can not acquire the lease while it is held.
+        assert_eq!(None, lease_spec(Some(&acquired), "agent-b", at(10)));
+
+        let taken_over = lease_spec(Some(&acquired), "agent-b", at(16)).unwrap();
+        assert_eq!(Some("agent-b".to_string()), taken_over.holder_identity);
+        assert_eq!(Some(1), taken_over.lease_transitions);
+    }
+
+    #[test]
+    fn read_last_reported_timestamp() {
+        let mut lease = Lease::default();
+        assert_eq!(None, last_reported_timestamp(&lease));
+
+        lease.metadata.annotations = Some(
+            [(
+                LAST_REPORTED_ANNOTATION.to_string(),
+                "1735732800".to_string(),
+            )]
+            .into(),
+        );
+        assert_eq!(Some(1735732800), last_reported_timestamp(&lease));
+    }
+}
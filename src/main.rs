@@ -1,15 +1,38 @@
+mod autoscaling;
+mod certificates;
+mod custom_resources;
+mod events;
+mod exposition;
+mod filter;
+mod jobs;
+mod kubelet;
+mod labels;
+mod leader_election;
+mod namespaces;
+mod otlp;
 mod ownership;
+mod prometheus;
+mod quantity;
+mod quotas;
+mod rollouts;
+mod routes;
+mod services;
+mod sharding;
+mod sinks;
+mod stats;
+mod storage;
+mod workloads;
+mod x509;
 
 extern crate time;
 
-use http::Request;
-use k8s_openapi::api::core::v1::{Node, Pod};
+use futures::{StreamExt, TryStreamExt};
+use k8s_openapi::api::core::v1::{Namespace, Node, PersistentVolumeClaim, Pod};
 use kube::api::ListParams;
 use kube::{Api, ResourceExt};
 use log::{info, trace, warn};
-use protobuf::Message;
-use reqwest::{Client, Response, Url};
 use std::env;
+use std::net::SocketAddr;
 use std::time::Duration;
 
 mod protocol {
@@ -18,10 +41,34 @@ mod protocol {
 }
 
 use protocol::kubernetes::{
-    Container, ContainerStatus, KubernetesMetrics, KubernetesMetricsBatch, OwnerReference, PodPhase,
+    Container, ContainerStatus, KubernetesMetrics, OwnerReference, PodPhase, PressureStall,
+    ResourceReference,
 };
 
+use crate::autoscaling::extract_horizontal_pod_autoscalers;
+use crate::certificates::{extract_certificates, TlsSecretCache};
+use crate::custom_resources::{extract_custom_resources, CustomResourceConfig};
+use crate::events::EventCollector;
+use crate::filter::Filter;
+use crate::jobs::extract_jobs;
+use crate::kubelet::Kubelet;
+use crate::labels::{select_annotations, LabelFilter};
+use crate::leader_election::LeaderElection;
+use crate::namespaces::NamespaceCache;
 use crate::ownership::{OwnershipResolver, ResourceIdentifier};
+use crate::quotas::extract_quotas;
+use crate::rollouts::extract_rollouts;
+use crate::routes::extract_routes;
+use crate::services::extract_services;
+use crate::sharding::{Scope, ShardMembership};
+use crate::sinks::{Report, Sinks};
+use crate::stats::StatsSource;
+use crate::storage::{extract_persistent_volumes, get_persistent_volume_claims};
+use crate::workloads::WorkloadAggregator;
+
+// The number of nodes whose pods are listed at the same time, by replicas
+// that only list the pods on their own nodes.
+const NODE_LIST_CONCURRENCY: usize = 8;
 
 // The threshold for metrics batch size, in bytes.
 // The body of a request to AppSignal will only exceed this threshold
@@ -30,6 +77,40 @@ const BATCH_SIZE_THRESHOLD: u32 = 400_000;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
+impl From<&ResourceIdentifier> for ResourceReference {
+    fn from(resource: &ResourceIdentifier) -> Self {
+        let mut reference = ResourceReference::new();
+        reference.set_kind(resource.gvk.kind.clone());
+        reference.set_name(resource.name.clone());
+        if let Some(namespace) = &resource.namespace {
+            reference.set_namespace(namespace.clone());
+        }
+        reference
+    }
+}
+
+impl From<ResourceIdentifier> for OwnerReference {
+    fn from(resource: ResourceIdentifier) -> Self {
+        let mut owner_reference = OwnerReference::new();
+        owner_reference.set_name(resource.name);
+        owner_reference.set_kind(resource.gvk.kind);
+        if let Some(namespace) = resource.namespace {
+            owner_reference.set_namespace(namespace);
+        }
+        owner_reference
+    }
+}
+
+impl From<&OwnerReference> for ResourceReference {
+    fn from(owner_reference: &OwnerReference) -> Self {
+        let mut reference = ResourceReference::new();
+        reference.set_kind(owner_reference.get_kind().to_string());
+        reference.set_name(owner_reference.get_name().to_string());
+        reference.set_namespace(owner_reference.get_namespace().to_string());
+        reference
+    }
+}
+
 impl KubernetesMetrics {
     pub fn from_node_json(json: serde_json::Value) -> Option<KubernetesMetrics> {
         match json["nodeName"].as_str() {
@@ -208,6 +289,15 @@ impl KubernetesMetrics {
         Some(metric)
     }
 
+    pub fn from_resource(resource: ResourceReference) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::new();
+
+        metric.set_resource(resource);
+        metric.set_timestamp(now_timestamp());
+
+        metric
+    }
+
     pub fn enrich_with_stats(&mut self, json: serde_json::Value) {
         if let Some(cpu_usage_nano_cores) = Self::extract_i64(&json, "/cpu/usageNanoCores") {
             self.set_cpu_usage_nano_cores(cpu_usage_nano_cores);
@@ -300,6 +390,40 @@ impl KubernetesMetrics {
         if let Some(swap_usage_bytes) = Self::extract_i64(&json, "/swap/swapUsageBytes") {
             self.set_swap_usage_bytes(swap_usage_bytes);
         }
+
+        if let Some((periods, throttled_periods, throttling)) = Self::extract_cpu_cfs(&json) {
+            self.set_cpu_cfs_periods(periods);
+            self.set_cpu_cfs_throttled_periods(throttled_periods);
+            self.set_cpu_throttling(throttling);
+        }
+
+        if let Some(pressure) = Self::extract_pressure(&json) {
+            self.set_pressure(pressure);
+        }
+
+        // The CPU throttling and pressure stall stats of the containers,
+        // which are added to the stats when cAdvisor is scraped
+        if let Some(containers) = json["containers"].as_array() {
+            for container_json in containers {
+                let container = self.mut_containers().iter_mut().find(|container| {
+                    container_json["name"].as_str() == Some(container.get_name())
+                });
+
+                if let Some(container) = container {
+                    if let Some((periods, throttled_periods, throttling)) =
+                        Self::extract_cpu_cfs(container_json)
+                    {
+                        container.set_cpu_cfs_periods(periods);
+                        container.set_cpu_cfs_throttled_periods(throttled_periods);
+                        container.set_cpu_throttling(throttling);
+                    }
+
+                    if let Some(pressure) = Self::extract_pressure(container_json) {
+                        container.set_pressure(pressure);
+                    }
+                }
+            }
+        }
     }
 
     pub fn from_volume_json(
@@ -339,24 +463,83 @@ impl KubernetesMetrics {
                     metric.set_fs_inodes_used(fs_inodes_used);
                 }
 
+                if let (Some(fs_capacity_bytes), Some(fs_used_bytes)) = (
+                    Self::extract_f64(&json, "/capacityBytes"),
+                    Self::extract_f64(&json, "/usedBytes"),
+                ) {
+                    metric.set_disk_usage(Self::percentage_from(fs_used_bytes, fs_capacity_bytes));
+                }
+
+                if let (Some(pvc_name), Some(pvc_namespace)) = (
+                    json.pointer("/pvcRef/name").and_then(|name| name.as_str()),
+                    json.pointer("/pvcRef/namespace")
+                        .and_then(|namespace| namespace.as_str()),
+                ) {
+                    let persistent_volume_claim = metric.mut_persistent_volume_claim();
+                    persistent_volume_claim.set_name(pvc_name.to_string());
+                    persistent_volume_claim.set_namespace(pvc_namespace.to_string());
+                }
+
                 Some(metric)
             }
             _ => None,
         }
     }
 
+    pub fn extract_volume_pod(&mut self, pod_json: &serde_json::Value) {
+        if let (Some(pod_name), Some(pod_namespace)) = (
+            pod_json
+                .pointer("/podRef/name")
+                .and_then(|name| name.as_str()),
+            pod_json
+                .pointer("/podRef/namespace")
+                .and_then(|namespace| namespace.as_str()),
+        ) {
+            self.set_pod_name(pod_name.to_string());
+            self.set_pod_namespace(pod_namespace.to_string());
+        }
+    }
+
+    pub fn extract_storage_class(
+        &mut self,
+        persistent_volume_claims: &kube::api::ObjectList<PersistentVolumeClaim>,
+    ) {
+        if !self.has_persistent_volume_claim() {
+            return;
+        }
+
+        if let Some(storage_class) = persistent_volume_claims
+            .iter()
+            .find(|persistent_volume_claim| {
+                persistent_volume_claim.metadata.name.as_deref()
+                    == Some(self.get_persistent_volume_claim().get_name())
+                    && persistent_volume_claim.metadata.namespace.as_deref()
+                        == Some(self.get_persistent_volume_claim().get_namespace())
+            })
+            .and_then(|persistent_volume_claim| persistent_volume_claim.spec.as_ref())
+            .and_then(|spec| spec.storage_class_name.clone())
+        {
+            self.mut_persistent_volume_claim()
+                .set_storage_class(storage_class);
+        }
+    }
+
     pub fn is_node(&self) -> bool {
-        !self.is_pod() && !self.is_volume()
+        !self.is_pod() && !self.is_volume() && !self.is_resource()
     }
 
     pub fn is_pod(&self) -> bool {
-        !self.pod_uuid.is_empty()
+        !self.pod_uuid.is_empty() && !self.is_resource()
     }
 
     pub fn is_volume(&self) -> bool {
         !self.volume_name.is_empty()
     }
 
+    pub fn is_resource(&self) -> bool {
+        self.has_resource()
+    }
+
     pub fn delta(&self, previous: KubernetesMetrics) -> KubernetesMetrics {
         let mut new = self.clone();
 
@@ -375,9 +558,14 @@ impl KubernetesMetrics {
         previous
             .iter()
             .find(|&p| {
-                (self.is_pod() && p.pod_uuid == self.pod_uuid)
-                    || (self.is_node() && p.node_name == self.node_name)
-                    || (self.is_volume() && p.volume_name == self.volume_name)
+                (self.is_pod() && p.is_pod() && p.pod_uuid == self.pod_uuid)
+                    || (self.is_node() && p.is_node() && p.node_name == self.node_name)
+                    || (self.is_volume()
+                        && p.is_volume()
+                        && p.volume_name == self.volume_name
+                        && p.pod_name == self.pod_name
+                        && p.pod_namespace == self.pod_namespace)
+                    || (self.is_resource() && p.is_resource() && p.resource == self.resource)
             })
             .map(|previous| self.delta(previous.clone()))
     }
@@ -463,36 +651,89 @@ impl KubernetesMetrics {
         };
     }
 
-    pub fn extract_pod_labels(&mut self, pods: &kube::api::ObjectList<Pod>) {
+    pub fn extract_pod_labels(
+        &mut self,
+        pods: &kube::api::ObjectList<Pod>,
+        namespace: Option<&Namespace>,
+        label_filter: &LabelFilter,
+    ) {
         if let Some(pod_data) = pods.iter().find(|pod| match &pod.metadata.name {
             Some(name) => name == &self.pod_name,
             _ => false,
         }) {
-            if let Some(labels) = &pod_data.metadata.labels {
-                let mut labels_map = std::collections::HashMap::new();
-                for (key, value) in labels {
-                    labels_map.insert(key.clone(), value.clone());
-                }
-                self.set_labels(labels_map);
-            }
+            let mut labels = match &pod_data.metadata.labels {
+                Some(labels) => label_filter.filter(labels),
+                None => std::collections::HashMap::new(),
+            };
+            label_filter.inherit_namespace_labels(&mut labels, namespace);
+            self.set_labels(labels);
         };
     }
 
-    pub fn extract_node_labels(&mut self, nodes: &kube::api::ObjectList<Node>) {
+    pub fn extract_node_labels(
+        &mut self,
+        nodes: &kube::api::ObjectList<Node>,
+        label_filter: &LabelFilter,
+    ) {
         if let Some(node_data) = nodes.iter().find(|node| match &node.metadata.name {
             Some(name) => name == &self.node_name,
             _ => false,
         }) {
             if let Some(labels) = &node_data.metadata.labels {
-                let mut labels_map = std::collections::HashMap::new();
-                for (key, value) in labels {
-                    labels_map.insert(key.clone(), value.clone());
-                }
-                self.set_labels(labels_map);
+                self.set_labels(label_filter.filter(labels));
             }
         };
     }
 
+    // Add the selected annotations of the pod's namespace and of the pod,
+    // in which the pod's own annotations take precedence.
+    pub fn extract_pod_annotations(
+        &mut self,
+        pods: &kube::api::ObjectList<Pod>,
+        namespace: Option<&Namespace>,
+        patterns: &[String],
+    ) {
+        if patterns.is_empty() {
+            return;
+        }
+
+        let mut annotations = std::collections::HashMap::new();
+
+        if let Some(namespace_annotations) =
+            namespace.and_then(|namespace| namespace.metadata.annotations.as_ref())
+        {
+            annotations.extend(select_annotations(patterns, namespace_annotations));
+        }
+
+        // Pods with the same name can exist in different namespaces.
+        if let Some(pod_annotations) = pods
+            .iter()
+            .find(|pod| {
+                pod.metadata.name.as_ref() == Some(&self.pod_name)
+                    && pod.metadata.namespace.as_ref() == Some(&self.pod_namespace)
+            })
+            .and_then(|pod| pod.metadata.annotations.as_ref())
+        {
+            annotations.extend(select_annotations(patterns, pod_annotations));
+        }
+
+        self.set_annotations(annotations);
+    }
+
+    pub fn extract_node_annotations(
+        &mut self,
+        nodes: &kube::api::ObjectList<Node>,
+        patterns: &[String],
+    ) {
+        if let Some(node_annotations) = nodes
+            .iter()
+            .find(|node| node.metadata.name.as_ref() == Some(&self.node_name))
+            .and_then(|node| node.metadata.annotations.as_ref())
+        {
+            self.set_annotations(select_annotations(patterns, node_annotations));
+        }
+    }
+
     pub async fn extract_owner_references(
         &mut self,
         resolver: &mut OwnershipResolver,
@@ -505,14 +746,8 @@ impl KubernetesMetrics {
             let resource = ResourceIdentifier::from_pod(pod);
             let owner_references = resolver.resolve_top_level_owners(&resource).await?;
             for owner_reference in owner_references {
-                let mut protocol_owner_reference = OwnerReference::new();
-                protocol_owner_reference.set_name(owner_reference.name);
-                protocol_owner_reference.set_kind(owner_reference.gvk.kind);
-                if let Some(namespace) = owner_reference.namespace {
-                    protocol_owner_reference.set_namespace(namespace);
-                }
-
-                self.mut_owner_references().push(protocol_owner_reference);
+                self.mut_owner_references()
+                    .push(OwnerReference::from(owner_reference));
             }
         };
 
@@ -540,6 +775,39 @@ impl KubernetesMetrics {
         }
     }
 
+    // The number of CFS scheduler periods since the previous scrape, the
+    // number of those periods that were throttled, and the percentage of
+    // throttled periods.
+    fn extract_cpu_cfs(json: &serde_json::Value) -> Option<(i64, i64, i32)> {
+        let periods = Self::extract_i64(json, "/cpuCfs/periods")?;
+        let throttled_periods = Self::extract_i64(json, "/cpuCfs/throttledPeriods")?;
+
+        if periods == 0 {
+            return None;
+        }
+
+        Some((
+            periods,
+            throttled_periods,
+            Self::percentage_from(throttled_periods as f64, periods as f64),
+        ))
+    }
+
+    fn extract_pressure(json: &serde_json::Value) -> Option<PressureStall> {
+        let pressure = json.get("pressure")?;
+        let percentage = |field: &str| pressure[field].as_f64().unwrap_or_default();
+
+        let mut proto_pressure = PressureStall::new();
+        proto_pressure.set_cpu_some(percentage("cpuSome"));
+        proto_pressure.set_cpu_full(percentage("cpuFull"));
+        proto_pressure.set_memory_some(percentage("memorySome"));
+        proto_pressure.set_memory_full(percentage("memoryFull"));
+        proto_pressure.set_io_some(percentage("ioSome"));
+        proto_pressure.set_io_full(percentage("ioFull"));
+
+        Some(proto_pressure)
+    }
+
     fn percentage_from(value: f64, total: f64) -> i32 {
         (value / total * 100.0).clamp(0.0, 100.0).round() as i32
     }
@@ -547,16 +815,55 @@ impl KubernetesMetrics {
 
 #[derive(Debug)]
 struct Config {
-    endpoint: String,
-    api_key: String,
+    custom_resources: Vec<CustomResourceConfig>,
+    filter: Filter,
+    labels: LabelFilter,
+    annotations: Vec<String>,
+    cluster_name: Option<String>,
+    leader_election: bool,
+    sharding: bool,
+    daemonset: bool,
+    metrics_address: Option<SocketAddr>,
 }
 
 impl Config {
     fn from_env() -> Config {
         Config {
-            api_key: env::var("APPSIGNAL_API_KEY").expect("APPSIGNAL_API_KEY not set"),
-            endpoint: env::var("APPSIGNAL_ENDPOINT")
-                .unwrap_or("https://appsignal-endpoint.net".to_owned()),
+            custom_resources: env::var("APPSIGNAL_CUSTOM_RESOURCES")
+                .ok()
+                .and_then(|json| match CustomResourceConfig::parse_list(&json) {
+                    Ok(custom_resources) => Some(custom_resources),
+                    Err(err) => {
+                        warn!("Could not parse APPSIGNAL_CUSTOM_RESOURCES: {}", err);
+                        None
+                    }
+                })
+                .unwrap_or_default(),
+            filter: Filter::from_env(),
+            labels: LabelFilter::from_env(),
+            annotations: filter::list_from_env("APPSIGNAL_ANNOTATIONS"),
+            cluster_name: env::var("APPSIGNAL_CLUSTER_NAME")
+                .ok()
+                .filter(|cluster_name| !cluster_name.is_empty()),
+            leader_election: env::var("APPSIGNAL_LEADER_ELECTION")
+                .map(|leader_election| leader_election == "true")
+                .unwrap_or(false),
+            sharding: env::var("APPSIGNAL_SHARDING")
+                .map(|sharding| sharding == "true")
+                .unwrap_or(false),
+            daemonset: env::var("APPSIGNAL_DAEMONSET")
+                .map(|daemonset| daemonset == "true")
+                .unwrap_or(false),
+            metrics_address: env::var("APPSIGNAL_METRICS_ADDRESS")
+                .ok()
+                .filter(|address| !address.is_empty())
+                .and_then(|address| match address.parse() {
+                    Ok(address) => Some(address),
+                    Err(err) => {
+                        warn!("Could not parse APPSIGNAL_METRICS_ADDRESS: {}", err);
+                        None
+                    }
+                }),
         }
     }
 }
@@ -565,13 +872,38 @@ impl Config {
 async fn main() -> Result<(), Error> {
     env_logger::init();
 
+    let config = Config::from_env();
     info!(
         "Starting Appsignal for Kubernetes with configuration: {:?}",
-        Config::from_env()
+        config
     );
 
     let client = kube::Client::try_default().await?;
     let mut resolver = OwnershipResolver::new(client.clone());
+    let events = EventCollector::new(client.clone());
+    // Namespaces are only watched when their labels or annotations are
+    // added to the metrics of their pods.
+    let namespaces = NamespaceCache::start(
+        client.clone(),
+        !config.labels.namespace_labels.is_empty() || !config.annotations.is_empty(),
+    );
+    let kubelet = Kubelet::from_env(config.daemonset)?;
+    let stats = StatsSource::from_env();
+    let mut tls_secrets = TlsSecretCache::from_env();
+    // As a DaemonSet, every replica scrapes the node it runs on.
+    let sharding = if config.sharding && !config.daemonset {
+        Some(ShardMembership::start(client.clone()).await)
+    } else {
+        None
+    };
+    // With sharding, or as a DaemonSet, a leader is elected to extract the
+    // cluster-scope metrics.
+    let leader_election = (config.leader_election || config.sharding || config.daemonset)
+        .then(|| LeaderElection::start(client.clone()));
+    let sinks = Sinks::from_env(config.metrics_address).await?;
+    if config.sharding || config.daemonset {
+        info!("No replica scrapes the stats of all pods with sharding or as a DaemonSet, so the CPU and memory usage of workloads is not reported");
+    }
     let duration = Duration::new(60, 0);
     let mut interval = tokio::time::interval(duration);
     let mut previous = Vec::new();
@@ -579,11 +911,89 @@ async fn main() -> Result<(), Error> {
     loop {
         interval.tick().await;
 
-        match run(&client, &mut resolver, previous).await {
-            Ok(results) => previous = results,
+        let timestamp = now_timestamp();
+
+        let is_leader = leader_election
+            .as_ref()
+            .map_or(true, |leader_election| leader_election.is_leader());
+
+        // Standbys do not watch events, so that they only report the events
+        // that occur after they become the leader.
+        events.set_watching(is_leader);
+
+        if let Some(leader_election) = &leader_election {
+            if !is_leader {
+                // Without sharding, standbys do not report metrics. They
+                // forget the previous run for the same reason.
+                if sharding.is_none() && kubelet.local_node_name().is_none() {
+                    trace!("Not the leader, skipping metrics extraction");
+                    previous = Vec::new();
+
+                    // Deliver an empty report, so that the Prometheus
+                    // endpoint stops serving the metrics of a previous lead.
+                    sinks.deliver(Report::default());
+
+                    continue;
+                }
+            } else if leader_election.has_reported(timestamp) {
+                info!("Metrics were already reported for this minute, skipping");
+
+                // Deliver an empty report, so that the Prometheus endpoint
+                // does not keep serving the metrics of the previous run.
+                sinks.deliver(Report::default());
+
+                continue;
+            }
+        }
+
+        match run(
+            &client,
+            &mut resolver,
+            &mut tls_secrets,
+            &events,
+            &namespaces,
+            &Scope {
+                kubelet: &kubelet,
+                shard: sharding.as_ref(),
+                cluster_scope: is_leader,
+            },
+            &stats,
+            previous,
+        )
+        .await
+        {
+            Ok(report) => {
+                // The leader claims the minute before reporting it, so that
+                // a new leader does not report it again after a failover
+                // during the delivery.
+                let claimed = match &leader_election {
+                    Some(leader_election) if is_leader => {
+                        match leader_election.claim_report(timestamp).await {
+                            Ok(claimed) => claimed,
+                            Err(err) => {
+                                warn!("Failed to claim reported metrics in leader lease: {}", err);
+                                false
+                            }
+                        }
+                    }
+                    _ => true,
+                };
+
+                previous = report.metrics.clone();
+
+                if claimed {
+                    sinks.deliver(report);
+                } else {
+                    info!("Metrics were already reported for this minute, skipping");
+                    sinks.deliver(Report::default());
+                }
+            }
             Err(err) => {
-                warn!("Failed to extract and report metrics: {}", err);
+                warn!("Failed to extract metrics: {}", err);
                 previous = Vec::new();
+
+                // Stale metrics are not served after a failed run.
+                sinks.deliver(Report::default());
             }
         }
 
@@ -591,33 +1001,98 @@ async fn main() -> Result<(), Error> {
     }
 }
 
+// The caches and watchers that are kept between runs are passed in, so
+// that every run does not start them again.
+#[allow(clippy::too_many_arguments)]
 async fn run(
     client: &kube::Client,
     resolver: &mut OwnershipResolver,
+    tls_secrets: &mut TlsSecretCache,
+    events: &EventCollector,
+    namespaces: &NamespaceCache,
+    scope: &Scope<'_>,
+    stats: &StatsSource,
     previous: Vec<KubernetesMetrics>,
-) -> Result<Vec<KubernetesMetrics>, Error> {
+) -> Result<Report, Error> {
     info!("Extracting metrics from Kubernetes cluster");
 
+    let config = Config::from_env();
+
+    let kubelet = scope.kubelet;
+    let cluster_scope = scope.cluster_scope;
+
+    // As a DaemonSet, only the metadata of the local node is listed.
+    let node_list_params = match kubelet.local_node_name() {
+        Some(node_name) => ListParams::default().fields(&format!("metadata.name={}", node_name)),
+        None => ListParams::default(),
+    };
+
     let nodes: Api<Node> = Api::all(client.clone());
-    let nodes_list = nodes.list(&ListParams::default()).await?;
+    let nodes_list = nodes.list(&node_list_params).await?;
 
+    // All pods are needed for the cluster-scope metrics. Other replicas only
+    // list the pods on their own nodes.
     let pods: Api<Pod> = Api::all(client.clone());
-    let pods_list = pods.list(&ListParams::default()).await?;
+    let mut pods_list = if cluster_scope {
+        pods.list(&config.filter.pod_list_params(None)).await?
+    } else {
+        list_pods_on_nodes(
+            &pods,
+            &config.filter,
+            nodes_list
+                .iter()
+                .filter_map(|node| node.metadata.name.as_deref())
+                .filter(|node_name| scope.owns_node(Some(&node_name.to_string())))
+                .collect(),
+        )
+        .await?
+    };
+    pods_list.items.retain(|pod| {
+        config
+            .filter
+            .includes_namespace(&pod.namespace().unwrap_or_default())
+    });
+
+    // All claims are reported with the cluster-scope metrics. Other replicas
+    // only need the claims of the volumes of the pods on their nodes.
+    let persistent_volume_claims_list = if cluster_scope {
+        let persistent_volume_claims: Api<PersistentVolumeClaim> = Api::all(client.clone());
+        persistent_volume_claims
+            .list(&config.filter.list_params())
+            .await?
+    } else {
+        get_persistent_volume_claims(
+            client,
+            pods_list.iter().filter(|pod| {
+                scope.owns_node(pod.spec.as_ref().and_then(|spec| spec.node_name.as_ref()))
+            }),
+        )
+        .await
+    };
 
     let mut metrics = Vec::new();
     let mut payload = Vec::new();
+    // Workloads are aggregated by the replica that extracts the cluster-scope
+    // metrics, from all pods. As a DaemonSet, or with sharding, it only
+    // scrapes the stats of the pods on its own nodes, so the usage of the
+    // pods is not aggregated.
+    let mut workloads = if scope.shard_identity().is_some() {
+        WorkloadAggregator::without_usage()
+    } else {
+        WorkloadAggregator::default()
+    };
 
     // Collect all stats/summary data from all nodes
     let mut stats_data = std::collections::HashMap::new();
 
     for node in &nodes_list {
         let name = node.name_any();
-        let url = format!("/api/v1/nodes/{}/proxy/stats/summary", name);
 
-        let kube_request = Request::get(url).body(Default::default())?;
-        let kube_response = client
-            .request::<serde_json::Value>(kube_request.clone())
-            .await?;
+        if !scope.owns_node(Some(&name)) {
+            continue;
+        }
+
+        let kube_response = stats.get_summary(kubelet, client, node, &pods_list).await?;
 
         trace!("JSON: {:?}", kube_response);
 
@@ -625,7 +1100,8 @@ async fn run(
         if let Some(mut node_metric) =
             KubernetesMetrics::from_node_json(kube_response["node"].clone())
         {
-            node_metric.extract_node_labels(&nodes_list);
+            node_metric.extract_node_labels(&nodes_list, &config.labels);
+            node_metric.extract_node_annotations(&nodes_list, &config.annotations);
 
             if let Some(metric) = node_metric.delta_from(previous.clone()) {
                 payload.push(metric);
@@ -640,6 +1116,10 @@ async fn run(
         if let Some(pods) = kube_response["pods"].as_array() {
             for pod in pods {
                 if let Some(pod_ref) = pod.get("podRef") {
+                    if !config.filter.includes_stats_pod(pod_ref, &pods_list) {
+                        continue;
+                    }
+
                     if let Some(pod_uid) = pod_ref.get("uid").and_then(|uid| uid.as_str()) {
                         stats_data.insert(pod_uid.to_string(), pod.clone());
                     }
@@ -650,12 +1130,19 @@ async fn run(
         // Process volume metrics
         if let Some(pods) = kube_response["pods"].as_array() {
             for pod in pods {
+                if !config.filter.includes_stats_pod(&pod["podRef"], &pods_list) {
+                    continue;
+                }
+
                 if let Some(volumes) = pod["volume"].as_array() {
                     for volume in volumes {
-                        if let Some(volume_metric) = KubernetesMetrics::from_volume_json(
+                        if let Some(mut volume_metric) = KubernetesMetrics::from_volume_json(
                             kube_response["node"]["nodeName"].as_str(),
                             volume.clone(),
                         ) {
+                            volume_metric.extract_volume_pod(pod);
+                            volume_metric.extract_storage_class(&persistent_volume_claims_list);
+
                             if let Some(metric) = volume_metric.delta_from(previous.clone()) {
                                 payload.push(metric);
                             }
@@ -670,12 +1157,27 @@ async fn run(
         }
     }
 
+    if !namespaces.wait_until_ready().await {
+        warn!("Namespaces are not listed yet, reporting pods without namespace labels and annotations");
+    }
+
     // Process all pods from Kubernetes API
     for pod in &pods_list {
+        let owns_pod = scope.owns_node(pod.spec.as_ref().and_then(|spec| spec.node_name.as_ref()));
+        if !owns_pod && !cluster_scope {
+            continue;
+        }
+
         if let Some(mut pod_metric) = KubernetesMetrics::from_pod_api(pod) {
             // Extract additional metadata from Kubernetes API
             pod_metric.extract_containers(&pods_list);
-            pod_metric.extract_pod_labels(&pods_list);
+            let namespace = namespaces.get(&pod_metric.pod_namespace);
+            pod_metric.extract_pod_labels(&pods_list, namespace.as_deref(), &config.labels);
+            pod_metric.extract_pod_annotations(
+                &pods_list,
+                namespace.as_deref(),
+                &config.annotations,
+            );
             pod_metric.extract_pod_restart_count_and_uptime(&pods_list);
 
             if let Err(err) = pod_metric
@@ -693,6 +1195,16 @@ async fn run(
                 pod_metric.enrich_with_stats(stats_json.clone());
             }
 
+            if cluster_scope {
+                workloads.add(pod, &pod_metric);
+            }
+
+            // Pods on the nodes of other replicas are reported by those
+            // replicas.
+            if !owns_pod {
+                continue;
+            }
+
             if let Some(metric) = pod_metric.delta_from(previous.clone()) {
                 payload.push(metric);
             }
@@ -703,80 +1215,218 @@ async fn run(
         }
     }
 
-    info!("Extracted {} metrics", metrics.len());
+    // Resources that are not on a node are only extracted by the replica
+    // that was elected to extract them, when running more than one replica.
+    if cluster_scope {
+        // Process PersistentVolumeClaim metrics, including claims that are not
+        // mounted by any pod
+        for persistent_volume_claim in &persistent_volume_claims_list {
+            let claim_metric =
+                KubernetesMetrics::from_persistent_volume_claim(persistent_volume_claim);
 
-    let config = Config::from_env();
-    let base = Url::parse(&config.endpoint).expect("Could not parse endpoint");
-    let path = format!("metrics/kubernetes?api_key={}", config.api_key);
-    let url = base.join(&path).expect("Could not build request URL");
-
-    let reqwest_client = Client::builder().timeout(Duration::from_secs(30)).build()?;
-
-    info!("Sending {} metrics to Appsignal", payload.len());
-
-    let mut batch_message = KubernetesMetricsBatch::new();
-    for metric in payload.into_iter() {
-        batch_message.mut_metrics().push(metric);
-        if batch_message.compute_size() > BATCH_SIZE_THRESHOLD {
-            // Remove the metric that went over the threshold from the batch,
-            // unless it is the only metric in the batch.
-            let metric = if batch_message.mut_metrics().len() > 1 {
-                metrics.pop()
-            } else {
-                None
-            };
+            if let Some(metric) = claim_metric.delta_from(previous.clone()) {
+                payload.push(metric);
+            }
+
+            metrics.push(claim_metric.clone());
+
+            trace!("PersistentVolumeClaim: {:?}", claim_metric);
+        }
+
+        // Process PersistentVolume metrics
+        match extract_persistent_volumes(client).await {
+            Ok(volume_metrics) => {
+                for volume_metric in volume_metrics {
+                    if let Some(metric) = volume_metric.delta_from(previous.clone()) {
+                        payload.push(metric);
+                    }
 
-            let batch = std::mem::take(&mut batch_message);
-            send_batch(batch, &url, &reqwest_client).await?;
+                    metrics.push(volume_metric.clone());
 
-            // Add the metric that went over the threshold to the next batch.
-            if let Some(metric) = metric {
-                batch_message.mut_metrics().push(metric);
+                    trace!("PersistentVolume: {:?}", volume_metric);
+                }
             }
+            Err(err) => warn!("Failed to extract persistent volumes: {}", err),
+        }
+
+        // Process HorizontalPodAutoscaler metrics
+        match extract_horizontal_pod_autoscalers(client).await {
+            Ok(autoscaler_metrics) => {
+                for autoscaler_metric in autoscaler_metrics {
+                    if let Some(metric) = autoscaler_metric.delta_from(previous.clone()) {
+                        payload.push(metric);
+                    }
+
+                    metrics.push(autoscaler_metric.clone());
+
+                    trace!("HorizontalPodAutoscaler: {:?}", autoscaler_metric);
+                }
+            }
+            Err(err) => warn!("Failed to extract horizontal pod autoscalers: {}", err),
+        }
+
+        // Process Service metrics
+        let service_metrics = match extract_services(client).await {
+            Ok(service_metrics) => service_metrics,
+            Err(err) => {
+                warn!("Failed to extract services: {}", err);
+                Vec::new()
+            }
+        };
+
+        // Process Ingress and HTTPRoute metrics. Their backends are checked
+        // against the Service metrics, so they are skipped without those.
+        if !service_metrics.is_empty() {
+            match extract_routes(client, resolver, &service_metrics).await {
+                Ok(route_metrics) => {
+                    for route_metric in route_metrics {
+                        if let Some(metric) = route_metric.delta_from(previous.clone()) {
+                            payload.push(metric);
+                        }
+
+                        metrics.push(route_metric.clone());
+
+                        trace!("Route: {:?}", route_metric);
+                    }
+                }
+                Err(err) => warn!("Failed to extract routes: {}", err),
+            }
+        }
+
+        for service_metric in service_metrics {
+            if let Some(metric) = service_metric.delta_from(previous.clone()) {
+                payload.push(metric);
+            }
+
+            metrics.push(service_metric.clone());
+
+            trace!("Service: {:?}", service_metric);
+        }
+
+        // Process ResourceQuota and LimitRange metrics
+        match extract_quotas(client).await {
+            Ok(quota_metrics) => {
+                for quota_metric in quota_metrics {
+                    if let Some(metric) = quota_metric.delta_from(previous.clone()) {
+                        payload.push(metric);
+                    }
+
+                    metrics.push(quota_metric.clone());
+
+                    trace!("Quota: {:?}", quota_metric);
+                }
+            }
+            Err(err) => warn!("Failed to extract resource quotas: {}", err),
+        }
+
+        // Process TLS certificate expiry metrics
+        match extract_certificates(client, resolver, tls_secrets).await {
+            Ok(certificate_metrics) => {
+                for certificate_metric in certificate_metrics {
+                    if let Some(metric) = certificate_metric.delta_from(previous.clone()) {
+                        payload.push(metric);
+                    }
+
+                    metrics.push(certificate_metric.clone());
+
+                    trace!("Certificate: {:?}", certificate_metric);
+                }
+            }
+            Err(err) => warn!("Failed to extract certificates: {}", err),
+        }
+
+        // Process the status of the configured custom resources
+        for custom_resource_metric in
+            extract_custom_resources(resolver, &config.custom_resources).await
+        {
+            if let Some(metric) = custom_resource_metric.delta_from(previous.clone()) {
+                payload.push(metric);
+            }
+
+            metrics.push(custom_resource_metric.clone());
+
+            trace!("Custom resource: {:?}", custom_resource_metric);
+        }
+
+        if let Err(err) = extract_rollouts(client, &mut workloads).await {
+            warn!("Failed to extract rollout status: {}", err);
+        }
+
+        if let Err(err) = extract_jobs(client, resolver, &mut workloads).await {
+            warn!("Failed to extract job status: {}", err);
         }
     }
 
-    if !batch_message.get_metrics().is_empty() {
-        send_batch(batch_message, &url, &reqwest_client).await?;
+    // Process workload metrics, aggregated from the pods they own
+    for workload_metric in workloads.into_metrics() {
+        if let Some(metric) = workload_metric.delta_from(previous.clone()) {
+            payload.push(metric);
+        }
+
+        metrics.push(workload_metric.clone());
+
+        trace!("Workload: {:?}", workload_metric);
     }
 
-    info!("All metrics sent");
+    if cluster_scope {
+        // Process Warning events that occurred since the previous run. These
+        // are not stored for the next run, as events are not compared over time.
+        for event_metric in events.extract_events(resolver, &pods_list).await {
+            payload.push(event_metric.clone());
 
-    Ok(metrics)
-}
+            trace!("Event: {:?}", event_metric);
+        }
+    }
 
-async fn send_batch(
-    batch: KubernetesMetricsBatch,
-    url: &Url,
-    client: &Client,
-) -> Result<Response, Error> {
-    let batch_bytes = batch.write_to_bytes().expect("Could not serialize batch");
+    // Drop the metrics of resources in namespaces that are not reported,
+    // as most resources are listed in all namespaces.
+    metrics.retain(|metric| config.filter.includes_metric(metric));
+    payload.retain(|metric| config.filter.includes_metric(metric));
 
-    let response = client.post(url.clone()).body(batch_bytes).send().await?;
+    info!("Extracted {} metrics", metrics.len());
 
-    let status = response.status();
+    // Identify the cluster the metrics came from. Without a configured
+    // cluster name, the cluster is named by its ID.
+    let cluster_id = match namespaces.cluster_id(client).await {
+        Ok(cluster_id) => cluster_id,
+        Err(err) => {
+            warn!("Failed to extract cluster ID: {}", err);
+            String::new()
+        }
+    };
+    let cluster_name = config.cluster_name.clone().unwrap_or(cluster_id.clone());
 
-    if status.is_success() {
-        info!(
-            "Batch of {} metrics sent successfully (HTTP response status: {})",
-            batch.get_metrics().len(),
-            status
-        );
-    } else if status == 401 {
-        warn!(
-            "Batch of {} metrics failed to send (HTTP response status: {}) - make sure you're using an *app-level* push API key",
-            batch.get_metrics().len(),
-            status
-        );
-    } else {
-        warn!(
-            "Batch of {} metrics failed to send (HTTP response status: {})",
-            batch.get_metrics().len(),
-            status
-        );
+    for metric in payload.iter_mut().chain(metrics.iter_mut()) {
+        metric.set_cluster_name(cluster_name.clone());
+        metric.set_cluster_id(cluster_id.clone());
+        metric.set_site_id(cluster_id.clone());
+
+        if let Some(shard) = scope.shard_identity() {
+            metric.set_shard(shard.to_string());
+        }
     }
 
-    Ok(response)
+    Ok(Report { payload, metrics })
+}
+
+// List the pods on the nodes, with a request per node, a few nodes at a
+// time.
+async fn list_pods_on_nodes(
+    pods: &Api<Pod>,
+    filter: &Filter,
+    node_names: Vec<&str>,
+) -> Result<kube::api::ObjectList<Pod>, Error> {
+    let lists: Vec<kube::api::ObjectList<Pod>> = futures::stream::iter(node_names)
+        .map(|node_name| async move { pods.list(&filter.pod_list_params(Some(node_name))).await })
+        .buffered(NODE_LIST_CONCURRENCY)
+        .try_collect()
+        .await?;
+
+    Ok(kube::api::ObjectList {
+        types: Default::default(),
+        metadata: Default::default(),
+        items: lists.into_iter().flat_map(|list| list.items).collect(),
+    })
 }
 
 fn now_timestamp() -> i64 {
@@ -1092,6 +1742,89 @@ mod tests {
         assert_eq!(8318251008, metric.fs_available_bytes);
     }
 
+    #[test]
+    fn extract_volume_metrics_with_persistent_volume_claim() {
+        use k8s_openapi::api::core::v1::{PersistentVolumeClaim, PersistentVolumeClaimSpec};
+
+        let mut metric = KubernetesMetrics::from_volume_json(
+            Some("node"),
+            json!({
+                "time": "2024-10-08T13:42:48Z",
+                "availableBytes": 512_u64,
+                "capacityBytes": 10240_u64,
+                "usedBytes": 9728_u64,
+                "name": "data",
+                "pvcRef": {
+                    "name": "data-postgres-0",
+                    "namespace": "database"
+                }
+            }),
+        )
+        .unwrap();
+
+        metric.extract_volume_pod(&json!({
+            "podRef": {
+                "name": "postgres-0",
+                "namespace": "database",
+                "uid": "postgres-0-uid"
+            }
+        }));
+
+        let mut persistent_volume_claim = PersistentVolumeClaim::default();
+        persistent_volume_claim.metadata.name = Some("data-postgres-0".to_string());
+        persistent_volume_claim.metadata.namespace = Some("database".to_string());
+        persistent_volume_claim.spec = Some(PersistentVolumeClaimSpec {
+            storage_class_name: Some("do-block-storage".to_string()),
+            ..Default::default()
+        });
+
+        metric.extract_storage_class(&kube::api::ObjectList {
+            types: Default::default(),
+            metadata: Default::default(),
+            items: vec![persistent_volume_claim],
+        });
+
+        assert!(metric.is_volume());
+        assert!(!metric.is_pod());
+        assert!(!metric.is_node());
+
+        assert_eq!("data", metric.volume_name);
+        assert_eq!("postgres-0", metric.pod_name);
+        assert_eq!("database", metric.pod_namespace);
+        assert_eq!("", metric.pod_uuid);
+
+        assert_eq!(95, metric.disk_usage);
+
+        let persistent_volume_claim = metric.get_persistent_volume_claim();
+        assert_eq!("data-postgres-0", persistent_volume_claim.get_name());
+        assert_eq!("database", persistent_volume_claim.get_namespace());
+        assert_eq!(
+            "do-block-storage",
+            persistent_volume_claim.get_storage_class()
+        );
+    }
+
+    #[test]
+    fn delta_from_volume() {
+        let volume = json!({
+            "name": "data",
+            "usedBytes": 1024_u64
+        });
+
+        let mut current =
+            KubernetesMetrics::from_volume_json(Some("node"), volume.clone()).unwrap();
+        current
+            .extract_volume_pod(&json!({ "podRef": { "name": "web-1", "namespace": "default" } }));
+
+        let mut other_pod =
+            KubernetesMetrics::from_volume_json(Some("node"), volume.clone()).unwrap();
+        other_pod
+            .extract_volume_pod(&json!({ "podRef": { "name": "web-2", "namespace": "default" } }));
+
+        assert_eq!(None, current.delta_from(vec![other_pod]));
+        assert!(current.delta_from(vec![current.clone()]).is_some());
+    }
+
     #[test]
     fn delta_subtracts_network_data() {
         let metric =
@@ -1137,6 +1870,37 @@ mod tests {
         assert_eq!(5, new.network_rx_bytes);
     }
 
+    #[test]
+    fn enrich_with_throttling_and_pressure() {
+        use crate::protocol::kubernetes::Container;
+
+        let mut metric = KubernetesMetrics::new();
+        let mut container = Container::new();
+        container.set_name("web".to_string());
+        metric.mut_containers().push(container);
+
+        metric.enrich_with_stats(json!({
+            "cpuCfs": { "periods": 600, "throttledPeriods": 150 },
+            "pressure": { "cpuSome": 2.5, "memoryFull": 0.5 },
+            "containers": [
+                { "name": "web", "cpuCfs": { "periods": 600, "throttledPeriods": 0 } },
+                { "name": "unknown", "cpuCfs": { "periods": 600, "throttledPeriods": 600 } }
+            ]
+        }));
+
+        assert_eq!(600, metric.cpu_cfs_periods);
+        assert_eq!(150, metric.cpu_cfs_throttled_periods);
+        assert_eq!(25, metric.cpu_throttling);
+        assert_eq!(2.5, metric.get_pressure().cpu_some);
+        assert_eq!(0.5, metric.get_pressure().memory_full);
+        assert_eq!(0.0, metric.get_pressure().io_some);
+
+        assert_eq!(1, metric.containers.len());
+        assert_eq!(600, metric.containers[0].cpu_cfs_periods);
+        assert_eq!(0, metric.containers[0].cpu_throttling);
+        assert!(!metric.containers[0].has_pressure());
+    }
+
     #[test]
     fn delta_from_pod() {
         use k8s_openapi::api::core::v1::{Pod, PodSpec};
@@ -1175,6 +1939,115 @@ mod tests {
         assert_eq!(4, new.network_rx_bytes);
     }
 
+    #[test]
+    fn delta_from_resource() {
+        use crate::protocol::kubernetes::{OwnerReference, ResourceReference};
+
+        let mut owner_reference = OwnerReference::new();
+        owner_reference.set_kind("Deployment".to_string());
+        owner_reference.set_name("web".to_string());
+        owner_reference.set_namespace("default".to_string());
+
+        let current = KubernetesMetrics::from_resource(ResourceReference::from(&owner_reference));
+
+        let node = KubernetesMetrics::from_node_json(json!({ "nodeName": "node" })).unwrap();
+        assert_eq!(None, current.delta_from(vec![node]));
+
+        let mut other_owner_reference = owner_reference.clone();
+        other_owner_reference.set_name("worker".to_string());
+        let other =
+            KubernetesMetrics::from_resource(ResourceReference::from(&other_owner_reference));
+        assert_eq!(None, current.delta_from(vec![other]));
+
+        let previous = KubernetesMetrics::from_resource(ResourceReference::from(&owner_reference));
+        assert!(current.delta_from(vec![previous]).is_some());
+    }
+
+    #[test]
+    fn extract_pod_annotations_with_namespace_annotations() {
+        use k8s_openapi::api::core::v1::{Namespace, Pod};
+
+        let annotations = |pairs: &[(&str, &str)]| {
+            Some(
+                pairs
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            )
+        };
+
+        let mut namespace = Namespace::default();
+        namespace.metadata.name = Some("shop".to_string());
+        namespace.metadata.annotations =
+            annotations(&[("team", "payments"), ("oncall", "payments-oncall")]);
+
+        let mut pod = Pod::default();
+        pod.metadata.name = Some("web-1".to_string());
+        pod.metadata.namespace = Some("shop".to_string());
+        pod.metadata.annotations = annotations(&[
+            ("oncall", "web-oncall"),
+            ("prometheus.io/port", "9090"),
+            ("kubectl.kubernetes.io/restartedAt", "2025-01-01T00:00:00Z"),
+        ]);
+
+        let pods = kube::api::ObjectList {
+            types: Default::default(),
+            metadata: Default::default(),
+            items: vec![pod],
+        };
+
+        let mut metric = KubernetesMetrics::new();
+        metric.set_pod_name("web-1".to_string());
+        metric.set_pod_namespace("shop".to_string());
+        metric.extract_pod_annotations(
+            &pods,
+            Some(&namespace),
+            &[
+                "team".to_string(),
+                "oncall".to_string(),
+                "prometheus.io/*".to_string(),
+            ],
+        );
+
+        let annotations = metric.get_annotations();
+        assert_eq!(3, annotations.len());
+        assert_eq!("payments", annotations["team"]);
+        assert_eq!("web-oncall", annotations["oncall"]);
+        assert_eq!("9090", annotations["prometheus.io/port"]);
+    }
+
+    #[test]
+    fn extract_pod_annotations_of_pods_with_the_same_name() {
+        use k8s_openapi::api::core::v1::Pod;
+
+        let pod = |namespace: &str, team: &str| {
+            let mut pod = Pod::default();
+            pod.metadata.name = Some("redis-0".to_string());
+            pod.metadata.namespace = Some(namespace.to_string());
+            pod.metadata.annotations = Some(
+                [("team".to_string(), team.to_string())]
+                    .into_iter()
+                    .collect(),
+            );
+            pod
+        };
+
+        let pods = kube::api::ObjectList {
+            types: Default::default(),
+            metadata: Default::default(),
+            items: vec![pod("shop", "payments"), pod("cache", "platform")],
+        };
+
+        for (namespace, team) in [("shop", "payments"), ("cache", "platform")] {
+            let mut metric = KubernetesMetrics::new();
+            metric.set_pod_name("redis-0".to_string());
+            metric.set_pod_namespace(namespace.to_string());
+            metric.extract_pod_annotations(&pods, None, &["team".to_string()]);
+
+            assert_eq!(team, metric.get_annotations()["team"]);
+        }
+    }
+
     #[test]
     fn enrich_with_stats_handles_negative_values() {
         use k8s_openapi::api::core::v1::{Pod, PodSpec};
@@ -0,0 +1,106 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Namespace;
+use kube::runtime::reflector::{self, reflector, ObjectRef, Store};
+use kube::runtime::{watcher, WatchStreamExt};
+use kube::Api;
+use log::warn;
+
+use crate::Error;
+
+// The kube-system namespace is created with the cluster and cannot be
+// deleted, so its UID identifies the cluster.
+const KUBE_SYSTEM_NAMESPACE: &str = "kube-system";
+
+// How long a run waits for the Namespaces to be listed for the first time.
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Caches the Namespaces in the cluster, kept up to date by watching them,
+// so their metadata can be added to the metrics of the pods in them.
+pub struct NamespaceCache {
+    store: Store<Namespace>,
+    watching: bool,
+    cluster_id: Mutex<Option<String>>,
+}
+
+impl NamespaceCache {
+    // Watch the Namespaces, unless their metadata is not needed. The cache
+    // is then empty.
+    pub fn start(client: kube::Client, watch: bool) -> Self {
+        let (store, writer) = reflector::store();
+        let cache = NamespaceCache {
+            store,
+            watching: watch,
+            cluster_id: Mutex::new(None),
+        };
+
+        if !watch {
+            return cache;
+        }
+
+        let namespaces: Api<Namespace> = Api::all(client);
+
+        tokio::spawn(async move {
+            let mut stream = reflector(
+                writer,
+                watcher(namespaces, watcher::Config::default()).default_backoff(),
+            )
+            .boxed();
+
+            while let Some(result) = stream.next().await {
+                if let Err(err) = result {
+                    warn!("Failed to watch Kubernetes namespaces: {}", err);
+                }
+            }
+        });
+
+        cache
+    }
+
+    // Wait a short while for the Namespaces to be listed for the first time,
+    // so that the first runs after starting report the metadata of the
+    // namespaces. Returns whether the cache is ready.
+    pub async fn wait_until_ready(&self) -> bool {
+        if !self.watching {
+            return true;
+        }
+
+        matches!(
+            tokio::time::timeout(READY_TIMEOUT, self.store.wait_until_ready()).await,
+            Ok(Ok(()))
+        )
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<Namespace>> {
+        self.store.get(&ObjectRef::new(name))
+    }
+
+    // The UID of the kube-system namespace, read from the API when the
+    // cache has not been populated yet. It never changes, so it is only
+    // read once.
+    pub async fn cluster_id(&self, client: &kube::Client) -> Result<String, Error> {
+        if let Some(cluster_id) = self
+            .cluster_id
+            .lock()
+            .expect("Cluster ID lock poisoned")
+            .clone()
+        {
+            return Ok(cluster_id);
+        }
+
+        let namespace = match self.get(KUBE_SYSTEM_NAMESPACE) {
+            Some(namespace) => namespace,
+            None => {
+                let namespaces: Api<Namespace> = Api::all(client.clone());
+                Arc::new(namespaces.get(KUBE_SYSTEM_NAMESPACE).await?)
+            }
+        };
+
+        let cluster_id = namespace.metadata.uid.clone().unwrap_or_default();
+        *self.cluster_id.lock().expect("Cluster ID lock poisoned") = Some(cluster_id.clone());
+
+        Ok(cluster_id)
+    }
+}
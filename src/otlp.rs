@@ -0,0 +1,1081 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use log::{info, warn};
+use protobuf::{Message, ProtobufEnum, RepeatedField};
+use reqwest::{Client, Url};
+
+use crate::exposition::enum_name;
+use crate::protocol::kubernetes::{Container, ContainerStatus, KubernetesMetrics, PressureStall};
+use crate::protocol::otlp::{
+    AggregationTemporality, AnyValue, ExportMetricsServiceRequest, ExportMetricsServiceResponse,
+    InstrumentationScope, KeyValue, Metric, NumberDataPoint, Resource, ResourceMetrics,
+    ScopeMetrics,
+};
+use crate::sinks::{send_batches, with_retries, Report, Sink};
+use crate::{Error, BATCH_SIZE_THRESHOLD};
+
+const SCOPE_NAME: &str = "appsignal-kubernetes";
+const METRICS_PATH: &str = "v1/metrics";
+
+// Exports the metrics to an OpenTelemetry Collector, or another OTLP
+// receiver, over OTLP/HTTP with the protobuf encoding. The metrics are
+// named after the `k8s.*` semantic conventions where they exist, and every
+// Kubernetes object is exported as a resource with the `k8s.*` resource
+// attributes of the object, its namespace and its owners.
+pub struct OtlpExporter {
+    url: Url,
+    headers: Vec<(String, String)>,
+    start_times: Mutex<StartTimes>,
+}
+
+impl OtlpExporter {
+    // Export to the OTLP/HTTP endpoint configured with
+    // `APPSIGNAL_OTLP_ENDPOINT`, such as `http://otel-collector:4318`, with
+    // the headers configured with `APPSIGNAL_OTLP_HEADERS` as a
+    // comma-separated list of `key=value` pairs.
+    pub fn from_env() -> Option<Self> {
+        let endpoint = env::var("APPSIGNAL_OTLP_ENDPOINT")
+            .ok()
+            .filter(|endpoint| !endpoint.is_empty())?;
+
+        let url = match metrics_url(&endpoint) {
+            Ok(url) => url,
+            Err(err) => {
+                warn!("Could not parse APPSIGNAL_OTLP_ENDPOINT: {}", err);
+                return None;
+            }
+        };
+
+        let headers = env::var("APPSIGNAL_OTLP_HEADERS")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|header| header.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+
+        Some(OtlpExporter {
+            url,
+            headers,
+            start_times: Mutex::new(StartTimes::default()),
+        })
+    }
+
+    pub async fn export(
+        &self,
+        metrics: &[KubernetesMetrics],
+        client: &Client,
+    ) -> Result<(), Error> {
+        if metrics.is_empty() {
+            return Ok(());
+        }
+
+        info!("Exporting {} metrics to {}", metrics.len(), self.url);
+
+        let all_resource_metrics: Vec<ResourceMetrics> = {
+            let mut start_times = self.start_times.lock().expect("Start times lock poisoned");
+            start_times.prune(metrics);
+            metrics
+                .iter()
+                .flat_map(|metric| resource_metrics(metric, &mut start_times))
+                .collect()
+        };
+
+        send_batches(requests(all_resource_metrics), |request| async move {
+            self.send_with_retries(&request, client).await
+        })
+        .await
+    }
+
+    async fn send_with_retries(
+        &self,
+        request: &ExportMetricsServiceRequest,
+        client: &Client,
+    ) -> Result<(), Error> {
+        with_retries("export metrics over OTLP", || self.send(request, client)).await
+    }
+
+    async fn send(
+        &self,
+        request: &ExportMetricsServiceRequest,
+        client: &Client,
+    ) -> Result<(), Error> {
+        let mut builder = client
+            .post(self.url.clone())
+            .header("Content-Type", "application/x-protobuf")
+            .body(request.write_to_bytes()?);
+        for (key, value) in &self.headers {
+            builder = builder.header(key, value);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("OTLP export failed (HTTP response status: {})", status).into());
+        }
+
+        let body = response.bytes().await?;
+        if let Ok(response) = ExportMetricsServiceResponse::parse_from_bytes(&body) {
+            let partial_success = response.get_partial_success();
+            if partial_success.rejected_data_points > 0 {
+                warn!(
+                    "OTLP receiver rejected {} data points: {}",
+                    partial_success.rejected_data_points, partial_success.error_message
+                );
+            }
+        }
+
+        info!(
+            "Exported {} resources (HTTP response status: {})",
+            request.get_resource_metrics().len(),
+            status
+        );
+
+        Ok(())
+    }
+}
+
+// The metrics are exported with their cumulative counters.
+impl Sink for OtlpExporter {
+    fn name(&self) -> &'static str {
+        "OTLP"
+    }
+
+    fn deliver<'a>(
+        &'a self,
+        report: &'a Report,
+        client: &'a Client,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        self.export(&report.metrics, client).boxed()
+    }
+}
+
+impl fmt::Debug for OtlpExporter {
+    // The header values are not shown, as they often contain credentials.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OtlpExporter")
+            .field("url", &self.url.as_str())
+            .field(
+                "headers",
+                &self.headers.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+// Split the resource metrics into requests that stay under the batch size
+// threshold. A request only exceeds the threshold if the metrics of a single
+// resource exceed it.
+fn requests(all_resource_metrics: Vec<ResourceMetrics>) -> Vec<ExportMetricsServiceRequest> {
+    let mut requests = Vec::new();
+
+    let mut request = ExportMetricsServiceRequest::new();
+    for resource_metrics in all_resource_metrics {
+        request.mut_resource_metrics().push(resource_metrics);
+
+        if request.compute_size() > BATCH_SIZE_THRESHOLD && request.resource_metrics.len() > 1 {
+            // Send the resource metrics that went over the threshold with
+            // the next request.
+            let resource_metrics = request.mut_resource_metrics().pop();
+            requests.push(std::mem::take(&mut request));
+            if let Some(resource_metrics) = resource_metrics {
+                request.mut_resource_metrics().push(resource_metrics);
+            }
+        }
+    }
+
+    if !request.get_resource_metrics().is_empty() {
+        requests.push(request);
+    }
+
+    requests
+}
+
+// The URL of the metrics path of the endpoint, unless it already is one.
+fn metrics_url(endpoint: &str) -> Result<Url, Error> {
+    let endpoint = endpoint.trim_end_matches('/');
+
+    if endpoint.ends_with(METRICS_PATH) {
+        Ok(Url::parse(endpoint)?)
+    } else {
+        Ok(Url::parse(&format!("{}/{}", endpoint, METRICS_PATH))?)
+    }
+}
+
+enum Number {
+    Int(i64),
+    Double(f64),
+}
+
+impl From<i64> for Number {
+    fn from(value: i64) -> Self {
+        Number::Int(value)
+    }
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Number::Int(value.into())
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Number::Double(value)
+    }
+}
+
+// The start times of the cumulative counters of nodes, which are not
+// reported. A node's counters are considered to start when the node was
+// first seen, or when its counters were last reset because it restarted.
+#[derive(Default)]
+struct StartTimes {
+    // The start timestamp and the CPU usage counter per node name.
+    nodes: HashMap<String, (i64, i64)>,
+}
+
+impl StartTimes {
+    // The start timestamp of the counters of a node metric.
+    fn node(&mut self, metric: &KubernetesMetrics) -> i64 {
+        let cpu_usage = metric.cpu_usage_core_nano_seconds;
+        let (start_time, previous_cpu_usage) = self
+            .nodes
+            .entry(metric.node_name.clone())
+            .or_insert((metric.timestamp, cpu_usage));
+
+        if cpu_usage < *previous_cpu_usage {
+            *start_time = metric.timestamp;
+        }
+        *previous_cpu_usage = cpu_usage;
+
+        *start_time
+    }
+
+    // Forget the nodes that are not in the metrics anymore.
+    fn prune(&mut self, metrics: &[KubernetesMetrics]) {
+        self.nodes.retain(|node_name, _| {
+            metrics
+                .iter()
+                .any(|metric| metric.is_node() && &metric.node_name == node_name)
+        });
+    }
+}
+
+// The metrics of a single resource.
+struct Metrics {
+    metrics: Vec<Metric>,
+    time_unix_nano: u64,
+    start_time_unix_nano: u64,
+}
+
+impl Metrics {
+    fn new(metric: &KubernetesMetrics) -> Self {
+        Metrics {
+            metrics: Vec::new(),
+            time_unix_nano: seconds_to_nanos(metric.timestamp),
+            start_time_unix_nano: 0,
+        }
+    }
+
+    fn data_point(&self, value: Number, attributes: &[(&str, &str)]) -> NumberDataPoint {
+        let mut data_point = NumberDataPoint::new();
+        data_point.set_time_unix_nano(self.time_unix_nano);
+        data_point.set_attributes(
+            attributes
+                .iter()
+                .map(|(key, value)| key_value(key, value))
+                .collect(),
+        );
+        match value {
+            Number::Int(value) => data_point.set_as_int(value),
+            Number::Double(value) => data_point.set_as_double(value),
+        }
+        data_point
+    }
+
+    // The metric with the name, adding it if this is its first data point.
+    fn metric(&mut self, name: &str, unit: &str) -> &mut Metric {
+        match self.metrics.iter().position(|metric| metric.name == name) {
+            Some(index) => &mut self.metrics[index],
+            None => {
+                let mut metric = Metric::new();
+                metric.set_name(name.to_string());
+                metric.set_unit(unit.to_string());
+                self.metrics.push(metric);
+                self.metrics.last_mut().expect("Metric")
+            }
+        }
+    }
+
+    fn gauge(&mut self, name: &str, unit: &str, value: impl Into<Number>) {
+        self.gauge_with_attributes(name, unit, value, &[]);
+    }
+
+    fn gauge_with_attributes(
+        &mut self,
+        name: &str,
+        unit: &str,
+        value: impl Into<Number>,
+        attributes: &[(&str, &str)],
+    ) {
+        let data_point = self.data_point(value.into(), attributes);
+        self.metric(name, unit)
+            .mut_gauge()
+            .mut_data_points()
+            .push(data_point);
+    }
+
+    // A monotonic sum, for the cumulative value of a counter.
+    fn sum(
+        &mut self,
+        name: &str,
+        unit: &str,
+        value: impl Into<Number>,
+        attributes: &[(&str, &str)],
+    ) {
+        let mut data_point = self.data_point(value.into(), attributes);
+        data_point.set_start_time_unix_nano(self.start_time_unix_nano);
+
+        let sum = self.metric(name, unit).mut_sum();
+        sum.set_aggregation_temporality(AggregationTemporality::AGGREGATION_TEMPORALITY_CUMULATIVE);
+        sum.set_is_monotonic(true);
+        sum.mut_data_points().push(data_point);
+    }
+
+    // A gauge per state, with a value of 1 for the current state and 0 for
+    // the other states, as in `k8s.pod.status.phase`.
+    fn state<E: ProtobufEnum>(&mut self, name: &str, current: E, extra: &[(&str, &str)]) {
+        for &state in E::values() {
+            let state_name = enum_name(state);
+            let mut attributes = vec![(name, state_name.as_str())];
+            attributes.extend_from_slice(extra);
+
+            let value = i64::from(state.value() == current.value());
+            self.gauge_with_attributes(name, "1", value, &attributes);
+        }
+    }
+
+    fn pressure(&mut self, prefix: &str, pressure: &PressureStall) {
+        for (resource, some, full) in [
+            ("cpu", pressure.cpu_some, pressure.cpu_full),
+            ("memory", pressure.memory_some, pressure.memory_full),
+            ("io", pressure.io_some, pressure.io_full),
+        ] {
+            // The percentages are exported as ratios.
+            self.gauge(
+                &format!("{}.pressure.{}.some", prefix, resource),
+                "1",
+                some / 100.0,
+            );
+            self.gauge(
+                &format!("{}.pressure.{}.full", prefix, resource),
+                "1",
+                full / 100.0,
+            );
+        }
+    }
+
+    fn throttling(&mut self, prefix: &str, periods: i64, throttled_periods: i64, throttling: i32) {
+        if periods == 0 {
+            return;
+        }
+
+        self.gauge(&format!("{}.cpu.periods", prefix), "{period}", periods);
+        self.gauge(
+            &format!("{}.cpu.throttled_periods", prefix),
+            "{period}",
+            throttled_periods,
+        );
+        self.gauge(
+            &format!("{}.cpu.throttling", prefix),
+            "1",
+            f64::from(throttling) / 100.0,
+        );
+    }
+}
+
+// The resource metrics of a metric, and of the containers of a pod, which
+// are exported as resources of their own. Events are not exported.
+fn resource_metrics(
+    metric: &KubernetesMetrics,
+    start_times: &mut StartTimes,
+) -> Vec<ResourceMetrics> {
+    let mut attributes = Vec::new();
+    push_attribute(&mut attributes, "k8s.cluster.name", &metric.cluster_name);
+    push_attribute(&mut attributes, "k8s.cluster.uid", &metric.cluster_id);
+
+    let mut metrics = Metrics::new(metric);
+    let mut containers = Vec::new();
+
+    if metric.is_resource() {
+        resource_attributes(&mut attributes, metric);
+        add_resource(&mut metrics, metric);
+    } else if metric.is_volume() {
+        push_attribute(&mut attributes, "k8s.node.name", &metric.node_name);
+        push_attribute(&mut attributes, "k8s.namespace.name", &metric.pod_namespace);
+        push_attribute(&mut attributes, "k8s.pod.name", &metric.pod_name);
+        push_attribute(&mut attributes, "k8s.volume.name", &metric.volume_name);
+        if metric.has_persistent_volume_claim() {
+            push_attribute(
+                &mut attributes,
+                "k8s.persistentvolumeclaim.name",
+                &metric.get_persistent_volume_claim().name,
+            );
+        }
+        add_volume(&mut metrics, metric);
+    } else if metric.is_pod() {
+        pod_attributes(&mut attributes, metric);
+
+        for container in metric.get_containers() {
+            let mut container_attributes = attributes.clone();
+            push_attribute(
+                &mut container_attributes,
+                "k8s.container.name",
+                &container.name,
+            );
+
+            let mut container_metrics = Metrics::new(metric);
+            add_container(&mut container_metrics, container);
+            containers.extend(build_resource_metrics(
+                container_attributes,
+                container_metrics,
+            ));
+        }
+
+        object_attributes(&mut attributes, "pod", metric);
+        add_pod(&mut metrics, metric);
+    } else {
+        push_attribute(&mut attributes, "k8s.node.name", &metric.node_name);
+        object_attributes(&mut attributes, "node", metric);
+        metrics.start_time_unix_nano = seconds_to_nanos(start_times.node(metric));
+        add_node(&mut metrics, metric);
+    }
+
+    build_resource_metrics(attributes, metrics)
+        .into_iter()
+        .chain(containers)
+        .collect()
+}
+
+fn build_resource_metrics(attributes: Vec<KeyValue>, metrics: Metrics) -> Option<ResourceMetrics> {
+    if metrics.metrics.is_empty() {
+        return None;
+    }
+
+    let mut scope = InstrumentationScope::new();
+    scope.set_name(SCOPE_NAME.to_string());
+    scope.set_version(env!("CARGO_PKG_VERSION").to_string());
+
+    let mut scope_metrics = ScopeMetrics::new();
+    scope_metrics.set_scope(scope);
+    scope_metrics.set_metrics(RepeatedField::from_vec(metrics.metrics));
+
+    let mut resource = Resource::new();
+    resource.set_attributes(RepeatedField::from_vec(attributes));
+
+    let mut resource_metrics = ResourceMetrics::new();
+    resource_metrics.set_resource(resource);
+    resource_metrics.mut_scope_metrics().push(scope_metrics);
+
+    Some(resource_metrics)
+}
+
+fn add_node(metrics: &mut Metrics, metric: &KubernetesMetrics) {
+    metrics.gauge(
+        "k8s.node.cpu.usage",
+        "{cpu}",
+        metric.cpu_usage_nano_cores as f64 / 1e9,
+    );
+    metrics.gauge(
+        "k8s.node.memory.available",
+        "By",
+        metric.memory_available_bytes,
+    );
+    metrics.gauge("k8s.node.memory.usage", "By", metric.memory_usage_bytes);
+    metrics.gauge(
+        "k8s.node.memory.working_set",
+        "By",
+        metric.memory_working_set_bytes,
+    );
+    metrics.gauge("k8s.node.memory.rss", "By", metric.memory_rss_bytes);
+    metrics.gauge(
+        "k8s.node.filesystem.available",
+        "By",
+        metric.fs_available_bytes,
+    );
+    metrics.gauge(
+        "k8s.node.filesystem.capacity",
+        "By",
+        metric.fs_capacity_bytes,
+    );
+    metrics.gauge("k8s.node.filesystem.usage", "By", metric.fs_used_bytes);
+    metrics.gauge("k8s.node.swap.usage", "By", metric.swap_usage_bytes);
+    metrics.gauge("k8s.node.swap.available", "By", metric.swap_available_bytes);
+
+    add_counters(metrics, "k8s.node", metric);
+}
+
+fn add_pod(metrics: &mut Metrics, metric: &KubernetesMetrics) {
+    // The cumulative counters of a pod start when the pod started.
+    if metric.pod_uptime_seconds > 0 {
+        metrics.start_time_unix_nano =
+            seconds_to_nanos(metric.timestamp - metric.pod_uptime_seconds);
+    }
+
+    metrics.gauge(
+        "k8s.pod.cpu.usage",
+        "{cpu}",
+        metric.cpu_usage_nano_cores as f64 / 1e9,
+    );
+    metrics.gauge("k8s.pod.memory.usage", "By", metric.memory_usage_bytes);
+    metrics.gauge(
+        "k8s.pod.memory.working_set",
+        "By",
+        metric.memory_working_set_bytes,
+    );
+    metrics.gauge("k8s.pod.memory.rss", "By", metric.memory_rss_bytes);
+    metrics.gauge(
+        "k8s.pod.filesystem.available",
+        "By",
+        metric.ephemeral_storage_available_bytes,
+    );
+    metrics.gauge(
+        "k8s.pod.filesystem.capacity",
+        "By",
+        metric.ephemeral_storage_capacity_bytes,
+    );
+    metrics.gauge(
+        "k8s.pod.filesystem.usage",
+        "By",
+        metric.ephemeral_storage_used_bytes,
+    );
+    metrics.gauge("k8s.pod.swap.usage", "By", metric.swap_usage_bytes);
+    metrics.gauge("k8s.pod.process.count", "{process}", metric.process_count);
+    metrics.gauge(
+        "k8s.pod.restart.count",
+        "{restart}",
+        metric.pod_restart_count,
+    );
+    metrics.gauge("k8s.pod.uptime", "s", metric.pod_uptime_seconds);
+    metrics.state("k8s.pod.status.phase", metric.get_pod_phase(), &[]);
+
+    add_counters(metrics, "k8s.pod", metric);
+
+    metrics.throttling(
+        "k8s.pod",
+        metric.cpu_cfs_periods,
+        metric.cpu_cfs_throttled_periods,
+        metric.cpu_throttling,
+    );
+    if metric.has_pressure() {
+        metrics.pressure("k8s.pod", metric.get_pressure());
+    }
+}
+
+fn add_container(metrics: &mut Metrics, container: &Container) {
+    let status = container.get_status();
+    let reason = [("k8s.container.status.reason", container.reason.as_str())];
+    let extra: &[(&str, &str)] = if container.reason.is_empty() {
+        &[]
+    } else {
+        &reason
+    };
+    metrics.state("k8s.container.status.state", status, extra);
+
+    if status == ContainerStatus::CONTAINER_STATUS_TERMINATED {
+        metrics.gauge(
+            "k8s.container.status.last_terminated.exit_code",
+            "1",
+            container.exit_code,
+        );
+    }
+
+    metrics.throttling(
+        "k8s.container",
+        container.cpu_cfs_periods,
+        container.cpu_cfs_throttled_periods,
+        container.cpu_throttling,
+    );
+    if container.has_pressure() {
+        metrics.pressure("k8s.container", container.get_pressure());
+    }
+}
+
+fn add_volume(metrics: &mut Metrics, metric: &KubernetesMetrics) {
+    metrics.gauge("k8s.volume.available", "By", metric.fs_available_bytes);
+    metrics.gauge("k8s.volume.capacity", "By", metric.fs_capacity_bytes);
+    metrics.gauge("k8s.volume.usage", "By", metric.fs_used_bytes);
+    metrics.gauge("k8s.volume.inodes", "{inode}", metric.fs_inodes);
+    metrics.gauge("k8s.volume.inodes.free", "{inode}", metric.fs_inodes_free);
+    metrics.gauge("k8s.volume.inodes.used", "{inode}", metric.fs_inodes_used);
+}
+
+fn add_resource(metrics: &mut Metrics, metric: &KubernetesMetrics) {
+    let prefix = format!("k8s.{}", kind_name(&metric.get_resource().kind));
+    let prefix = prefix.as_str();
+
+    if metric.has_workload() {
+        let workload = metric.get_workload();
+        metrics.gauge(&format!("{}.pods", prefix), "{pod}", workload.pods);
+        metrics.gauge(
+            &format!("{}.running_pods", prefix),
+            "{pod}",
+            workload.pods_running,
+        );
+        metrics.gauge(
+            &format!("{}.not_ready_pods", prefix),
+            "{pod}",
+            workload.pods_not_ready,
+        );
+        metrics.gauge(
+            &format!("{}.restart.count", prefix),
+            "{restart}",
+            workload.restart_count,
+        );
+    }
+
+    if metric.has_rollout() {
+        let rollout = metric.get_rollout();
+        for (name, value) in [
+            ("desired_pods", rollout.desired_replicas),
+            ("updated_pods", rollout.updated_replicas),
+            ("ready_pods", rollout.ready_replicas),
+            ("available_pods", rollout.available_replicas),
+            ("unavailable_pods", rollout.unavailable_replicas),
+        ] {
+            metrics.gauge(&format!("{}.{}", prefix, name), "{pod}", value);
+        }
+    }
+
+    if metric.has_job() {
+        let job = metric.get_job();
+        metrics.gauge("k8s.job.active_pods", "{pod}", job.active);
+        metrics.gauge("k8s.job.successful_pods", "{pod}", job.succeeded);
+        metrics.gauge("k8s.job.failed_pods", "{pod}", job.failed);
+        metrics.gauge("k8s.job.duration", "s", job.duration_seconds);
+    }
+
+    if metric.has_cron_job() {
+        let cron_job = metric.get_cron_job();
+        metrics.gauge("k8s.cronjob.active_jobs", "{job}", cron_job.active);
+    }
+
+    if metric.has_horizontal_pod_autoscaler() {
+        let autoscaler = metric.get_horizontal_pod_autoscaler();
+        metrics.gauge("k8s.hpa.current_pods", "{pod}", autoscaler.current_replicas);
+        metrics.gauge("k8s.hpa.desired_pods", "{pod}", autoscaler.desired_replicas);
+        metrics.gauge("k8s.hpa.min_pods", "{pod}", autoscaler.min_replicas);
+        metrics.gauge("k8s.hpa.max_pods", "{pod}", autoscaler.max_replicas);
+    }
+
+    if metric.has_resource_quota() {
+        for quota_resource in metric.get_resource_quota().get_resources() {
+            let attributes = [(
+                "k8s.resourcequota.resource_name",
+                quota_resource.name.as_str(),
+            )];
+            metrics.gauge_with_attributes(
+                "k8s.resourcequota.hard_limit",
+                "1",
+                quota_resource.hard,
+                &attributes,
+            );
+            metrics.gauge_with_attributes(
+                "k8s.resourcequota.used",
+                "1",
+                quota_resource.used,
+                &attributes,
+            );
+        }
+    }
+
+    if metric.has_persistent_volume_claim() {
+        let claim = metric.get_persistent_volume_claim();
+        metrics.gauge(
+            "k8s.persistentvolumeclaim.requested",
+            "By",
+            claim.requested_bytes,
+        );
+        metrics.gauge(
+            "k8s.persistentvolumeclaim.capacity",
+            "By",
+            claim.capacity_bytes,
+        );
+        metrics.state("k8s.persistentvolumeclaim.phase", claim.get_phase(), &[]);
+    }
+
+    if metric.has_persistent_volume() {
+        let volume = metric.get_persistent_volume();
+        metrics.gauge("k8s.persistentvolume.capacity", "By", volume.capacity_bytes);
+        metrics.state("k8s.persistentvolume.phase", volume.get_phase(), &[]);
+    }
+
+    if metric.has_service() {
+        let service = metric.get_service();
+        for (condition, value) in [
+            ("ready", service.ready_endpoints),
+            ("not_ready", service.not_ready_endpoints),
+            ("terminating", service.terminating_endpoints),
+        ] {
+            metrics.gauge_with_attributes(
+                "k8s.service.endpoint.count",
+                "{endpoint}",
+                value,
+                &[("k8s.service.endpoint.condition", condition)],
+            );
+        }
+    }
+
+    if metric.has_certificate() {
+        let certificate = metric.get_certificate();
+        metrics.gauge("k8s.certificate.not_after", "s", certificate.not_after);
+        metrics.gauge(
+            "k8s.certificate.days_until_expiry",
+            "d",
+            certificate.days_until_expiry,
+        );
+    }
+}
+
+// The cumulative CPU, page fault and network counters of nodes and pods.
+fn add_counters(metrics: &mut Metrics, prefix: &str, metric: &KubernetesMetrics) {
+    metrics.sum(
+        &format!("{}.cpu.time", prefix),
+        "s",
+        metric.cpu_usage_core_nano_seconds as f64 / 1e9,
+        &[],
+    );
+
+    let page_faults = format!("{}.memory.page_faults", prefix);
+    metrics.sum(
+        &page_faults,
+        "{fault}",
+        metric.memory_page_faults,
+        &[("type", "minor")],
+    );
+    metrics.sum(
+        &page_faults,
+        "{fault}",
+        metric.memory_major_page_faults,
+        &[("type", "major")],
+    );
+
+    let network_io = format!("{}.network.io", prefix);
+    let network_errors = format!("{}.network.errors", prefix);
+    let receive = [("network.io.direction", "receive")];
+    let transmit = [("network.io.direction", "transmit")];
+    metrics.sum(&network_io, "By", metric.network_rx_bytes, &receive);
+    metrics.sum(&network_io, "By", metric.network_tx_bytes, &transmit);
+    metrics.sum(
+        &network_errors,
+        "{error}",
+        metric.network_rx_errors,
+        &receive,
+    );
+    metrics.sum(
+        &network_errors,
+        "{error}",
+        metric.network_tx_errors,
+        &transmit,
+    );
+}
+
+fn pod_attributes(attributes: &mut Vec<KeyValue>, metric: &KubernetesMetrics) {
+    push_attribute(attributes, "k8s.node.name", &metric.node_name);
+    push_attribute(attributes, "k8s.namespace.name", &metric.pod_namespace);
+    push_attribute(attributes, "k8s.pod.name", &metric.pod_name);
+    push_attribute(attributes, "k8s.pod.uid", &metric.pod_uuid);
+    owner_attributes(attributes, metric);
+}
+
+fn resource_attributes(attributes: &mut Vec<KeyValue>, metric: &KubernetesMetrics) {
+    let resource = metric.get_resource();
+    let kind = kind_name(&resource.kind);
+
+    push_attribute(attributes, "k8s.namespace.name", &resource.namespace);
+    push_attribute(attributes, &format!("k8s.{}.name", kind), &resource.name);
+    owner_attributes(attributes, metric);
+    object_attributes(attributes, &kind, metric);
+}
+
+// The names of the owners of an object, such as `k8s.deployment.name` for
+// a pod that is owned by a Deployment through a ReplicaSet.
+fn owner_attributes(attributes: &mut Vec<KeyValue>, metric: &KubernetesMetrics) {
+    for owner_reference in metric.get_owner_references() {
+        push_attribute(
+            attributes,
+            &format!("k8s.{}.name", kind_name(&owner_reference.kind)),
+            &owner_reference.name,
+        );
+    }
+}
+
+// The labels and annotations of an object, such as `k8s.pod.label.app`.
+fn object_attributes(attributes: &mut Vec<KeyValue>, kind: &str, metric: &KubernetesMetrics) {
+    let mut labels: Vec<_> = metric.labels.iter().collect();
+    labels.sort();
+    for (key, value) in labels {
+        push_attribute(attributes, &format!("k8s.{}.label.{}", kind, key), value);
+    }
+
+    let mut annotations: Vec<_> = metric.annotations.iter().collect();
+    annotations.sort();
+    for (key, value) in annotations {
+        push_attribute(
+            attributes,
+            &format!("k8s.{}.annotation.{}", kind, key),
+            value,
+        );
+    }
+}
+
+// The name of a kind in the semantic conventions, such as `replicaset`.
+fn kind_name(kind: &str) -> String {
+    match kind {
+        "HorizontalPodAutoscaler" => "hpa".to_string(),
+        _ => kind.to_lowercase(),
+    }
+}
+
+fn push_attribute(attributes: &mut Vec<KeyValue>, key: &str, value: &str) {
+    if !value.is_empty() {
+        attributes.push(key_value(key, value));
+    }
+}
+
+fn key_value(key: &str, value: &str) -> KeyValue {
+    let mut any_value = AnyValue::new();
+    any_value.set_string_value(value.to_string());
+
+    let mut key_value = KeyValue::new();
+    key_value.set_key(key.to_string());
+    key_value.set_value(any_value);
+    key_value
+}
+
+fn seconds_to_nanos(seconds: i64) -> u64 {
+    u64::try_from(seconds).unwrap_or_default() * 1_000_000_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{metrics_url, requests, resource_metrics, StartTimes};
+    use crate::protocol::kubernetes::{
+        Container, ContainerStatus, KubernetesMetrics, OwnerReference, PodPhase,
+    };
+    use crate::protocol::otlp::{AggregationTemporality, KeyValue, ResourceMetrics};
+    use crate::sinks::send_batches;
+    use crate::BATCH_SIZE_THRESHOLD;
+
+    fn attributes(resource_metrics: &ResourceMetrics) -> Vec<(String, String)> {
+        resource_metrics
+            .get_resource()
+            .get_attributes()
+            .iter()
+            .map(|attribute: &KeyValue| {
+                (
+                    attribute.key.clone(),
+                    attribute.get_value().get_string_value().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn attribute(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn export_pod_with_semantic_conventions() {
+        let mut pod = KubernetesMetrics::new();
+        pod.set_timestamp(1735732800);
+        pod.set_cluster_name("production".to_string());
+        pod.set_node_name("node-1".to_string());
+        pod.set_pod_name("web-1".to_string());
+        pod.set_pod_namespace("shop".to_string());
+        pod.set_pod_uuid("uid-1".to_string());
+        pod.set_pod_uptime_seconds(600);
+        pod.set_pod_phase(PodPhase::POD_PHASE_RUNNING);
+        pod.set_cpu_usage_nano_cores(250_000_000);
+        pod.set_network_rx_bytes(2048);
+        pod.mut_labels()
+            .insert("app".to_string(), "web".to_string());
+
+        for (kind, name) in [("ReplicaSet", "web-abc"), ("Deployment", "web")] {
+            let mut owner_reference = OwnerReference::new();
+            owner_reference.set_kind(kind.to_string());
+            owner_reference.set_name(name.to_string());
+            pod.mut_owner_references().push(owner_reference);
+        }
+
+        let mut container = Container::new();
+        container.set_name("web".to_string());
+        container.set_status(ContainerStatus::CONTAINER_STATUS_RUNNING);
+        pod.mut_containers().push(container);
+
+        let exported = resource_metrics(&pod, &mut StartTimes::default());
+        assert_eq!(2, exported.len());
+
+        assert_eq!(
+            vec![
+                attribute("k8s.cluster.name", "production"),
+                attribute("k8s.node.name", "node-1"),
+                attribute("k8s.namespace.name", "shop"),
+                attribute("k8s.pod.name", "web-1"),
+                attribute("k8s.pod.uid", "uid-1"),
+                attribute("k8s.replicaset.name", "web-abc"),
+                attribute("k8s.deployment.name", "web"),
+                attribute("k8s.pod.label.app", "web"),
+            ],
+            attributes(&exported[0])
+        );
+
+        let metrics = exported[0].get_scope_metrics()[0].get_metrics();
+        let metric = |name: &str| {
+            metrics
+                .iter()
+                .find(|metric| metric.name == name)
+                .unwrap_or_else(|| panic!("No metric {}", name))
+        };
+
+        let cpu_usage = &metric("k8s.pod.cpu.usage").get_gauge().get_data_points()[0];
+        assert_eq!(0.25, cpu_usage.get_as_double());
+        assert_eq!(1_735_732_800_000_000_000, cpu_usage.time_unix_nano);
+
+        let network_io = metric("k8s.pod.network.io").get_sum();
+        assert_eq!(
+            AggregationTemporality::AGGREGATION_TEMPORALITY_CUMULATIVE,
+            network_io.aggregation_temporality
+        );
+        assert!(network_io.is_monotonic);
+        assert_eq!(2, network_io.get_data_points().len());
+        assert_eq!(2048, network_io.get_data_points()[0].get_as_int());
+        assert_eq!(
+            1_735_732_200_000_000_000,
+            network_io.get_data_points()[0].start_time_unix_nano
+        );
+
+        let phases = metric("k8s.pod.status.phase").get_gauge().get_data_points();
+        let running = phases
+            .iter()
+            .find(|data_point| {
+                data_point.get_attributes()[0]
+                    .get_value()
+                    .get_string_value()
+                    == "running"
+            })
+            .unwrap();
+        assert_eq!(1, running.get_as_int());
+        assert_eq!(5, phases.len());
+
+        assert_eq!(
+            attribute("k8s.container.name", "web"),
+            attributes(&exported[1]).pop().unwrap()
+        );
+        assert_eq!(
+            "k8s.container.status.state",
+            exported[1].get_scope_metrics()[0].get_metrics()[0].name
+        );
+    }
+
+    #[test]
+    fn node_counters_start_when_first_seen() {
+        let node = |timestamp: i64, cpu_usage: i64| {
+            let mut node = KubernetesMetrics::new();
+            node.set_timestamp(timestamp);
+            node.set_node_name("node-1".to_string());
+            node.set_cpu_usage_core_nano_seconds(cpu_usage);
+            node
+        };
+        let start_time = |exported: &[ResourceMetrics]| {
+            exported[0].get_scope_metrics()[0]
+                .get_metrics()
+                .iter()
+                .find(|metric| metric.name == "k8s.node.cpu.time")
+                .unwrap()
+                .get_sum()
+                .get_data_points()[0]
+                .start_time_unix_nano
+        };
+
+        let mut start_times = StartTimes::default();
+        let exported = resource_metrics(&node(1000, 5_000_000_000), &mut start_times);
+        assert_eq!(1_000_000_000_000, start_time(&exported));
+
+        let exported = resource_metrics(&node(1060, 6_000_000_000), &mut start_times);
+        assert_eq!(1_000_000_000_000, start_time(&exported));
+
+        // The node restarted, which reset its counters.
+        let exported = resource_metrics(&node(1120, 1_000_000_000), &mut start_times);
+        assert_eq!(1_120_000_000_000, start_time(&exported));
+
+        start_times.prune(&[]);
+        let exported = resource_metrics(&node(1180, 2_000_000_000), &mut start_times);
+        assert_eq!(1_180_000_000_000, start_time(&exported));
+    }
+
+    #[test]
+    fn export_remaining_requests_after_a_failure() {
+        let size = BATCH_SIZE_THRESHOLD as usize / 2;
+        let all_resource_metrics = ["node-1", "node-2", "node-3"]
+            .iter()
+            .flat_map(|node_name| {
+                let mut node = KubernetesMetrics::new();
+                node.set_node_name(node_name.to_string());
+                node.mut_labels()
+                    .insert("padding".to_string(), "x".repeat(size));
+                resource_metrics(&node, &mut StartTimes::default())
+            })
+            .collect();
+
+        let requests = requests(all_resource_metrics);
+        assert_eq!(3, requests.len());
+
+        let mut exported = Vec::new();
+        let result = futures::executor::block_on(send_batches(requests, |request| {
+            let node_name = attributes(&request.get_resource_metrics()[0])[0].1.clone();
+            exported.push(node_name.clone());
+            async move {
+                if node_name == "node-1" {
+                    Err("OTLP export failed (HTTP response status: 503)".into())
+                } else {
+                    Ok(())
+                }
+            }
+        }));
+
+        assert_eq!(vec!["node-1", "node-2", "node-3"], exported);
+        assert_eq!(
+            "1 of 3 batches failed to send",
+            result.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn skip_events() {
+        let mut event = KubernetesMetrics::new();
+        event.mut_resource().set_kind("Pod".to_string());
+        event.mut_event().set_reason("BackOff".to_string());
+
+        assert!(resource_metrics(&event, &mut StartTimes::default()).is_empty());
+    }
+
+    #[test]
+    fn build_metrics_url() {
+        assert_eq!(
+            "http://otel-collector:4318/v1/metrics",
+            metrics_url("http://otel-collector:4318").unwrap().as_str()
+        );
+        assert_eq!(
+            "http://otel-collector:4318/v1/metrics",
+            metrics_url("http://otel-collector:4318/v1/metrics/")
+                .unwrap()
+                .as_str()
+        );
+        assert!(metrics_url("otel-collector").is_err());
+    }
+}
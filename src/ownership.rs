@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
 use crate::Error;
-use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::autoscaling::v2::CrossVersionObjectReference;
+use k8s_openapi::api::core::v1::{ObjectReference, Pod};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
 use k8s_openapi::Resource;
-use kube::api::{ApiResource, DynamicObject, GroupVersionKind};
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind, ListParams};
+use kube::core::GroupVersion;
 use kube::discovery::ApiCapabilities;
 use kube::{Api, ResourceExt};
 use log::{trace, warn};
@@ -61,6 +63,43 @@ impl ResourceIdentifier {
             namespace: pod.namespace(),
         }
     }
+
+    pub fn from_object_reference(object_reference: &ObjectReference) -> Option<Self> {
+        let group_version: GroupVersion = object_reference.api_version.as_deref()?.parse().ok()?;
+
+        Some(ResourceIdentifier {
+            gvk: group_version.with_kind(object_reference.kind.as_deref()?),
+            name: object_reference.name.clone()?,
+            namespace: object_reference.namespace.clone(),
+        })
+    }
+
+    // Cross-version object references, such as the scale target of a
+    // HorizontalPodAutoscaler, always refer to an object in the namespace
+    // of the object that references it.
+    pub fn from_cross_version_object_reference(
+        object_reference: &CrossVersionObjectReference,
+        namespace: Option<String>,
+    ) -> Option<Self> {
+        let group_version: GroupVersion = object_reference.api_version.as_deref()?.parse().ok()?;
+
+        Some(ResourceIdentifier {
+            gvk: group_version.with_kind(&object_reference.kind),
+            name: object_reference.name.clone(),
+            namespace,
+        })
+    }
+
+    pub fn from_object<K>(object: &K) -> Self
+    where
+        K: Resource + kube::Resource,
+    {
+        ResourceIdentifier {
+            gvk: GroupVersionKind::gvk(K::GROUP, K::VERSION, K::KIND),
+            name: object.name_any(),
+            namespace: object.namespace(),
+        }
+    }
 }
 
 type OwnerCache = std::collections::HashMap<ResourceIdentifier, HashSet<ResourceIdentifier>>;
@@ -128,6 +167,39 @@ impl OwnershipResolver {
         }
     }
 
+    // Use the discovery cache to list all objects of a GroupVersionKind in
+    // all namespaces. Returns `None` if the API is not available in the
+    // cluster, such as when the custom resource that defines it is not
+    // installed.
+    pub async fn list_objects(
+        &mut self,
+        gvk: &GroupVersionKind,
+    ) -> Result<Option<Vec<DynamicObject>>, Error> {
+        // Unlike `resolve_gvk`, an unavailable API is expected here, so only
+        // run the discovery process if it has not run since the last reset.
+        if self.should_discover
+            && self
+                .discovery
+                .as_ref()
+                .and_then(|d| d.resolve_gvk(gvk))
+                .is_none()
+        {
+            self.discover().await?;
+        }
+
+        let api_resource = match self.discovery.as_ref().and_then(|d| d.resolve_gvk(gvk)) {
+            Some((api_resource, _)) => api_resource,
+            None => {
+                trace!("Skipping unavailable GroupVersionKind {:?}", gvk);
+                return Ok(None);
+            }
+        };
+
+        let api: Api<DynamicObject> = Api::all_with(self.client.clone(), &api_resource);
+
+        Ok(Some(api.list(&ListParams::default()).await?.items))
+    }
+
     // Use the discovery cache to resolve an API for a given GroupVersionKind
     // and namespace.
     async fn resolve_api(
@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+// A sample in the Prometheus text exposition format, such as:
+//
+//     container_cpu_usage_seconds_total{container="web",namespace="shop",pod="web-1"} 12.5 1735732800000
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+    // The timestamp of the sample in milliseconds, if it has one.
+    pub timestamp: Option<i64>,
+}
+
+impl Sample {
+    pub fn label(&self, name: &str) -> &str {
+        self.labels
+            .get(name)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+// Parse the samples in the Prometheus text exposition format. Comments,
+// such as the `# HELP` and `# TYPE` lines, and invalid lines are skipped.
+pub fn parse(text: &str) -> Vec<Sample> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<Sample> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let name = &line[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    let (labels, rest) = if line[name_end..].starts_with('{') {
+        parse_labels(&line[name_end + 1..])?
+    } else {
+        (BTreeMap::new(), &line[name_end..])
+    };
+
+    let mut fields = rest.split_whitespace();
+    let value = parse_value(fields.next()?)?;
+    let timestamp = match fields.next() {
+        Some(timestamp) => Some(timestamp.parse().ok()?),
+        None => None,
+    };
+
+    Some(Sample {
+        name: name.to_string(),
+        labels,
+        value,
+        timestamp,
+    })
+}
+
+// Parse the labels after the opening brace, returning the labels and the
+// remainder of the line after the closing brace.
+fn parse_labels(mut input: &str) -> Option<(BTreeMap<String, String>, &str)> {
+    let mut labels = BTreeMap::new();
+
+    loop {
+        input = input.trim_start();
+
+        if let Some(rest) = input.strip_prefix('}') {
+            return Some((labels, rest));
+        }
+
+        let (name, rest) = input.split_once('=')?;
+        let rest = rest.trim_start().strip_prefix('"')?;
+
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        let end = loop {
+            match chars.next()? {
+                (index, '"') => break index,
+                (_, '\\') => match chars.next()? {
+                    (_, 'n') => value.push('\n'),
+                    (_, c) => value.push(c),
+                },
+                (_, c) => value.push(c),
+            }
+        };
+
+        labels.insert(name.trim().to_string(), value);
+
+        input = rest[end + 1..].trim_start();
+        input = input.strip_prefix(',').unwrap_or(input);
+    }
+}
+
+fn parse_value(value: &str) -> Option<f64> {
+    match value {
+        "+Inf" => Some(f64::INFINITY),
+        "-Inf" => Some(f64::NEG_INFINITY),
+        _ => value.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn parse_samples() {
+        let samples = parse(
+            r#"
+# HELP node_cpu_usage_seconds_total [STABLE] Cumulative cpu time consumed by the node in core-seconds
+# TYPE node_cpu_usage_seconds_total counter
+node_cpu_usage_seconds_total 357.35491 1735732800000
+container_memory_working_set_bytes{container="web",namespace="shop",pod="web-1"} 1.2288e+07 1735732801000
+scrape_error 0
+label_escapes{path="C:\\dir",quote="say \"hi\"",newline="a\nb",} +Inf
+not a sample
+"#,
+        );
+
+        assert_eq!(4, samples.len());
+
+        assert_eq!("node_cpu_usage_seconds_total", samples[0].name);
+        assert!(samples[0].labels.is_empty());
+        assert_eq!(357.35491, samples[0].value);
+        assert_eq!(Some(1735732800000), samples[0].timestamp);
+
+        assert_eq!("web-1", samples[1].label("pod"));
+        assert_eq!("shop", samples[1].label("namespace"));
+        assert_eq!(12288000.0, samples[1].value);
+
+        assert_eq!(None, samples[2].timestamp);
+
+        assert_eq!("C:\\dir", samples[3].label("path"));
+        assert_eq!("say \"hi\"", samples[3].label("quote"));
+        assert_eq!("a\nb", samples[3].label("newline"));
+        assert_eq!(f64::INFINITY, samples[3].value);
+        assert_eq!("", samples[3].label("missing"));
+    }
+}
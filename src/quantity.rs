@@ -0,0 +1,81 @@
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+// Parse a Kubernetes resource quantity, such as "10Gi", "500m" or "1e3",
+// into its numeric value.
+pub fn parse_quantity(quantity: &Quantity) -> Option<f64> {
+    let quantity = quantity.0.trim();
+
+    let suffix_start = quantity
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '+' || c == '-'))
+        .unwrap_or(quantity.len());
+    let (number, suffix) = quantity.split_at(suffix_start);
+    let number: f64 = number.parse().ok()?;
+
+    // Divide by the fractional suffixes, rather than multiplying by their
+    // inverse, to avoid rounding errors in values such as "1200m".
+    let multiplier = match suffix {
+        "" => 1.0,
+        "n" => return Some(number / 1e9),
+        "u" => return Some(number / 1e6),
+        "m" => return Some(number / 1e3),
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024_f64,
+        "Mi" => 1024_f64.powi(2),
+        "Gi" => 1024_f64.powi(3),
+        "Ti" => 1024_f64.powi(4),
+        "Pi" => 1024_f64.powi(5),
+        "Ei" => 1024_f64.powi(6),
+        exponent if exponent.starts_with(['e', 'E']) => {
+            let exponent: i32 = exponent[1..].parse().ok()?;
+            if exponent < 0 {
+                return Some(number / 10_f64.powi(-exponent));
+            }
+            10_f64.powi(exponent)
+        }
+        _ => return None,
+    };
+
+    Some(number * multiplier)
+}
+
+// Parse a Kubernetes resource quantity into a whole number of bytes.
+pub fn parse_bytes(quantity: &Quantity) -> Option<i64> {
+    parse_quantity(quantity).map(|bytes| bytes.round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bytes, parse_quantity};
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    fn quantity(value: &str) -> Quantity {
+        Quantity(value.to_string())
+    }
+
+    #[test]
+    fn parse_quantities_with_suffixes() {
+        assert_eq!(Some(10.0), parse_quantity(&quantity("10")));
+        assert_eq!(Some(0.5), parse_quantity(&quantity("500m")));
+        assert_eq!(Some(1500.0), parse_quantity(&quantity("1.5k")));
+        assert_eq!(Some(2e9), parse_quantity(&quantity("2G")));
+        assert_eq!(Some(1e3), parse_quantity(&quantity("1e3")));
+        assert_eq!(Some(3e18), parse_quantity(&quantity("3E")));
+        assert_eq!(None, parse_quantity(&quantity("10Xi")));
+        assert_eq!(None, parse_quantity(&quantity("")));
+    }
+
+    #[test]
+    fn parse_binary_quantities_as_bytes() {
+        assert_eq!(Some(1024), parse_bytes(&quantity("1Ki")));
+        assert_eq!(
+            Some(10 * 1024 * 1024 * 1024),
+            parse_bytes(&quantity("10Gi"))
+        );
+        assert_eq!(Some(1536 * 1024 * 1024), parse_bytes(&quantity("1.5Gi")));
+    }
+}
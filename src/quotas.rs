@@ -0,0 +1,279 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use k8s_openapi::api::core::v1::{LimitRange, LimitRangeItem, ResourceQuota};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::ListParams;
+use kube::Api;
+
+use crate::ownership::ResourceIdentifier;
+use crate::protocol::kubernetes::{
+    KubernetesMetrics, LimitRangeLimit, LimitRangeStatus, ResourceQuotaResource,
+    ResourceQuotaStatus, ResourceReference,
+};
+use crate::quantity::parse_quantity;
+use crate::Error;
+
+// Extract the usage of every ResourceQuota in the cluster, and the
+// constraints of every LimitRange.
+pub async fn extract_quotas(client: &kube::Client) -> Result<Vec<KubernetesMetrics>, Error> {
+    let mut metrics = Vec::new();
+
+    let resource_quotas: Api<ResourceQuota> = Api::all(client.clone());
+    for resource_quota in resource_quotas.list(&ListParams::default()).await? {
+        metrics.push(KubernetesMetrics::from_resource_quota(&resource_quota));
+    }
+
+    let limit_ranges: Api<LimitRange> = Api::all(client.clone());
+    for limit_range in limit_ranges.list(&ListParams::default()).await? {
+        metrics.push(KubernetesMetrics::from_limit_range(&limit_range));
+    }
+
+    Ok(metrics)
+}
+
+impl KubernetesMetrics {
+    pub fn from_resource_quota(resource_quota: &ResourceQuota) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(resource_quota),
+        ));
+
+        let mut status = ResourceQuotaStatus::new();
+
+        // The hard limits in the status are those currently enforced, which
+        // may lag behind the spec shortly after the quota is changed.
+        let hard = resource_quota
+            .status
+            .as_ref()
+            .and_then(|status| status.hard.as_ref())
+            .or(resource_quota
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.hard.as_ref()));
+        let used = resource_quota
+            .status
+            .as_ref()
+            .and_then(|status| status.used.as_ref());
+
+        for (name, hard_quantity) in hard.into_iter().flatten() {
+            let hard_value = match parse_quantity(hard_quantity) {
+                Some(hard_value) => hard_value,
+                None => continue,
+            };
+            let used_value = used
+                .and_then(|used| used.get(name))
+                .and_then(parse_quantity)
+                .unwrap_or_default();
+
+            let mut resource = ResourceQuotaResource::new();
+            resource.set_name(name.clone());
+            resource.set_hard(hard_value);
+            resource.set_used(used_value);
+
+            // A hard limit of zero forbids the resource entirely, so it is
+            // only fully used once anything is used.
+            let usage = if hard_value > 0.0 {
+                KubernetesMetrics::percentage_from(used_value, hard_value)
+            } else if used_value > 0.0 {
+                100
+            } else {
+                0
+            };
+            resource.set_usage(usage);
+
+            status.set_max_usage(status.get_max_usage().max(usage));
+            status.mut_resources().push(resource);
+        }
+
+        metric.set_resource_quota(status);
+
+        metric
+    }
+
+    pub fn from_limit_range(limit_range: &LimitRange) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(limit_range),
+        ));
+
+        let mut status = LimitRangeStatus::new();
+
+        for item in limit_range
+            .spec
+            .as_ref()
+            .map(|spec| spec.limits.as_slice())
+            .unwrap_or_default()
+        {
+            for limit in limit_range_limits(item) {
+                status.mut_limits().push(limit);
+            }
+        }
+
+        metric.set_limit_range(status);
+
+        metric
+    }
+
+    pub fn is_resource_quota(&self) -> bool {
+        self.has_resource_quota()
+    }
+
+    pub fn is_limit_range(&self) -> bool {
+        self.has_limit_range()
+    }
+}
+
+// Flatten a LimitRange item, which sets constraints for each resource
+// separately, into a limit for each resource.
+fn limit_range_limits(item: &LimitRangeItem) -> Vec<LimitRangeLimit> {
+    let constraints = [
+        &item.min,
+        &item.max,
+        &item.default,
+        &item.default_request,
+        &item.max_limit_request_ratio,
+    ];
+
+    let resources: BTreeSet<&String> = constraints
+        .iter()
+        .filter_map(|constraint| constraint.as_ref())
+        .flat_map(|constraint| constraint.keys())
+        .collect();
+
+    resources
+        .into_iter()
+        .map(|resource| {
+            let mut limit = LimitRangeLimit::new();
+            limit.set_field_type(item.type_.clone());
+            limit.set_resource(resource.clone());
+
+            if let Some(min) = constraint_value(&item.min, resource) {
+                limit.set_min(min);
+            }
+            if let Some(max) = constraint_value(&item.max, resource) {
+                limit.set_max(max);
+            }
+            if let Some(default_limit) = constraint_value(&item.default, resource) {
+                limit.set_default_limit(default_limit);
+            }
+            if let Some(default_request) = constraint_value(&item.default_request, resource) {
+                limit.set_default_request(default_request);
+            }
+            if let Some(ratio) = constraint_value(&item.max_limit_request_ratio, resource) {
+                limit.set_max_limit_request_ratio(ratio);
+            }
+
+            limit
+        })
+        .collect()
+}
+
+fn constraint_value(
+    constraint: &Option<BTreeMap<String, Quantity>>,
+    resource: &str,
+) -> Option<f64> {
+    constraint
+        .as_ref()
+        .and_then(|constraint| constraint.get(resource))
+        .and_then(parse_quantity)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::kubernetes::KubernetesMetrics;
+    use k8s_openapi::api::core::v1::{
+        LimitRange, LimitRangeItem, LimitRangeSpec, ResourceQuota,
+        ResourceQuotaStatus as KubernetesStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use std::collections::BTreeMap;
+
+    fn quantities(values: &[(&str, &str)]) -> Option<BTreeMap<String, Quantity>> {
+        Some(
+            values
+                .iter()
+                .map(|(name, value)| (name.to_string(), Quantity(value.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn resource_quota_metric_with_usage() {
+        let mut resource_quota = ResourceQuota::default();
+        resource_quota.metadata.name = Some("compute".to_string());
+        resource_quota.metadata.namespace = Some("tenant-a".to_string());
+        resource_quota.status = Some(KubernetesStatus {
+            hard: quantities(&[
+                ("pods", "10"),
+                ("requests.cpu", "4"),
+                ("requests.memory", "8Gi"),
+                ("services.loadbalancers", "0"),
+            ]),
+            used: quantities(&[
+                ("pods", "9"),
+                ("requests.cpu", "1500m"),
+                ("requests.memory", "2Gi"),
+                ("services.loadbalancers", "0"),
+            ]),
+        });
+
+        let metric = KubernetesMetrics::from_resource_quota(&resource_quota);
+
+        assert!(metric.is_resource_quota());
+        assert!(metric.is_resource());
+        assert_eq!("ResourceQuota", metric.get_resource().get_kind());
+        assert_eq!("tenant-a", metric.get_resource().get_namespace());
+
+        let status = metric.get_resource_quota();
+        assert_eq!(90, status.get_max_usage());
+
+        let resources = status.get_resources();
+        assert_eq!(4, resources.len());
+
+        assert_eq!("pods", resources[0].get_name());
+        assert_eq!(10.0, resources[0].get_hard());
+        assert_eq!(9.0, resources[0].get_used());
+        assert_eq!(90, resources[0].get_usage());
+
+        assert_eq!("requests.cpu", resources[1].get_name());
+        assert_eq!(1.5, resources[1].get_used());
+        assert_eq!(38, resources[1].get_usage());
+
+        assert_eq!("requests.memory", resources[2].get_name());
+        assert_eq!(25, resources[2].get_usage());
+
+        assert_eq!("services.loadbalancers", resources[3].get_name());
+        assert_eq!(0, resources[3].get_usage());
+    }
+
+    #[test]
+    fn limit_range_metric_by_resource() {
+        let limit_range = LimitRange {
+            spec: Some(LimitRangeSpec {
+                limits: vec![LimitRangeItem {
+                    type_: "Container".to_string(),
+                    default: quantities(&[("cpu", "500m"), ("memory", "512Mi")]),
+                    default_request: quantities(&[("cpu", "100m")]),
+                    max: quantities(&[("memory", "1Gi")]),
+                    ..Default::default()
+                }],
+            }),
+            ..Default::default()
+        };
+
+        let metric = KubernetesMetrics::from_limit_range(&limit_range);
+
+        assert!(metric.is_limit_range());
+
+        let limits = metric.get_limit_range().get_limits();
+        assert_eq!(2, limits.len());
+
+        assert_eq!("Container", limits[0].get_field_type());
+        assert_eq!("cpu", limits[0].get_resource());
+        assert_eq!(0.5, limits[0].get_default_limit());
+        assert_eq!(0.1, limits[0].get_default_request());
+        assert_eq!(0.0, limits[0].get_max());
+
+        assert_eq!("memory", limits[1].get_resource());
+        assert_eq!(512.0 * 1024.0 * 1024.0, limits[1].get_default_limit());
+        assert_eq!(1024.0 * 1024.0 * 1024.0, limits[1].get_max());
+    }
+}
@@ -0,0 +1,285 @@
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use kube::api::ListParams;
+use kube::Api;
+
+use crate::ownership::ResourceIdentifier;
+use crate::protocol::kubernetes::{ConditionStatus, RolloutStatus};
+use crate::workloads::WorkloadAggregator;
+use crate::Error;
+
+// Extract the rollout status of every Deployment, StatefulSet, DaemonSet
+// and ReplicaSet in the cluster, and add it to its workload metric.
+pub async fn extract_rollouts(
+    client: &kube::Client,
+    workloads: &mut WorkloadAggregator,
+) -> Result<(), Error> {
+    let deployments: Api<Deployment> = Api::all(client.clone());
+    for deployment in deployments.list(&ListParams::default()).await? {
+        workloads.add_rollout(
+            &ResourceIdentifier::from_object(&deployment),
+            RolloutStatus::from_deployment(&deployment),
+        );
+    }
+
+    let stateful_sets: Api<StatefulSet> = Api::all(client.clone());
+    for stateful_set in stateful_sets.list(&ListParams::default()).await? {
+        workloads.add_rollout(
+            &ResourceIdentifier::from_object(&stateful_set),
+            RolloutStatus::from_stateful_set(&stateful_set),
+        );
+    }
+
+    let daemon_sets: Api<DaemonSet> = Api::all(client.clone());
+    for daemon_set in daemon_sets.list(&ListParams::default()).await? {
+        workloads.add_rollout(
+            &ResourceIdentifier::from_object(&daemon_set),
+            RolloutStatus::from_daemon_set(&daemon_set),
+        );
+    }
+
+    let replica_sets: Api<ReplicaSet> = Api::all(client.clone());
+    for replica_set in replica_sets.list(&ListParams::default()).await? {
+        let rollout = RolloutStatus::from_replica_set(&replica_set);
+
+        // Deployments keep their previous ReplicaSets around, scaled down
+        // to zero, as their rollout history. Skip those.
+        if rollout.get_desired_replicas() == 0 && rollout.get_ready_replicas() == 0 {
+            continue;
+        }
+
+        workloads.add_rollout(&ResourceIdentifier::from_object(&replica_set), rollout);
+    }
+
+    Ok(())
+}
+
+impl RolloutStatus {
+    pub fn from_deployment(deployment: &Deployment) -> RolloutStatus {
+        let mut rollout = RolloutStatus::new();
+
+        let desired_replicas = deployment
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+        rollout.set_desired_replicas(desired_replicas);
+
+        if let Some(generation) = deployment.metadata.generation {
+            rollout.set_generation(generation);
+        }
+
+        if let Some(status) = &deployment.status {
+            rollout.set_updated_replicas(status.updated_replicas.unwrap_or_default());
+            rollout.set_ready_replicas(status.ready_replicas.unwrap_or_default());
+            rollout.set_available_replicas(status.available_replicas.unwrap_or_default());
+            rollout.set_unavailable_replicas(status.unavailable_replicas.unwrap_or_default());
+            rollout.set_observed_generation(status.observed_generation.unwrap_or_default());
+
+            if let Some(progressing) = status
+                .conditions
+                .iter()
+                .flatten()
+                .find(|condition| condition.type_ == "Progressing")
+            {
+                rollout.set_progressing(ConditionStatus::from(progressing.status.as_str()));
+                if let Some(reason) = &progressing.reason {
+                    rollout.set_progressing_reason(reason.clone());
+                }
+            }
+        }
+
+        rollout
+    }
+
+    pub fn from_stateful_set(stateful_set: &StatefulSet) -> RolloutStatus {
+        let mut rollout = RolloutStatus::new();
+
+        let desired_replicas = stateful_set
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+        rollout.set_desired_replicas(desired_replicas);
+
+        if let Some(generation) = stateful_set.metadata.generation {
+            rollout.set_generation(generation);
+        }
+
+        if let Some(status) = &stateful_set.status {
+            let available_replicas = status.available_replicas.unwrap_or_default();
+
+            rollout.set_updated_replicas(status.updated_replicas.unwrap_or_default());
+            rollout.set_ready_replicas(status.ready_replicas.unwrap_or_default());
+            rollout.set_available_replicas(available_replicas);
+            rollout.set_unavailable_replicas((desired_replicas - available_replicas).max(0));
+            rollout.set_observed_generation(status.observed_generation.unwrap_or_default());
+        }
+
+        rollout
+    }
+
+    pub fn from_daemon_set(daemon_set: &DaemonSet) -> RolloutStatus {
+        let mut rollout = RolloutStatus::new();
+
+        if let Some(generation) = daemon_set.metadata.generation {
+            rollout.set_generation(generation);
+        }
+
+        if let Some(status) = &daemon_set.status {
+            rollout.set_desired_replicas(status.desired_number_scheduled);
+            rollout.set_updated_replicas(status.updated_number_scheduled.unwrap_or_default());
+            rollout.set_ready_replicas(status.number_ready);
+            rollout.set_available_replicas(status.number_available.unwrap_or_default());
+            rollout.set_unavailable_replicas(status.number_unavailable.unwrap_or_default());
+            rollout.set_observed_generation(status.observed_generation.unwrap_or_default());
+        }
+
+        rollout
+    }
+
+    pub fn from_replica_set(replica_set: &ReplicaSet) -> RolloutStatus {
+        let mut rollout = RolloutStatus::new();
+
+        let desired_replicas = replica_set
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+        rollout.set_desired_replicas(desired_replicas);
+
+        if let Some(generation) = replica_set.metadata.generation {
+            rollout.set_generation(generation);
+        }
+
+        if let Some(status) = &replica_set.status {
+            let available_replicas = status.available_replicas.unwrap_or_default();
+
+            // ReplicaSets have no notion of updated replicas, as all of their
+            // replicas share the same pod template.
+            rollout.set_updated_replicas(status.replicas);
+            rollout.set_ready_replicas(status.ready_replicas.unwrap_or_default());
+            rollout.set_available_replicas(available_replicas);
+            rollout.set_unavailable_replicas((desired_replicas - available_replicas).max(0));
+            rollout.set_observed_generation(status.observed_generation.unwrap_or_default());
+        }
+
+        rollout
+    }
+}
+
+impl From<&str> for ConditionStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "True" => ConditionStatus::CONDITION_STATUS_TRUE,
+            "False" => ConditionStatus::CONDITION_STATUS_FALSE,
+            _ => ConditionStatus::CONDITION_STATUS_UNKNOWN,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::kubernetes::{ConditionStatus, RolloutStatus};
+    use k8s_openapi::api::apps::v1::{
+        DaemonSet, DaemonSetStatus, Deployment, DeploymentCondition, DeploymentSpec,
+        DeploymentStatus, StatefulSet, StatefulSetSpec, StatefulSetStatus,
+    };
+
+    #[test]
+    fn rollout_status_from_deployment() {
+        let mut deployment = Deployment::default();
+        deployment.metadata.generation = Some(4);
+        deployment.spec = Some(DeploymentSpec {
+            replicas: Some(3),
+            ..Default::default()
+        });
+        deployment.status = Some(DeploymentStatus {
+            updated_replicas: Some(1),
+            ready_replicas: Some(2),
+            available_replicas: Some(2),
+            unavailable_replicas: Some(1),
+            observed_generation: Some(3),
+            conditions: Some(vec![DeploymentCondition {
+                type_: "Progressing".to_string(),
+                status: "False".to_string(),
+                reason: Some("ProgressDeadlineExceeded".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        let rollout = RolloutStatus::from_deployment(&deployment);
+
+        assert_eq!(3, rollout.get_desired_replicas());
+        assert_eq!(1, rollout.get_updated_replicas());
+        assert_eq!(2, rollout.get_ready_replicas());
+        assert_eq!(2, rollout.get_available_replicas());
+        assert_eq!(1, rollout.get_unavailable_replicas());
+        assert_eq!(4, rollout.get_generation());
+        assert_eq!(3, rollout.get_observed_generation());
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_FALSE,
+            rollout.get_progressing()
+        );
+        assert_eq!("ProgressDeadlineExceeded", rollout.get_progressing_reason());
+    }
+
+    #[test]
+    fn rollout_status_from_deployment_without_status() {
+        let rollout = RolloutStatus::from_deployment(&Deployment::default());
+
+        assert_eq!(1, rollout.get_desired_replicas());
+        assert_eq!(0, rollout.get_ready_replicas());
+        assert_eq!(
+            ConditionStatus::CONDITION_STATUS_UNKNOWN,
+            rollout.get_progressing()
+        );
+    }
+
+    #[test]
+    fn rollout_status_from_stateful_set() {
+        let stateful_set = StatefulSet {
+            spec: Some(StatefulSetSpec {
+                replicas: Some(3),
+                ..Default::default()
+            }),
+            status: Some(StatefulSetStatus {
+                replicas: 3,
+                updated_replicas: Some(3),
+                ready_replicas: Some(2),
+                available_replicas: Some(2),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let rollout = RolloutStatus::from_stateful_set(&stateful_set);
+
+        assert_eq!(3, rollout.get_desired_replicas());
+        assert_eq!(3, rollout.get_updated_replicas());
+        assert_eq!(2, rollout.get_available_replicas());
+        assert_eq!(1, rollout.get_unavailable_replicas());
+    }
+
+    #[test]
+    fn rollout_status_from_daemon_set() {
+        let daemon_set = DaemonSet {
+            status: Some(DaemonSetStatus {
+                desired_number_scheduled: 5,
+                updated_number_scheduled: Some(4),
+                number_ready: 4,
+                number_available: Some(4),
+                number_unavailable: Some(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let rollout = RolloutStatus::from_daemon_set(&daemon_set);
+
+        assert_eq!(5, rollout.get_desired_replicas());
+        assert_eq!(4, rollout.get_updated_replicas());
+        assert_eq!(4, rollout.get_ready_replicas());
+        assert_eq!(1, rollout.get_unavailable_replicas());
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+
+use k8s_openapi::api::networking::v1::{Ingress, IngressBackend};
+use kube::api::{DynamicObject, GroupVersionKind, ListParams};
+use kube::{Api, ResourceExt};
+
+use crate::ownership::{OwnershipResolver, ResourceIdentifier};
+use crate::protocol::kubernetes::{
+    KubernetesMetrics, ResourceReference, RouteBackend, RouteStatus, ServiceStatus,
+};
+use crate::Error;
+
+const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
+
+// The versions of the Gateway API in which HTTPRoutes are served, from
+// the most to the least preferred.
+const HTTP_ROUTE_VERSIONS: [&str; 2] = ["v1", "v1beta1"];
+
+// The status of each Service, by its namespace and name.
+type ServiceIndex<'a> = HashMap<(String, String), &'a ServiceStatus>;
+
+// Extract every Ingress and Gateway API HTTPRoute in the cluster, and the
+// health of the Services they route to. HTTPRoutes are only extracted if
+// the Gateway API is installed in the cluster.
+pub async fn extract_routes(
+    client: &kube::Client,
+    resolver: &mut OwnershipResolver,
+    service_metrics: &[KubernetesMetrics],
+) -> Result<Vec<KubernetesMetrics>, Error> {
+    let services: ServiceIndex = service_metrics
+        .iter()
+        .filter(|metric| metric.is_service())
+        .map(|metric| {
+            (
+                (
+                    metric.get_resource().get_namespace().to_string(),
+                    metric.get_resource().get_name().to_string(),
+                ),
+                metric.get_service(),
+            )
+        })
+        .collect();
+
+    let mut metrics = Vec::new();
+
+    let ingresses: Api<Ingress> = Api::all(client.clone());
+    for ingress in ingresses.list(&ListParams::default()).await? {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(&ingress),
+        ));
+        metric.set_route(RouteStatus::from_ingress(&ingress, &services));
+        metrics.push(metric);
+    }
+
+    for version in HTTP_ROUTE_VERSIONS {
+        let gvk = GroupVersionKind::gvk(GATEWAY_API_GROUP, version, "HTTPRoute");
+
+        if let Some(http_routes) = resolver.list_objects(&gvk).await? {
+            for http_route in http_routes {
+                let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+                    &ResourceIdentifier {
+                        gvk: gvk.clone(),
+                        name: http_route.name_any(),
+                        namespace: http_route.namespace(),
+                    },
+                ));
+                metric.set_route(RouteStatus::from_http_route(&http_route, &services));
+                metrics.push(metric);
+            }
+
+            break;
+        }
+    }
+
+    Ok(metrics)
+}
+
+impl KubernetesMetrics {
+    pub fn is_route(&self) -> bool {
+        self.has_route()
+    }
+}
+
+impl RouteStatus {
+    pub fn from_ingress(ingress: &Ingress, services: &ServiceIndex) -> RouteStatus {
+        let mut route = RouteStatus::new();
+        let namespace = ingress.namespace().unwrap_or_default();
+
+        let spec = match &ingress.spec {
+            Some(spec) => spec,
+            None => return route,
+        };
+
+        route.set_class_name(spec.ingress_class_name.clone().unwrap_or_default());
+
+        let backends = spec.default_backend.iter().chain(
+            spec.rules
+                .iter()
+                .flatten()
+                .filter_map(|rule| rule.http.as_ref())
+                .flat_map(|http| http.paths.iter().map(|path| &path.backend)),
+        );
+
+        for backend in backends {
+            // Backends can also refer to a custom resource, rather than a
+            // Service. Those are not reported.
+            if let Some(service) = &backend.service {
+                route.add_backend(
+                    &namespace,
+                    &service.name,
+                    ingress_backend_port(backend),
+                    services,
+                );
+            }
+        }
+
+        for host in spec
+            .rules
+            .iter()
+            .flatten()
+            .filter_map(|rule| rule.host.as_ref())
+        {
+            if !route.get_hostnames().contains(host) {
+                route.mut_hostnames().push(host.clone());
+            }
+        }
+
+        route
+    }
+
+    pub fn from_http_route(http_route: &DynamicObject, services: &ServiceIndex) -> RouteStatus {
+        let mut route = RouteStatus::new();
+        let namespace = http_route.namespace().unwrap_or_default();
+        let spec = &http_route.data["spec"];
+
+        for hostname in spec["hostnames"].as_array().into_iter().flatten() {
+            if let Some(hostname) = hostname.as_str() {
+                route.mut_hostnames().push(hostname.to_string());
+            }
+        }
+
+        for parent_ref in spec["parentRefs"].as_array().into_iter().flatten() {
+            if let Some(name) = parent_ref["name"].as_str() {
+                let mut parent = ResourceReference::new();
+                parent.set_kind(parent_ref["kind"].as_str().unwrap_or("Gateway").to_string());
+                parent.set_name(name.to_string());
+                parent.set_namespace(
+                    parent_ref["namespace"]
+                        .as_str()
+                        .unwrap_or(&namespace)
+                        .to_string(),
+                );
+                route.mut_parents().push(parent);
+            }
+        }
+
+        let backend_refs = spec["rules"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|rule| rule["backendRefs"].as_array())
+            .flatten();
+
+        for backend_ref in backend_refs {
+            // Backends can also refer to other kinds of objects, such as
+            // a ServiceImport. Only Services in the core group are reported.
+            let group = backend_ref["group"].as_str().unwrap_or_default();
+            let kind = backend_ref["kind"].as_str().unwrap_or("Service");
+            if !group.is_empty() || kind != "Service" {
+                continue;
+            }
+
+            if let Some(name) = backend_ref["name"].as_str() {
+                route.add_backend(
+                    backend_ref["namespace"].as_str().unwrap_or(&namespace),
+                    name,
+                    backend_ref["port"].as_i64().unwrap_or_default() as i32,
+                    services,
+                );
+            }
+        }
+
+        route
+    }
+
+    // Add a backend Service to the route, unless it was already added for
+    // another rule of the route, and flag it if it has no ready endpoints.
+    fn add_backend(&mut self, namespace: &str, name: &str, port: i32, services: &ServiceIndex) {
+        if self.get_backends().iter().any(|backend| {
+            backend.get_service().get_namespace() == namespace
+                && backend.get_service().get_name() == name
+                && backend.get_port() == port
+        }) {
+            return;
+        }
+
+        let mut service = ResourceReference::new();
+        service.set_kind("Service".to_string());
+        service.set_name(name.to_string());
+        service.set_namespace(namespace.to_string());
+
+        let mut backend = RouteBackend::new();
+        backend.set_service(service);
+        backend.set_port(port);
+
+        match services.get(&(namespace.to_string(), name.to_string())) {
+            // ExternalName Services route to a DNS name, not to endpoints.
+            Some(service_status) => backend.set_no_ready_endpoints(
+                service_status.get_field_type() != "ExternalName"
+                    && service_status.get_ready_endpoints() == 0,
+            ),
+            None => {
+                backend.set_service_missing(true);
+                backend.set_no_ready_endpoints(true);
+            }
+        }
+
+        if backend.get_no_ready_endpoints() {
+            self.set_backends_without_ready_endpoints(
+                self.get_backends_without_ready_endpoints() + 1,
+            );
+        }
+
+        self.mut_backends().push(backend);
+    }
+}
+
+// The port number of an Ingress backend. Backends that refer to a port
+// by its name report no port number.
+fn ingress_backend_port(backend: &IngressBackend) -> i32 {
+    backend
+        .service
+        .as_ref()
+        .and_then(|service| service.port.as_ref())
+        .and_then(|port| port.number)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServiceIndex;
+    use crate::protocol::kubernetes::{RouteStatus, ServiceStatus};
+    use k8s_openapi::api::networking::v1::{
+        HTTPIngressPath, HTTPIngressRuleValue, Ingress, IngressBackend, IngressRule,
+        IngressServiceBackend, IngressSpec, ServiceBackendPort,
+    };
+    use kube::api::DynamicObject;
+
+    fn service_status(ready_endpoints: i32) -> ServiceStatus {
+        let mut status = ServiceStatus::new();
+        status.set_field_type("ClusterIP".to_string());
+        status.set_ready_endpoints(ready_endpoints);
+        status
+    }
+
+    fn ingress_backend(name: &str, port: i32) -> IngressBackend {
+        IngressBackend {
+            service: Some(IngressServiceBackend {
+                name: name.to_string(),
+                port: Some(ServiceBackendPort {
+                    number: Some(port),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn ingress_path(name: &str, port: i32) -> HTTPIngressPath {
+        HTTPIngressPath {
+            backend: ingress_backend(name, port),
+            path: Some("/".to_string()),
+            path_type: "Prefix".to_string(),
+        }
+    }
+
+    #[test]
+    fn route_status_from_ingress() {
+        let web = service_status(2);
+        let api = service_status(0);
+        let services: ServiceIndex = [
+            (("default".to_string(), "web".to_string()), &web),
+            (("default".to_string(), "api".to_string()), &api),
+        ]
+        .into();
+
+        let mut ingress = Ingress::default();
+        ingress.metadata.namespace = Some("default".to_string());
+        ingress.spec = Some(IngressSpec {
+            ingress_class_name: Some("nginx".to_string()),
+            default_backend: Some(ingress_backend("web", 80)),
+            rules: Some(vec![
+                IngressRule {
+                    host: Some("example.com".to_string()),
+                    http: Some(HTTPIngressRuleValue {
+                        paths: vec![ingress_path("web", 80), ingress_path("api", 8080)],
+                    }),
+                },
+                IngressRule {
+                    host: Some("admin.example.com".to_string()),
+                    http: Some(HTTPIngressRuleValue {
+                        paths: vec![ingress_path("admin", 80)],
+                    }),
+                },
+            ]),
+            ..Default::default()
+        });
+
+        let route = RouteStatus::from_ingress(&ingress, &services);
+
+        assert_eq!("nginx", route.get_class_name());
+        assert_eq!(
+            &["example.com".to_string(), "admin.example.com".to_string()],
+            route.get_hostnames()
+        );
+        assert_eq!(2, route.get_backends_without_ready_endpoints());
+
+        let backends = route.get_backends();
+        assert_eq!(3, backends.len());
+
+        assert_eq!("web", backends[0].get_service().get_name());
+        assert_eq!(80, backends[0].get_port());
+        assert!(!backends[0].get_no_ready_endpoints());
+
+        assert_eq!("api", backends[1].get_service().get_name());
+        assert!(backends[1].get_no_ready_endpoints());
+        assert!(!backends[1].get_service_missing());
+
+        assert_eq!("admin", backends[2].get_service().get_name());
+        assert!(backends[2].get_no_ready_endpoints());
+        assert!(backends[2].get_service_missing());
+    }
+
+    #[test]
+    fn route_status_from_http_route() {
+        let web = service_status(1);
+        let services: ServiceIndex = [(("shop".to_string(), "web".to_string()), &web)].into();
+
+        let http_route: DynamicObject = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "web", "namespace": "shop" },
+            "spec": {
+                "hostnames": ["shop.example.com"],
+                "parentRefs": [{ "name": "public", "namespace": "gateways" }],
+                "rules": [
+                    { "backendRefs": [{ "name": "web", "port": 80 }] },
+                    { "backendRefs": [
+                        { "name": "checkout", "namespace": "payments", "port": 8080 },
+                        { "group": "multicluster.x-k8s.io", "kind": "ServiceImport", "name": "remote" }
+                    ] }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let route = RouteStatus::from_http_route(&http_route, &services);
+
+        assert_eq!(&["shop.example.com".to_string()], route.get_hostnames());
+
+        let parents = route.get_parents();
+        assert_eq!(1, parents.len());
+        assert_eq!("Gateway", parents[0].get_kind());
+        assert_eq!("public", parents[0].get_name());
+        assert_eq!("gateways", parents[0].get_namespace());
+
+        let backends = route.get_backends();
+        assert_eq!(2, backends.len());
+        assert_eq!("shop", backends[0].get_service().get_namespace());
+        assert!(!backends[0].get_no_ready_endpoints());
+        assert_eq!("payments", backends[1].get_service().get_namespace());
+        assert_eq!(8080, backends[1].get_port());
+        assert!(backends[1].get_service_missing());
+        assert_eq!(1, route.get_backends_without_ready_endpoints());
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, HashSet};
+
+use k8s_openapi::api::core::v1::Service;
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use kube::api::ListParams;
+use kube::{Api, ResourceExt};
+
+use crate::ownership::ResourceIdentifier;
+use crate::protocol::kubernetes::{KubernetesMetrics, ResourceReference, ServiceStatus};
+use crate::Error;
+
+// The label that EndpointSlices are labeled with, naming the Service
+// they belong to.
+const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+// Extract the endpoint health of every Service in the cluster.
+pub async fn extract_services(client: &kube::Client) -> Result<Vec<KubernetesMetrics>, Error> {
+    let services: Api<Service> = Api::all(client.clone());
+    let services_list = services.list(&ListParams::default()).await?;
+
+    let endpoint_slices: Api<EndpointSlice> = Api::all(client.clone());
+    let endpoint_slices_list = endpoint_slices
+        .list(&ListParams::default().labels(SERVICE_NAME_LABEL))
+        .await?;
+
+    // Group the EndpointSlices by the namespace and name of their Service.
+    let mut service_endpoint_slices: BTreeMap<(String, String), Vec<&EndpointSlice>> =
+        BTreeMap::new();
+    for endpoint_slice in &endpoint_slices_list {
+        if let Some(service_name) = endpoint_slice.labels().get(SERVICE_NAME_LABEL) {
+            service_endpoint_slices
+                .entry((
+                    endpoint_slice.namespace().unwrap_or_default(),
+                    service_name.clone(),
+                ))
+                .or_default()
+                .push(endpoint_slice);
+        }
+    }
+
+    Ok(services_list
+        .iter()
+        .map(|service| {
+            let key = (service.namespace().unwrap_or_default(), service.name_any());
+            let endpoint_slices = service_endpoint_slices
+                .get(&key)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            KubernetesMetrics::from_service(service, endpoint_slices)
+        })
+        .collect())
+}
+
+impl KubernetesMetrics {
+    pub fn from_service(
+        service: &Service,
+        endpoint_slices: &[&EndpointSlice],
+    ) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(service),
+        ));
+
+        metric.set_service(ServiceStatus::from_service(service, endpoint_slices));
+
+        metric
+    }
+
+    pub fn is_service(&self) -> bool {
+        self.has_service()
+    }
+}
+
+impl ServiceStatus {
+    pub fn from_service(service: &Service, endpoint_slices: &[&EndpointSlice]) -> ServiceStatus {
+        let mut status = ServiceStatus::new();
+
+        let spec = service.spec.as_ref();
+        status.set_field_type(
+            spec.and_then(|spec| spec.type_.clone())
+                .unwrap_or_else(|| "ClusterIP".to_string()),
+        );
+
+        // The same endpoint is listed in more than one EndpointSlice for
+        // Services with more than one IP family.
+        let mut seen = HashSet::new();
+
+        for endpoint in endpoint_slices
+            .iter()
+            .flat_map(|endpoint_slice| &endpoint_slice.endpoints)
+        {
+            let endpoint_key = endpoint
+                .target_ref
+                .as_ref()
+                .and_then(|target_ref| target_ref.uid.clone().or(target_ref.name.clone()))
+                .or(endpoint.addresses.first().cloned());
+            if let Some(endpoint_key) = endpoint_key {
+                if !seen.insert(endpoint_key) {
+                    continue;
+                }
+            }
+
+            let conditions = endpoint.conditions.as_ref();
+
+            // A missing ready condition means the endpoint is ready.
+            if conditions.and_then(|conditions| conditions.ready) != Some(false) {
+                status.set_ready_endpoints(status.get_ready_endpoints() + 1);
+            } else {
+                status.set_not_ready_endpoints(status.get_not_ready_endpoints() + 1);
+            }
+
+            if conditions.and_then(|conditions| conditions.terminating) == Some(true) {
+                status.set_terminating_endpoints(status.get_terminating_endpoints() + 1);
+            }
+        }
+
+        // Services without a selector have their endpoints managed by
+        // something other than Kubernetes, and do not select any pods. The
+        // endpoints of Services with a selector are the pods they select,
+        // including pods that are not reported because of the pod filters.
+        if spec
+            .and_then(|spec| spec.selector.as_ref())
+            .is_some_and(|selector| !selector.is_empty())
+        {
+            status.set_has_selector(true);
+            status.set_no_ready_pods(status.get_ready_endpoints() == 0);
+        }
+
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::kubernetes::ServiceStatus;
+    use k8s_openapi::api::core::v1::{ObjectReference, Service, ServiceSpec};
+    use k8s_openapi::api::discovery::v1::{Endpoint, EndpointConditions, EndpointSlice};
+
+    fn service(selector: &[(&str, &str)]) -> Service {
+        let mut service = Service::default();
+        service.metadata.name = Some("web".to_string());
+        service.metadata.namespace = Some("default".to_string());
+        service.spec = Some(ServiceSpec {
+            selector: Some(
+                selector
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        });
+        service
+    }
+
+    fn endpoint(pod_name: &str, ready: Option<bool>) -> Endpoint {
+        Endpoint {
+            addresses: vec![format!("10.0.0.{}", pod_name.len())],
+            conditions: Some(EndpointConditions {
+                ready,
+                ..Default::default()
+            }),
+            target_ref: Some(ObjectReference {
+                kind: Some("Pod".to_string()),
+                name: Some(pod_name.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn service_status_counts_ready_and_not_ready_endpoints() {
+        let ipv4 = EndpointSlice {
+            endpoints: vec![
+                endpoint("web-1", Some(true)),
+                endpoint("web-2", None),
+                endpoint("web-3", Some(false)),
+            ],
+            ..Default::default()
+        };
+        let ipv6 = EndpointSlice {
+            endpoints: vec![endpoint("web-1", Some(true))],
+            ..Default::default()
+        };
+
+        let status = ServiceStatus::from_service(&service(&[("app", "web")]), &[&ipv4, &ipv6]);
+
+        assert_eq!("ClusterIP", status.get_field_type());
+        assert_eq!(2, status.get_ready_endpoints());
+        assert_eq!(1, status.get_not_ready_endpoints());
+        assert!(status.get_has_selector());
+        assert!(!status.get_no_ready_pods());
+    }
+
+    #[test]
+    fn service_status_flags_selector_without_ready_pods() {
+        let not_ready = EndpointSlice {
+            endpoints: vec![endpoint("web-1", Some(false))],
+            ..Default::default()
+        };
+        let status = ServiceStatus::from_service(&service(&[("app", "web")]), &[&not_ready]);
+        assert!(status.get_no_ready_pods());
+
+        let status = ServiceStatus::from_service(&service(&[("app", "api")]), &[]);
+        assert!(status.get_no_ready_pods());
+    }
+
+    #[test]
+    fn service_status_with_pods_excluded_by_pod_label_selector() {
+        // The pods of the Service are not listed when they do not match the
+        // configured pod label selector, but they are its endpoints.
+        let ready = EndpointSlice {
+            endpoints: vec![endpoint("web-1", Some(true))],
+            ..Default::default()
+        };
+        let status = ServiceStatus::from_service(&service(&[("app", "web")]), &[&ready]);
+
+        assert!(status.get_has_selector());
+        assert!(!status.get_no_ready_pods());
+    }
+
+    #[test]
+    fn service_status_without_selector() {
+        let status = ServiceStatus::from_service(&service(&[]), &[]);
+
+        assert!(!status.get_has_selector());
+        assert!(!status.get_no_ready_pods());
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::Utc;
+use k8s_openapi::api::coordination::v1::Lease;
+use kube::api::{DeleteParams, ListParams, ObjectMeta};
+use kube::{Api, ResourceExt};
+use log::{info, warn};
+
+use crate::kubelet::Kubelet;
+use crate::leader_election::{acquire_or_renew, identity, is_expired, lease_name, RENEW_INTERVAL};
+use crate::Error;
+
+// The label on the membership Leases of the replicas, with the name of the
+// leader election Lease as its value, to list the members of a group.
+const SHARD_GROUP_LABEL: &str = "appsignal.com/shard-group";
+
+// Splits the nodes in the cluster between the replicas. Every replica holds
+// a membership Lease, and each node is assigned to one of the replicas with
+// a live Lease by rendezvous hashing, so that only the nodes of a replica
+// that joins or leaves move to another replica.
+pub struct ShardMembership {
+    identity: String,
+    members: Arc<Mutex<Vec<String>>>,
+}
+
+impl ShardMembership {
+    // Join the group before the first run, so that the first run scrapes
+    // this replica's share of the nodes. When joining fails, this replica is
+    // its only member until the membership is renewed.
+    pub async fn start(client: kube::Client) -> Self {
+        let leases: Api<Lease> = Api::default_namespaced(client);
+        let group = lease_name();
+        let identity = identity();
+
+        let initial_members = match renew_membership(&leases, &group, &identity).await {
+            Ok(members) => members,
+            Err(err) => {
+                warn!("Failed to join shard group, scraping all nodes: {}", err);
+                vec![identity.clone()]
+            }
+        };
+        info!("Sharding nodes between replicas: {:?}", initial_members);
+        let members = Arc::new(Mutex::new(initial_members));
+
+        let renew_identity = identity.clone();
+        let renew_members = members.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval_at(
+                tokio::time::Instant::now() + RENEW_INTERVAL,
+                RENEW_INTERVAL,
+            );
+
+            loop {
+                interval.tick().await;
+
+                match renew_membership(&leases, &group, &renew_identity).await {
+                    Ok(current_members) => {
+                        let mut members = renew_members.lock().expect("Shard lock poisoned");
+
+                        if *members != current_members {
+                            info!("Sharding nodes between replicas: {:?}", current_members);
+                            *members = current_members;
+                        }
+                    }
+                    Err(err) => warn!("Failed to renew shard membership: {}", err),
+                }
+            }
+        });
+
+        ShardMembership { identity, members }
+    }
+
+    pub fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    // Whether a node is assigned to this replica.
+    pub fn owns(&self, node_name: &str) -> bool {
+        let members = self.members.lock().expect("Shard lock poisoned");
+
+        owner(&members, node_name) == Some(self.identity.as_str())
+    }
+}
+
+// The nodes and resources that a replica extracts metrics for.
+pub struct Scope<'a> {
+    pub kubelet: &'a Kubelet,
+    pub shard: Option<&'a ShardMembership>,
+    // Whether this replica extracts the metrics of resources that are not
+    // on a node, when it is the elected leader or the only replica.
+    pub cluster_scope: bool,
+}
+
+impl Scope<'_> {
+    // Whether this replica scrapes a node and the pods on it. As a
+    // DaemonSet, or when the nodes are sharded between replicas, only the
+    // nodes assigned to this replica are scraped. Pods that are not
+    // scheduled on a node are reported with the cluster-scope metrics.
+    pub fn owns_node(&self, node_name: Option<&String>) -> bool {
+        match node_name {
+            Some(node_name) => match (self.kubelet.local_node_name(), self.shard) {
+                (Some(local_node_name), _) => node_name == local_node_name,
+                (None, Some(shard)) => shard.owns(node_name),
+                (None, None) => true,
+            },
+            None => self.cluster_scope,
+        }
+    }
+
+    // The identity of the part of the nodes this replica scrapes, when it
+    // does not scrape all nodes.
+    pub fn shard_identity(&self) -> Option<&str> {
+        self.kubelet
+            .local_node_name()
+            .or(self.shard.map(|shard| shard.identity()))
+    }
+}
+
+// Renew this replica's membership Lease, and list the identities of the
+// replicas with live membership Leases. Expired Leases, of replicas that
+// no longer exist, are deleted.
+async fn renew_membership(
+    leases: &Api<Lease>,
+    group: &str,
+    identity: &str,
+) -> Result<Vec<String>, Error> {
+    let metadata = ObjectMeta {
+        name: Some(format!("{}-{}", group, identity)),
+        labels: Some(BTreeMap::from([(
+            SHARD_GROUP_LABEL.to_owned(),
+            group.to_owned(),
+        )])),
+        ..Default::default()
+    };
+    acquire_or_renew(leases, metadata, identity).await?;
+
+    let now = Utc::now();
+    let mut members = Vec::new();
+
+    let list_params = ListParams::default().labels(&format!("{}={}", SHARD_GROUP_LABEL, group));
+    for lease in leases.list(&list_params).await? {
+        let spec = match &lease.spec {
+            Some(spec) => spec,
+            None => continue,
+        };
+
+        if is_expired(spec, now) {
+            if let Err(err) = leases
+                .delete(&lease.name_any(), &DeleteParams::default())
+                .await
+            {
+                warn!("Failed to delete expired shard membership: {}", err);
+            }
+            continue;
+        }
+
+        if let Some(holder_identity) = &spec.holder_identity {
+            members.push(holder_identity.clone());
+        }
+    }
+
+    members.sort();
+
+    Ok(members)
+}
+
+// The member a node is assigned to, which is the member with the highest
+// hash of its identity and the node's name.
+fn owner<'a>(members: &'a [String], node_name: &str) -> Option<&'a str> {
+    members
+        .iter()
+        .max_by_key(|member| hash(&[member.as_bytes(), node_name.as_bytes()]))
+        .map(|member| member.as_str())
+}
+
+// The 64-bit FNV-1a hash of the parts, separated by a zero byte, with its
+// bits mixed so that similar names have unrelated hashes. This is stable
+// between replicas and versions, unlike the standard library's hasher.
+fn hash(parts: &[&[u8]]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+
+    for (index, part) in parts.iter().enumerate() {
+        // Hash the zero byte separator.
+        if index > 0 {
+            hash = hash.wrapping_mul(PRIME);
+        }
+
+        for byte in *part {
+            hash = (hash ^ u64::from(*byte)).wrapping_mul(PRIME);
+        }
+    }
+
+    // The finalizer of MurmurHash3.
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{owner, Scope};
+    use crate::kubelet::Kubelet;
+
+    fn members(identities: &[&str]) -> Vec<String> {
+        identities
+            .iter()
+            .map(|identity| identity.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn assign_nodes_to_members() {
+        let nodes: Vec<String> = (0..100).map(|i| format!("node-{}", i)).collect();
+        let three = members(&["agent-a", "agent-b", "agent-c"]);
+
+        // Every member is assigned some of the nodes.
+        for member in &three {
+            assert!(nodes
+                .iter()
+                .any(|node| owner(&three, node) == Some(member.as_str())));
+        }
+
+        // When a member leaves, only its nodes are assigned to another member.
+        let two = members(&["agent-a", "agent-c"]);
+        for node in &nodes {
+            if owner(&three, node) != Some("agent-b") {
+                assert_eq!(owner(&three, node), owner(&two, node));
+            }
+        }
+
+        assert_eq!(None, owner(&[], "node-1"));
+    }
+
+    #[test]
+    fn scope_of_local_node() {
+        let kubelet = Kubelet::Local {
+            node_name: "node-1".to_string(),
+            address: "10.0.0.1:10250".parse().unwrap(),
+            client: reqwest::Client::new(),
+        };
+        let scope = Scope {
+            kubelet: &kubelet,
+            shard: None,
+            cluster_scope: false,
+        };
+
+        assert!(scope.owns_node(Some(&"node-1".to_string())));
+        assert!(!scope.owns_node(Some(&"node-2".to_string())));
+        assert!(!scope.owns_node(None));
+        assert_eq!(Some("node-1"), scope.shard_identity());
+
+        let scope = Scope {
+            kubelet: &Kubelet::Proxy,
+            shard: None,
+            cluster_scope: true,
+        };
+
+        assert!(scope.owns_node(Some(&"node-2".to_string())));
+        assert!(scope.owns_node(None));
+        assert_eq!(None, scope.shard_identity());
+    }
+}
@@ -0,0 +1,523 @@
+use std::env;
+use std::fs::OpenOptions;
+use std::future::Future;
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use log::{info, warn};
+use protobuf::Message;
+use reqwest::{Client, StatusCode, Url};
+use tokio::task::JoinHandle;
+
+use crate::exposition::MetricsEndpoint;
+use crate::otlp::OtlpExporter;
+use crate::protocol::kubernetes::{KubernetesMetrics, KubernetesMetricsBatch};
+use crate::{Error, BATCH_SIZE_THRESHOLD};
+
+const RETRY_ATTEMPTS: u32 = 3;
+const RETRY_BACKOFF: Duration = Duration::from_secs(2);
+
+// Every request to a sink times out on its own, so that a slow request
+// does not stop the retries and the other batches of a delivery.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+// The metrics extracted in a run.
+#[derive(Debug, Default)]
+pub struct Report {
+    // The metrics as they are sent to AppSignal: with their counters as the
+    // difference from the previous run, and the events since that run.
+    pub payload: Vec<KubernetesMetrics>,
+    // The metrics with their cumulative counters, without events.
+    pub metrics: Vec<KubernetesMetrics>,
+}
+
+// A destination for the metrics of every run. Every sink batches and
+// retries its deliveries on its own.
+pub trait Sink: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn deliver<'a>(
+        &'a self,
+        report: &'a Report,
+        client: &'a Client,
+    ) -> BoxFuture<'a, Result<(), Error>>;
+}
+
+// The configured sinks, which every report is delivered to.
+pub struct Sinks {
+    sinks: Vec<Arc<dyn Sink>>,
+    client: Client,
+    // The delivery in progress of every sink.
+    deliveries: Mutex<Vec<Option<JoinHandle<()>>>>,
+}
+
+impl Sinks {
+    // Send the metrics to AppSignal when `APPSIGNAL_API_KEY` is set, and to
+    // the other sinks that are configured.
+    pub async fn from_env(metrics_address: Option<SocketAddr>) -> Result<Sinks, Error> {
+        let mut sinks: Vec<Arc<dyn Sink>> = Vec::new();
+
+        if let Some(appsignal) = AppsignalSink::from_env() {
+            sinks.push(Arc::new(appsignal));
+        }
+        if let Some(otlp) = OtlpExporter::from_env() {
+            sinks.push(Arc::new(otlp));
+        }
+        if let Some(address) = metrics_address {
+            sinks.push(Arc::new(MetricsEndpoint::start(address).await?));
+        }
+        if env::var("APPSIGNAL_OUTPUT_STDOUT").as_deref() == Ok("true") {
+            sinks.push(Arc::new(OutputSink::Stdout));
+        }
+        if let Some(path) = env::var("APPSIGNAL_OUTPUT_FILE")
+            .ok()
+            .filter(|path| !path.is_empty())
+        {
+            sinks.push(Arc::new(OutputSink::File(path.into())));
+        }
+
+        if sinks.is_empty() {
+            return Err("No metrics outputs configured: set APPSIGNAL_API_KEY, APPSIGNAL_OTLP_ENDPOINT, APPSIGNAL_METRICS_ADDRESS, APPSIGNAL_OUTPUT_STDOUT or APPSIGNAL_OUTPUT_FILE".into());
+        }
+
+        info!(
+            "Delivering metrics to: {}",
+            sinks
+                .iter()
+                .map(|sink| sink.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        Ok(Sinks::new(
+            sinks,
+            Client::builder().timeout(REQUEST_TIMEOUT).build()?,
+        ))
+    }
+
+    fn new(sinks: Vec<Arc<dyn Sink>>, client: Client) -> Self {
+        let deliveries = Mutex::new(sinks.iter().map(|_| None).collect());
+
+        Sinks {
+            sinks,
+            client,
+            deliveries,
+        }
+    }
+
+    // Deliver the report to every sink at the same time, in the background,
+    // so that the next run does not wait for the deliveries. A sink that
+    // fails does not affect the delivery to the other sinks. A sink that is
+    // still delivering the previous report skips this one.
+    pub fn deliver(&self, report: Report) {
+        let report = Arc::new(report);
+        let mut deliveries = self.deliveries.lock().expect("Deliveries lock poisoned");
+
+        for (sink, delivery) in self.sinks.iter().zip(deliveries.iter_mut()) {
+            if delivery
+                .as_ref()
+                .is_some_and(|delivery| !delivery.is_finished())
+            {
+                warn!(
+                    "Still delivering the previous metrics to {}, skipping these metrics",
+                    sink.name()
+                );
+                continue;
+            }
+
+            let sink = sink.clone();
+            let report = report.clone();
+            let client = self.client.clone();
+            *delivery = Some(tokio::spawn(async move {
+                if let Err(err) = sink.deliver(&report, &client).await {
+                    warn!("Failed to deliver metrics to {}: {}", sink.name(), err);
+                }
+            }));
+        }
+    }
+}
+
+// Attempt an operation a few times, waiting a little longer after every
+// failed attempt.
+pub async fn with_retries<T, F, Fut>(description: &str, mut attempt: F) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut attempts = 1;
+    loop {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempts < RETRY_ATTEMPTS => {
+                warn!("Failed to {}, retrying: {}", description, err);
+                tokio::time::sleep(RETRY_BACKOFF * attempts).await;
+                attempts += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+// Send every batch, also when an earlier batch failed to send, and return
+// how many of them failed.
+pub async fn send_batches<B, F, Fut>(batches: Vec<B>, mut send: F) -> Result<(), Error>
+where
+    F: FnMut(B) -> Fut,
+    Fut: Future<Output = Result<(), Error>>,
+{
+    let total = batches.len();
+    let mut failed = 0;
+    for (index, batch) in batches.into_iter().enumerate() {
+        if let Err(err) = send(batch).await {
+            warn!("Failed to send batch {} of {}: {}", index + 1, total, err);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} batches failed to send", failed, total).into());
+    }
+
+    Ok(())
+}
+
+// Sends the payload to AppSignal in protobuf batches.
+pub struct AppsignalSink {
+    url: Url,
+}
+
+impl AppsignalSink {
+    pub fn from_env() -> Option<Self> {
+        let api_key = env::var("APPSIGNAL_API_KEY")
+            .ok()
+            .filter(|api_key| !api_key.is_empty())?;
+        let endpoint =
+            env::var("APPSIGNAL_ENDPOINT").unwrap_or("https://appsignal-endpoint.net".to_owned());
+
+        let base = Url::parse(&endpoint).expect("Could not parse endpoint");
+        let path = format!("metrics/kubernetes?api_key={}", api_key);
+        let url = base.join(&path).expect("Could not build request URL");
+
+        Some(AppsignalSink { url })
+    }
+
+    async fn send(&self, report: &Report, client: &Client) -> Result<(), Error> {
+        if report.payload.is_empty() {
+            return Ok(());
+        }
+
+        info!("Sending {} metrics to Appsignal", report.payload.len());
+
+        send_batches(batches(report.payload.clone()), |batch| async move {
+            with_retries("send batch to Appsignal", || {
+                send_batch(&batch, &self.url, client)
+            })
+            .await
+        })
+        .await?;
+
+        info!("All metrics sent");
+
+        Ok(())
+    }
+}
+
+impl Sink for AppsignalSink {
+    fn name(&self) -> &'static str {
+        "AppSignal"
+    }
+
+    fn deliver<'a>(
+        &'a self,
+        report: &'a Report,
+        client: &'a Client,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        self.send(report, client).boxed()
+    }
+}
+
+// Split the metrics into batches that stay under the batch size threshold.
+// A batch only exceeds the threshold if a single metric exceeds it.
+fn batches(metrics: Vec<KubernetesMetrics>) -> Vec<KubernetesMetricsBatch> {
+    let mut batches = Vec::new();
+
+    let mut batch_message = KubernetesMetricsBatch::new();
+    for metric in metrics.into_iter() {
+        batch_message.mut_metrics().push(metric);
+        if batch_message.compute_size() > BATCH_SIZE_THRESHOLD {
+            // Remove the metric that went over the threshold from the batch,
+            // unless it is the only metric in the batch.
+            let metric = if batch_message.get_metrics().len() > 1 {
+                batch_message.mut_metrics().pop()
+            } else {
+                None
+            };
+
+            batches.push(std::mem::take(&mut batch_message));
+
+            // Add the metric that went over the threshold to the next batch.
+            if let Some(metric) = metric {
+                batch_message.mut_metrics().push(metric);
+            }
+        }
+    }
+
+    if !batch_message.get_metrics().is_empty() {
+        batches.push(batch_message);
+    }
+
+    batches
+}
+
+// Send a batch to AppSignal. Server errors are returned to be retried. Other
+// failures are logged, as sending the batch again would not succeed.
+async fn send_batch(
+    batch: &KubernetesMetricsBatch,
+    url: &Url,
+    client: &Client,
+) -> Result<(), Error> {
+    let batch_bytes = batch.write_to_bytes().expect("Could not serialize batch");
+
+    let response = client.post(url.clone()).body(batch_bytes).send().await?;
+
+    let status = response.status();
+
+    if status.is_success() {
+        info!(
+            "Batch of {} metrics sent successfully (HTTP response status: {})",
+            batch.get_metrics().len(),
+            status
+        );
+    } else if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(format!(
+            "Batch of {} metrics failed to send (HTTP response status: {})",
+            batch.get_metrics().len(),
+            status
+        )
+        .into());
+    } else if status == 401 {
+        warn!(
+            "Batch of {} metrics failed to send (HTTP response status: {}) - make sure you're using an *app-level* push API key",
+            batch.get_metrics().len(),
+            status
+        );
+    } else {
+        warn!(
+            "Batch of {} metrics failed to send (HTTP response status: {})",
+            batch.get_metrics().len(),
+            status
+        );
+    }
+
+    Ok(())
+}
+
+// Writes the payload to the container's output or appends it to a file,
+// with one metric per line in the protobuf text format, to inspect the
+// metrics that are reported.
+pub enum OutputSink {
+    Stdout,
+    File(PathBuf),
+}
+
+impl OutputSink {
+    fn write(&self, metrics: &[KubernetesMetrics]) -> Result<(), Error> {
+        match self {
+            OutputSink::Stdout => write_lines(&mut io::stdout().lock(), metrics)?,
+            OutputSink::File(path) => {
+                let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+                write_lines(&mut file, metrics)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Sink for OutputSink {
+    fn name(&self) -> &'static str {
+        match self {
+            OutputSink::Stdout => "stdout",
+            OutputSink::File(_) => "file",
+        }
+    }
+
+    fn deliver<'a>(
+        &'a self,
+        report: &'a Report,
+        _client: &'a Client,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        async move { self.write(&report.payload) }.boxed()
+    }
+}
+
+fn write_lines(writer: &mut impl Write, metrics: &[KubernetesMetrics]) -> io::Result<()> {
+    let mut output = Vec::new();
+    for metric in metrics {
+        writeln!(output, "{}", protobuf::text_format::print_to_string(metric))?;
+    }
+
+    // Write every metric at once, so that lines of a report are not
+    // interleaved with log lines.
+    writer.write_all(&output)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{batches, send_batches, OutputSink, Report, Sink, Sinks};
+    use crate::protocol::kubernetes::KubernetesMetrics;
+    use crate::{Error, BATCH_SIZE_THRESHOLD};
+    use futures::future::BoxFuture;
+    use futures::FutureExt;
+    use reqwest::Client;
+    use std::fs;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    // Counts its deliveries, which take the given time.
+    struct CountingSink {
+        deliveries: AtomicUsize,
+        duration: Duration,
+    }
+
+    impl Sink for CountingSink {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn deliver<'a>(
+            &'a self,
+            _report: &'a Report,
+            _client: &'a Client,
+        ) -> BoxFuture<'a, Result<(), Error>> {
+            self.deliveries.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.duration).map(Ok).boxed()
+        }
+    }
+
+    fn counting_sink(duration: Duration) -> Arc<CountingSink> {
+        Arc::new(CountingSink {
+            deliveries: AtomicUsize::new(0),
+            duration,
+        })
+    }
+
+    fn pod_metric(name: &str, size: usize) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::new();
+        metric.set_pod_name(name.to_string());
+        metric
+            .mut_labels()
+            .insert("padding".to_string(), "x".repeat(size));
+        metric
+    }
+
+    #[test]
+    fn batch_metrics_under_threshold() {
+        let size = BATCH_SIZE_THRESHOLD as usize / 3;
+        let metrics = vec![
+            pod_metric("web-1", size),
+            pod_metric("web-2", size),
+            pod_metric("web-3", size),
+            pod_metric("huge", BATCH_SIZE_THRESHOLD as usize),
+            pod_metric("web-4", size),
+        ];
+
+        let batches = batches(metrics);
+        let names: Vec<Vec<&str>> = batches
+            .iter()
+            .map(|batch| {
+                batch
+                    .get_metrics()
+                    .iter()
+                    .map(|metric| metric.get_pod_name())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(
+            vec![
+                vec!["web-1", "web-2"],
+                vec!["web-3"],
+                vec!["huge"],
+                vec!["web-4"]
+            ],
+            names
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_without_waiting_for_slow_sinks() {
+        let slow = counting_sink(Duration::from_secs(3600));
+        let fast = counting_sink(Duration::ZERO);
+        let sinks = Sinks::new(vec![slow.clone(), fast.clone()], Client::new());
+
+        sinks.deliver(Report::default());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        sinks.deliver(Report::default());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // The slow sink skips the report it receives while it is still
+        // delivering the previous one.
+        assert_eq!(1, slow.deliveries.load(Ordering::SeqCst));
+        assert_eq!(2, fast.deliveries.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn send_remaining_batches_after_a_failure() {
+        let mut sent = Vec::new();
+        let result = futures::executor::block_on(send_batches(vec![1, 2, 3], |batch| {
+            sent.push(batch);
+            async move {
+                if batch == 1 {
+                    Err("Connection refused".into())
+                } else {
+                    Ok(())
+                }
+            }
+        }));
+
+        assert_eq!(vec![1, 2, 3], sent);
+        assert_eq!(
+            "1 of 3 batches failed to send",
+            result.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn batch_no_metrics() {
+        assert!(batches(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn append_metrics_to_file() {
+        let path = std::env::temp_dir().join(format!(
+            "appsignal-kubernetes-output-{}.txt",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut metric = KubernetesMetrics::new();
+        metric.set_pod_name("web-1".to_string());
+        metric.set_cpu_usage_nano_cores(250_000_000);
+
+        let sink = OutputSink::File(path.clone());
+        sink.write(&[metric.clone()]).unwrap();
+        sink.write(&[]).unwrap();
+        sink.write(&[metric]).unwrap();
+
+        let output = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(2, lines.len());
+        assert!(lines[0].contains("pod_name: \"web-1\""));
+        assert!(lines[0].contains("cpu_usage_nano_cores: 250000000"));
+    }
+}
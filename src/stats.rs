@@ -0,0 +1,632 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::sync::Mutex;
+
+use chrono::Utc;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::api::ObjectList;
+use kube::ResourceExt;
+use serde_json::{json, Map, Value};
+
+use crate::kubelet::Kubelet;
+use crate::prometheus::{self, Sample};
+use crate::quantity::parse_bytes;
+use crate::Error;
+
+// How long the previous value of a counter is kept when it is no longer
+// scraped, such as for a pod that was deleted.
+const COUNTER_RETENTION_MILLIS: i64 = 10 * 60 * 1000;
+
+// Which kubelet endpoint the node and pod stats are read from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Source {
+    // The JSON `/stats/summary` endpoint.
+    Summary,
+    // The Prometheus `/metrics/resource` endpoint, which only reports CPU,
+    // memory working set and swap usage. When cAdvisor is also scraped, it
+    // is complemented with the memory, page fault and network stats of the
+    // `/metrics/cadvisor` endpoint.
+    Resource,
+}
+
+// Reads the node and pod stats from the kubelets, in the shape of the
+// `/stats/summary` endpoint, whichever source they are read from. When
+// cAdvisor is scraped, the CPU throttling and pressure stall stats of pods
+// and containers are added, which the summary does not report.
+pub struct StatsSource {
+    source: Source,
+    cadvisor: bool,
+    counters: Mutex<Counters>,
+}
+
+impl StatsSource {
+    pub fn from_env() -> Self {
+        let source = match env::var("APPSIGNAL_STATS_SOURCE").as_deref() {
+            Ok("resource") => Source::Resource,
+            _ => Source::Summary,
+        };
+
+        StatsSource {
+            source,
+            cadvisor: env::var("APPSIGNAL_STATS_CADVISOR").as_deref() == Ok("true"),
+            counters: Mutex::new(Counters::default()),
+        }
+    }
+
+    // The stats of a node and the pods on it, in the shape of the
+    // `/stats/summary` endpoint.
+    pub async fn get_summary(
+        &self,
+        kubelet: &Kubelet,
+        client: &kube::Client,
+        node: &Node,
+        pods: &ObjectList<Pod>,
+    ) -> Result<Value, Error> {
+        let node_name = node.name_any();
+
+        let resource = match self.source {
+            Source::Summary => None,
+            Source::Resource => Some(prometheus::parse(
+                &kubelet
+                    .get_text(client, &node_name, "/metrics/resource")
+                    .await?,
+            )),
+        };
+        let cadvisor = if self.cadvisor {
+            prometheus::parse(
+                &kubelet
+                    .get_text(client, &node_name, "/metrics/cadvisor")
+                    .await?,
+            )
+        } else {
+            Vec::new()
+        };
+
+        let mut summary = match &resource {
+            Some(_) => Value::Null,
+            None => {
+                kubelet
+                    .get_json(client, &node_name, "/stats/summary")
+                    .await?
+            }
+        };
+
+        let mut counters = self.counters.lock().expect("Counters lock poisoned");
+        let now = Utc::now().timestamp_millis();
+        counters.prune(now);
+
+        if let Some(resource) = resource {
+            summary = summary_from_samples(node, &resource, &cadvisor, pods, &mut counters, now);
+        }
+        add_container_stats(&mut summary, &cadvisor, pods, &mut counters, now);
+
+        Ok(summary)
+    }
+}
+
+// The previous values of counters, to calculate their rate of change
+// between scrapes.
+#[derive(Default)]
+pub struct Counters {
+    values: HashMap<String, (f64, i64)>,
+}
+
+impl Counters {
+    // Store the value of a counter at the timestamp in milliseconds, and
+    // return how much it increased since its previous value and the number
+    // of seconds in between. Returns `None` for the first value of a
+    // counter, when its previous value expired, and when the counter was
+    // reset.
+    pub fn delta(&mut self, key: String, value: f64, timestamp: i64) -> Option<(f64, f64)> {
+        let (previous_value, previous_timestamp) = self.values.insert(key, (value, timestamp))?;
+        let seconds = (timestamp - previous_timestamp) as f64 / 1000.0;
+
+        if value < previous_value
+            || seconds <= 0.0
+            || timestamp - previous_timestamp >= COUNTER_RETENTION_MILLIS
+        {
+            return None;
+        }
+
+        Some((value - previous_value, seconds))
+    }
+
+    // Forget the values of counters that were not stored recently, such as
+    // those of pods that were deleted. Called once per scrape.
+    pub fn prune(&mut self, timestamp: i64) {
+        self.values
+            .retain(|_, (_, previous)| timestamp - *previous < COUNTER_RETENTION_MILLIS);
+    }
+
+    // The rate per second of a counter since its previous value.
+    pub fn rate(&mut self, key: String, value: f64, timestamp: i64) -> Option<f64> {
+        self.delta(key, value, timestamp)
+            .map(|(delta, seconds)| delta / seconds)
+    }
+}
+
+// Convert the samples of the `/metrics/resource` and `/metrics/cadvisor`
+// endpoints of a node to the shape of the `/stats/summary` endpoint. The
+// CPU usage in cores is calculated from the CPU usage counters of the
+// previous scrape.
+fn summary_from_samples(
+    node: &Node,
+    resource: &[Sample],
+    cadvisor: &[Sample],
+    pods: &ObjectList<Pod>,
+    counters: &mut Counters,
+    now: i64,
+) -> Value {
+    let node_name = node.name_any();
+    let mut node_stats = Stats::default();
+    let mut pod_stats: BTreeMap<(String, String), Stats> = BTreeMap::new();
+
+    for sample in resource {
+        let stats = match sample.name.as_str() {
+            "node_cpu_usage_seconds_total"
+            | "node_memory_working_set_bytes"
+            | "node_swap_usage_bytes" => &mut node_stats,
+            "pod_cpu_usage_seconds_total"
+            | "pod_memory_working_set_bytes"
+            | "pod_swap_usage_bytes" => pod_stats.entry(pod_key(sample)).or_default(),
+            _ => continue,
+        };
+
+        match sample.name.split_once('_').map(|(_, name)| name) {
+            Some("cpu_usage_seconds_total") => {
+                stats.cpu_usage_seconds = Some((sample.value, sample.timestamp.unwrap_or(now)))
+            }
+            Some("memory_working_set_bytes") => stats.set("memory", "workingSetBytes", sample),
+            Some("swap_usage_bytes") => stats.set("swap", "swapUsageBytes", sample),
+            _ => {}
+        }
+    }
+
+    for sample in cadvisor {
+        // The stats of the node are reported for the root cgroup, and the
+        // stats of a pod for its pod cgroup, which has no container name.
+        // Network stats are reported for the pod's sandbox container. The
+        // network stats of the root cgroup include the interfaces of all
+        // pods, so they are not reported for the node.
+        let is_node = sample.label("id") == "/";
+        let stats = if is_node {
+            &mut node_stats
+        } else if !sample.label("pod").is_empty() && matches!(sample.label("container"), "" | "POD")
+        {
+            pod_stats.entry(pod_key(sample)).or_default()
+        } else {
+            continue;
+        };
+
+        match (sample.name.as_str(), sample.label("container")) {
+            ("container_memory_usage_bytes", "") => stats.set("memory", "usageBytes", sample),
+            ("container_memory_rss", "") => stats.set("memory", "rssBytes", sample),
+            ("container_memory_failures_total", "")
+                if matches!(sample.label("scope"), "" | "hierarchy") =>
+            {
+                match sample.label("failure_type") {
+                    "pgfault" => stats.set("memory", "pageFaults", sample),
+                    "pgmajfault" => stats.set("memory", "majorPageFaults", sample),
+                    _ => {}
+                }
+            }
+            (name, _) if is_node && name.starts_with("container_network_") => {}
+            ("container_network_receive_bytes_total", _) => stats.add_network("rxBytes", sample),
+            ("container_network_receive_errors_total", _) => stats.add_network("rxErrors", sample),
+            ("container_network_transmit_bytes_total", _) => stats.add_network("txBytes", sample),
+            ("container_network_transmit_errors_total", _) => stats.add_network("txErrors", sample),
+            _ => {}
+        }
+    }
+
+    let mut node_json = node_stats.into_json(format!("node/{}", node_name), counters);
+    node_json["nodeName"] = json!(node_name);
+
+    // The summary reports the memory available to the node as its memory
+    // capacity minus the working set.
+    let capacity = node
+        .status
+        .as_ref()
+        .and_then(|status| status.capacity.as_ref())
+        .and_then(|capacity| capacity.get("memory"))
+        .and_then(parse_bytes);
+    if let (Some(capacity), Some(working_set)) =
+        (capacity, node_json["memory"]["workingSetBytes"].as_i64())
+    {
+        node_json["memory"]["availableBytes"] = json!((capacity - working_set).max(0));
+    }
+
+    let pods_json: Vec<Value> = pod_stats
+        .into_iter()
+        .filter_map(|((namespace, name), stats)| {
+            let uid = pod_uid(pods, &namespace, &name)?;
+
+            let mut pod_json = stats.into_json(format!("pod/{}", uid), counters);
+            pod_json["podRef"] = json!({ "name": name, "namespace": namespace, "uid": uid });
+
+            Some(pod_json)
+        })
+        .collect();
+
+    json!({ "node": node_json, "pods": pods_json })
+}
+
+// The namespace and name of a pod, and the name of a container in it, or an
+// empty name for the pod itself.
+type ContainerKey = (String, String, String);
+
+// Add the CPU throttling and pressure stall stats of the cAdvisor samples
+// to the pods in the summary and their containers. These are reported as
+// the increase of their counters since the previous scrape: the number of
+// CFS scheduler periods in which the pod or container was throttled, and
+// the percentage of time in which some or all of its tasks were stalled.
+fn add_container_stats(
+    summary: &mut Value,
+    cadvisor: &[Sample],
+    pods: &ObjectList<Pod>,
+    counters: &mut Counters,
+    now: i64,
+) {
+    let mut samples: BTreeMap<ContainerKey, Vec<(&str, &Sample)>> = BTreeMap::new();
+
+    for sample in cadvisor {
+        // The stats of a pod are reported for its pod cgroup, which has no
+        // container name.
+        if sample.label("pod").is_empty() || sample.label("container") == "POD" {
+            continue;
+        }
+
+        let field = match sample.name.as_str() {
+            "container_cpu_cfs_periods_total" => "periods",
+            "container_cpu_cfs_throttled_periods_total" => "throttledPeriods",
+            "container_pressure_cpu_waiting_seconds_total" => "cpuSome",
+            "container_pressure_cpu_stalled_seconds_total" => "cpuFull",
+            "container_pressure_memory_waiting_seconds_total" => "memorySome",
+            "container_pressure_memory_stalled_seconds_total" => "memoryFull",
+            "container_pressure_io_waiting_seconds_total" => "ioSome",
+            "container_pressure_io_stalled_seconds_total" => "ioFull",
+            _ => continue,
+        };
+
+        let (namespace, pod) = pod_key(sample);
+        samples
+            .entry((namespace, pod, sample.label("container").to_string()))
+            .or_default()
+            .push((field, sample));
+    }
+
+    for ((namespace, name, container), samples) in samples {
+        let uid = match pod_uid(pods, &namespace, &name) {
+            Some(uid) => uid,
+            None => continue,
+        };
+
+        let mut cpu_cfs = Map::new();
+        let mut pressure = Map::new();
+
+        for (field, sample) in samples {
+            let key = format!("pod/{}/{}/{}", uid, container, field);
+            let timestamp = sample.timestamp.unwrap_or(now);
+            let (delta, seconds) = match counters.delta(key, sample.value, timestamp) {
+                Some(delta) => delta,
+                None => continue,
+            };
+
+            if matches!(field, "periods" | "throttledPeriods") {
+                cpu_cfs.insert(field.to_string(), json!(delta.round() as i64));
+            } else {
+                pressure.insert(field.to_string(), json!(delta / seconds * 100.0));
+            }
+        }
+
+        let pod_json = summary["pods"].as_array_mut().and_then(|pods_json| {
+            pods_json
+                .iter_mut()
+                .find(|pod_json| pod_json["podRef"]["uid"].as_str() == Some(uid.as_str()))
+        });
+        let pod_json = match pod_json {
+            Some(pod_json) => pod_json,
+            None => continue,
+        };
+
+        let stats = if container.is_empty() {
+            pod_json
+        } else {
+            if !pod_json["containers"].is_array() {
+                pod_json["containers"] = json!([]);
+            }
+            let containers = pod_json["containers"]
+                .as_array_mut()
+                .expect("Containers array");
+
+            match containers
+                .iter()
+                .position(|container_json| container_json["name"] == container.as_str())
+            {
+                Some(index) => &mut containers[index],
+                None => {
+                    containers.push(json!({ "name": container }));
+                    containers.last_mut().expect("Container")
+                }
+            }
+        };
+
+        if !cpu_cfs.is_empty() {
+            stats["cpuCfs"] = Value::Object(cpu_cfs);
+        }
+        if !pressure.is_empty() {
+            stats["pressure"] = Value::Object(pressure);
+        }
+    }
+}
+
+// The UID of a pod, as the Prometheus samples only name the pods.
+fn pod_uid(pods: &ObjectList<Pod>, namespace: &str, name: &str) -> Option<String> {
+    pods.iter()
+        .find(|pod| {
+            pod.metadata.namespace.as_deref() == Some(namespace)
+                && pod.metadata.name.as_deref() == Some(name)
+        })
+        .and_then(|pod| pod.metadata.uid.clone())
+}
+
+fn pod_key(sample: &Sample) -> (String, String) {
+    (
+        sample.label("namespace").to_string(),
+        sample.label("pod").to_string(),
+    )
+}
+
+#[derive(Default)]
+struct Stats {
+    // The cumulative CPU usage in seconds, and its timestamp.
+    cpu_usage_seconds: Option<(f64, i64)>,
+    values: BTreeMap<&'static str, Map<String, Value>>,
+    // The network counters per interface, summed when converted to JSON.
+    network: BTreeMap<(&'static str, String), f64>,
+}
+
+impl Stats {
+    fn set(&mut self, group: &'static str, field: &str, sample: &Sample) {
+        if sample.value.is_finite() && sample.value >= 0.0 {
+            self.values
+                .entry(group)
+                .or_default()
+                .insert(field.to_string(), json!(sample.value.round() as i64));
+        }
+    }
+
+    fn add_network(&mut self, field: &'static str, sample: &Sample) {
+        if sample.value.is_finite() && sample.value >= 0.0 {
+            self.network
+                .insert((field, sample.label("interface").to_string()), sample.value);
+        }
+    }
+
+    fn into_json(self, key: String, counters: &mut Counters) -> Value {
+        let mut json = Map::new();
+
+        let mut values = self.values;
+        if let Some((seconds, timestamp)) = self.cpu_usage_seconds {
+            let cpu = values.entry("cpu").or_default();
+            cpu.insert(
+                "usageCoreNanoSeconds".to_string(),
+                json!((seconds * 1e9).round() as i64),
+            );
+
+            if let Some(rate) = counters.rate(key, seconds, timestamp) {
+                cpu.insert(
+                    "usageNanoCores".to_string(),
+                    json!((rate * 1e9).round() as i64),
+                );
+            }
+        }
+
+        for ((field, _), value) in self.network {
+            let network = values.entry("network").or_default();
+            let total = network
+                .get(field)
+                .and_then(Value::as_i64)
+                .unwrap_or_default();
+            network.insert(field.to_string(), json!(total + value.round() as i64));
+        }
+
+        for (group, fields) in values {
+            json.insert(group.to_string(), Value::Object(fields));
+        }
+
+        Value::Object(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_container_stats, summary_from_samples, Counters, COUNTER_RETENTION_MILLIS};
+    use crate::prometheus::parse;
+    use k8s_openapi::api::core::v1::{Node, Pod};
+    use kube::api::ObjectList;
+    use serde_json::json;
+
+    fn node() -> Node {
+        serde_json::from_value(json!({
+            "metadata": { "name": "node-1" },
+            "status": { "capacity": { "memory": "4Gi" } }
+        }))
+        .unwrap()
+    }
+
+    fn pods() -> ObjectList<Pod> {
+        serde_json::from_value(json!({
+            "metadata": {},
+            "items": [{
+                "metadata": { "name": "web-1", "namespace": "shop", "uid": "uid-1" }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn convert_resource_and_cadvisor_samples_to_summary() {
+        let mut counters = Counters::default();
+
+        let first = summary_from_samples(
+            &node(),
+            &parse(
+                r#"
+node_cpu_usage_seconds_total 100 1735732800000
+node_memory_working_set_bytes 1073741824 1735732800000
+pod_cpu_usage_seconds_total{namespace="shop",pod="web-1"} 10 1735732800000
+pod_memory_working_set_bytes{namespace="shop",pod="web-1"} 1.048576e+06 1735732800000
+pod_cpu_usage_seconds_total{namespace="shop",pod="deleted"} 10 1735732800000
+"#,
+            ),
+            &[],
+            &pods(),
+            &mut counters,
+            1735732800000,
+        );
+
+        assert_eq!(
+            json!({
+                "nodeName": "node-1",
+                "cpu": { "usageCoreNanoSeconds": 100000000000_i64 },
+                "memory": { "workingSetBytes": 1073741824, "availableBytes": 3221225472_i64 }
+            }),
+            first["node"]
+        );
+        assert_eq!(
+            json!([{
+                "podRef": { "name": "web-1", "namespace": "shop", "uid": "uid-1" },
+                "cpu": { "usageCoreNanoSeconds": 10000000000_i64 },
+                "memory": { "workingSetBytes": 1048576 }
+            }]),
+            first["pods"]
+        );
+
+        let second = summary_from_samples(
+            &node(),
+            &parse(
+                r#"
+node_cpu_usage_seconds_total 130 1735732860000
+pod_cpu_usage_seconds_total{namespace="shop",pod="web-1"} 13 1735732860000
+"#,
+            ),
+            &parse(
+                r#"
+container_memory_usage_bytes{container="",id="/",image="",name="",namespace="",pod=""} 2e+09
+container_memory_rss{container="",id="/kubepods/pod-1",image="",name="",namespace="shop",pod="web-1"} 524288
+container_memory_rss{container="web",id="/kubepods/pod-1/c-1",image="web",name="c-1",namespace="shop",pod="web-1"} 262144
+container_memory_failures_total{container="",failure_type="pgmajfault",id="/kubepods/pod-1",namespace="shop",pod="web-1",scope="hierarchy"} 3
+container_memory_failures_total{container="",failure_type="pgmajfault",id="/kubepods/pod-1",namespace="shop",pod="web-1",scope="container"} 1
+container_network_receive_bytes_total{container="POD",id="/kubepods/pod-1/p-1",interface="eth0",namespace="shop",pod="web-1"} 1000
+container_network_receive_bytes_total{container="",id="/kubepods/pod-1",interface="eth0",namespace="shop",pod="web-1"} 1000
+container_network_receive_bytes_total{container="",id="/kubepods/pod-1",interface="eth1",namespace="shop",pod="web-1"} 500
+"#,
+            ),
+            &pods(),
+            &mut counters,
+            1735732860000,
+        );
+
+        assert_eq!(500000000, second["node"]["cpu"]["usageNanoCores"]);
+        assert_eq!(2000000000_i64, second["node"]["memory"]["usageBytes"]);
+        assert_eq!(50000000, second["pods"][0]["cpu"]["usageNanoCores"]);
+        assert_eq!(524288, second["pods"][0]["memory"]["rssBytes"]);
+        assert_eq!(3, second["pods"][0]["memory"]["majorPageFaults"]);
+        assert_eq!(1500, second["pods"][0]["network"]["rxBytes"]);
+    }
+
+    #[test]
+    fn add_throttling_and_pressure_to_summary() {
+        let mut counters = Counters::default();
+        let mut summary = json!({
+            "pods": [{
+                "podRef": { "name": "web-1", "namespace": "shop", "uid": "uid-1" },
+                "containers": [{ "name": "web", "cpu": { "usageNanoCores": 1000 } }]
+            }]
+        });
+
+        let samples = |periods: u64,
+                       throttled_periods: u64,
+                       stalled_seconds: f64,
+                       timestamp: u64| {
+            parse(&format!(
+                r#"
+container_cpu_cfs_periods_total{{container="web",namespace="shop",pod="web-1"}} {periods} {timestamp}
+container_cpu_cfs_throttled_periods_total{{container="web",namespace="shop",pod="web-1"}} {throttled_periods} {timestamp}
+container_cpu_cfs_periods_total{{container="",namespace="shop",pod="web-1"}} {periods} {timestamp}
+container_cpu_cfs_throttled_periods_total{{container="",namespace="shop",pod="web-1"}} {throttled_periods} {timestamp}
+container_pressure_memory_stalled_seconds_total{{container="",namespace="shop",pod="web-1"}} {stalled_seconds} {timestamp}
+container_pressure_cpu_waiting_seconds_total{{container="sidecar",namespace="shop",pod="web-1"}} {stalled_seconds} {timestamp}
+container_cpu_cfs_periods_total{{container="web",namespace="shop",pod="deleted"}} {periods} {timestamp}
+"#
+            ))
+        };
+
+        add_container_stats(
+            &mut summary,
+            &samples(1000, 100, 1.0, 1735732800000),
+            &pods(),
+            &mut counters,
+            1735732800000,
+        );
+        assert_eq!(None, summary["pods"][0].get("cpuCfs"));
+
+        add_container_stats(
+            &mut summary,
+            &samples(1600, 250, 4.0, 1735732860000),
+            &pods(),
+            &mut counters,
+            1735732860000,
+        );
+
+        let pod = &summary["pods"][0];
+        assert_eq!(
+            json!({ "periods": 600, "throttledPeriods": 150 }),
+            pod["cpuCfs"]
+        );
+        assert_eq!(json!({ "memoryFull": 5.0 }), pod["pressure"]);
+        assert_eq!(
+            json!([
+                {
+                    "name": "web",
+                    "cpu": { "usageNanoCores": 1000 },
+                    "cpuCfs": { "periods": 600, "throttledPeriods": 150 }
+                },
+                { "name": "sidecar", "pressure": { "cpuSome": 5.0 } }
+            ]),
+            pod["containers"]
+        );
+    }
+
+    #[test]
+    fn counter_deltas() {
+        let mut counters = Counters::default();
+
+        assert_eq!(None, counters.delta("a".to_string(), 10.0, 0));
+        assert_eq!(
+            Some((5.0, 60.0)),
+            counters.delta("a".to_string(), 15.0, 60000)
+        );
+        // The counter was reset.
+        assert_eq!(None, counters.delta("a".to_string(), 2.0, 120000));
+        assert_eq!(Some(0.1), counters.rate("a".to_string(), 8.0, 180000));
+    }
+
+    #[test]
+    fn prune_expired_counters() {
+        let mut counters = Counters::default();
+        counters.delta("a".to_string(), 10.0, 0);
+        counters.delta("b".to_string(), 10.0, COUNTER_RETENTION_MILLIS);
+
+        counters.prune(COUNTER_RETENTION_MILLIS + 1);
+        assert_eq!(1, counters.values.len());
+        assert!(counters.values.contains_key("b"));
+
+        // An expired value is not used even when it was not pruned yet.
+        assert_eq!(
+            None,
+            counters.delta("b".to_string(), 20.0, 2 * COUNTER_RETENTION_MILLIS)
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use k8s_openapi::api::core::v1::{PersistentVolume, PersistentVolumeClaim, Pod};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, ResourceExt};
+use log::warn;
+
+use crate::ownership::ResourceIdentifier;
+use crate::protocol::kubernetes::{
+    KubernetesMetrics, PersistentVolumeClaimPhase, PersistentVolumeClaimStatus,
+    PersistentVolumePhase, PersistentVolumeStatus, ResourceReference,
+};
+use crate::quantity::parse_bytes;
+use crate::Error;
+
+// Extract the status of every PersistentVolume in the cluster, whether or
+// not it is bound to a claim that is mounted by a pod.
+pub async fn extract_persistent_volumes(
+    client: &kube::Client,
+) -> Result<Vec<KubernetesMetrics>, Error> {
+    let persistent_volumes: Api<PersistentVolume> = Api::all(client.clone());
+
+    Ok(persistent_volumes
+        .list(&ListParams::default())
+        .await?
+        .iter()
+        .map(KubernetesMetrics::from_persistent_volume)
+        .collect())
+}
+
+// Get the PersistentVolumeClaims of the volumes of the given pods, rather
+// than listing all claims in the cluster, for replicas that only report
+// the volumes of the pods on their own nodes.
+pub async fn get_persistent_volume_claims<'a>(
+    client: &kube::Client,
+    pods: impl Iterator<Item = &'a Pod>,
+) -> ObjectList<PersistentVolumeClaim> {
+    let mut items = Vec::new();
+
+    for (namespace, name) in pods.flat_map(claim_names).collect::<BTreeSet<_>>() {
+        let persistent_volume_claims: Api<PersistentVolumeClaim> =
+            Api::namespaced(client.clone(), &namespace);
+
+        match persistent_volume_claims.get_opt(&name).await {
+            Ok(Some(persistent_volume_claim)) => items.push(persistent_volume_claim),
+            Ok(None) => {}
+            Err(err) => warn!(
+                "Failed to get persistent volume claim {}/{}: {}",
+                namespace, name, err
+            ),
+        }
+    }
+
+    ObjectList {
+        types: Default::default(),
+        metadata: Default::default(),
+        items,
+    }
+}
+
+// The namespaces and names of the claims of a pod's volumes, including the
+// claims of generic ephemeral volumes, which are named after the pod and
+// the volume.
+fn claim_names(pod: &Pod) -> Vec<(String, String)> {
+    let namespace = pod.namespace().unwrap_or_default();
+
+    pod.spec
+        .iter()
+        .flat_map(|spec| spec.volumes.iter().flatten())
+        .filter_map(|volume| {
+            if let Some(claim) = &volume.persistent_volume_claim {
+                Some(claim.claim_name.clone())
+            } else if volume.ephemeral.is_some() {
+                Some(format!("{}-{}", pod.name_any(), volume.name))
+            } else {
+                None
+            }
+        })
+        .map(|name| (namespace.clone(), name))
+        .collect()
+}
+
+impl KubernetesMetrics {
+    pub fn from_persistent_volume_claim(
+        persistent_volume_claim: &PersistentVolumeClaim,
+    ) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(persistent_volume_claim),
+        ));
+
+        let mut status = PersistentVolumeClaimStatus::new();
+        status.set_name(
+            persistent_volume_claim
+                .metadata
+                .name
+                .clone()
+                .unwrap_or_default(),
+        );
+        status.set_namespace(
+            persistent_volume_claim
+                .metadata
+                .namespace
+                .clone()
+                .unwrap_or_default(),
+        );
+
+        if let Some(spec) = &persistent_volume_claim.spec {
+            status.set_storage_class(spec.storage_class_name.clone().unwrap_or_default());
+            status.set_volume_name(spec.volume_name.clone().unwrap_or_default());
+
+            if let Some(requested_bytes) = spec
+                .resources
+                .as_ref()
+                .and_then(|resources| storage_bytes(resources.requests.as_ref()))
+            {
+                status.set_requested_bytes(requested_bytes);
+            }
+        }
+
+        if let Some(claim_status) = &persistent_volume_claim.status {
+            status.set_phase(PersistentVolumeClaimPhase::from(
+                claim_status.phase.as_deref().unwrap_or_default(),
+            ));
+
+            if let Some(capacity_bytes) = storage_bytes(claim_status.capacity.as_ref()) {
+                status.set_capacity_bytes(capacity_bytes);
+            }
+
+            // The access modes of a bound claim are those of its volume,
+            // which may include more than the ones that were requested.
+            if let Some(access_modes) = &claim_status.access_modes {
+                status.set_access_modes(access_modes.clone().into());
+            }
+        }
+
+        if status.get_access_modes().is_empty() {
+            if let Some(access_modes) = persistent_volume_claim
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.access_modes.as_ref())
+            {
+                status.set_access_modes(access_modes.clone().into());
+            }
+        }
+
+        metric.set_persistent_volume_claim(status);
+
+        metric
+    }
+
+    pub fn from_persistent_volume(persistent_volume: &PersistentVolume) -> KubernetesMetrics {
+        let mut metric = KubernetesMetrics::from_resource(ResourceReference::from(
+            &ResourceIdentifier::from_object(persistent_volume),
+        ));
+
+        let mut status = PersistentVolumeStatus::new();
+
+        if let Some(spec) = &persistent_volume.spec {
+            if let Some(capacity_bytes) = storage_bytes(spec.capacity.as_ref()) {
+                status.set_capacity_bytes(capacity_bytes);
+            }
+
+            if let Some(access_modes) = &spec.access_modes {
+                status.set_access_modes(access_modes.clone().into());
+            }
+
+            status.set_reclaim_policy(
+                spec.persistent_volume_reclaim_policy
+                    .clone()
+                    .unwrap_or_default(),
+            );
+            status.set_storage_class(spec.storage_class_name.clone().unwrap_or_default());
+
+            // Released volumes keep the reference to the claim they were
+            // bound to, until they are reclaimed.
+            if let Some(claim_ref) = &spec.claim_ref {
+                status.set_claim_name(claim_ref.name.clone().unwrap_or_default());
+                status.set_claim_namespace(claim_ref.namespace.clone().unwrap_or_default());
+            }
+        }
+
+        if let Some(volume_status) = &persistent_volume.status {
+            status.set_phase(PersistentVolumePhase::from(
+                volume_status.phase.as_deref().unwrap_or_default(),
+            ));
+        }
+
+        metric.set_persistent_volume(status);
+
+        metric
+    }
+
+    pub fn is_persistent_volume(&self) -> bool {
+        self.has_persistent_volume()
+    }
+}
+
+fn storage_bytes(resources: Option<&BTreeMap<String, Quantity>>) -> Option<i64> {
+    resources
+        .and_then(|resources| resources.get("storage"))
+        .and_then(parse_bytes)
+}
+
+impl From<&str> for PersistentVolumeClaimPhase {
+    fn from(phase: &str) -> Self {
+        match phase {
+            "Pending" => PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_PENDING,
+            "Bound" => PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_BOUND,
+            "Lost" => PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_LOST,
+            _ => PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_UNKNOWN,
+        }
+    }
+}
+
+impl From<&str> for PersistentVolumePhase {
+    fn from(phase: &str) -> Self {
+        match phase {
+            "Pending" => PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_PENDING,
+            "Available" => PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_AVAILABLE,
+            "Bound" => PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_BOUND,
+            "Released" => PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_RELEASED,
+            "Failed" => PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_FAILED,
+            _ => PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_UNKNOWN,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::claim_names;
+    use crate::protocol::kubernetes::{
+        KubernetesMetrics, PersistentVolumeClaimPhase, PersistentVolumePhase,
+    };
+    use k8s_openapi::api::core::v1::{
+        EmptyDirVolumeSource, EphemeralVolumeSource, ObjectReference, PersistentVolume,
+        PersistentVolumeClaim, PersistentVolumeClaimSpec, PersistentVolumeClaimStatus,
+        PersistentVolumeClaimVolumeSource, PersistentVolumeSpec, PersistentVolumeStatus, Pod,
+        PodSpec, Volume, VolumeResourceRequirements,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    fn storage(value: &str) -> std::collections::BTreeMap<String, Quantity> {
+        [("storage".to_string(), Quantity(value.to_string()))].into()
+    }
+
+    #[test]
+    fn persistent_volume_claim_metric_from_pending_claim() {
+        let mut persistent_volume_claim = PersistentVolumeClaim::default();
+        persistent_volume_claim.metadata.name = Some("data-postgres-0".to_string());
+        persistent_volume_claim.metadata.namespace = Some("database".to_string());
+        persistent_volume_claim.spec = Some(PersistentVolumeClaimSpec {
+            storage_class_name: Some("fast-ssd".to_string()),
+            access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+            resources: Some(VolumeResourceRequirements {
+                requests: Some(storage("10Gi")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        persistent_volume_claim.status = Some(PersistentVolumeClaimStatus {
+            phase: Some("Pending".to_string()),
+            ..Default::default()
+        });
+
+        let metric = KubernetesMetrics::from_persistent_volume_claim(&persistent_volume_claim);
+
+        assert!(metric.is_resource());
+        assert!(!metric.is_volume());
+        assert!(!metric.is_node());
+        assert_eq!("PersistentVolumeClaim", metric.get_resource().get_kind());
+        assert_eq!("data-postgres-0", metric.get_resource().get_name());
+        assert_eq!("database", metric.get_resource().get_namespace());
+
+        let status = metric.get_persistent_volume_claim();
+        assert_eq!(
+            PersistentVolumeClaimPhase::PERSISTENT_VOLUME_CLAIM_PHASE_PENDING,
+            status.get_phase()
+        );
+        assert_eq!("fast-ssd", status.get_storage_class());
+        assert_eq!(10 * 1024 * 1024 * 1024, status.get_requested_bytes());
+        assert_eq!(0, status.get_capacity_bytes());
+        assert_eq!(&["ReadWriteOnce".to_string()], status.get_access_modes());
+        assert_eq!("", status.get_volume_name());
+    }
+
+    #[test]
+    fn persistent_volume_metric_from_released_volume() {
+        let mut persistent_volume = PersistentVolume::default();
+        persistent_volume.metadata.name = Some("pvc-1234".to_string());
+        persistent_volume.spec = Some(PersistentVolumeSpec {
+            capacity: Some(storage("20Gi")),
+            access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+            persistent_volume_reclaim_policy: Some("Retain".to_string()),
+            storage_class_name: Some("standard".to_string()),
+            claim_ref: Some(ObjectReference {
+                name: Some("data-postgres-0".to_string()),
+                namespace: Some("database".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        persistent_volume.status = Some(PersistentVolumeStatus {
+            phase: Some("Released".to_string()),
+            ..Default::default()
+        });
+
+        let metric = KubernetesMetrics::from_persistent_volume(&persistent_volume);
+
+        assert!(metric.is_persistent_volume());
+        assert!(metric.is_resource());
+        assert_eq!("PersistentVolume", metric.get_resource().get_kind());
+        assert_eq!("pvc-1234", metric.get_resource().get_name());
+        assert_eq!("", metric.get_resource().get_namespace());
+
+        let status = metric.get_persistent_volume();
+        assert_eq!(
+            PersistentVolumePhase::PERSISTENT_VOLUME_PHASE_RELEASED,
+            status.get_phase()
+        );
+        assert_eq!(20 * 1024 * 1024 * 1024, status.get_capacity_bytes());
+        assert_eq!("Retain", status.get_reclaim_policy());
+        assert_eq!("standard", status.get_storage_class());
+        assert_eq!("data-postgres-0", status.get_claim_name());
+        assert_eq!("database", status.get_claim_namespace());
+    }
+
+    #[test]
+    fn claim_names_of_pod_volumes() {
+        let mut pod = Pod::default();
+        pod.metadata.name = Some("db-0".to_string());
+        pod.metadata.namespace = Some("shop".to_string());
+        pod.spec = Some(PodSpec {
+            volumes: Some(vec![
+                Volume {
+                    name: "data".to_string(),
+                    persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                        claim_name: "data-db-0".to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Volume {
+                    name: "scratch".to_string(),
+                    ephemeral: Some(EphemeralVolumeSource::default()),
+                    ..Default::default()
+                },
+                Volume {
+                    name: "cache".to_string(),
+                    empty_dir: Some(EmptyDirVolumeSource::default()),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            vec![
+                ("shop".to_string(), "data-db-0".to_string()),
+                ("shop".to_string(), "db-0-scratch".to_string()),
+            ],
+            claim_names(&pod)
+        );
+        assert!(claim_names(&Pod::default()).is_empty());
+    }
+}
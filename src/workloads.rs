@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::Pod;
+
+use crate::ownership::ResourceIdentifier;
+use crate::protocol::kubernetes::{KubernetesMetrics, PodPhase, ResourceReference, RolloutStatus};
+
+type WorkloadKey = (String, String, String);
+
+// Aggregates pod metrics into workload metrics, one for each top-level
+// owner (such as a Deployment, StatefulSet or CronJob) of the pods.
+#[derive(Default)]
+pub struct WorkloadAggregator {
+    workloads: BTreeMap<WorkloadKey, KubernetesMetrics>,
+    without_usage: bool,
+}
+
+impl WorkloadAggregator {
+    // Aggregate the status of the pods, but not their usage, for when only
+    // the stats of some of the pods are scraped.
+    pub fn without_usage() -> Self {
+        WorkloadAggregator {
+            without_usage: true,
+            ..Default::default()
+        }
+    }
+
+    // Add a pod's status and usage to the workload metrics for each of
+    // its top-level owners. The owner references must already have been
+    // extracted into the pod metric.
+    pub fn add(&mut self, pod: &Pod, pod_metric: &KubernetesMetrics) {
+        let without_usage = self.without_usage;
+
+        for owner_reference in pod_metric.get_owner_references() {
+            let workload_metric = self.entry(ResourceReference::from(owner_reference));
+
+            if !without_usage {
+                workload_metric.set_cpu_usage_nano_cores(
+                    workload_metric.get_cpu_usage_nano_cores()
+                        + pod_metric.get_cpu_usage_nano_cores(),
+                );
+                workload_metric.set_memory_usage_bytes(
+                    workload_metric.get_memory_usage_bytes() + pod_metric.get_memory_usage_bytes(),
+                );
+                workload_metric.set_memory_working_set_bytes(
+                    workload_metric.get_memory_working_set_bytes()
+                        + pod_metric.get_memory_working_set_bytes(),
+                );
+                workload_metric.set_memory_rss_bytes(
+                    workload_metric.get_memory_rss_bytes() + pod_metric.get_memory_rss_bytes(),
+                );
+            }
+
+            let workload = workload_metric.mut_workload();
+            workload.set_pods(workload.get_pods() + 1);
+            workload.set_restart_count(
+                workload.get_restart_count() + pod_metric.get_pod_restart_count(),
+            );
+
+            match pod_metric.get_pod_phase() {
+                PodPhase::POD_PHASE_RUNNING => {
+                    workload.set_pods_running(workload.get_pods_running() + 1);
+                }
+                // Completed pods, such as those owned by a finished Job,
+                // are not expected to be ready.
+                PodPhase::POD_PHASE_SUCCEEDED | PodPhase::POD_PHASE_FAILED => continue,
+                _ => {}
+            }
+
+            if !is_ready(pod) {
+                workload.set_pods_not_ready(workload.get_pods_not_ready() + 1);
+            }
+        }
+    }
+
+    // Add the rollout status of a Deployment, StatefulSet, DaemonSet or
+    // ReplicaSet to its workload metric.
+    pub fn add_rollout(&mut self, resource: &ResourceIdentifier, rollout: RolloutStatus) {
+        self.resource(resource).set_rollout(rollout);
+    }
+
+    // Return the workload metric for a resource, creating it if needed.
+    pub fn resource(&mut self, resource: &ResourceIdentifier) -> &mut KubernetesMetrics {
+        self.entry(ResourceReference::from(resource))
+    }
+
+    fn entry(&mut self, resource: ResourceReference) -> &mut KubernetesMetrics {
+        let key = (
+            resource.get_kind().to_string(),
+            resource.get_namespace().to_string(),
+            resource.get_name().to_string(),
+        );
+
+        self.workloads
+            .entry(key)
+            .or_insert_with(|| KubernetesMetrics::from_resource(resource))
+    }
+
+    pub fn into_metrics(self) -> Vec<KubernetesMetrics> {
+        self.workloads.into_values().collect()
+    }
+}
+
+impl KubernetesMetrics {
+    pub fn is_workload(&self) -> bool {
+        self.has_workload()
+    }
+
+    pub fn is_rollout(&self) -> bool {
+        self.has_rollout()
+    }
+
+    pub fn is_job(&self) -> bool {
+        self.has_job()
+    }
+
+    pub fn is_cron_job(&self) -> bool {
+        self.has_cron_job()
+    }
+}
+
+fn is_ready(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .map(|conditions| {
+            conditions
+                .iter()
+                .any(|condition| condition.type_ == "Ready" && condition.status == "True")
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WorkloadAggregator;
+    use crate::protocol::kubernetes::{KubernetesMetrics, OwnerReference, PodPhase};
+    use k8s_openapi::api::core::v1::{Pod, PodCondition, PodStatus};
+
+    fn pod(ready: bool) -> Pod {
+        Pod {
+            status: Some(PodStatus {
+                conditions: Some(vec![PodCondition {
+                    type_: "Ready".to_string(),
+                    status: if ready { "True" } else { "False" }.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod_metric(owner_name: &str, phase: PodPhase) -> KubernetesMetrics {
+        let mut owner_reference = OwnerReference::new();
+        owner_reference.set_kind("Deployment".to_string());
+        owner_reference.set_name(owner_name.to_string());
+        owner_reference.set_namespace("default".to_string());
+
+        let mut metric = KubernetesMetrics::new();
+        metric.set_pod_uuid(format!("{}-uid", owner_name));
+        metric.set_pod_phase(phase);
+        metric.set_pod_restart_count(2);
+        metric.set_cpu_usage_nano_cores(1000);
+        metric.set_memory_working_set_bytes(2048);
+        metric.mut_owner_references().push(owner_reference);
+        metric
+    }
+
+    #[test]
+    fn aggregates_pods_by_top_level_owner() {
+        let mut aggregator = WorkloadAggregator::default();
+        aggregator.add(&pod(true), &pod_metric("web", PodPhase::POD_PHASE_RUNNING));
+        aggregator.add(&pod(false), &pod_metric("web", PodPhase::POD_PHASE_RUNNING));
+        aggregator.add(&pod(false), &pod_metric("web", PodPhase::POD_PHASE_PENDING));
+        aggregator.add(
+            &pod(true),
+            &pod_metric("worker", PodPhase::POD_PHASE_RUNNING),
+        );
+
+        let metrics = aggregator.into_metrics();
+        assert_eq!(2, metrics.len());
+
+        let web = &metrics[0];
+        assert!(web.is_workload());
+        assert!(web.is_resource());
+        assert!(!web.is_node());
+        assert!(!web.is_pod());
+        assert_eq!("Deployment", web.get_resource().get_kind());
+        assert_eq!("web", web.get_resource().get_name());
+        assert_eq!("default", web.get_resource().get_namespace());
+
+        assert_eq!(3, web.get_workload().get_pods());
+        assert_eq!(2, web.get_workload().get_pods_running());
+        assert_eq!(2, web.get_workload().get_pods_not_ready());
+        assert_eq!(6, web.get_workload().get_restart_count());
+        assert_eq!(3000, web.cpu_usage_nano_cores);
+        assert_eq!(6144, web.memory_working_set_bytes);
+
+        let worker = &metrics[1];
+        assert_eq!("worker", worker.get_resource().get_name());
+        assert_eq!(1, worker.get_workload().get_pods());
+        assert_eq!(0, worker.get_workload().get_pods_not_ready());
+    }
+
+    #[test]
+    fn aggregates_pods_without_usage() {
+        let mut aggregator = WorkloadAggregator::without_usage();
+        aggregator.add(&pod(true), &pod_metric("web", PodPhase::POD_PHASE_RUNNING));
+        aggregator.add(&pod(false), &pod_metric("web", PodPhase::POD_PHASE_RUNNING));
+
+        let metrics = aggregator.into_metrics();
+        let web = &metrics[0];
+
+        assert_eq!(2, web.get_workload().get_pods());
+        assert_eq!(1, web.get_workload().get_pods_not_ready());
+        assert_eq!(4, web.get_workload().get_restart_count());
+        assert_eq!(0, web.cpu_usage_nano_cores);
+        assert_eq!(0, web.memory_working_set_bytes);
+    }
+
+    #[test]
+    fn does_not_count_completed_pods_as_not_ready() {
+        let mut aggregator = WorkloadAggregator::default();
+        aggregator.add(
+            &pod(false),
+            &pod_metric("job", PodPhase::POD_PHASE_SUCCEEDED),
+        );
+        aggregator.add(&pod(false), &pod_metric("job", PodPhase::POD_PHASE_FAILED));
+
+        let metrics = aggregator.into_metrics();
+        let job = &metrics[0];
+
+        assert_eq!(2, job.get_workload().get_pods());
+        assert_eq!(0, job.get_workload().get_pods_running());
+        assert_eq!(0, job.get_workload().get_pods_not_ready());
+    }
+}
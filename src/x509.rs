@@ -0,0 +1,304 @@
+use base64::Engine;
+use chrono::{DateTime, NaiveDate, Utc};
+
+const PEM_CERTIFICATE_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_CERTIFICATE_END: &str = "-----END CERTIFICATE-----";
+
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_OBJECT_IDENTIFIER: u8 = 0x06;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_VERSION: u8 = 0xa0;
+const TAG_EXTENSIONS: u8 = 0xa3;
+const TAG_DNS_NAME: u8 = 0x82;
+
+// The DER-encoded object identifiers of the common name attribute
+// (2.5.4.3) and the subject alternative name extension (2.5.29.17).
+const OID_COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+const OID_SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1d, 0x11];
+
+// The fields of an X.509 certificate that are reported.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Certificate {
+    pub not_after: DateTime<Utc>,
+    pub common_name: Option<String>,
+    pub issuer_common_name: Option<String>,
+    pub dns_names: Vec<String>,
+}
+
+impl Certificate {
+    // Parse the first certificate in a PEM-encoded certificate chain, which
+    // is the certificate that the chain was issued for.
+    pub fn from_pem(pem: &[u8]) -> Option<Certificate> {
+        let pem = std::str::from_utf8(pem).ok()?;
+
+        let start = pem.find(PEM_CERTIFICATE_BEGIN)? + PEM_CERTIFICATE_BEGIN.len();
+        let end = start + pem[start..].find(PEM_CERTIFICATE_END)?;
+        let base64: String = pem[start..end]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+
+        let der = base64::engine::general_purpose::STANDARD
+            .decode(base64)
+            .ok()?;
+
+        Certificate::from_der(&der)
+    }
+
+    // Parse a DER-encoded certificate. Only the fields that are reported
+    // are read, without verifying the certificate.
+    pub fn from_der(der: &[u8]) -> Option<Certificate> {
+        let (tag, certificate, _) = read_element(der)?;
+        if tag != TAG_SEQUENCE {
+            return None;
+        }
+
+        let (tag, tbs_certificate, _) = read_element(certificate)?;
+        if tag != TAG_SEQUENCE {
+            return None;
+        }
+
+        let elements = read_elements(tbs_certificate)?;
+
+        // The version is optional, and precedes the serial number and the
+        // signature algorithm.
+        let offset = match elements.first() {
+            Some((TAG_VERSION, _)) => 3,
+            _ => 2,
+        };
+
+        let (_, issuer) = elements.get(offset)?;
+        let (_, validity) = elements.get(offset + 1)?;
+        let (_, subject) = elements.get(offset + 2)?;
+
+        let validity = read_elements(validity)?;
+        let (not_after_tag, not_after) = validity.get(1)?;
+
+        let dns_names = elements
+            .iter()
+            .find(|(tag, _)| *tag == TAG_EXTENSIONS)
+            .and_then(|(_, extensions)| subject_alt_dns_names(extensions))
+            .unwrap_or_default();
+
+        Some(Certificate {
+            not_after: parse_time(*not_after_tag, not_after)?,
+            common_name: common_name(subject),
+            issuer_common_name: common_name(issuer),
+            dns_names,
+        })
+    }
+}
+
+// Read a DER element into its tag, its contents and the remaining data.
+fn read_element(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let tag = *data.first()?;
+    let length_byte = *data.get(1)?;
+
+    let (length, header_length) = if length_byte < 0x80 {
+        (length_byte as usize, 2)
+    } else {
+        // In the long form, the low bits are the number of bytes that
+        // encode the length.
+        let length_bytes = (length_byte & 0x7f) as usize;
+        if length_bytes == 0 || length_bytes > 4 {
+            return None;
+        }
+
+        let length = data
+            .get(2..2 + length_bytes)?
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+
+        (length, 2 + length_bytes)
+    };
+
+    let contents = data.get(header_length..header_length.checked_add(length)?)?;
+    let rest = &data[header_length + length..];
+
+    Some((tag, contents, rest))
+}
+
+// Read all the DER elements in a constructed element's contents.
+fn read_elements(mut data: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut elements = Vec::new();
+
+    while !data.is_empty() {
+        let (tag, contents, rest) = read_element(data)?;
+        elements.push((tag, contents));
+        data = rest;
+    }
+
+    Some(elements)
+}
+
+// Find the common name in a distinguished name, which is a sequence of
+// sets of attribute type and value pairs.
+fn common_name(name: &[u8]) -> Option<String> {
+    for (_, set) in read_elements(name)? {
+        for (_, attribute) in read_elements(set)? {
+            let attribute = read_elements(attribute)?;
+
+            if let [(TAG_OBJECT_IDENTIFIER, OID_COMMON_NAME), (_, value), ..] = attribute.as_slice()
+            {
+                return String::from_utf8(value.to_vec()).ok();
+            }
+        }
+    }
+
+    None
+}
+
+// Find the DNS names in the subject alternative name extension.
+fn subject_alt_dns_names(extensions: &[u8]) -> Option<Vec<String>> {
+    let (_, extensions, _) = read_element(extensions)?;
+
+    for (_, extension) in read_elements(extensions)? {
+        let extension = read_elements(extension)?;
+
+        if extension.first() != Some(&(TAG_OBJECT_IDENTIFIER, OID_SUBJECT_ALT_NAME)) {
+            continue;
+        }
+
+        // The extension's value follows its optional critical flag.
+        let (_, value) = extension.iter().find(|(tag, _)| *tag == TAG_OCTET_STRING)?;
+        let (_, general_names, _) = read_element(value)?;
+
+        return Some(
+            read_elements(general_names)?
+                .into_iter()
+                .filter(|(tag, _)| *tag == TAG_DNS_NAME)
+                .filter_map(|(_, name)| String::from_utf8(name.to_vec()).ok())
+                .collect(),
+        );
+    }
+
+    None
+}
+
+// Parse a UTCTime ("YYMMDDHHMMSSZ") or GeneralizedTime ("YYYYMMDDHHMMSSZ").
+fn parse_time(tag: u8, time: &[u8]) -> Option<DateTime<Utc>> {
+    // Times only consist of ASCII digits, so that they can be sliced by
+    // byte offsets.
+    if !time.is_ascii() {
+        return None;
+    }
+    let time = std::str::from_utf8(time).ok()?.strip_suffix('Z')?;
+
+    let time = match tag {
+        // Two-digit years from 50 are in the 20th century.
+        TAG_UTC_TIME if time.len() == 12 => {
+            let century = if time[0..2].parse::<u32>().ok()? >= 50 {
+                "19"
+            } else {
+                "20"
+            };
+            format!("{}{}", century, time)
+        }
+        TAG_GENERALIZED_TIME if time.len() == 14 => time.to_string(),
+        _ => return None,
+    };
+
+    let number = |range: std::ops::Range<usize>| time.get(range)?.parse::<u32>().ok();
+
+    Some(
+        NaiveDate::from_ymd_opt(number(0..4)? as i32, number(4..6)?, number(6..8)?)?
+            .and_hms_opt(number(8..10)?, number(10..12)?, number(12..14)?)?
+            .and_utc(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_time, Certificate, TAG_GENERALIZED_TIME, TAG_UTC_TIME};
+    use base64::Engine;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn parse_certificate_from_pem() {
+        let pem =
+            std::fs::read("test/fixtures/certificate.pem").expect("Could not open example file");
+
+        let certificate = Certificate::from_pem(&pem).expect("Could not parse certificate");
+
+        assert_eq!(
+            Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+            certificate.not_after
+        );
+        assert_eq!(Some("example.com".to_string()), certificate.common_name);
+        assert_eq!(
+            Some("example.com".to_string()),
+            certificate.issuer_common_name
+        );
+        assert_eq!(
+            vec!["example.com".to_string(), "www.example.com".to_string()],
+            certificate.dns_names
+        );
+    }
+
+    #[test]
+    fn parse_invalid_certificates() {
+        assert_eq!(None, Certificate::from_pem(b"not a certificate"));
+        assert_eq!(
+            None,
+            Certificate::from_pem(b"-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----")
+        );
+        assert_eq!(None, Certificate::from_der(&[0x30, 0x82, 0xff]));
+    }
+
+    #[test]
+    fn parse_utc_and_generalized_times() {
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2049, 12, 31, 23, 59, 59).unwrap()),
+            parse_time(TAG_UTC_TIME, b"491231235959Z")
+        );
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(1999, 1, 1, 0, 0, 0).unwrap()),
+            parse_time(TAG_UTC_TIME, b"990101000000Z")
+        );
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2050, 6, 1, 12, 0, 0).unwrap()),
+            parse_time(TAG_GENERALIZED_TIME, b"20500601120000Z")
+        );
+        assert_eq!(None, parse_time(TAG_UTC_TIME, b"20500601120000Z"));
+    }
+
+    #[test]
+    fn parse_non_ascii_times() {
+        assert_eq!(None, parse_time(TAG_UTC_TIME, "€123123595Z".as_bytes()));
+        assert_eq!(None, parse_time(TAG_UTC_TIME, "4€31235959Z".as_bytes()));
+        assert_eq!(
+            None,
+            parse_time(TAG_GENERALIZED_TIME, "2050€601120000Z".as_bytes())
+        );
+        assert_eq!(None, parse_time(TAG_UTC_TIME, &[0xff; 13]));
+    }
+
+    #[test]
+    fn parse_truncated_and_corrupted_certificates() {
+        let pem =
+            std::fs::read("test/fixtures/certificate.pem").expect("Could not open example file");
+        let pem = std::str::from_utf8(&pem).unwrap();
+        let base64: String = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect();
+        let der = base64::engine::general_purpose::STANDARD
+            .decode(base64)
+            .unwrap();
+        assert!(Certificate::from_der(&der).is_some());
+
+        for length in 0..der.len() {
+            Certificate::from_der(&der[..length]);
+        }
+
+        for index in 0..der.len() {
+            for byte in [0x00, 0x7f, 0x80, 0xe2, 0xff] {
+                let mut corrupted = der.clone();
+                corrupted[index] = byte;
+                Certificate::from_der(&corrupted);
+            }
+        }
+    }
+}